@@ -0,0 +1,29 @@
+#![deny(warnings)]
+#![no_std]
+
+use soroban_sdk::{contractclient, contractspecfn, Address, Bytes, Env};
+pub struct Spec;
+
+/// Interface for contracts receiving SoroswapPair flash swaps
+#[contractspecfn(name = "Spec", export = false)]
+#[contractclient(name = "SoroswapCalleeClient")]
+
+/// Trait that must be implemented by the `to` address of a SoroswapPair `flash_swap`.
+pub trait SoroswapCalleeTrait {
+
+    /// Called by the SoroswapPair after the requested amounts have been sent to this contract,
+    /// and before the pair checks the K constant. Before returning, this function must send back
+    /// to the pair enough tokens (of any of both tokens) to pay for the amounts received plus the fee.
+    ///
+    /// The pair contract is the direct invoker of this function, so implementations can call
+    /// `pair.require_auth()` to make sure that they are being called by the pair they expect.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `pair` - The address of the SoroswapPair executing the flash swap.
+    /// * `amount_0_out` - The amount of token 0 sent to this contract.
+    /// * `amount_1_out` - The amount of token 1 sent to this contract.
+    /// * `data` - The opaque payload passed to `flash_swap`.
+    fn soroswap_call(e: Env, pair: Address, amount_0_out: i128, amount_1_out: i128, data: Bytes);
+}
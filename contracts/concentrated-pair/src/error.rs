@@ -0,0 +1,48 @@
+use soroban_sdk::{self, contracterror};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SoroswapConcentratedPairError {
+    /// SoroswapConcentratedPair: already initialized
+    InitializeAlreadyInitialized = 801,
+    /// SoroswapConcentratedPair: not yet initialized
+    NotInitialized = 802,
+
+    /// SoroswapConcentratedPair: token_0 must be less than token_1 while initializing
+    InitializeTokenOrderInvalid = 803,
+    /// SoroswapConcentratedPair: fee is greater than the maximum allowed while initializing
+    InitializeInvalidFee = 804,
+    /// SoroswapConcentratedPair: tick spacing is out of range while initializing
+    InitializeInvalidTickSpacing = 805,
+    /// SoroswapConcentratedPair: square root price is out of range while initializing
+    InitializeInvalidPrice = 806,
+
+    /// SoroswapConcentratedPair: ticks are out of range, unordered or not multiples of the tick spacing
+    InvalidTickRange = 807,
+
+    /// SoroswapConcentratedPair: liquidity must be positive while doing mint
+    MintZeroLiquidity = 808,
+    /// SoroswapConcentratedPair: the amounts needed are greater than the maximum amounts while doing mint
+    MintAmountExceedsMax = 809,
+    /// SoroswapConcentratedPair: the liquidity of a tick would be greater than the maximum while doing mint
+    MintTickLiquidityOverflow = 810,
+
+    /// SoroswapConcentratedPair: the position does not exist
+    PositionDoesNotExist = 811,
+    /// SoroswapConcentratedPair: the position has less liquidity than the amount to burn
+    BurnInsufficientLiquidity = 812,
+    /// SoroswapConcentratedPair: negative amounts requested while doing collect
+    CollectNegativeAmount = 813,
+
+    /// SoroswapConcentratedPair: amount specified is zero while doing swap
+    SwapZeroAmount = 814,
+    /// SoroswapConcentratedPair: price limit is on the wrong side of the price or out of range while doing swap
+    SwapInvalidPriceLimit = 815,
+
+    /// SoroswapConcentratedPair: OVERFLOW while doing the math of a mint, burn or swap
+    UpdateOverflow = 816,
+
+    /// SoroswapConcentratedPair: this pair or all the pairs are paused by the factory, only burns and collects are allowed
+    Paused = 817,
+}
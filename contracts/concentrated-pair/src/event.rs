@@ -0,0 +1,133 @@
+//! Definition of the Events used in the contract
+use soroban_sdk::{contracttype, symbol_short, Env, Address, BytesN};
+
+// MINT EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintEvent {
+    pub owner: Address,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub liquidity: u128,
+    pub amount_0: i128,
+    pub amount_1: i128,
+}
+
+pub(crate) fn mint(
+    e: &Env,
+    owner: Address,
+    tick_lower: i32,
+    tick_upper: i32,
+    liquidity: u128,
+    amount_0: i128,
+    amount_1: i128) {
+
+    let event: MintEvent = MintEvent {
+        owner: owner,
+        tick_lower: tick_lower,
+        tick_upper: tick_upper,
+        liquidity: liquidity,
+        amount_0: amount_0,
+        amount_1: amount_1,
+    };
+    e.events().publish(("SoroswapConcentratedPair", symbol_short!("mint")), event);
+}
+
+// BURN EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BurnEvent {
+    pub owner: Address,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub liquidity: u128,
+    pub amount_0: i128,
+    pub amount_1: i128,
+}
+
+pub(crate) fn burn(
+    e: &Env,
+    owner: Address,
+    tick_lower: i32,
+    tick_upper: i32,
+    liquidity: u128,
+    amount_0: i128,
+    amount_1: i128) {
+
+    let event: BurnEvent = BurnEvent {
+        owner: owner,
+        tick_lower: tick_lower,
+        tick_upper: tick_upper,
+        liquidity: liquidity,
+        amount_0: amount_0,
+        amount_1: amount_1,
+    };
+    e.events().publish(("SoroswapConcentratedPair", symbol_short!("burn")), event);
+}
+
+// COLLECT EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollectEvent {
+    pub owner: Address,
+    pub to: Address,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub amount_0: i128,
+    pub amount_1: i128,
+}
+
+pub(crate) fn collect(
+    e: &Env,
+    owner: Address,
+    to: Address,
+    tick_lower: i32,
+    tick_upper: i32,
+    amount_0: i128,
+    amount_1: i128) {
+
+    let event: CollectEvent = CollectEvent {
+        owner: owner,
+        to: to,
+        tick_lower: tick_lower,
+        tick_upper: tick_upper,
+        amount_0: amount_0,
+        amount_1: amount_1,
+    };
+    e.events().publish(("SoroswapConcentratedPair", symbol_short!("collect")), event);
+}
+
+// SWAP EVENT. Amounts are positive when they go into the pair and negative when they go out
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapEvent {
+    pub from: Address,
+    pub to: Address,
+    pub amount_0: i128,
+    pub amount_1: i128,
+    pub sqrt_price: u128,
+    pub liquidity: u128,
+    pub tick: i32,
+}
+
+pub(crate) fn swap(
+    e: &Env,
+    from: Address,
+    to: Address,
+    amount_0: i128,
+    amount_1: i128,
+    sqrt_price: u128,
+    liquidity: u128,
+    tick: i32) {
+
+    let event: SwapEvent = SwapEvent {
+        from: from,
+        to: to,
+        amount_0: amount_0,
+        amount_1: amount_1,
+        sqrt_price: sqrt_price,
+        liquidity: liquidity,
+        tick: tick,
+    };
+    e.events().publish(("SoroswapConcentratedPair", symbol_short!("swap")), event);
+}
@@ -0,0 +1,603 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, contractmeta, Address, BytesN, Env};
+
+mod storage;
+mod event;
+mod error;
+mod test;
+// Same U256 as in soroswap-library
+#[allow(dead_code)]
+mod math;
+mod tick_math;
+mod sqrt_price_math;
+mod swap_math;
+mod tick;
+mod position;
+
+use storage::*;
+use soroswap_pair_core::*;
+use error::SoroswapConcentratedPairError;
+use math::{add_delta, mul_div, Q64};
+use tick_math::{sqrt_price_at_tick, tick_at_sqrt_price, MIN_TICK, MAX_TICK, MIN_SQRT_PRICE, MAX_SQRT_PRICE};
+use sqrt_price_math::{amount_0_delta, amount_1_delta};
+use swap_math::compute_swap_step;
+use position::{PositionInfo, PositionKey};
+use tick::TickInfo;
+
+// First topic of the events of the concentrated pairs
+const PAIR_NAME: &str = "SoroswapConcentratedPair";
+
+// Fees are expressed in basis points. The factory cannot set a fee greater than 10%
+static MAXIMUM_FEE: u32 = 1_000;
+// Like in Uniswap V3, so a swap never has to look at more than 128 · 16_383 ticks to move between two words of the bitmap
+static MAXIMUM_TICK_SPACING: u32 = 16_383;
+
+// Metadata that is added on to the WASM custom section
+contractmeta!(
+    key = "Description",
+    val = "Soroswap.Finance Protocol - Concentrated liquidity AMM, with positions over tick ranges"
+);
+
+pub trait SoroswapConcentratedPairTrait{
+    // Sets the token contract addresses, the swap fee, the tick spacing and the initial square root price of this pool
+    fn initialize(e: Env, factory: Address, token_0: Address, token_1: Address, fee: u32, tick_spacing: u32, sqrt_price: u128) -> Result<(), SoroswapConcentratedPairError>;
+
+    // Adds liquidity to the position of owner between tick_lower and tick_upper. The tokens are taken from the owner
+    fn mint(e: Env, owner: Address, tick_lower: i32, tick_upper: i32, liquidity: u128, amount_0_max: i128, amount_1_max: i128) -> Result<(i128, i128), SoroswapConcentratedPairError>;
+
+    // Removes liquidity from a position. The tokens are owed to the owner until they are collected
+    fn burn(e: Env, owner: Address, tick_lower: i32, tick_upper: i32, liquidity: u128) -> Result<(i128, i128), SoroswapConcentratedPairError>;
+
+    // Sends the tokens owed to a position, from burned liquidity and fees, to the specified to address
+    fn collect(e: Env, owner: Address, tick_lower: i32, tick_upper: i32, to: Address, amount_0_requested: i128, amount_1_requested: i128) -> Result<(i128, i128), SoroswapConcentratedPairError>;
+
+    // Swaps until amount_specified is filled or the price reaches sqrt_price_limit. The input is taken from `from`
+    fn swap(e: Env, from: Address, to: Address, zero_for_one: bool, amount_specified: i128, sqrt_price_limit: u128) -> Result<(i128, i128), SoroswapConcentratedPairError>;
+
+    fn token_0(e: Env) -> Address;
+    fn token_1(e: Env) -> Address;
+    fn factory(e: Env) -> Address;
+
+    // Swap fee in basis points
+    fn fee(e: Env) -> u32;
+
+    fn tick_spacing(e: Env) -> u32;
+
+    // Current square root price as a Q64.64 number, and current tick
+    fn get_price(e: Env) -> (u128, i32);
+
+    // Liquidity of the positions whose range contains the current price
+    fn liquidity(e: Env) -> u128;
+
+    // Fees earned per unit of liquidity since the creation of the pair, as Q64.64 numbers
+    fn fee_growth_global(e: Env) -> (u128, u128);
+
+    fn tick_info(e: Env, tick: i32) -> Option<TickInfo>;
+
+    fn position(e: Env, owner: Address, tick_lower: i32, tick_upper: i32) -> Option<PositionInfo>;
+
+    // Upgrades the code of the pair to the given wasm hash. Only the factory can upgrade a pair
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), SoroswapConcentratedPairError>;
+
+    // Returns true if this pair or all the pairs were paused by the factory.
+    // While paused, mints and swaps are rejected but burns and collects still work
+    fn paused(e: Env) -> bool;
+    fn set_paused(e: Env, is_paused: bool) -> Result<(), SoroswapConcentratedPairError>;
+
+}
+
+#[contract]
+struct SoroswapConcentratedPair;
+
+#[contractimpl]
+impl SoroswapConcentratedPairTrait for SoroswapConcentratedPair {
+
+    /// Initializes a new Soroswap concentrated pair by setting token addresses, factory, swap fee, tick spacing and price.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `factory` - The address of the Soroswap factory contract.
+    /// * `token_0` - The address of the first token in the pair.
+    /// * `token_1` - The address of the second token in the pair.
+    /// * `fee` - The swap fee, in basis points.
+    /// * `tick_spacing` - Only multiples of it can be the bounds of a position, between 1 and 16_383.
+    /// * `sqrt_price` - Square root of the price of token 0 in token 1, as a Q64.64 number.
+    fn initialize(e: Env, factory: Address, token_0: Address, token_1: Address, fee: u32, tick_spacing: u32, sqrt_price: u128) -> Result<(), SoroswapConcentratedPairError> {
+        if has_token_0(&e) {
+            return Err(SoroswapConcentratedPairError::InitializeAlreadyInitialized);
+        }
+
+        if token_0 >= token_1 {
+            return Err(SoroswapConcentratedPairError::InitializeTokenOrderInvalid);
+        }
+
+        if fee > MAXIMUM_FEE {
+            return Err(SoroswapConcentratedPairError::InitializeInvalidFee);
+        }
+
+        if tick_spacing == 0 || tick_spacing > MAXIMUM_TICK_SPACING {
+            return Err(SoroswapConcentratedPairError::InitializeInvalidTickSpacing);
+        }
+
+        let tick = tick_at_sqrt_price(sqrt_price).ok_or(SoroswapConcentratedPairError::InitializeInvalidPrice)?;
+
+        put_factory(&e, factory);
+        put_token_0(&e, token_0);
+        put_token_1(&e, token_1);
+        put_fee(&e, fee);
+        put_tick_spacing(&e, tick_spacing);
+        put_sqrt_price(&e, sqrt_price);
+        put_tick(&e, tick);
+        put_liquidity(&e, 0);
+        put_fee_growth_global_0(&e, 0);
+        put_fee_growth_global_1(&e, 0);
+        extend_instance_ttl(&e);
+
+        Ok(())
+    }
+
+    /// Adds liquidity to the position of `owner` between `tick_lower` and `tick_upper`.
+    ///
+    /// Below the range the position holds only token 0, above it only token 1, and inside it both.
+    /// The amounts needed are rounded up and taken from `owner`, who must authorize the call.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `owner` - The owner of the position.
+    /// * `tick_lower` - The lower tick of the range, a multiple of the tick spacing.
+    /// * `tick_upper` - The upper tick of the range, a multiple of the tick spacing.
+    /// * `liquidity` - The amount of liquidity to add.
+    /// * `amount_0_max` - The maximum amount of token 0 to pay.
+    /// * `amount_1_max` - The maximum amount of token 1 to pay.
+    ///
+    /// # Returns
+    /// The amounts of token 0 and token 1 paid.
+    fn mint(e: Env, owner: Address, tick_lower: i32, tick_upper: i32, liquidity: u128, amount_0_max: i128, amount_1_max: i128) -> Result<(i128, i128), SoroswapConcentratedPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapConcentratedPairError::NotInitialized);
+        }
+
+        if is_paused(&e) {
+            return Err(SoroswapConcentratedPairError::Paused);
+        }
+
+        owner.require_auth();
+
+        if liquidity == 0 {
+            return Err(SoroswapConcentratedPairError::MintZeroLiquidity);
+        }
+        let liquidity_delta = i128::try_from(liquidity).map_err(|_| SoroswapConcentratedPairError::MintTickLiquidityOverflow)?;
+
+        let key = PositionKey { owner: owner.clone(), tick_lower, tick_upper };
+        let (amount_0, amount_1) = modify_position(&e, &key, liquidity_delta)?;
+
+        if amount_0 > amount_0_max || amount_1 > amount_1_max {
+            return Err(SoroswapConcentratedPairError::MintAmountExceedsMax);
+        }
+
+        if amount_0 > 0 {
+            transfer_to_pair(&e, get_token_0(&e), &owner, amount_0);
+        }
+        if amount_1 > 0 {
+            transfer_to_pair(&e, get_token_1(&e), &owner, amount_1);
+        }
+
+        event::mint(&e, owner, tick_lower, tick_upper, liquidity, amount_0, amount_1);
+
+        Ok((amount_0, amount_1))
+    }
+
+    /// Removes liquidity from the position of `owner` between `tick_lower` and `tick_upper`.
+    ///
+    /// The amounts, rounded down, are added to the tokens owed to the position with the fees it earned,
+    /// and can be sent anywhere with `collect`. Burning zero liquidity only updates the fees owed.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `owner` - The owner of the position, who must authorize the call.
+    /// * `tick_lower` - The lower tick of the range.
+    /// * `tick_upper` - The upper tick of the range.
+    /// * `liquidity` - The amount of liquidity to remove.
+    ///
+    /// # Returns
+    /// The amounts of token 0 and token 1 released by the liquidity, without the fees.
+    fn burn(e: Env, owner: Address, tick_lower: i32, tick_upper: i32, liquidity: u128) -> Result<(i128, i128), SoroswapConcentratedPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapConcentratedPairError::NotInitialized);
+        }
+
+        owner.require_auth();
+
+        let key = PositionKey { owner: owner.clone(), tick_lower, tick_upper };
+        let position = get_position(&e, &key).ok_or(SoroswapConcentratedPairError::PositionDoesNotExist)?;
+        if position.liquidity == 0 || liquidity > position.liquidity {
+            return Err(SoroswapConcentratedPairError::BurnInsufficientLiquidity);
+        }
+
+        // The liquidity of a position is at most the maximum liquidity per tick, so it fits in an i128
+        let (amount_0, amount_1) = modify_position(&e, &key, -(liquidity as i128))?;
+
+        let mut position = get_position(&e, &key).unwrap();
+        position.tokens_owed_0 = position.tokens_owed_0.checked_add(amount_0).ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+        position.tokens_owed_1 = position.tokens_owed_1.checked_add(amount_1).ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+        put_position(&e, &key, &position);
+
+        event::burn(&e, owner, tick_lower, tick_upper, liquidity, amount_0, amount_1);
+
+        Ok((amount_0, amount_1))
+    }
+
+    /// Sends up to the requested amounts of the tokens owed to the position of `owner` to `to`.
+    /// Pass `i128::MAX` to collect everything. The position is removed once it is empty.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `owner` - The owner of the position, who must authorize the call.
+    /// * `tick_lower` - The lower tick of the range.
+    /// * `tick_upper` - The upper tick of the range.
+    /// * `to` - The address where the tokens will be sent.
+    /// * `amount_0_requested` - The maximum amount of token 0 to collect.
+    /// * `amount_1_requested` - The maximum amount of token 1 to collect.
+    ///
+    /// # Returns
+    /// The amounts of token 0 and token 1 collected.
+    fn collect(e: Env, owner: Address, tick_lower: i32, tick_upper: i32, to: Address, amount_0_requested: i128, amount_1_requested: i128) -> Result<(i128, i128), SoroswapConcentratedPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapConcentratedPairError::NotInitialized);
+        }
+
+        owner.require_auth();
+
+        if amount_0_requested < 0 || amount_1_requested < 0 {
+            return Err(SoroswapConcentratedPairError::CollectNegativeAmount);
+        }
+
+        let key = PositionKey { owner: owner.clone(), tick_lower, tick_upper };
+        let mut position = get_position(&e, &key).ok_or(SoroswapConcentratedPairError::PositionDoesNotExist)?;
+
+        let amount_0 = amount_0_requested.min(position.tokens_owed_0);
+        let amount_1 = amount_1_requested.min(position.tokens_owed_1);
+        position.tokens_owed_0 -= amount_0;
+        position.tokens_owed_1 -= amount_1;
+
+        if position.is_empty() {
+            remove_position(&e, &key);
+        } else {
+            put_position(&e, &key, &position);
+        }
+
+        if amount_0 > 0 {
+            transfer_from_pair(&e, get_token_0(&e), &to, amount_0);
+        }
+        if amount_1 > 0 {
+            transfer_from_pair(&e, get_token_1(&e), &to, amount_1);
+        }
+
+        event::collect(&e, owner, to, tick_lower, tick_upper, amount_0, amount_1);
+
+        Ok((amount_0, amount_1))
+    }
+
+    /// Swaps one token for the other, crossing as many tick ranges as needed.
+    ///
+    /// A positive `amount_specified` is an exact input, fee included; a negative one is an exact output.
+    /// The swap stops early if the price reaches `sqrt_price_limit`. The input is taken from `from`, who
+    /// must authorize the call, and the output is sent to `to`.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `from` - The address that pays the input.
+    /// * `to` - The address that receives the output.
+    /// * `zero_for_one` - True to swap token 0 for token 1, which moves the price down.
+    /// * `amount_specified` - The exact input (positive) or output (negative).
+    /// * `sqrt_price_limit` - The price the swap cannot go beyond, as a Q64.64 square root.
+    ///
+    /// # Returns
+    /// The amounts of token 0 and token 1, positive when paid to the pair and negative when paid by it.
+    fn swap(e: Env, from: Address, to: Address, zero_for_one: bool, amount_specified: i128, sqrt_price_limit: u128) -> Result<(i128, i128), SoroswapConcentratedPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapConcentratedPairError::NotInitialized);
+        }
+
+        if is_paused(&e) {
+            return Err(SoroswapConcentratedPairError::Paused);
+        }
+
+        from.require_auth();
+
+        if amount_specified == 0 {
+            return Err(SoroswapConcentratedPairError::SwapZeroAmount);
+        }
+
+        let sqrt_price_start = get_sqrt_price(&e);
+        let valid_limit = if zero_for_one {
+            sqrt_price_limit < sqrt_price_start && sqrt_price_limit > MIN_SQRT_PRICE
+        } else {
+            sqrt_price_limit > sqrt_price_start && sqrt_price_limit < MAX_SQRT_PRICE
+        };
+        if !valid_limit {
+            return Err(SoroswapConcentratedPairError::SwapInvalidPriceLimit);
+        }
+
+        let fee = get_fee(&e);
+        let tick_spacing = get_tick_spacing(&e);
+        let exact_in = amount_specified > 0;
+
+        let mut amount_remaining = amount_specified;
+        let mut amount_calculated: i128 = 0;
+        let mut sqrt_price = sqrt_price_start;
+        let mut tick = get_tick(&e);
+        let mut liquidity = get_liquidity(&e);
+        let mut fee_growth_global = (get_fee_growth_global_0(&e), get_fee_growth_global_1(&e));
+
+        // Each step goes to the next initialized tick, the end of a word of the bitmap or the price limit,
+        // whichever is closer. Crossing an initialized tick changes the liquidity in range
+        while amount_remaining != 0 && sqrt_price != sqrt_price_limit {
+            let sqrt_price_step_start = sqrt_price;
+
+            let (tick_next, initialized) = tick::next_initialized_tick_within_one_word(&e, tick, tick_spacing, zero_for_one);
+            let tick_next = tick_next.clamp(MIN_TICK, MAX_TICK);
+            let sqrt_price_tick_next = sqrt_price_at_tick(tick_next).ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+
+            let sqrt_price_target = if zero_for_one {
+                sqrt_price_tick_next.max(sqrt_price_limit)
+            } else {
+                sqrt_price_tick_next.min(sqrt_price_limit)
+            };
+
+            let step = compute_swap_step(sqrt_price, sqrt_price_target, liquidity, amount_remaining, fee)
+                .ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+            sqrt_price = step.sqrt_price_next;
+
+            let amount_in = step.amount_in.checked_add(step.fee_amount)
+                .and_then(|amount| i128::try_from(amount).ok())
+                .ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+            let amount_out = i128::try_from(step.amount_out).map_err(|_| SoroswapConcentratedPairError::UpdateOverflow)?;
+
+            if exact_in {
+                amount_remaining = amount_remaining.checked_sub(amount_in).ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+                amount_calculated = amount_calculated.checked_sub(amount_out).ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+            } else {
+                amount_remaining = amount_remaining.checked_add(amount_out).ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+                amount_calculated = amount_calculated.checked_add(amount_in).ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+            }
+
+            // The fee goes to the liquidity in range, in the input token
+            if liquidity > 0 {
+                let fee_growth = mul_div(step.fee_amount, Q64, liquidity).ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+                if zero_for_one {
+                    fee_growth_global.0 = fee_growth_global.0.wrapping_add(fee_growth);
+                } else {
+                    fee_growth_global.1 = fee_growth_global.1.wrapping_add(fee_growth);
+                }
+            }
+
+            if sqrt_price == sqrt_price_tick_next {
+                if initialized {
+                    let liquidity_net = tick::cross(&e, tick_next, fee_growth_global);
+                    // Going down, the price leaves the ranges that start at this tick and enters those that end at it
+                    let liquidity_delta = if zero_for_one { -liquidity_net } else { liquidity_net };
+                    liquidity = add_delta(liquidity, liquidity_delta).ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+                }
+                tick = if zero_for_one { tick_next - 1 } else { tick_next };
+            } else if sqrt_price != sqrt_price_step_start {
+                tick = tick_at_sqrt_price(sqrt_price).ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+            }
+        }
+
+        put_sqrt_price(&e, sqrt_price);
+        put_tick(&e, tick);
+        put_liquidity(&e, liquidity);
+        put_fee_growth_global_0(&e, fee_growth_global.0);
+        put_fee_growth_global_1(&e, fee_growth_global.1);
+
+        let amount_specified_filled = amount_specified - amount_remaining;
+        let (amount_0, amount_1) = if zero_for_one == exact_in {
+            (amount_specified_filled, amount_calculated)
+        } else {
+            (amount_calculated, amount_specified_filled)
+        };
+
+        if amount_0 < 0 {
+            transfer_from_pair(&e, get_token_0(&e), &to, -amount_0);
+        } else if amount_0 > 0 {
+            transfer_to_pair(&e, get_token_0(&e), &from, amount_0);
+        }
+        if amount_1 < 0 {
+            transfer_from_pair(&e, get_token_1(&e), &to, -amount_1);
+        } else if amount_1 > 0 {
+            transfer_to_pair(&e, get_token_1(&e), &from, amount_1);
+        }
+
+        event::swap(&e, from, to, amount_0, amount_1, sqrt_price, liquidity, tick);
+
+        Ok((amount_0, amount_1))
+    }
+
+    /// Returns the address of the first token in the concentrated pair.
+    fn token_0(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        get_token_0(&e)
+    }
+
+    /// Returns the address of the second token in the concentrated pair.
+    fn token_1(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        get_token_1(&e)
+    }
+
+    /// Returns the address of the Soroswap factory contract.
+    fn factory(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        get_factory(&e)
+    }
+
+    /// Returns the swap fee of the concentrated pair, in basis points.
+    fn fee(e: Env) -> u32 {
+        extend_instance_ttl(&e);
+        get_fee(&e)
+    }
+
+    /// Returns the tick spacing of the concentrated pair.
+    fn tick_spacing(e: Env) -> u32 {
+        extend_instance_ttl(&e);
+        get_tick_spacing(&e)
+    }
+
+    /// Returns the current square root price, as a Q64.64 number, and the current tick.
+    fn get_price(e: Env) -> (u128, i32) {
+        extend_instance_ttl(&e);
+        (get_sqrt_price(&e), get_tick(&e))
+    }
+
+    /// Returns the liquidity of the positions whose range contains the current price.
+    fn liquidity(e: Env) -> u128 {
+        extend_instance_ttl(&e);
+        get_liquidity(&e)
+    }
+
+    /// Returns the fees of token 0 and token 1 earned per unit of liquidity, as Q64.64 numbers that can wrap around.
+    fn fee_growth_global(e: Env) -> (u128, u128) {
+        extend_instance_ttl(&e);
+        (get_fee_growth_global_0(&e), get_fee_growth_global_1(&e))
+    }
+
+    /// Returns the state of a tick, if it is the bound of a position.
+    fn tick_info(e: Env, tick: i32) -> Option<TickInfo> {
+        extend_instance_ttl(&e);
+        get_tick_info(&e, tick)
+    }
+
+    /// Returns the state of the position of `owner` between `tick_lower` and `tick_upper`, if it exists.
+    /// The fees owed are only updated by `burn`, which can burn zero liquidity for that.
+    fn position(e: Env, owner: Address, tick_lower: i32, tick_upper: i32) -> Option<PositionInfo> {
+        extend_instance_ttl(&e);
+        get_position(&e, &PositionKey { owner, tick_lower, tick_upper })
+    }
+
+    /// Upgrades the code of the concentrated pair, keeping its stored data. Can only be called by the factory.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `new_wasm_hash` - The hash of the new wasm code, already uploaded to the network.
+    ///
+    /// # Errors
+    /// - `NotInitialized`: The concentrated pair has not been initialized.
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), SoroswapConcentratedPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapConcentratedPairError::NotInitialized);
+        }
+
+        upgrade(&e, PAIR_NAME, new_wasm_hash);
+        Ok(())
+    }
+
+    /// Returns true if mints and swaps are currently blocked, either because this pair was paused
+    /// or because the factory paused all the pairs.
+    fn paused(e: Env) -> bool {
+        extend_instance_ttl(&e);
+        has_token_0(&e) && is_paused(&e)
+    }
+
+    /// Pauses or unpauses this pair. Can only be called by the factory, on behalf of its guardian.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `is_paused` - Whether mints and swaps should be blocked.
+    ///
+    /// # Errors
+    /// - `NotInitialized`: The concentrated pair has not been initialized.
+    fn set_paused(e: Env, is_paused: bool) -> Result<(), SoroswapConcentratedPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapConcentratedPairError::NotInitialized);
+        }
+
+        set_paused(&e, PAIR_NAME, is_paused);
+        Ok(())
+    }
+}
+
+fn check_ticks(tick_lower: i32, tick_upper: i32, tick_spacing: u32) -> Result<(), SoroswapConcentratedPairError> {
+    let tick_spacing = tick_spacing as i32;
+    if tick_lower >= tick_upper
+        || tick_lower < MIN_TICK
+        || tick_upper > MAX_TICK
+        || tick_lower % tick_spacing != 0
+        || tick_upper % tick_spacing != 0
+    {
+        return Err(SoroswapConcentratedPairError::InvalidTickRange);
+    }
+    Ok(())
+}
+
+/// Adds `liquidity_delta` to a position: updates its bounds, the bitmap, its fees and the liquidity in range.
+/// Returns the amounts of token 0 and token 1 the liquidity is worth at the current price, rounded up
+/// when liquidity is added and down when it is removed.
+fn modify_position(e: &Env, key: &PositionKey, liquidity_delta: i128) -> Result<(i128, i128), SoroswapConcentratedPairError> {
+    let tick_spacing = get_tick_spacing(e);
+    check_ticks(key.tick_lower, key.tick_upper, tick_spacing)?;
+
+    let tick_current = get_tick(e);
+    let fee_growth_global = (get_fee_growth_global_0(e), get_fee_growth_global_1(e));
+
+    let max_liquidity = tick::max_liquidity_per_tick(tick_spacing);
+    let flipped_lower = tick::update(e, key.tick_lower, tick_current, liquidity_delta, fee_growth_global, false, max_liquidity)?;
+    let flipped_upper = tick::update(e, key.tick_upper, tick_current, liquidity_delta, fee_growth_global, true, max_liquidity)?;
+    if flipped_lower {
+        tick::flip_tick(e, key.tick_lower, tick_spacing);
+    }
+    if flipped_upper {
+        tick::flip_tick(e, key.tick_upper, tick_spacing);
+    }
+
+    let (fee_growth_inside_0, fee_growth_inside_1) = tick::fee_growth_inside(e, key.tick_lower, key.tick_upper, tick_current, fee_growth_global);
+    let mut position = get_position(e, key).unwrap_or_default();
+    position.update(liquidity_delta, fee_growth_inside_0, fee_growth_inside_1)?;
+    put_position(e, key, &position);
+
+    // A bound that no longer has liquidity is removed once the position read its fee growth
+    if liquidity_delta < 0 {
+        if flipped_lower {
+            tick::clear(e, key.tick_lower);
+        }
+        if flipped_upper {
+            tick::clear(e, key.tick_upper);
+        }
+    }
+
+    // The ticks were checked, so their prices exist
+    let sqrt_price_lower = sqrt_price_at_tick(key.tick_lower).unwrap();
+    let sqrt_price_upper = sqrt_price_at_tick(key.tick_upper).unwrap();
+    let sqrt_price = get_sqrt_price(e);
+    let round_up = liquidity_delta > 0;
+    let liquidity = liquidity_delta.unsigned_abs();
+
+    let (amount_0, amount_1) = if tick_current < key.tick_lower {
+        (amount_0_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up), Some(0))
+    } else if tick_current < key.tick_upper {
+        let liquidity_in_range = add_delta(get_liquidity(e), liquidity_delta).ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+        put_liquidity(e, liquidity_in_range);
+        (
+            amount_0_delta(sqrt_price, sqrt_price_upper, liquidity, round_up),
+            amount_1_delta(sqrt_price_lower, sqrt_price, liquidity, round_up),
+        )
+    } else {
+        (Some(0), amount_1_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up))
+    };
+
+    let amount_0 = amount_0.and_then(|amount| i128::try_from(amount).ok()).ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+    let amount_1 = amount_1.and_then(|amount| i128::try_from(amount).ok()).ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+    Ok((amount_0, amount_1))
+}
@@ -0,0 +1,62 @@
+//! Positions: liquidity owned by an address between two ticks, and the fees it has earned.
+
+use soroban_sdk::{contracttype, Address};
+use crate::error::SoroswapConcentratedPairError;
+use crate::math::{add_delta, mul_div, Q64};
+
+/// Identifies a position. An owner has at most one position for each range.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PositionKey {
+    pub owner: Address,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+}
+
+/// State of a position.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PositionInfo {
+    /// Liquidity of the position.
+    pub liquidity: u128,
+    /// Fee growth of token 0 inside the range the last time the position was updated, as a Q64.64 number.
+    pub fee_growth_inside_0_last: u128,
+    /// Fee growth of token 1 inside the range the last time the position was updated, as a Q64.64 number.
+    pub fee_growth_inside_1_last: u128,
+    /// Amount of token 0 the owner can collect, from fees and burned liquidity.
+    pub tokens_owed_0: i128,
+    /// Amount of token 1 the owner can collect, from fees and burned liquidity.
+    pub tokens_owed_1: i128,
+}
+
+impl PositionInfo {
+    /// Credits the fees earned since the last update and adds `liquidity_delta` to the position.
+    pub fn update(
+        &mut self,
+        liquidity_delta: i128,
+        fee_growth_inside_0: u128,
+        fee_growth_inside_1: u128,
+    ) -> Result<(), SoroswapConcentratedPairError> {
+        let liquidity_next = add_delta(self.liquidity, liquidity_delta).ok_or(SoroswapConcentratedPairError::BurnInsufficientLiquidity)?;
+
+        // Fees are earned by the liquidity the position had since the last update, rounded down
+        let fees_0 = mul_div(fee_growth_inside_0.wrapping_sub(self.fee_growth_inside_0_last), self.liquidity, Q64)
+            .and_then(|fees| i128::try_from(fees).ok())
+            .ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+        let fees_1 = mul_div(fee_growth_inside_1.wrapping_sub(self.fee_growth_inside_1_last), self.liquidity, Q64)
+            .and_then(|fees| i128::try_from(fees).ok())
+            .ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+
+        self.liquidity = liquidity_next;
+        self.fee_growth_inside_0_last = fee_growth_inside_0;
+        self.fee_growth_inside_1_last = fee_growth_inside_1;
+        self.tokens_owed_0 = self.tokens_owed_0.checked_add(fees_0).ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+        self.tokens_owed_1 = self.tokens_owed_1.checked_add(fees_1).ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+        Ok(())
+    }
+
+    /// True if the position has no liquidity and nothing left to collect, so it can be removed.
+    pub fn is_empty(&self) -> bool {
+        self.liquidity == 0 && self.tokens_owed_0 == 0 && self.tokens_owed_1 == 0
+    }
+}
@@ -0,0 +1,116 @@
+//! Amounts of tokens between two square root prices, and the square root price after adding or removing
+//! an amount of a token.
+//!
+//! Amounts paid to the pair are rounded up and amounts paid by the pair are rounded down, so rounding
+//! always favors the liquidity providers.
+
+use crate::math::{U256, Q64};
+
+/// Amount of token 0 held by `liquidity` between the square root prices `sqrt_price_a` and `sqrt_price_b`,
+/// that is `liquidity · (sqrt_price_b - sqrt_price_a) / (sqrt_price_a · sqrt_price_b)`.
+///
+/// Returns None if a square root price is zero or the amount does not fit in a u128.
+pub fn amount_0_delta(sqrt_price_a: u128, sqrt_price_b: u128, liquidity: u128, round_up: bool) -> Option<u128> {
+    let (sqrt_price_lower, sqrt_price_upper) = if sqrt_price_a <= sqrt_price_b {
+        (sqrt_price_a, sqrt_price_b)
+    } else {
+        (sqrt_price_b, sqrt_price_a)
+    };
+    if sqrt_price_lower == 0 {
+        return None;
+    }
+
+    // Dividing by the lower price before multiplying by the difference keeps the product within 256 bits.
+    // Both divisions round in the same direction, so the result is still rounded the right way
+    let numerator = U256::mul(liquidity, Q64);
+    let difference = sqrt_price_upper - sqrt_price_lower;
+    let amount = if round_up {
+        numerator.checked_ceiling_div(U256::from_u128(sqrt_price_lower))?
+            .checked_mul_u128(difference)?
+            .checked_ceiling_div(U256::from_u128(sqrt_price_upper))?
+    } else {
+        numerator.checked_div(U256::from_u128(sqrt_price_lower))?
+            .checked_mul_u128(difference)?
+            .checked_div(U256::from_u128(sqrt_price_upper))?
+    };
+    amount.to_u128()
+}
+
+/// Amount of token 1 held by `liquidity` between the square root prices `sqrt_price_a` and `sqrt_price_b`,
+/// that is `liquidity · (sqrt_price_b - sqrt_price_a)`.
+///
+/// Returns None if the amount does not fit in a u128.
+pub fn amount_1_delta(sqrt_price_a: u128, sqrt_price_b: u128, liquidity: u128, round_up: bool) -> Option<u128> {
+    let difference = sqrt_price_a.abs_diff(sqrt_price_b);
+    let product = U256::mul(liquidity, difference);
+    let amount = if round_up {
+        product.checked_ceiling_div(U256::from_u128(Q64))?
+    } else {
+        product.checked_div(U256::from_u128(Q64))?
+    };
+    amount.to_u128()
+}
+
+/// Square root price after `amount_in` of the input token is added to the pair, rounded so that the
+/// pair never gives more than the amount is worth.
+///
+/// Returns None if `sqrt_price` or `liquidity` is zero, or the price goes out of the u128 range.
+pub fn next_sqrt_price_from_input(sqrt_price: u128, liquidity: u128, amount_in: u128, zero_for_one: bool) -> Option<u128> {
+    if sqrt_price == 0 || liquidity == 0 {
+        return None;
+    }
+    if zero_for_one {
+        next_sqrt_price_from_amount_0(sqrt_price, liquidity, amount_in, true)
+    } else {
+        next_sqrt_price_from_amount_1(sqrt_price, liquidity, amount_in, true)
+    }
+}
+
+/// Square root price after `amount_out` of the output token is taken from the pair, rounded so that the
+/// pair never gives more than it receives.
+///
+/// Returns None if `sqrt_price` or `liquidity` is zero, or if the pair cannot give `amount_out`.
+pub fn next_sqrt_price_from_output(sqrt_price: u128, liquidity: u128, amount_out: u128, zero_for_one: bool) -> Option<u128> {
+    if sqrt_price == 0 || liquidity == 0 {
+        return None;
+    }
+    if zero_for_one {
+        next_sqrt_price_from_amount_1(sqrt_price, liquidity, amount_out, false)
+    } else {
+        next_sqrt_price_from_amount_0(sqrt_price, liquidity, amount_out, false)
+    }
+}
+
+// liquidity · sqrt_price / (liquidity ± amount · sqrt_price), computed as liquidity / (liquidity / sqrt_price ± amount)
+// so that it fits in 256 bits. The inner division rounds down and the outer one up, so the result is rounded up
+fn next_sqrt_price_from_amount_0(sqrt_price: u128, liquidity: u128, amount: u128, add: bool) -> Option<u128> {
+    if amount == 0 {
+        return Some(sqrt_price);
+    }
+    let numerator = U256::mul(liquidity, Q64);
+    let quotient = numerator.checked_div(U256::from_u128(sqrt_price))?;
+    let denominator = if add {
+        quotient.checked_add(U256::from_u128(amount))?
+    } else {
+        quotient.checked_sub(U256::from_u128(amount))?
+    };
+    if denominator == U256::ZERO {
+        return None;
+    }
+    numerator.checked_ceiling_div(denominator)?.to_u128()
+}
+
+// sqrt_price ± amount / liquidity, rounded down
+fn next_sqrt_price_from_amount_1(sqrt_price: u128, liquidity: u128, amount: u128, add: bool) -> Option<u128> {
+    if add {
+        let quotient = U256::mul(amount, Q64).checked_div(U256::from_u128(liquidity))?.to_u128()?;
+        sqrt_price.checked_add(quotient)
+    } else {
+        let quotient = U256::mul(amount, Q64).checked_ceiling_div(U256::from_u128(liquidity))?.to_u128()?;
+        // The price cannot reach zero
+        if quotient >= sqrt_price {
+            return None;
+        }
+        Some(sqrt_price - quotient)
+    }
+}
@@ -0,0 +1,129 @@
+use soroban_sdk::{contracttype, Env, IntoVal, TryFromVal, Val};
+use crate::position::{PositionInfo, PositionKey};
+use crate::tick::TickInfo;
+
+// Keys of the data only the concentrated pairs have. The tokens, factory and fee are stored by soroswap-pair-core
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    TickSpacing, // u32. Only multiples of it can be the bounds of a position. Instance storage
+    SqrtPrice, // u128. Current square root price as a Q64.64 number. Instance storage
+    Tick, // i32. Current tick, the greatest tick whose price is at or below the current price. Instance storage
+    Liquidity, // u128. Liquidity of the positions whose range contains the current tick. Instance storage
+    FeeGrowthGlobal0, // u128. Fees of token 0 earned per unit of liquidity, as a Q64.64 number. Instance storage
+    FeeGrowthGlobal1, // u128. Fees of token 1 earned per unit of liquidity, as a Q64.64 number. Instance storage
+    Ticks(i32), // TickInfo of each tick that bounds a position. Persistent storage
+    TickBitmap(i32), // u128. Bitmap of the initialized ticks, 128 ticks per word. Persistent storage
+    Positions(PositionKey), // PositionInfo of each position. Persistent storage
+}
+
+const DAY_IN_LEDGERS: u32 = 17280;
+
+const PERSISTENT_BUMP_AMOUNT: u32 = 60 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// Fetch an entry in persistent storage, extending its TTL if it exists
+fn get_persistent_extend<V: TryFromVal<Env, Val>>(e: &Env, key: &DataKey) -> Option<V> {
+    let result = e.storage().persistent().get(key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+    result
+}
+
+fn put_persistent_extend<V: IntoVal<Env, Val>>(e: &Env, key: &DataKey, value: &V) {
+    e.storage().persistent().set(key, value);
+    e.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn get_tick_spacing(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::TickSpacing).unwrap()
+}
+
+pub fn get_sqrt_price(e: &Env) -> u128 {
+    e.storage().instance().get(&DataKey::SqrtPrice).unwrap()
+}
+
+pub fn get_tick(e: &Env) -> i32 {
+    e.storage().instance().get(&DataKey::Tick).unwrap()
+}
+
+pub fn get_liquidity(e: &Env) -> u128 {
+    e.storage().instance().get(&DataKey::Liquidity).unwrap_or(0)
+}
+
+pub fn get_fee_growth_global_0(e: &Env) -> u128 {
+    e.storage().instance().get(&DataKey::FeeGrowthGlobal0).unwrap_or(0)
+}
+
+pub fn get_fee_growth_global_1(e: &Env) -> u128 {
+    e.storage().instance().get(&DataKey::FeeGrowthGlobal1).unwrap_or(0)
+}
+
+pub fn put_tick_spacing(e: &Env, tick_spacing: u32) {
+    e.storage().instance().set(&DataKey::TickSpacing, &tick_spacing);
+}
+
+pub fn put_sqrt_price(e: &Env, sqrt_price: u128) {
+    e.storage().instance().set(&DataKey::SqrtPrice, &sqrt_price);
+}
+
+pub fn put_tick(e: &Env, tick: i32) {
+    e.storage().instance().set(&DataKey::Tick, &tick);
+}
+
+pub fn put_liquidity(e: &Env, liquidity: u128) {
+    e.storage().instance().set(&DataKey::Liquidity, &liquidity);
+}
+
+pub fn put_fee_growth_global_0(e: &Env, fee_growth: u128) {
+    e.storage().instance().set(&DataKey::FeeGrowthGlobal0, &fee_growth);
+}
+
+pub fn put_fee_growth_global_1(e: &Env, fee_growth: u128) {
+    e.storage().instance().set(&DataKey::FeeGrowthGlobal1, &fee_growth);
+}
+
+// Ticks(i32)
+pub fn get_tick_info(e: &Env, tick: i32) -> Option<TickInfo> {
+    get_persistent_extend(e, &DataKey::Ticks(tick))
+}
+
+pub fn put_tick_info(e: &Env, tick: i32, info: &TickInfo) {
+    put_persistent_extend(e, &DataKey::Ticks(tick), info);
+}
+
+pub fn remove_tick_info(e: &Env, tick: i32) {
+    e.storage().persistent().remove(&DataKey::Ticks(tick));
+}
+
+// TickBitmap(i32)
+pub fn get_tick_bitmap_word(e: &Env, word_position: i32) -> u128 {
+    get_persistent_extend(e, &DataKey::TickBitmap(word_position)).unwrap_or(0)
+}
+
+pub fn put_tick_bitmap_word(e: &Env, word_position: i32, word: u128) {
+    let key = DataKey::TickBitmap(word_position);
+    if word == 0 {
+        e.storage().persistent().remove(&key);
+    } else {
+        put_persistent_extend(e, &key, &word);
+    }
+}
+
+// Positions(PositionKey)
+pub fn get_position(e: &Env, key: &PositionKey) -> Option<PositionInfo> {
+    get_persistent_extend(e, &DataKey::Positions(key.clone()))
+}
+
+pub fn put_position(e: &Env, key: &PositionKey, position: &PositionInfo) {
+    put_persistent_extend(e, &DataKey::Positions(key.clone()), position);
+}
+
+pub fn remove_position(e: &Env, key: &PositionKey) {
+    e.storage().persistent().remove(&DataKey::Positions(key.clone()));
+}
@@ -0,0 +1,94 @@
+//! One step of a swap, within a range where the liquidity does not change.
+
+use crate::math::{mul_div, mul_div_ceil};
+use crate::sqrt_price_math::{amount_0_delta, amount_1_delta, next_sqrt_price_from_input, next_sqrt_price_from_output};
+
+// Fees are expressed in basis points
+pub const FEE_DENOMINATOR: u128 = 10_000;
+
+/// Result of a swap step.
+pub struct SwapStep {
+    /// The square root price after the step, between the current one and `sqrt_price_target`.
+    pub sqrt_price_next: u128,
+    /// Amount of the input token that goes into the liquidity, without the fee.
+    pub amount_in: u128,
+    /// Amount of the output token.
+    pub amount_out: u128,
+    /// Swap fee paid in the input token.
+    pub fee_amount: u128,
+}
+
+/// Swaps as much of `amount_remaining` as possible between `sqrt_price_current` and `sqrt_price_target`.
+/// The price moves down when `sqrt_price_target` is below `sqrt_price_current` (token 0 in, token 1 out) and up otherwise.
+///
+/// A positive `amount_remaining` is an exact input that includes the fee, a negative one is an exact output.
+///
+/// Returns None if a step of the math overflows.
+pub fn compute_swap_step(
+    sqrt_price_current: u128,
+    sqrt_price_target: u128,
+    liquidity: u128,
+    amount_remaining: i128,
+    fee: u32,
+) -> Option<SwapStep> {
+    let zero_for_one = sqrt_price_current >= sqrt_price_target;
+    let exact_in = amount_remaining >= 0;
+    let fee = fee as u128;
+
+    // Amount needed to reach the target, in or out depending on the kind of swap
+    let amount_to_target = match (exact_in, zero_for_one) {
+        (true, true) => amount_0_delta(sqrt_price_target, sqrt_price_current, liquidity, true)?,
+        (true, false) => amount_1_delta(sqrt_price_current, sqrt_price_target, liquidity, true)?,
+        (false, true) => amount_1_delta(sqrt_price_target, sqrt_price_current, liquidity, false)?,
+        (false, false) => amount_0_delta(sqrt_price_current, sqrt_price_target, liquidity, false)?,
+    };
+
+    let amount_remaining_abs = amount_remaining.unsigned_abs();
+    let sqrt_price_next = if exact_in {
+        let amount_remaining_less_fee = mul_div(amount_remaining_abs, FEE_DENOMINATOR - fee, FEE_DENOMINATOR)?;
+        if amount_remaining_less_fee >= amount_to_target {
+            sqrt_price_target
+        } else {
+            next_sqrt_price_from_input(sqrt_price_current, liquidity, amount_remaining_less_fee, zero_for_one)?
+        }
+    } else if amount_remaining_abs >= amount_to_target {
+        sqrt_price_target
+    } else {
+        next_sqrt_price_from_output(sqrt_price_current, liquidity, amount_remaining_abs, zero_for_one)?
+    };
+
+    let reached_target = sqrt_price_next == sqrt_price_target;
+
+    let (mut amount_in, mut amount_out) = if zero_for_one {
+        (
+            if reached_target && exact_in { amount_to_target } else { amount_0_delta(sqrt_price_next, sqrt_price_current, liquidity, true)? },
+            if reached_target && !exact_in { amount_to_target } else { amount_1_delta(sqrt_price_next, sqrt_price_current, liquidity, false)? },
+        )
+    } else {
+        (
+            if reached_target && exact_in { amount_to_target } else { amount_1_delta(sqrt_price_current, sqrt_price_next, liquidity, true)? },
+            if reached_target && !exact_in { amount_to_target } else { amount_0_delta(sqrt_price_current, sqrt_price_next, liquidity, false)? },
+        )
+    };
+
+    // The rounding of the price can leave a bit more than the exact output requested
+    if !exact_in && amount_out > amount_remaining_abs {
+        amount_out = amount_remaining_abs;
+    }
+
+    let fee_amount = if exact_in && !reached_target {
+        // The price stopped before the target, so the whole remaining amount is spent and what is left is the fee.
+        // The rounding of the price can make amount_in one unit more than what is left after the fee
+        amount_in = amount_in.min(amount_remaining_abs);
+        amount_remaining_abs - amount_in
+    } else {
+        mul_div_ceil(amount_in, fee, FEE_DENOMINATOR - fee)?
+    };
+
+    Some(SwapStep {
+        sqrt_price_next,
+        amount_in,
+        amount_out,
+        fee_amount,
+    })
+}
@@ -0,0 +1,132 @@
+#![cfg(test)]
+extern crate std;
+use soroban_sdk::{
+    testutils::{Address as _},
+    Address,
+    BytesN,
+    Env,
+    String,
+};
+
+// TOKEN CONTRACT
+mod token {
+    soroban_sdk::contractimport!(file = "../token/target/wasm32-unknown-unknown/release/soroban_token_contract.wasm");
+    pub type TokenClient<'a> = Client<'a>;
+}
+use token::TokenClient;
+fn create_token_contract<'a>(e: &Env) -> TokenClient<'a> {
+    let token_address = &e.register_contract_wasm(None, token::WASM);
+    let token = TokenClient::new(e, token_address);
+    token
+}
+
+// FACTORY CONTRACT
+mod factory {
+    soroban_sdk::contractimport!(file = "../factory/target/wasm32-unknown-unknown/release/soroswap_factory.wasm");
+    pub type SoroswapFactoryClient<'a> = Client<'a>;
+}
+use factory::SoroswapFactoryClient;
+
+fn create_factory_contract<'a>(e: & Env, setter: & Address, pair_wasm_hash: & BytesN<32>) -> SoroswapFactoryClient<'a> {
+    let factory_address = &e.register_contract_wasm(None, factory::WASM);
+    let factory = SoroswapFactoryClient::new(e, factory_address);
+    factory.initialize(&setter, pair_wasm_hash);
+    factory
+}
+
+// PAIR CONTRACT
+// WASM
+fn pair_token_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../pair/target/wasm32-unknown-unknown/release/soroswap_pair.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+// CONCENTRATED PAIR CONTRACT
+pub mod concentrated_pair {
+    soroban_sdk::contractimport!(file = "./target/wasm32-unknown-unknown/release/soroswap_concentrated_pair.wasm");
+    pub type SoroswapConcentratedPairClient<'a> = Client<'a>;
+}
+use concentrated_pair::SoroswapConcentratedPairClient;
+
+fn create_concentrated_pair_contract<'a>(
+    e: & Env
+) -> SoroswapConcentratedPairClient<'a> {
+    let pair_address = &e.register_contract_wasm(None, concentrated_pair::WASM);
+    SoroswapConcentratedPairClient::new(e, pair_address)
+}
+
+// Fee (0.3%), tick spacing and initial square root price (1.0, tick 0) used by most of the tests
+pub const FEE: u32 = 30;
+pub const TICK_SPACING: u32 = 60;
+pub const SQRT_PRICE: u128 = 1 << 64;
+// Liquidity of the positions of most of the tests
+pub const LIQUIDITY: u128 = 10_000_000_000_000;
+
+// THE TEST
+pub struct SoroswapConcentratedPairTest<'a> {
+    env: Env,
+    admin: Address,
+    user: Address,
+    token_0: TokenClient<'a>,
+    token_1: TokenClient<'a>,
+    factory: SoroswapFactoryClient<'a>,
+    contract: SoroswapConcentratedPairClient<'a>,
+}
+
+impl<'a> SoroswapConcentratedPairTest<'a> {
+    fn setup() -> Self {
+
+        let env = Env::default();
+        env.mock_all_auths();
+        let user = Address::generate(&env);
+        let admin = Address::generate(&env);
+        let mut token_0 = create_token_contract(&env);
+        let mut token_1 = create_token_contract(&env);
+        if &token_1.address < &token_0.address {
+            std::mem::swap(&mut token_0, &mut token_1);
+        }
+
+        let name_0 = String::from_str(&env, "Token 0");
+        let symbol_0 = String::from_str(&env, "TOK0");
+        let name_1 = String::from_str(&env, "Token 1");
+        let symbol_1 = String::from_str(&env, "TOK1");
+        let decimals = 7;
+
+        token_0.initialize(&admin, &decimals, &name_0, &symbol_0);
+        token_1.initialize(&admin, &decimals, &name_1, &symbol_1);
+
+        token_0.mint(&user, &123_000_000_000_000_000_000);
+        token_1.mint(&user, &321_000_000_000_000_000_000);
+
+        let pair_token_wasm_binding = pair_token_wasm(&env);
+        let factory = create_factory_contract(&env, &admin, &pair_token_wasm_binding);
+
+        let contract = create_concentrated_pair_contract(&env);
+
+        env.budget().reset_unlimited();
+
+        SoroswapConcentratedPairTest {
+            env,
+            admin,
+            user,
+            token_0,
+            token_1,
+            factory,
+            contract,
+        }
+    }
+
+    fn initialize(&self) {
+        self.contract.initialize(&self.factory.address, &self.token_0.address, &self.token_1.address, &FEE, &TICK_SPACING, &SQRT_PRICE);
+    }
+}
+
+mod initialize;
+mod mint;
+mod swap;
+mod burn;
+mod tick_math;
+mod pause;
+mod upgrade;
@@ -0,0 +1,141 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::test::{SoroswapConcentratedPairTest, LIQUIDITY};
+use crate::test::concentrated_pair::{SoroswapConcentratedPairError, PositionInfo};
+use crate::test::mint::{mint, AMOUNT_IN_RANGE};
+use crate::tick_math::{MIN_SQRT_PRICE, MAX_SQRT_PRICE};
+
+// Amounts of each token given back by half of LIQUIDITY between ticks -600 and 600 at tick 0, rounded down
+const AMOUNT_HALF: i128 = 147_765_054_395;
+
+#[test]
+fn burn_not_yet_initialized() {
+    let test = SoroswapConcentratedPairTest::setup();
+    let res = test.contract.try_burn(&test.user, &-600, &600, &LIQUIDITY);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::NotInitialized)));
+}
+
+#[test]
+fn burn_position_does_not_exist() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    mint(&test, -600, 600, LIQUIDITY);
+    let res = test.contract.try_burn(&test.user, &-1_200, &600, &LIQUIDITY);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::PositionDoesNotExist)));
+}
+
+#[test]
+fn burn_insufficient_liquidity() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    mint(&test, -600, 600, LIQUIDITY);
+    let res = test.contract.try_burn(&test.user, &-600, &600, &(LIQUIDITY + 1));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::BurnInsufficientLiquidity)));
+}
+
+#[test]
+fn burn_in_two_steps() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    mint(&test, -600, 600, LIQUIDITY);
+
+    assert_eq!(test.contract.burn(&test.user, &-600, &600, &(LIQUIDITY / 2)), (AMOUNT_HALF, AMOUNT_HALF));
+    assert_eq!(test.contract.liquidity(), LIQUIDITY / 2);
+    assert_eq!(test.contract.tick_info(&-600).unwrap().liquidity_gross, LIQUIDITY / 2);
+
+    assert_eq!(test.contract.burn(&test.user, &-600, &600, &(LIQUIDITY / 2)), (AMOUNT_HALF, AMOUNT_HALF));
+    assert_eq!(test.contract.liquidity(), 0);
+
+    // Burning only credits the position; the ticks are cleared once they have no liquidity left
+    assert_eq!(test.contract.position(&test.user, &-600, &600), Some(PositionInfo {
+        liquidity: 0,
+        fee_growth_inside_0_last: 0,
+        fee_growth_inside_1_last: 0,
+        tokens_owed_0: 2 * AMOUNT_HALF,
+        tokens_owed_1: 2 * AMOUNT_HALF,
+    }));
+    assert_eq!(test.contract.tick_info(&-600), None);
+    assert_eq!(test.contract.tick_info(&600), None);
+
+    // An empty position cannot be burned again
+    let res = test.contract.try_burn(&test.user, &-600, &600, &0);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::BurnInsufficientLiquidity)));
+}
+
+#[test]
+fn collect_negative_amount() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    mint(&test, -600, 600, LIQUIDITY);
+    let res = test.contract.try_collect(&test.user, &-600, &600, &test.user, &-1, &0);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::CollectNegativeAmount)));
+    let res = test.contract.try_collect(&test.user, &-600, &600, &test.user, &0, &-1);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::CollectNegativeAmount)));
+}
+
+#[test]
+fn collect_position_does_not_exist() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    let res = test.contract.try_collect(&test.user, &-600, &600, &test.user, &i128::MAX, &i128::MAX);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::PositionDoesNotExist)));
+}
+
+#[test]
+fn collect_after_burn() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    mint(&test, -600, 600, LIQUIDITY);
+    test.contract.burn(&test.user, &-600, &600, &LIQUIDITY);
+
+    // A partial collect keeps the position
+    let to = Address::generate(&test.env);
+    assert_eq!(test.contract.collect(&test.user, &-600, &600, &to, &1_000, &0), (1_000, 0));
+    assert_eq!(test.token_0.balance(&to), 1_000);
+    assert_eq!(test.token_1.balance(&to), 0);
+    assert_eq!(test.contract.position(&test.user, &-600, &600).unwrap().tokens_owed_0, 2 * AMOUNT_HALF - 1_000);
+
+    // Collecting the rest removes it
+    assert_eq!(test.contract.collect(&test.user, &-600, &600, &to, &i128::MAX, &i128::MAX), (2 * AMOUNT_HALF - 1_000, 2 * AMOUNT_HALF));
+    assert_eq!(test.token_0.balance(&to), 2 * AMOUNT_HALF);
+    assert_eq!(test.token_1.balance(&to), 2 * AMOUNT_HALF);
+    assert_eq!(test.contract.position(&test.user, &-600, &600), None);
+
+    // The rounding of the mint and the burn leaves the pair with a dust of each token
+    assert_eq!(test.token_0.balance(&test.contract.address), AMOUNT_IN_RANGE - 2 * AMOUNT_HALF);
+    assert_eq!(test.token_1.balance(&test.contract.address), AMOUNT_IN_RANGE - 2 * AMOUNT_HALF);
+}
+
+#[test]
+fn collect_fees() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+
+    // A second provider with three times the liquidity in the same range
+    let user_2 = Address::generate(&test.env);
+    test.token_0.mint(&user_2, &1_000_000_000_000_000);
+    test.token_1.mint(&user_2, &1_000_000_000_000_000);
+    mint(&test, -600, 600, LIQUIDITY);
+    test.contract.mint(&user_2, &-600, &600, &(3 * LIQUIDITY), &i128::MAX, &i128::MAX);
+
+    assert_eq!(test.contract.swap(&test.user, &test.user, &true, &1_000_000_000, &(MIN_SQRT_PRICE + 1)), (1_000_000_000, -996_975_150));
+    assert_eq!(test.contract.swap(&test.user, &test.user, &false, &1_000_000_000, &(MAX_SQRT_PRICE - 1)), (-997_024_849, 1_000_000_000));
+
+    // Burning zero liquidity only updates the fees owed to the position
+    assert_eq!(test.contract.burn(&test.user, &-600, &600, &0), (0, 0));
+    assert_eq!(test.contract.burn(&user_2, &-600, &600, &0), (0, 0));
+
+    // Each swap paid 3_000_000 of fees, shared in proportion to the liquidity and rounded down
+    let position_1 = test.contract.position(&test.user, &-600, &600).unwrap();
+    assert_eq!((position_1.tokens_owed_0, position_1.tokens_owed_1), (749_999, 749_999));
+    let position_2 = test.contract.position(&user_2, &-600, &600).unwrap();
+    assert_eq!((position_2.tokens_owed_0, position_2.tokens_owed_1), (2_249_999, 2_249_999));
+
+    let to = Address::generate(&test.env);
+    assert_eq!(test.contract.collect(&user_2, &-600, &600, &to, &i128::MAX, &i128::MAX), (2_249_999, 2_249_999));
+    assert_eq!(test.token_0.balance(&to), 2_249_999);
+    assert_eq!(test.token_1.balance(&to), 2_249_999);
+
+    // The position keeps its liquidity after its fees are collected
+    assert_eq!(test.contract.position(&user_2, &-600, &600).unwrap().liquidity, 3 * LIQUIDITY);
+    assert_eq!(test.contract.liquidity(), 4 * LIQUIDITY);
+}
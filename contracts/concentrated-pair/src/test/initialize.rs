@@ -0,0 +1,67 @@
+use crate::test::{SoroswapConcentratedPairTest, FEE, TICK_SPACING, SQRT_PRICE};
+use crate::test::concentrated_pair::SoroswapConcentratedPairError;
+use crate::tick_math::{MIN_SQRT_PRICE, MAX_SQRT_PRICE};
+
+#[test]
+fn initialize_token_1_less_than_token_0() {
+    let test = SoroswapConcentratedPairTest::setup();
+    let res = test.contract.try_initialize(&test.factory.address, &test.token_1.address, &test.token_0.address, &FEE, &TICK_SPACING, &SQRT_PRICE);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::InitializeTokenOrderInvalid)));
+}
+
+#[test]
+fn double_initialize() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    let res = test.contract.try_initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &FEE, &TICK_SPACING, &SQRT_PRICE);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::InitializeAlreadyInitialized)));
+}
+
+#[test]
+fn initialize_invalid_fee() {
+    let test = SoroswapConcentratedPairTest::setup();
+    let res = test.contract.try_initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &1_001, &TICK_SPACING, &SQRT_PRICE);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::InitializeInvalidFee)));
+}
+
+#[test]
+fn initialize_invalid_tick_spacing() {
+    let test = SoroswapConcentratedPairTest::setup();
+    let res = test.contract.try_initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &FEE, &0, &SQRT_PRICE);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::InitializeInvalidTickSpacing)));
+    let res = test.contract.try_initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &FEE, &16_384, &SQRT_PRICE);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::InitializeInvalidTickSpacing)));
+}
+
+#[test]
+fn initialize_invalid_price() {
+    let test = SoroswapConcentratedPairTest::setup();
+    let res = test.contract.try_initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &FEE, &TICK_SPACING, &(MIN_SQRT_PRICE - 1));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::InitializeInvalidPrice)));
+    let res = test.contract.try_initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &FEE, &TICK_SPACING, &MAX_SQRT_PRICE);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::InitializeInvalidPrice)));
+}
+
+#[test]
+fn initialize_initial_values() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    assert_eq!(test.factory.guardian(), test.admin);
+    assert_eq!(test.contract.token_0(), test.token_0.address);
+    assert_eq!(test.contract.token_1(), test.token_1.address);
+    assert_eq!(test.contract.factory(), test.factory.address);
+    assert_eq!(test.contract.fee(), FEE);
+    assert_eq!(test.contract.tick_spacing(), TICK_SPACING);
+    assert_eq!(test.contract.get_price(), (SQRT_PRICE, 0));
+    assert_eq!(test.contract.liquidity(), 0);
+    assert_eq!(test.contract.fee_growth_global(), (0, 0));
+    assert_eq!(test.contract.paused(), false);
+}
+
+#[test]
+fn initialize_price_between_ticks() {
+    let test = SoroswapConcentratedPairTest::setup();
+    // The current tick is the greatest tick at or below the price
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &FEE, &TICK_SPACING, &(SQRT_PRICE - 1));
+    assert_eq!(test.contract.get_price(), (SQRT_PRICE - 1, -1));
+}
@@ -0,0 +1,137 @@
+use crate::test::{SoroswapConcentratedPairTest, LIQUIDITY};
+use crate::test::concentrated_pair::{SoroswapConcentratedPairError, PositionInfo, TickInfo};
+
+// Amounts of each token needed by LIQUIDITY between ticks -600 and 600 at tick 0, rounded up
+pub const AMOUNT_IN_RANGE: i128 = 295_530_108_792;
+// Amount of a single token needed by LIQUIDITY in a range of 600 ticks next to tick 0, rounded up
+const AMOUNT_OUT_OF_RANGE: i128 = 286_796_304_272;
+
+pub fn mint(test: &SoroswapConcentratedPairTest, tick_lower: i32, tick_upper: i32, liquidity: u128) -> (i128, i128) {
+    test.contract.mint(&test.user, &tick_lower, &tick_upper, &liquidity, &i128::MAX, &i128::MAX)
+}
+
+#[test]
+fn mint_not_yet_initialized() {
+    let test = SoroswapConcentratedPairTest::setup();
+    let res = test.contract.try_mint(&test.user, &-600, &600, &LIQUIDITY, &i128::MAX, &i128::MAX);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::NotInitialized)));
+}
+
+#[test]
+fn mint_zero_liquidity() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    let res = test.contract.try_mint(&test.user, &-600, &600, &0, &i128::MAX, &i128::MAX);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::MintZeroLiquidity)));
+}
+
+#[test]
+fn mint_invalid_ticks() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    // Unordered, not multiples of the tick spacing and out of range
+    for (tick_lower, tick_upper) in [(600, -600), (600, 600), (-600, 610), (-610, 600), (-443_640, 600), (-600, 443_640)] {
+        let res = test.contract.try_mint(&test.user, &tick_lower, &tick_upper, &LIQUIDITY, &i128::MAX, &i128::MAX);
+        assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::InvalidTickRange)));
+    }
+}
+
+#[test]
+fn mint_amount_exceeds_max() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    let res = test.contract.try_mint(&test.user, &-600, &600, &LIQUIDITY, &(AMOUNT_IN_RANGE - 1), &i128::MAX);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::MintAmountExceedsMax)));
+    let res = test.contract.try_mint(&test.user, &-600, &600, &LIQUIDITY, &i128::MAX, &(AMOUNT_IN_RANGE - 1));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::MintAmountExceedsMax)));
+}
+
+#[test]
+fn mint_tick_liquidity_overflow() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    // The maximum liquidity per tick with a tick spacing of 60
+    let max_liquidity: u128 = 23_012_265_295_255_187_899_058_267_899_625_901;
+    let res = test.contract.try_mint(&test.user, &-600, &600, &(max_liquidity + 1), &i128::MAX, &i128::MAX);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::MintTickLiquidityOverflow)));
+}
+
+#[test]
+fn mint_in_range() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+
+    let balance_0_before = test.token_0.balance(&test.user);
+    let balance_1_before = test.token_1.balance(&test.user);
+
+    assert_eq!(mint(&test, -600, 600, LIQUIDITY), (AMOUNT_IN_RANGE, AMOUNT_IN_RANGE));
+
+    assert_eq!(test.token_0.balance(&test.user), balance_0_before - AMOUNT_IN_RANGE);
+    assert_eq!(test.token_1.balance(&test.user), balance_1_before - AMOUNT_IN_RANGE);
+    assert_eq!(test.token_0.balance(&test.contract.address), AMOUNT_IN_RANGE);
+    assert_eq!(test.token_1.balance(&test.contract.address), AMOUNT_IN_RANGE);
+
+    // The position contains the current price, so its liquidity is in range
+    assert_eq!(test.contract.liquidity(), LIQUIDITY);
+    assert_eq!(test.contract.position(&test.user, &-600, &600), Some(PositionInfo {
+        liquidity: LIQUIDITY,
+        fee_growth_inside_0_last: 0,
+        fee_growth_inside_1_last: 0,
+        tokens_owed_0: 0,
+        tokens_owed_1: 0,
+    }));
+    assert_eq!(test.contract.tick_info(&-600), Some(TickInfo {
+        liquidity_gross: LIQUIDITY,
+        liquidity_net: LIQUIDITY as i128,
+        fee_growth_outside_0: 0,
+        fee_growth_outside_1: 0,
+    }));
+    assert_eq!(test.contract.tick_info(&600), Some(TickInfo {
+        liquidity_gross: LIQUIDITY,
+        liquidity_net: -(LIQUIDITY as i128),
+        fee_growth_outside_0: 0,
+        fee_growth_outside_1: 0,
+    }));
+    assert_eq!(test.contract.tick_info(&0), None);
+}
+
+#[test]
+fn mint_above_price() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    // Above the price the position only holds token 0, and its liquidity is not in range
+    assert_eq!(mint(&test, 600, 1_200, LIQUIDITY), (AMOUNT_OUT_OF_RANGE, 0));
+    assert_eq!(test.contract.liquidity(), 0);
+}
+
+#[test]
+fn mint_below_price() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    // Below the price the position only holds token 1
+    assert_eq!(mint(&test, -1_200, -600, LIQUIDITY), (0, AMOUNT_OUT_OF_RANGE));
+    assert_eq!(test.contract.liquidity(), 0);
+}
+
+#[test]
+fn mint_twice() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    mint(&test, -600, 600, LIQUIDITY);
+    mint(&test, -600, 600, LIQUIDITY);
+
+    assert_eq!(test.contract.liquidity(), 2 * LIQUIDITY);
+    assert_eq!(test.contract.position(&test.user, &-600, &600).unwrap().liquidity, 2 * LIQUIDITY);
+    assert_eq!(test.contract.tick_info(&-600).unwrap().liquidity_gross, 2 * LIQUIDITY);
+}
+
+#[test]
+fn mint_paused() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    test.factory.set_paused(&true);
+    assert_eq!(test.contract.paused(), true);
+
+    let res = test.contract.try_mint(&test.user, &-600, &600, &LIQUIDITY, &i128::MAX, &i128::MAX);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::Paused)));
+}
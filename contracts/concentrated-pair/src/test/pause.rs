@@ -0,0 +1,33 @@
+use crate::test::mint::mint;
+use crate::test::{SoroswapConcentratedPairTest, LIQUIDITY};
+use crate::tick_math::MIN_SQRT_PRICE;
+use crate::test::concentrated_pair::SoroswapConcentratedPairError;
+
+#[test]
+fn set_paused_not_initialized() {
+    let test = SoroswapConcentratedPairTest::setup();
+    let res = test.contract.try_set_paused(&true);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::NotInitialized)));
+}
+
+#[test]
+fn set_paused_blocks_only_this_pair() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    mint(&test, -600, 600, LIQUIDITY);
+
+    test.contract.set_paused(&true);
+    assert_eq!(test.contract.paused(), true);
+    assert_eq!(test.factory.paused(), false);
+
+    let res = test.contract.try_swap(&test.user, &test.user, &true, &1_000_000_000, &(MIN_SQRT_PRICE + 1));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::Paused)));
+
+    let res = test.contract.try_mint(&test.user, &-600, &600, &LIQUIDITY, &i128::MAX, &i128::MAX);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::Paused)));
+
+    // Once unpaused everything works again
+    test.contract.set_paused(&false);
+    assert_eq!(test.contract.paused(), false);
+    test.contract.swap(&test.user, &test.user, &true, &1_000_000_000, &(MIN_SQRT_PRICE + 1));
+}
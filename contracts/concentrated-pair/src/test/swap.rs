@@ -0,0 +1,143 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::test::{SoroswapConcentratedPairTest, LIQUIDITY, SQRT_PRICE};
+use crate::test::concentrated_pair::SoroswapConcentratedPairError;
+use crate::test::mint::mint;
+use crate::tick_math::{sqrt_price_at_tick, MIN_SQRT_PRICE, MAX_SQRT_PRICE};
+
+#[test]
+fn swap_not_yet_initialized() {
+    let test = SoroswapConcentratedPairTest::setup();
+    let res = test.contract.try_swap(&test.user, &test.user, &true, &1_000_000_000, &(MIN_SQRT_PRICE + 1));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::NotInitialized)));
+}
+
+#[test]
+fn swap_zero_amount() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    mint(&test, -600, 600, LIQUIDITY);
+    let res = test.contract.try_swap(&test.user, &test.user, &true, &0, &(MIN_SQRT_PRICE + 1));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::SwapZeroAmount)));
+}
+
+#[test]
+fn swap_invalid_price_limit() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    mint(&test, -600, 600, LIQUIDITY);
+    // Selling token 0 moves the price down, so the limit must be below the current price
+    for limit in [SQRT_PRICE, SQRT_PRICE + 1, MIN_SQRT_PRICE] {
+        let res = test.contract.try_swap(&test.user, &test.user, &true, &1_000_000_000, &limit);
+        assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::SwapInvalidPriceLimit)));
+    }
+    // Selling token 1 moves it up
+    for limit in [SQRT_PRICE, SQRT_PRICE - 1, MAX_SQRT_PRICE] {
+        let res = test.contract.try_swap(&test.user, &test.user, &false, &1_000_000_000, &limit);
+        assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::SwapInvalidPriceLimit)));
+    }
+}
+
+#[test]
+fn swap_paused() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    mint(&test, -600, 600, LIQUIDITY);
+    test.factory.set_paused(&true);
+    let res = test.contract.try_swap(&test.user, &test.user, &true, &1_000_000_000, &(MIN_SQRT_PRICE + 1));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::Paused)));
+}
+
+#[test]
+fn swap_zero_for_one_exact_in() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    mint(&test, -600, 600, LIQUIDITY);
+
+    let to = Address::generate(&test.env);
+    let balance_0_before = test.token_0.balance(&test.user);
+    let pair_balance_1_before = test.token_1.balance(&test.contract.address);
+
+    let amounts = test.contract.swap(&test.user, &to, &true, &1_000_000_000, &(MIN_SQRT_PRICE + 1));
+    assert_eq!(amounts, (1_000_000_000, -996_900_609));
+
+    assert_eq!(test.token_0.balance(&test.user), balance_0_before - 1_000_000_000);
+    assert_eq!(test.token_1.balance(&to), 996_900_609);
+    assert_eq!(test.token_1.balance(&test.contract.address), pair_balance_1_before - 996_900_609);
+
+    assert_eq!(test.contract.get_price(), (18_444_905_116_669_419_675, -2));
+    assert_eq!(test.contract.liquidity(), LIQUIDITY);
+    // The 0.3% fee of token 0, per unit of liquidity, as a Q64.64
+    assert_eq!(test.contract.fee_growth_global(), (5_534_023_222_112, 0));
+}
+
+#[test]
+fn swap_one_for_zero_exact_in() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    mint(&test, -600, 600, LIQUIDITY);
+
+    let amounts = test.contract.swap(&test.user, &test.user, &false, &1_000_000_000, &(MAX_SQRT_PRICE - 1));
+    assert_eq!(amounts, (-996_900_609, 1_000_000_000));
+    assert_eq!(test.contract.get_price(), (18_448_583_214_093_700_458, 1));
+    assert_eq!(test.contract.fee_growth_global(), (0, 5_534_023_222_112));
+}
+
+#[test]
+fn swap_zero_for_one_exact_out() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    mint(&test, -600, 600, LIQUIDITY);
+
+    let amounts = test.contract.swap(&test.user, &test.user, &true, &-1_000_000_000, &(MIN_SQRT_PRICE + 1));
+    assert_eq!(amounts, (1_003_109_340, -1_000_000_000));
+    assert_eq!(test.contract.get_price(), (18_444_899_399_302_180_660, -3));
+}
+
+#[test]
+fn swap_stops_at_price_limit() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    mint(&test, -600, 600, LIQUIDITY);
+
+    // Only part of the input is used before the price reaches the limit
+    let limit = sqrt_price_at_tick(-60).unwrap();
+    let amounts = test.contract.swap(&test.user, &test.user, &true, &1_000_000_000_000, &limit);
+    assert_eq!(amounts, (30_133_942_456, -29_953_549_559));
+    assert_eq!(test.contract.get_price(), (limit, -60));
+}
+
+#[test]
+fn swap_crosses_ticks() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    mint(&test, -600, 600, LIQUIDITY);
+    mint(&test, -1_200, -600, 2 * LIQUIDITY);
+    assert_eq!(test.contract.liquidity(), LIQUIDITY);
+
+    // The swap leaves the first range at tick -600 and continues in the second one
+    let amounts = test.contract.swap(&test.user, &test.user, &true, &400_000_000_000, &(MIN_SQRT_PRICE + 1));
+    assert_eq!(amounts, (400_000_000_000, -383_906_375_779));
+    assert_eq!(test.contract.get_price(), (17_820_074_526_448_158_606, -692));
+    assert_eq!(test.contract.liquidity(), 2 * LIQUIDITY);
+
+    // Crossing tick -600 flips its fee growth outside to the global value at that moment
+    assert_eq!(test.contract.fee_growth_global(), (1_951_977_890_016_905, 0));
+    let tick_info = test.contract.tick_info(&-600).unwrap();
+    assert_eq!(tick_info.liquidity_gross, 3 * LIQUIDITY);
+    assert_eq!(tick_info.liquidity_net, -(LIQUIDITY as i128));
+    assert_eq!(tick_info.fee_growth_outside_0, 1_690_346_489_343_990);
+    assert_eq!(tick_info.fee_growth_outside_1, 0);
+}
+
+#[test]
+fn swap_without_liquidity_reaches_limit() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    mint(&test, 600, 1_200, LIQUIDITY);
+
+    // There is no liquidity below the price, so nothing is swapped
+    let limit = sqrt_price_at_tick(-600).unwrap();
+    let amounts = test.contract.swap(&test.user, &test.user, &true, &1_000_000_000, &limit);
+    assert_eq!(amounts, (0, 0));
+    assert_eq!(test.contract.get_price(), (limit, -600));
+}
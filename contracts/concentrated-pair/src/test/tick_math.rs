@@ -0,0 +1,47 @@
+use crate::tick_math::{sqrt_price_at_tick, tick_at_sqrt_price, MIN_TICK, MAX_TICK, MIN_SQRT_PRICE, MAX_SQRT_PRICE};
+use crate::test::SQRT_PRICE;
+
+#[test]
+fn sqrt_price_at_tick_bounds() {
+    assert_eq!(sqrt_price_at_tick(0), Some(SQRT_PRICE));
+    assert_eq!(sqrt_price_at_tick(MIN_TICK), Some(MIN_SQRT_PRICE));
+    assert_eq!(sqrt_price_at_tick(MAX_TICK), Some(MAX_SQRT_PRICE));
+    assert_eq!(sqrt_price_at_tick(MIN_TICK - 1), None);
+    assert_eq!(sqrt_price_at_tick(MAX_TICK + 1), None);
+}
+
+#[test]
+fn sqrt_price_at_tick_values() {
+    assert_eq!(sqrt_price_at_tick(-1_200), Some(17_372_541_442_796_994_606));
+    assert_eq!(sqrt_price_at_tick(-600), Some(17_901_587_245_414_554_122));
+    assert_eq!(sqrt_price_at_tick(600), Some(19_008_502_556_559_666_135));
+}
+
+#[test]
+fn sqrt_price_at_tick_increases() {
+    let mut previous = 0;
+    for tick in (MIN_TICK..=MAX_TICK).step_by(997) {
+        let sqrt_price = sqrt_price_at_tick(tick).unwrap();
+        assert!(sqrt_price > previous);
+        previous = sqrt_price;
+    }
+}
+
+#[test]
+fn tick_at_sqrt_price_bounds() {
+    assert_eq!(tick_at_sqrt_price(MIN_SQRT_PRICE), Some(MIN_TICK));
+    assert_eq!(tick_at_sqrt_price(MAX_SQRT_PRICE - 1), Some(MAX_TICK - 1));
+    assert_eq!(tick_at_sqrt_price(MIN_SQRT_PRICE - 1), None);
+    assert_eq!(tick_at_sqrt_price(MAX_SQRT_PRICE), None);
+}
+
+#[test]
+fn tick_at_sqrt_price_round_trip() {
+    for tick in [MIN_TICK, -600, -60, -1, 0, 1, 60, 600, MAX_TICK - 1] {
+        let sqrt_price = sqrt_price_at_tick(tick).unwrap();
+        assert_eq!(tick_at_sqrt_price(sqrt_price), Some(tick));
+        // Any price below the next tick still belongs to this one
+        let sqrt_price_next = sqrt_price_at_tick(tick + 1).unwrap();
+        assert_eq!(tick_at_sqrt_price(sqrt_price_next - 1), Some(tick));
+    }
+}
@@ -0,0 +1,59 @@
+use crate::test::mint::mint;
+use crate::test::{SoroswapConcentratedPairTest, concentrated_pair, FEE, TICK_SPACING, LIQUIDITY};
+use crate::test::concentrated_pair::SoroswapConcentratedPairError;
+use soroban_sdk::{
+    IntoVal,
+    testutils::{
+        MockAuth,
+        MockAuthInvoke,
+    },
+};
+
+#[test]
+fn upgrade_not_initialized() {
+    let test = SoroswapConcentratedPairTest::setup();
+    let new_wasm_hash = test.env.deployer().upload_contract_wasm(concentrated_pair::WASM);
+    let res = test.contract.try_upgrade(&new_wasm_hash);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPairError::NotInitialized)));
+}
+
+#[test]
+fn upgrade_keeps_data() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    mint(&test, -600, 600, LIQUIDITY);
+
+    let new_wasm_hash = test.env.deployer().upload_contract_wasm(concentrated_pair::WASM);
+    test.contract.upgrade(&new_wasm_hash);
+
+    assert_eq!(test.contract.token_0(), test.token_0.address);
+    assert_eq!(test.contract.token_1(), test.token_1.address);
+    assert_eq!(test.contract.factory(), test.factory.address);
+    assert_eq!(test.contract.fee(), FEE);
+    assert_eq!(test.contract.tick_spacing(), TICK_SPACING);
+    assert_eq!(test.contract.liquidity(), LIQUIDITY);
+}
+
+#[test]
+#[should_panic]
+fn upgrade_with_mock_auth_not_allowed() {
+    let test = SoroswapConcentratedPairTest::setup();
+    test.initialize();
+    let new_wasm_hash = test.env.deployer().upload_contract_wasm(concentrated_pair::WASM);
+
+    // Only the factory can upgrade the pair
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.admin.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "upgrade",
+                    args: (new_wasm_hash.clone(),).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .upgrade(&new_wasm_hash);
+}
@@ -0,0 +1,173 @@
+//! The ticks that bound the positions, and the bitmap the swaps use to find them.
+
+use soroban_sdk::{contracttype, Env};
+use crate::error::SoroswapConcentratedPairError;
+use crate::math::add_delta;
+use crate::storage::*;
+use crate::tick_math::{MIN_TICK, MAX_TICK};
+
+/// State of a tick that is the lower or upper bound of at least one position.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TickInfo {
+    /// Total liquidity of the positions that have this tick as a bound.
+    pub liquidity_gross: u128,
+    /// Liquidity added when the price crosses this tick going up, and removed when it crosses going down.
+    pub liquidity_net: i128,
+    /// Fee growth of token 0 on the other side of this tick from the current tick, as a Q64.64 number.
+    pub fee_growth_outside_0: u128,
+    /// Fee growth of token 1 on the other side of this tick from the current tick, as a Q64.64 number.
+    pub fee_growth_outside_1: u128,
+}
+
+/// Maximum liquidity that can reference a single tick, so that the liquidity of the pair cannot overflow
+/// even if every usable tick is a bound of a position.
+pub fn max_liquidity_per_tick(tick_spacing: u32) -> u128 {
+    let tick_spacing = tick_spacing as i32;
+    let min_tick = MIN_TICK / tick_spacing * tick_spacing;
+    let max_tick = MAX_TICK / tick_spacing * tick_spacing;
+    let number_of_ticks = ((max_tick - min_tick) / tick_spacing) as u128 + 1;
+    u128::MAX / number_of_ticks
+}
+
+/// Adds `liquidity_delta` to a bound of a position.
+///
+/// Returns true if the tick went from unused to used or the other way around, so it needs to be flipped in the bitmap.
+/// The tick is saved even if it is no longer used; call `clear` after the position has read its fee growth.
+pub fn update(
+    e: &Env,
+    tick: i32,
+    tick_current: i32,
+    liquidity_delta: i128,
+    fee_growth_global: (u128, u128),
+    upper: bool,
+    max_liquidity: u128,
+) -> Result<bool, SoroswapConcentratedPairError> {
+    let mut info = get_tick_info(e, tick).unwrap_or_default();
+
+    let liquidity_gross_before = info.liquidity_gross;
+    let liquidity_gross_after = add_delta(liquidity_gross_before, liquidity_delta).ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+    if liquidity_gross_after > max_liquidity {
+        return Err(SoroswapConcentratedPairError::MintTickLiquidityOverflow);
+    }
+
+    let flipped = (liquidity_gross_after == 0) != (liquidity_gross_before == 0);
+
+    if liquidity_gross_before == 0 {
+        // By convention, all the fees so far were earned below the tick
+        if tick <= tick_current {
+            (info.fee_growth_outside_0, info.fee_growth_outside_1) = fee_growth_global;
+        }
+    }
+
+    info.liquidity_gross = liquidity_gross_after;
+    // The price crosses the lower bound into the position going up, and the upper bound out of it
+    info.liquidity_net = if upper {
+        info.liquidity_net.checked_sub(liquidity_delta)
+    } else {
+        info.liquidity_net.checked_add(liquidity_delta)
+    }.ok_or(SoroswapConcentratedPairError::UpdateOverflow)?;
+
+    put_tick_info(e, tick, &info);
+    Ok(flipped)
+}
+
+/// Removes a tick that is no longer the bound of any position.
+pub fn clear(e: &Env, tick: i32) {
+    remove_tick_info(e, tick);
+}
+
+/// Flips the side of the tick its fee growth is measured on, as the price crosses it. Returns its liquidity_net.
+pub fn cross(e: &Env, tick: i32, fee_growth_global: (u128, u128)) -> i128 {
+    let (fee_growth_global_0, fee_growth_global_1) = fee_growth_global;
+    // Only initialized ticks are crossed, so the tick is always stored
+    let mut info = get_tick_info(e, tick).unwrap();
+    info.fee_growth_outside_0 = fee_growth_global_0.wrapping_sub(info.fee_growth_outside_0);
+    info.fee_growth_outside_1 = fee_growth_global_1.wrapping_sub(info.fee_growth_outside_1);
+    put_tick_info(e, tick, &info);
+    info.liquidity_net
+}
+
+/// Fee growth of both tokens between `tick_lower` and `tick_upper`, as Q64.64 numbers.
+/// Like the global fee growth they can wrap around, only their differences are meaningful.
+pub fn fee_growth_inside(
+    e: &Env,
+    tick_lower: i32,
+    tick_upper: i32,
+    tick_current: i32,
+    fee_growth_global: (u128, u128),
+) -> (u128, u128) {
+    let (fee_growth_global_0, fee_growth_global_1) = fee_growth_global;
+    let lower = get_tick_info(e, tick_lower).unwrap();
+    let upper = get_tick_info(e, tick_upper).unwrap();
+
+    let (below_0, below_1) = if tick_current >= tick_lower {
+        (lower.fee_growth_outside_0, lower.fee_growth_outside_1)
+    } else {
+        (
+            fee_growth_global_0.wrapping_sub(lower.fee_growth_outside_0),
+            fee_growth_global_1.wrapping_sub(lower.fee_growth_outside_1),
+        )
+    };
+
+    let (above_0, above_1) = if tick_current < tick_upper {
+        (upper.fee_growth_outside_0, upper.fee_growth_outside_1)
+    } else {
+        (
+            fee_growth_global_0.wrapping_sub(upper.fee_growth_outside_0),
+            fee_growth_global_1.wrapping_sub(upper.fee_growth_outside_1),
+        )
+    };
+
+    (
+        fee_growth_global_0.wrapping_sub(below_0).wrapping_sub(above_0),
+        fee_growth_global_1.wrapping_sub(below_1).wrapping_sub(above_1),
+    )
+}
+
+// Word and bit in the bitmap of a tick divided by the tick spacing
+fn bitmap_position(compressed: i32) -> (i32, u32) {
+    (compressed >> 7, (compressed & 127) as u32)
+}
+
+/// Marks a tick as used or unused in the bitmap. The tick must be a multiple of the tick spacing.
+pub fn flip_tick(e: &Env, tick: i32, tick_spacing: u32) {
+    let (word_position, bit_position) = bitmap_position(tick / tick_spacing as i32);
+    let word = get_tick_bitmap_word(e, word_position);
+    put_tick_bitmap_word(e, word_position, word ^ (1u128 << bit_position));
+}
+
+/// Next used tick at or below `tick` when `lte` is true, or above `tick` otherwise, looking only at the word of
+/// the bitmap where the search starts. If there is none, it returns the last tick of the word and false, so the
+/// swap can move the price there and keep searching in the next word.
+pub fn next_initialized_tick_within_one_word(e: &Env, tick: i32, tick_spacing: u32, lte: bool) -> (i32, bool) {
+    let tick_spacing = tick_spacing as i32;
+    let compressed = tick.div_euclid(tick_spacing);
+
+    if lte {
+        let (word_position, bit_position) = bitmap_position(compressed);
+        // All the bits at or below bit_position
+        let mask = if bit_position == 127 { u128::MAX } else { (1u128 << (bit_position + 1)) - 1 };
+        let masked = get_tick_bitmap_word(e, word_position) & mask;
+
+        if masked != 0 {
+            let most_significant_bit = 127 - masked.leading_zeros();
+            ((compressed - (bit_position - most_significant_bit) as i32) * tick_spacing, true)
+        } else {
+            ((compressed - bit_position as i32) * tick_spacing, false)
+        }
+    } else {
+        // Start from the next tick, the current one is already at or below the price
+        let (word_position, bit_position) = bitmap_position(compressed + 1);
+        // All the bits at or above bit_position
+        let mask = !((1u128 << bit_position) - 1);
+        let masked = get_tick_bitmap_word(e, word_position) & mask;
+
+        if masked != 0 {
+            let least_significant_bit = masked.trailing_zeros();
+            ((compressed + 1 + (least_significant_bit - bit_position) as i32) * tick_spacing, true)
+        } else {
+            ((compressed + 1 + (127 - bit_position) as i32) * tick_spacing, false)
+        }
+    }
+}
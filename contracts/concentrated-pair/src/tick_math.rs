@@ -0,0 +1,85 @@
+//! Conversions between ticks and square root prices.
+//!
+//! The price at a tick is `1.0001^tick`, and the pair stores its square root as a Q64.64 number.
+//! The ticks are limited to [`MIN_TICK`, `MAX_TICK`], where the square root price goes from 2^-32 to 2^32,
+//! so every square root price and every Q64.64 product the pair needs fits in a u128.
+
+use crate::math::Q64;
+
+/// The minimum tick, where the price is about 2^-64.
+pub const MIN_TICK: i32 = -443_636;
+/// The maximum tick, where the price is about 2^64.
+pub const MAX_TICK: i32 = 443_636;
+
+/// The square root price at `MIN_TICK`.
+pub const MIN_SQRT_PRICE: u128 = 4_295_048_016;
+/// The square root price at `MAX_TICK`.
+pub const MAX_SQRT_PRICE: u128 = 79_226_673_521_066_979_257_578_248_091;
+
+// 1.0001^(-2^i / 2) as Q64.64 numbers, rounded down. They are the upper 64 bits of the constants of Uniswap V3
+const FACTORS: [u128; 19] = [
+    0xfffcb933bd6fad37,
+    0xfff97272373d4132,
+    0xfff2e50f5f656932,
+    0xffe5caca7e10e4e6,
+    0xffcb9843d60f6159,
+    0xff973b41fa98c081,
+    0xff2ea16466c96a38,
+    0xfe5dee046a99a2a8,
+    0xfcbe86c7900a88ae,
+    0xf987a7253ac41317,
+    0xf3392b0822b70005,
+    0xe7159475a2c29b74,
+    0xd097f3bdfd2022b8,
+    0xa9f746462d870fdf,
+    0x70d869a156d2a1b8,
+    0x31be135f97d08fd9,
+    0x09aa508b5b7a84e1,
+    0x005d6af8dedb8119,
+    0x00002216e584f5fa,
+];
+
+/// Square root of `1.0001^tick` as a Q64.64 number.
+///
+/// Returns None if the tick is out of range.
+pub fn sqrt_price_at_tick(tick: i32) -> Option<u128> {
+    if !(MIN_TICK..=MAX_TICK).contains(&tick) {
+        return None;
+    }
+
+    // 1.0001^(-|tick| / 2) is the product of the factors of the bits of |tick|. Every factor is below 1.0,
+    // so the ratio never goes above 2^64 and each product fits in a u128
+    let abs_tick = tick.unsigned_abs();
+    let mut ratio = Q64;
+    for (bit, factor) in FACTORS.iter().enumerate() {
+        if abs_tick & (1 << bit) != 0 {
+            ratio = (ratio * factor) >> 64;
+        }
+    }
+
+    if tick > 0 {
+        ratio = u128::MAX / ratio;
+    }
+    Some(ratio)
+}
+
+/// The greatest tick whose square root price is less than or equal to `sqrt_price`.
+///
+/// Returns None if `sqrt_price` is below `MIN_SQRT_PRICE` or not below `MAX_SQRT_PRICE`.
+pub fn tick_at_sqrt_price(sqrt_price: u128) -> Option<i32> {
+    if !(MIN_SQRT_PRICE..MAX_SQRT_PRICE).contains(&sqrt_price) {
+        return None;
+    }
+
+    // sqrt_price_at_tick grows with the tick, so a binary search finds the tick in 20 steps
+    let (mut low, mut high) = (MIN_TICK, MAX_TICK);
+    while low < high {
+        let middle = low + (high - low + 1) / 2;
+        if sqrt_price_at_tick(middle)? <= sqrt_price {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+    Some(low)
+}
@@ -20,5 +20,50 @@ pub enum FactoryError {
 
     /// SoroswapFactory: index does not exist
     IndexDoesNotExist = 206,
+
+    /// SoroswapFactory: fee is greater than the maximum allowed
+    SetPairFeeInvalidFee = 207,
+
+    /// SoroswapFactory: protocol fee fraction must be greater than 0 and less than 1
+    SetProtocolFeeFractionInvalid = 208,
+
+    /// SoroswapFactory: the stored data is newer than the contract code
+    MigrateInvalidVersion = 209,
+
+    /// SoroswapFactory: maximum referral fee is greater than the maximum allowed
+    SetMaxReferralFeeInvalid = 210,
+
+    /// SoroswapFactory: the stable pair wasm hash has not been set
+    StablePairWasmHashNotSet = 211,
+
+    /// SoroswapFactory: amplification coefficient is out of range
+    CreateStablePairInvalidAmp = 212,
+
+    /// SoroswapFactory: the weighted pair wasm hash has not been set
+    WeightedPairWasmHashNotSet = 213,
+
+    /// SoroswapFactory: weight is out of range
+    CreateWeightedPairInvalidWeights = 214,
+
+    /// SoroswapFactory: the concentrated pair wasm hash has not been set
+    ConcentratedPairWasmHashNotSet = 215,
+
+    /// SoroswapFactory: tick spacing is out of range
+    CreateConcentratedPairInvalidTickSpacing = 216,
+
+    /// SoroswapFactory: initial square root price is out of range
+    CreateConcentratedPairInvalidPrice = 217,
+
+    /// SoroswapFactory: there is no template for the kind of pool
+    PoolTemplateDoesNotExist = 218,
+
+    /// SoroswapFactory: the template of the kind of pool has no arguments for `initialize`
+    PoolTemplateMissingInitArgs = 219,
+
+    /// SoroswapFactory: the pair can not change its fee
+    SetPairFeeNotSupported = 220,
+
+    /// SoroswapFactory: the arguments of the template do not fit the `initialize` of its pair
+    PoolTemplateInvalidInitArgs = 221,
 }
 
@@ -1,12 +1,15 @@
 #![deny(warnings)]
 #![no_std]
 
-use soroban_sdk::{contractclient, contractspecfn, Address, Env, BytesN};
+use soroban_sdk::{contractclient, contractspecfn, Address, Env, BytesN, Symbol, Val, Vec};
 pub struct Spec;
 
 mod error;
 pub use error::FactoryError;
 
+mod template;
+pub use template::{PoolTemplate, CONSTANT_PRODUCT_KIND, STABLE_KIND, WEIGHTED_KIND, CONCENTRATED_KIND};
+
 /// Interface for SoroswapFactory
 #[contractspecfn(name = "Spec", export = false)]
 #[contractclient(name = "SoroswapFactoryClient")]
@@ -28,15 +31,72 @@ pub trait SoroswapFactoryTrait {
     /// Returns the total number of pairs created through the factory so far.
     fn all_pairs_length(e: Env) -> Result<u32, FactoryError>;
 
-    /// Returns the address of the pair for `token_a` and `token_b`, if it has been created.
+    /// Returns the address of the constant product pair for `token_a` and `token_b`, if it has been created.
     fn get_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError>;
 
+    /// Returns the address of the pair of the given `kind` for `token_a` and `token_b`, if it has been created.
+    fn get_pair_with_kind(e: Env, token_a: Address, token_b: Address, kind: Symbol) -> Result<Address, FactoryError>;
+
     /// Returns the address of the nth pair (0-indexed) created through the factory.
     fn all_pairs(e: Env, n: u32) -> Result<Address, FactoryError>;
 
-    /// Returns a boolean indicating if a pair exists for the given `token_a` and `token_b`.
+    /// Returns the address and the kind of the nth pair (0-indexed) created through the factory.
+    fn all_pairs_with_kind(e: Env, n: u32) -> Result<(Address, Symbol), FactoryError>;
+
+    /// Returns a boolean indicating if a constant product pair exists for the given `token_a` and `token_b`.
     fn pair_exists(e: Env, token_a: Address, token_b: Address) -> Result<bool, FactoryError>;
 
+    /// Returns the swap fee, in basis points, of the `variant` pair for `token_a` and `token_b`.
+    fn pair_fee(e: Env, token_a: Address, token_b: Address, variant: Symbol) -> Result<u32, FactoryError>;
+
+    /// Returns the fraction of the liquidity providers fees that goes to `fee_to`, as `(numerator, denominator)`.
+    fn protocol_fee_fraction(e: Env) -> Result<(u32, u32), FactoryError>;
+
+    /// Returns the maximum referral fee, in basis points, that the router lets integrators charge on a swap.
+    fn max_referral_fee(e: Env) -> Result<u32, FactoryError>;
+
+    /// Returns the address allowed to pause and unpause the pairs.
+    fn guardian(e: Env) -> Result<Address, FactoryError>;
+
+    /// Checks if all the pairs have been paused by the guardian.
+    fn paused(e: Env) -> Result<bool, FactoryError>;
+
+    /// Returns the Wasm hash used to deploy new pairs.
+    fn pair_wasm_hash(e: Env) -> Result<BytesN<32>, FactoryError>;
+
+    /// Returns the version of the storage layout of the factory.
+    fn storage_version(e: Env) -> Result<u32, FactoryError>;
+
+    /// Returns the address of the stable pair for `token_a` and `token_b`, if it has been created.
+    fn get_stable_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError>;
+
+    /// Returns the Wasm hash used to deploy new stable pairs.
+    fn stable_pair_wasm_hash(e: Env) -> Result<BytesN<32>, FactoryError>;
+
+    /// Returns the address of the weighted pair for `token_a` and `token_b`, if it has been created.
+    fn get_weighted_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError>;
+
+    /// Returns the Wasm hash used to deploy new weighted pairs.
+    fn weighted_pair_wasm_hash(e: Env) -> Result<BytesN<32>, FactoryError>;
+
+    /// Returns the address of the concentrated pair for `token_a` and `token_b`, if it has been created.
+    fn get_concentrated_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError>;
+
+    /// Returns the Wasm hash used to deploy new concentrated pairs.
+    fn concentrated_pair_wasm_hash(e: Env) -> Result<BytesN<32>, FactoryError>;
+
+    /// Returns the kinds of pairs that have a template, in registration order, starting with the constant product kind.
+    fn pool_kinds(e: Env) -> Result<Vec<Symbol>, FactoryError>;
+
+    /// Returns the template used to deploy the pairs of the given `kind`.
+    fn pool_template(e: Env, kind: Symbol) -> Result<PoolTemplate, FactoryError>;
+
+    /// Returns every variant (kind) of pair created for `token_a` and `token_b`, built-in kinds first.
+    fn pair_variants(e: Env, token_a: Address, token_b: Address) -> Result<Vec<Symbol>, FactoryError>;
+
+    /// Returns the variant and the address of every pair created for `token_a` and `token_b`.
+    fn get_pairs(e: Env, token_a: Address, token_b: Address) -> Result<Vec<(Symbol, Address)>, FactoryError>;
+
     /*  *** State-Changing Functions: *** */
 
     /// Sets the `fee_to_setter` address and initializes the factory.
@@ -72,7 +132,7 @@ pub trait SoroswapFactoryTrait {
     /// * `is_enabled` - A boolean indicating whether fees are enabled or disabled.
     fn set_fees_enabled(e: Env, is_enabled: bool)-> Result<(), FactoryError>;
 
-    /// Creates a pair for `token_a` and `token_b` if one doesn't exist already.
+    /// Creates a constant product pair for `token_a` and `token_b` if one doesn't exist already.
     /// 
     /// # Arguments
     /// 
@@ -80,4 +140,175 @@ pub trait SoroswapFactoryTrait {
     /// * `token_a` - The address of the first token in the pair.
     /// * `token_b` - The address of the second token in the pair.
     fn create_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError>;
+
+    /// Creates a pair of the given `kind` for `token_a` and `token_b` from the template of that kind, if one
+    /// doesn't exist already.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `kind` - Any kind with a template, built-in or registered with `set_pool_template`.
+    fn create_pair_with_kind(e: Env, token_a: Address, token_b: Address, kind: Symbol) -> Result<Address, FactoryError>;
+
+    /// Creates a StableSwap pair for `token_a` and `token_b` if one doesn't exist already.
+    /// It lives alongside the constant product pair of the same tokens.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `amp` - The amplification coefficient of the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    fn create_stable_pair(e: Env, token_a: Address, token_b: Address, amp: u32, fee: u32) -> Result<Address, FactoryError>;
+
+    /// Creates a weighted pair for `token_a` and `token_b` if one doesn't exist already.
+    /// It lives alongside the constant product and the stable pairs of the same tokens.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `weight_a` - The weight of `token_a`, in basis points. `token_b` gets the rest.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    fn create_weighted_pair(e: Env, token_a: Address, token_b: Address, weight_a: u32, fee: u32) -> Result<Address, FactoryError>;
+
+    /// Creates a concentrated liquidity pair for `token_a` and `token_b` if one doesn't exist already.
+    /// It lives alongside the other kinds of pairs of the same tokens.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    /// * `tick_spacing` - The distance between the ticks where positions can start or end.
+    /// * `sqrt_price` - The square root of the initial price of `token_a` in `token_b`, as a Q64.64 number.
+    fn create_concentrated_pair(e: Env, token_a: Address, token_b: Address, fee: u32, tick_spacing: u32, sqrt_price: u128) -> Result<Address, FactoryError>;
+
+    /// Sets the swap fee of the `variant` pair for `token_a` and `token_b`. Only the "cp" variant can change its fee,
+    /// the fee of the other variants, fee tiers included, is fixed when they are created.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `variant` - The variant of the pair, as returned by `pair_variants`.
+    /// * `fee` - The new swap fee, in basis points.
+    fn set_pair_fee(e: Env, token_a: Address, token_b: Address, variant: Symbol, fee: u32) -> Result<(), FactoryError>;
+
+    /// Sets the fraction of the liquidity providers fees that goes to `fee_to` when fees are enabled.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `numerator` - The numerator of the fraction.
+    /// * `denominator` - The denominator of the fraction.
+    fn set_protocol_fee_fraction(e: Env, numerator: u32, denominator: u32) -> Result<(), FactoryError>;
+
+    /// Sets the maximum referral fee that the router lets integrators charge on a swap.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `max_fee` - The new maximum referral fee, in basis points.
+    fn set_max_referral_fee(e: Env, max_fee: u32) -> Result<(), FactoryError>;
+
+    /// Sets the `guardian` address.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `new_guardian` - The address to set as the new `guardian`.
+    fn set_guardian(e: Env, new_guardian: Address) -> Result<(), FactoryError>;
+
+    /// Pauses or unpauses deposits and swaps in all the pairs. Withdraws are never paused.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `is_paused` - A boolean indicating whether the pairs are paused.
+    fn set_paused(e: Env, is_paused: bool) -> Result<(), FactoryError>;
+
+    /// Pauses or unpauses deposits and swaps in the `variant` pair for `token_a` and `token_b`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `variant` - The variant of the pair, as returned by `pair_variants`.
+    /// * `is_paused` - A boolean indicating whether the pair is paused.
+    fn set_pair_paused(e: Env, token_a: Address, token_b: Address, variant: Symbol, is_paused: bool) -> Result<(), FactoryError>;
+
+    /// Sets the Wasm hash used to deploy new pairs. Existing pairs are not affected.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `pair_wasm_hash` - The Wasm hash of the new SoroswapPair contract.
+    fn set_pair_wasm_hash(e: Env, pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError>;
+
+    /// Sets the Wasm hash of the template of the stable pairs. Existing stable pairs are not affected.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `stable_pair_wasm_hash` - The Wasm hash of the SoroswapStablePair contract.
+    fn set_stable_pair_wasm_hash(e: Env, stable_pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError>;
+
+    /// Sets the Wasm hash of the template of the weighted pairs. Existing weighted pairs are not affected.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `weighted_pair_wasm_hash` - The Wasm hash of the SoroswapWeightedPair contract.
+    fn set_weighted_pair_wasm_hash(e: Env, weighted_pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError>;
+
+    /// Sets the Wasm hash of the template of the concentrated pairs. Existing concentrated pairs are not affected.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `concentrated_pair_wasm_hash` - The Wasm hash of the SoroswapConcentratedPair contract.
+    fn set_concentrated_pair_wasm_hash(e: Env, concentrated_pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError>;
+
+    /// Registers or replaces the template used by `create_pair_with_kind` to deploy the pairs of the given `kind`.
+    /// Existing pairs of that kind are not affected.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `kind` - The name of the kind of pool, built-in or new.
+    /// * `wasm_hash` - The Wasm hash of the pool contract.
+    /// * `init_args` - The arguments passed to `initialize` after the factory, `token_0` and `token_1`.
+    fn set_pool_template(e: Env, kind: Symbol, wasm_hash: BytesN<32>, init_args: Vec<Val>) -> Result<(), FactoryError>;
+
+    /// Upgrades the code of the factory.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `new_wasm_hash` - The Wasm hash of the new SoroswapFactory contract.
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), FactoryError>;
+
+    /// Migrates the stored data of the factory to the storage layout of the current code.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    fn migrate(e: Env) -> Result<u32, FactoryError>;
+
+    /// Upgrades the code of an existing pair and migrates the stored data of the constant product pairs.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `pair` - The address of the pair to upgrade, of any pair type.
+    /// * `new_wasm_hash` - The Wasm hash of the new code of the pair.
+    fn upgrade_pair(e: Env, pair: Address, new_wasm_hash: BytesN<32>) -> Result<(), FactoryError>;
 }
@@ -0,0 +1,22 @@
+use soroban_sdk::{contracttype, symbol_short, BytesN, Symbol, Val, Vec};
+
+/// Kind of the constant product pairs, deployed from `pair_wasm_hash`.
+pub const CONSTANT_PRODUCT_KIND: Symbol = symbol_short!("cp");
+/// Kind of the pairs created with `create_stable_pair`.
+pub const STABLE_KIND: Symbol = symbol_short!("stable");
+/// Kind of the pairs created with `create_weighted_pair`.
+pub const WEIGHTED_KIND: Symbol = symbol_short!("weighted");
+/// Kind of the pairs created with `create_concentrated_pair`.
+pub const CONCENTRATED_KIND: Symbol = symbol_short!("conc");
+
+/// Template of a kind of pool that the factory can deploy.
+///
+/// The pool is deployed from `wasm_hash` and its `initialize` function is called with the factory, `token_0`
+/// and `token_1`, followed by `init_args`. The functions that create the pairs of a built-in kind, like
+/// `create_stable_pair`, pass their own arguments instead of `init_args`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolTemplate {
+    pub wasm_hash: BytesN<32>,
+    pub init_args: Vec<Val>,
+}
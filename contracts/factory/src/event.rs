@@ -1,5 +1,6 @@
 //! Definition of the Events used in the contract
-use soroban_sdk::{contracttype, symbol_short, Env, Address};
+use soroban_sdk::{contracttype, symbol_short, Env, Address, BytesN, Symbol};
+use soroswap_factory_interface::PoolTemplate;
 
 // INITIALIZED
 #[contracttype]
@@ -106,3 +107,453 @@ pub(crate) fn new_fees_enabled(
     };
     e.events().publish(("SoroswapFactory", symbol_short!("fees")), event);
 }
+
+
+
+// NEW PAIR FEE
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewPairFeeEvent {
+    pub token_0: Address,
+    pub token_1: Address,
+    pub pair: Address,
+    pub old: u32,
+    pub new: u32
+}
+
+pub(crate) fn new_pair_fee(
+    e: &Env,
+    token_0: Address,
+    token_1: Address,
+    pair: Address,
+    old: u32,
+    new: u32) {
+    
+    let event: NewPairFeeEvent = NewPairFeeEvent {
+        token_0: token_0,
+        token_1: token_1,
+        pair: pair,
+        old: old,
+        new: new
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("pair_fee")), event);
+}
+
+
+
+// NEW PROTOCOL FEE FRACTION
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewProtocolFeeFractionEvent {
+    pub old_numerator: u32,
+    pub old_denominator: u32,
+    pub new_numerator: u32,
+    pub new_denominator: u32
+}
+
+pub(crate) fn new_protocol_fee_fraction(
+    e: &Env,
+    old: (u32, u32),
+    new: (u32, u32)) {
+    
+    let event: NewProtocolFeeFractionEvent = NewProtocolFeeFractionEvent {
+        old_numerator: old.0,
+        old_denominator: old.1,
+        new_numerator: new.0,
+        new_denominator: new.1
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("prot_fee")), event);
+}
+
+
+
+// NEW MAX REFERRAL FEE
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewMaxReferralFeeEvent {
+    pub old: u32,
+    pub new: u32
+}
+
+pub(crate) fn new_max_referral_fee(
+    e: &Env,
+    old: u32,
+    new: u32) {
+    
+    let event: NewMaxReferralFeeEvent = NewMaxReferralFeeEvent {
+        old: old,
+        new: new
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("ref_fee")), event);
+}
+
+
+// NEW STABLE PAIR CREATED EVENT: stbl_pair
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewStablePairEvent {
+    pub token_0: Address,
+    pub token_1: Address,
+    pub pair: Address,
+    pub amp: u32,
+    pub fee: u32,
+    pub new_pairs_length: u32
+}
+
+pub(crate) fn new_stable_pair(
+    e: &Env,
+    token_0: Address,
+    token_1: Address,
+    pair: Address,
+    amp: u32,
+    fee: u32,
+    new_pairs_length: u32) {
+
+    let event: NewStablePairEvent = NewStablePairEvent {
+        token_0: token_0,
+        token_1: token_1,
+        pair: pair,
+        amp: amp,
+        fee: fee,
+        new_pairs_length: new_pairs_length,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("stbl_pair")), event);
+}
+
+
+// NEW WEIGHTED PAIR CREATED EVENT: wght_pair
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewWeightedPairEvent {
+    pub token_0: Address,
+    pub token_1: Address,
+    pub pair: Address,
+    pub weight_0: u32,
+    pub fee: u32,
+    pub new_pairs_length: u32
+}
+
+pub(crate) fn new_weighted_pair(
+    e: &Env,
+    token_0: Address,
+    token_1: Address,
+    pair: Address,
+    weight_0: u32,
+    fee: u32,
+    new_pairs_length: u32) {
+
+    let event: NewWeightedPairEvent = NewWeightedPairEvent {
+        token_0: token_0,
+        token_1: token_1,
+        pair: pair,
+        weight_0: weight_0,
+        fee: fee,
+        new_pairs_length: new_pairs_length,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("wght_pair")), event);
+}
+
+
+// NEW CONCENTRATED PAIR CREATED EVENT: conc_pair
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewConcentratedPairEvent {
+    pub token_0: Address,
+    pub token_1: Address,
+    pub pair: Address,
+    pub fee: u32,
+    pub tick_spacing: u32,
+    pub sqrt_price: u128,
+    pub new_pairs_length: u32
+}
+
+pub(crate) fn new_concentrated_pair(
+    e: &Env,
+    token_0: Address,
+    token_1: Address,
+    pair: Address,
+    fee: u32,
+    tick_spacing: u32,
+    sqrt_price: u128,
+    new_pairs_length: u32) {
+
+    let event: NewConcentratedPairEvent = NewConcentratedPairEvent {
+        token_0: token_0,
+        token_1: token_1,
+        pair: pair,
+        fee: fee,
+        tick_spacing: tick_spacing,
+        sqrt_price: sqrt_price,
+        new_pairs_length: new_pairs_length,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("conc_pair")), event);
+}
+
+
+// NEW PAIR OF A REGISTERED KIND CREATED EVENT: kind_pair
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewKindPairEvent {
+    pub token_0: Address,
+    pub token_1: Address,
+    pub pair: Address,
+    pub kind: Symbol,
+    pub new_pairs_length: u32
+}
+
+pub(crate) fn new_kind_pair(
+    e: &Env,
+    token_0: Address,
+    token_1: Address,
+    pair: Address,
+    kind: Symbol,
+    new_pairs_length: u32) {
+
+    let event: NewKindPairEvent = NewKindPairEvent {
+        token_0: token_0,
+        token_1: token_1,
+        pair: pair,
+        kind: kind,
+        new_pairs_length: new_pairs_length,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("kind_pair")), event);
+}
+
+
+// NEW PAIR WASM HASH
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewPairWasmHashEvent {
+    pub old: BytesN<32>,
+    pub new: BytesN<32>
+}
+
+pub(crate) fn new_pair_wasm_hash(
+    e: &Env,
+    old: BytesN<32>,
+    new: BytesN<32>) {
+    
+    let event: NewPairWasmHashEvent = NewPairWasmHashEvent {
+        old: old,
+        new: new
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("pair_wasm")), event);
+}
+
+// NEW STABLE PAIR WASM HASH. There is no old hash the first time it is set
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewStablePairWasmHashEvent {
+    pub old: Option<BytesN<32>>,
+    pub new: BytesN<32>
+}
+
+pub(crate) fn new_stable_pair_wasm_hash(
+    e: &Env,
+    old: Option<BytesN<32>>,
+    new: BytesN<32>) {
+
+    let event: NewStablePairWasmHashEvent = NewStablePairWasmHashEvent {
+        old: old,
+        new: new
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("stbl_wasm")), event);
+}
+
+// NEW WEIGHTED PAIR WASM HASH. There is no old hash the first time it is set
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewWeightedPairWasmHashEvent {
+    pub old: Option<BytesN<32>>,
+    pub new: BytesN<32>
+}
+
+pub(crate) fn new_weighted_pair_wasm_hash(
+    e: &Env,
+    old: Option<BytesN<32>>,
+    new: BytesN<32>) {
+
+    let event: NewWeightedPairWasmHashEvent = NewWeightedPairWasmHashEvent {
+        old: old,
+        new: new
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("wght_wasm")), event);
+}
+
+// NEW CONCENTRATED PAIR WASM HASH. There is no old hash the first time it is set
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewConcentratedPairWasmHashEvent {
+    pub old: Option<BytesN<32>>,
+    pub new: BytesN<32>
+}
+
+pub(crate) fn new_concentrated_pair_wasm_hash(
+    e: &Env,
+    old: Option<BytesN<32>>,
+    new: BytesN<32>) {
+
+    let event: NewConcentratedPairWasmHashEvent = NewConcentratedPairWasmHashEvent {
+        old: old,
+        new: new
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("conc_wasm")), event);
+}
+
+// NEW POOL TEMPLATE. There is no old template the first time a kind is registered
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewPoolTemplateEvent {
+    pub kind: Symbol,
+    pub old: Option<PoolTemplate>,
+    pub new: PoolTemplate
+}
+
+pub(crate) fn new_pool_template(
+    e: &Env,
+    kind: Symbol,
+    old: Option<PoolTemplate>,
+    new: PoolTemplate) {
+
+    let event: NewPoolTemplateEvent = NewPoolTemplateEvent {
+        kind: kind,
+        old: old,
+        new: new
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("pool_tmpl")), event);
+}
+
+
+
+// FACTORY UPGRADED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeEvent {
+    pub new_wasm_hash: BytesN<32>
+}
+
+pub(crate) fn upgrade(
+    e: &Env,
+    new_wasm_hash: BytesN<32>) {
+    
+    let event: UpgradeEvent = UpgradeEvent {
+        new_wasm_hash: new_wasm_hash
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("upgrade")), event);
+}
+
+
+
+// FACTORY STORAGE MIGRATED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrateEvent {
+    pub old_version: u32,
+    pub new_version: u32
+}
+
+pub(crate) fn migrate(
+    e: &Env,
+    old_version: u32,
+    new_version: u32) {
+    
+    let event: MigrateEvent = MigrateEvent {
+        old_version: old_version,
+        new_version: new_version
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("migrate")), event);
+}
+
+
+
+// PAIR UPGRADED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradePairEvent {
+    pub pair: Address,
+    pub new_wasm_hash: BytesN<32>,
+    pub storage_version: u32
+}
+
+pub(crate) fn upgrade_pair(
+    e: &Env,
+    pair: Address,
+    new_wasm_hash: BytesN<32>,
+    storage_version: u32) {
+    
+    let event: UpgradePairEvent = UpgradePairEvent {
+        pair: pair,
+        new_wasm_hash: new_wasm_hash,
+        storage_version: storage_version
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("upg_pair")), event);
+}
+
+
+
+// NEW GUARDIAN
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewGuardianEvent {
+    pub old: Address,
+    pub new: Address
+}
+
+pub(crate) fn new_guardian(
+    e: &Env,
+    old: Address,
+    new: Address) {
+    
+    let event: NewGuardianEvent = NewGuardianEvent {
+        old: old,
+        new: new
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("guardian")), event);
+}
+
+
+
+// ALL PAIRS PAUSED OR UNPAUSED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PausedEvent {
+    pub is_paused: bool
+}
+
+pub(crate) fn paused(
+    e: &Env,
+    is_paused: bool) {
+    
+    let event: PausedEvent = PausedEvent {
+        is_paused: is_paused
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("paused")), event);
+}
+
+
+
+// ONE PAIR PAUSED OR UNPAUSED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PairPausedEvent {
+    pub token_0: Address,
+    pub token_1: Address,
+    pub pair: Address,
+    pub is_paused: bool
+}
+
+pub(crate) fn pair_paused(
+    e: &Env,
+    token_0: Address,
+    token_1: Address,
+    pair: Address,
+    is_paused: bool) {
+    
+    let event: PairPausedEvent = PairPausedEvent {
+        token_0: token_0,
+        token_1: token_1,
+        pair: pair,
+        is_paused: is_paused
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("pair_paus")), event);
+}
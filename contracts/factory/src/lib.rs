@@ -8,12 +8,38 @@ mod storage;
 use soroban_sdk::{
     contract,
     contractimpl,
-    Address, BytesN, Env,
+    panic_with_error,
+    vec,
+    Address, BytesN, Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+};
+use soroswap_factory_interface::{
+    SoroswapFactoryTrait,
+    FactoryError,
+    PoolTemplate,
+    CONSTANT_PRODUCT_KIND,
+    STABLE_KIND,
+    WEIGHTED_KIND,
+    CONCENTRATED_KIND,
 };
-use soroswap_factory_interface::{SoroswapFactoryTrait, FactoryError};
 use pair::{create_contract, Pair, PairError};
 use storage::*;
 
+// Maximum swap fee that can be set for a pair, in basis points (10%)
+const MAXIMUM_PAIR_FEE: u32 = 1_000;
+// Maximum referral fee the router can be allowed to charge, in basis points (10%)
+const MAXIMUM_REFERRAL_FEE: u32 = 1_000;
+// Bounds of the amplification coefficient of the stable pairs, the same the stable pair checks
+const MINIMUM_STABLE_AMP: u32 = 1;
+const MAXIMUM_STABLE_AMP: u32 = 1_000_000;
+// Bounds of the weight of each token of the weighted pairs in basis points, the same the weighted pair checks
+const MINIMUM_WEIGHT: u32 = 100;
+const MAXIMUM_WEIGHT: u32 = 9_900;
+const WEIGHT_DENOMINATOR: u32 = 10_000;
+// Bounds of the tick spacing and of the initial square root price of the concentrated pairs, the same the concentrated pair checks
+const MAXIMUM_TICK_SPACING: u32 = 16_383;
+const MINIMUM_SQRT_PRICE: u128 = 4_295_048_016;
+const MAXIMUM_SQRT_PRICE: u128 = 79_226_673_521_066_979_257_578_248_091;
+
 impl From<PairError> for FactoryError {
     fn from(pair_error: PairError) -> Self {
         match pair_error {
@@ -23,6 +49,124 @@ impl From<PairError> for FactoryError {
     }
 }
 
+// The pairs of these built-in kinds can not be initialized without arguments, their templates only have them if
+// they were given with set_pool_template. The constant product template always has its fee, except the one migrated
+// from a factory of version 0, which still deploys the pairs of that version
+fn needs_init_args(kind: &Symbol) -> bool {
+    kind == &STABLE_KIND || kind == &WEIGHTED_KIND || kind == &CONCENTRATED_KIND
+}
+
+fn is_built_in_kind(kind: &Symbol) -> bool {
+    kind == &CONSTANT_PRODUCT_KIND || needs_init_args(kind)
+}
+
+// Deploys the pair of the given kind from its template and initializes it with the factory, the tokens and
+// `init_args`, or the init args of the template if there are none. Every pair is created through here
+fn deploy_pair(e: &Env, token_pair: &Pair, kind: &Symbol, init_args: Option<Vec<Val>>) -> Result<Address, FactoryError> {
+    if get_pair_exists_by_variant(e, token_pair.clone(), kind.clone()) {
+        return Err(FactoryError::CreatePairAlreadyExists);
+    }
+
+    let template = get_pool_template(e, kind.clone())?;
+    let init_args = init_args.unwrap_or(template.init_args);
+    if init_args.is_empty() && needs_init_args(kind) {
+        return Err(FactoryError::PoolTemplateMissingInitArgs);
+    }
+    let pair_address = create_contract(e, template.wasm_hash, token_pair.salt(e, kind));
+
+    let mut args: Vec<Val> = vec![
+        e,
+        e.current_contract_address().into_val(e),
+        token_pair.token_0().into_val(e),
+        token_pair.token_1().into_val(e),
+    ];
+    args.append(&init_args);
+    e.invoke_contract::<()>(&pair_address, &Symbol::new(e, "initialize"), args);
+
+    put_pair_address_by_variant(e, token_pair.clone(), kind.clone(), &pair_address);
+    add_pair_to_all_pairs(e, &pair_address, kind);
+    Ok(pair_address)
+}
+
+fn get_init_arg<T: TryFromVal<Env, Val>>(e: &Env, init_args: &Vec<Val>, index: u32) -> Result<T, FactoryError> {
+    let arg = init_args.get(index).ok_or(FactoryError::PoolTemplateInvalidInitArgs)?;
+    T::try_from_val(e, &arg).map_err(|_| FactoryError::PoolTemplateInvalidInitArgs)
+}
+
+// Checks the init args of a template against the `initialize` of its pair, with the same bounds as the create
+// functions. A registered kind is checked like the built-in kind whose wasm it uses, as the fee tiers do
+fn check_init_args(e: &Env, kind: &Symbol, template: &PoolTemplate) -> Result<(), FactoryError> {
+    let mut pair_kind = kind.clone();
+    if !is_built_in_kind(kind) {
+        for built_in_kind in [CONSTANT_PRODUCT_KIND, STABLE_KIND, WEIGHTED_KIND, CONCENTRATED_KIND] {
+            if matches!(get_pool_template(e, built_in_kind.clone()), Ok(built_in) if built_in.wasm_hash == template.wasm_hash) {
+                pair_kind = built_in_kind;
+                break;
+            }
+        }
+        if !is_built_in_kind(&pair_kind) {
+            return Ok(());
+        }
+    }
+
+    let init_args = &template.init_args;
+    // The pairs of these kinds get their arguments from the create functions when the template has none
+    if init_args.is_empty() && needs_init_args(kind) {
+        return Ok(());
+    }
+    let fee: u32 = if pair_kind == CONSTANT_PRODUCT_KIND {
+        if init_args.len() != 1 {
+            return Err(FactoryError::PoolTemplateInvalidInitArgs);
+        }
+        get_init_arg(e, init_args, 0)?
+    } else if pair_kind == STABLE_KIND {
+        if init_args.len() != 2 {
+            return Err(FactoryError::PoolTemplateInvalidInitArgs);
+        }
+        let amp: u32 = get_init_arg(e, init_args, 0)?;
+        if amp < MINIMUM_STABLE_AMP || amp > MAXIMUM_STABLE_AMP {
+            return Err(FactoryError::CreateStablePairInvalidAmp);
+        }
+        get_init_arg(e, init_args, 1)?
+    } else if pair_kind == WEIGHTED_KIND {
+        if init_args.len() != 2 {
+            return Err(FactoryError::PoolTemplateInvalidInitArgs);
+        }
+        // The weight of token_1 is the rest, so both are in range
+        let weight_0: u32 = get_init_arg(e, init_args, 0)?;
+        if weight_0 < MINIMUM_WEIGHT || weight_0 > MAXIMUM_WEIGHT {
+            return Err(FactoryError::CreateWeightedPairInvalidWeights);
+        }
+        get_init_arg(e, init_args, 1)?
+    } else {
+        if init_args.len() != 3 {
+            return Err(FactoryError::PoolTemplateInvalidInitArgs);
+        }
+        let tick_spacing: u32 = get_init_arg(e, init_args, 1)?;
+        if tick_spacing == 0 || tick_spacing > MAXIMUM_TICK_SPACING {
+            return Err(FactoryError::CreateConcentratedPairInvalidTickSpacing);
+        }
+        let sqrt_price_0: u128 = get_init_arg(e, init_args, 2)?;
+        if sqrt_price_0 < MINIMUM_SQRT_PRICE || sqrt_price_0 >= MAXIMUM_SQRT_PRICE {
+            return Err(FactoryError::CreateConcentratedPairInvalidPrice);
+        }
+        get_init_arg(e, init_args, 0)?
+    };
+    if fee > MAXIMUM_PAIR_FEE {
+        return Err(FactoryError::SetPairFeeInvalidFee);
+    }
+    Ok(())
+}
+
+// Every pair type has the same `fee`, so the client of SoroswapPair can call all of them. The pairs created before
+// the fee could be set have no `fee` and charge the default one
+fn get_fee_of_pair(e: &Env, pair_address: &Address) -> u32 {
+    match pair::Client::new(e, pair_address).try_fee() {
+        Ok(Ok(fee)) => fee,
+        _ => DEFAULT_PAIR_FEE,
+    }
+}
+
 
 #[contract]
 struct SoroswapFactory;
@@ -100,7 +244,7 @@ fn all_pairs_length(e: Env) -> Result<u32, FactoryError> {
     Ok(get_total_pairs(&e))
 }
 
-/// Returns the address of the pair for `token_a` and `token_b`, if it has been created.
+/// Returns the address of the constant product pair for `token_a` and `token_b`, if it has been created.
 /// 
 /// # Arguments
 /// 
@@ -117,10 +261,31 @@ fn get_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, Facto
     }
     extend_instance_ttl(&e);
     let token_pair = Pair::new(token_a, token_b)?;
-    get_pair_address_by_token_pair(&e, token_pair)
+    get_pair_address_by_variant(&e, token_pair, CONSTANT_PRODUCT_KIND)
+}
+
+/// Returns the address of the pair of the given `kind` for `token_a` and `token_b`, if it has been created.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `kind` - The kind of the pair, either a built-in kind or a kind registered with `set_pool_template`.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the pair does not exist
+fn get_pair_with_kind(e: Env, token_a: Address, token_b: Address, kind: Symbol) -> Result<Address, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    let token_pair = Pair::new(token_a, token_b)?;
+    get_pair_address_by_variant(&e, token_pair, kind)
 }
 
-/// Returns the address of the nth pair (0-indexed) created through the factory, or address(0) if not enough pairs have been created yet.
+/// Returns the address of the nth pair (0-indexed) created through the factory.
 /// 
 /// # Arguments
 /// 
@@ -135,10 +300,28 @@ fn all_pairs(e: Env, n: u32) -> Result<Address, FactoryError> {
         return Err(FactoryError::NotInitialized);
     }
     extend_instance_ttl(&e);
-    get_all_pairs(e,n)
+    get_all_pairs(&e, n)
+}
+
+/// Returns the address and the kind of the nth pair (0-indexed) created through the factory.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `n` - The index of the pair to retrieve.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if index `n` does not exist.
+fn all_pairs_with_kind(e: Env, n: u32) -> Result<(Address, Symbol), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    get_all_pairs_with_kind(&e, n)
 }
 
-/// Checks if a pair exists for the given `token_a` and `token_b`.
+/// Checks if a constant product pair exists for the given `token_a` and `token_b`.
 /// 
 /// # Arguments
 /// 
@@ -158,110 +341,173 @@ fn pair_exists(e: Env, token_a: Address, token_b: Address) -> Result<bool, Facto
     let token_pair = Pair::new(token_a, token_b)?;
     
     // Proceed with the existence check
-    Ok(get_pair_exists(&e, token_pair))
+    Ok(get_pair_exists_by_variant(&e, token_pair, CONSTANT_PRODUCT_KIND))
 }
 
+/// Returns the swap fee, in basis points, of the `variant` pair for `token_a` and `token_b`.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `variant` - The variant of the pair, as returned by `pair_variants`.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the pair does not exist.
+fn pair_fee(e: Env, token_a: Address, token_b: Address, variant: Symbol) -> Result<u32, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
 
-/* *** State-Changing Functions: *** */
+    let token_pair = Pair::new(token_a, token_b)?;
+    let pair_address = get_pair_address_by_variant(&e, token_pair, variant)?;
+    Ok(get_fee_of_pair(&e, &pair_address))
+}
 
-/// Sets the `fee_to_setter` address and initializes the factory.
+/// Returns the fraction of the liquidity providers fees that goes to `fee_to`, as `(numerator, denominator)`.
 /// 
 /// # Arguments
 /// 
 /// * `e` - An instance of the `Env` struct.
-/// * `setter` - The address to set as the current `fee_to_setter`.
-/// * `pair_wasm_hash` - The Wasm hash of the pair.
 /// 
 /// # Errors
 /// 
-/// Returns an error if the Factory is already initialized.
-fn initialize(e: Env, setter: Address, pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
-    if has_total_pairs(&e) {
-        return Err(FactoryError::InitializeAlreadyInitialized);
+/// Returns an error if the Factory is not yet initialized.
+fn protocol_fee_fraction(e: Env) -> Result<(u32, u32), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
     }
-    put_fee_to_setter(&e, &setter);
-    put_fee_to(&e, setter.clone());
-    put_pair_wasm_hash(&e, pair_wasm_hash);
-    put_total_pairs(&e, 0);
-    event::initialized(&e, setter);
     extend_instance_ttl(&e);
-    Ok(())
+    Ok(get_protocol_fee_fraction(&e))
 }
 
-/// Sets the `fee_to` address.
+/// Returns the maximum referral fee, in basis points, that the router lets integrators charge on a swap.
 /// 
 /// # Arguments
 /// 
 /// * `e` - An instance of the `Env` struct.
-/// * `to` - The address to set as the `fee_to`.
 /// 
 /// # Errors
 /// 
-/// Returns an error if the Factory is not yet initialized or if the caller is not the current `fee_to_setter`.
-fn set_fee_to(e: Env, to: Address) -> Result<(), FactoryError> {
+/// Returns an error if the Factory is not yet initialized.
+fn max_referral_fee(e: Env) -> Result<u32, FactoryError> {
     if !has_total_pairs(&e) {
         return Err(FactoryError::NotInitialized);
     }
-    
     extend_instance_ttl(&e);
-    let setter = get_fee_to_setter(&e);
-    setter.require_auth();
-
-    let old = get_fee_to(&e);
-    put_fee_to(&e, to.clone());
-    event::new_fee_to(&e, setter, old, to);
-    Ok(())
+    Ok(get_max_referral_fee(&e))
 }
 
-/// Sets the `fee_to_setter` address.
+/// Returns the address allowed to pause and unpause the pairs.
 /// 
 /// # Arguments
 /// 
 /// * `e` - An instance of the `Env` struct.
-/// * `new_setter` - The address to set as the new `fee_to_setter`.
 /// 
 /// # Errors
 /// 
-/// Returns an error if the Factory is not yet initialized or if the caller is not the existing `fee_to_setter`.
-fn set_fee_to_setter(e: Env, new_setter: Address) -> Result<(), FactoryError> {
+/// Returns an error if the Factory is not yet initialized.
+fn guardian(e: Env) -> Result<Address, FactoryError> {
     if !has_total_pairs(&e) {
         return Err(FactoryError::NotInitialized);
     }
+    extend_instance_ttl(&e);
+    Ok(get_guardian(&e))
+}
 
+/// Checks if all the pairs have been paused by the guardian.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn paused(e: Env) -> Result<bool, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
     extend_instance_ttl(&e);
-    let setter = get_fee_to_setter(&e);
-    setter.require_auth();
+    Ok(get_paused(&e))
+}
 
-    put_fee_to_setter(&e, &new_setter);
-    event::new_setter(&e, setter, new_setter);
-    Ok(())
+/// Returns the Wasm hash used to deploy new pairs.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn pair_wasm_hash(e: Env) -> Result<BytesN<32>, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    get_pair_wasm_hash(&e)
 }
 
-/// Sets whether fees are enabled or disabled.
+/// Returns the version of the storage layout of the factory.
 /// 
 /// # Arguments
 /// 
 /// * `e` - An instance of the `Env` struct.
-/// * `is_enabled` - A boolean indicating whether fees are enabled or disabled.
 /// 
 /// # Errors
 /// 
-/// Returns an error if the Factory is not yet initialized or if the caller is not the current `fee_to_setter`.
-fn set_fees_enabled(e: Env, is_enabled: bool) -> Result<(), FactoryError> {
+/// Returns an error if the Factory is not yet initialized.
+fn storage_version(e: Env) -> Result<u32, FactoryError> {
     if !has_total_pairs(&e) {
         return Err(FactoryError::NotInitialized);
     }
+    extend_instance_ttl(&e);
+    Ok(get_storage_version(&e))
+}
 
+
+/// Returns the address of the stable pair for `token_a` and `token_b`, if it has been created.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the stable pair does not exist.
+fn get_stable_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
     extend_instance_ttl(&e);
-    let setter = get_fee_to_setter(&e);
-    setter.require_auth();
+    let token_pair = Pair::new(token_a, token_b)?;
+    get_pair_address_by_variant(&e, token_pair, STABLE_KIND)
+}
 
-    put_fees_enabled(&e, &is_enabled);
-    event::new_fees_enabled(&e, is_enabled);
-    Ok(())
+/// Returns the Wasm hash used to deploy new stable pairs.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the stable pair wasm hash has not been set.
+fn stable_pair_wasm_hash(e: Env) -> Result<BytesN<32>, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    get_stable_pair_wasm_hash(&e)
 }
 
-/// Creates a pair for `token_a` and `token_b` if one doesn't exist already.
+/// Returns the address of the weighted pair for `token_a` and `token_b`, if it has been created.
 /// 
 /// # Arguments
 /// 
@@ -271,34 +517,865 @@ fn set_fees_enabled(e: Env, is_enabled: bool) -> Result<(), FactoryError> {
 /// 
 /// # Errors
 /// 
-/// Returns an error if the pair is not yet initialized, if `token_a` and `token_b` have identical addresses, or if the pair already exists between `token_a` and `token_b`.
-fn create_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError> {
+/// Returns an error if the Factory is not yet initialized or if the weighted pair does not exist.
+fn get_weighted_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError> {
     if !has_total_pairs(&e) {
         return Err(FactoryError::NotInitialized);
     }
-
     extend_instance_ttl(&e);
     let token_pair = Pair::new(token_a, token_b)?;
+    get_pair_address_by_variant(&e, token_pair, WEIGHTED_KIND)
+}
 
-    if get_pair_exists(&e, token_pair.clone()) {
-        return Err(FactoryError::CreatePairAlreadyExists);
+/// Returns the Wasm hash used to deploy new weighted pairs.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the weighted pair wasm hash has not been set.
+fn weighted_pair_wasm_hash(e: Env) -> Result<BytesN<32>, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
     }
+    extend_instance_ttl(&e);
+    get_weighted_pair_wasm_hash(&e)
+}
 
-    let pair_wasm_hash = get_pair_wasm_hash(&e)?;
-    let pair_address = create_contract(&e, pair_wasm_hash, &token_pair);
+/// Returns the address of the concentrated pair for `token_a` and `token_b`, if it has been created.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the concentrated pair does not exist.
+fn get_concentrated_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    let token_pair = Pair::new(token_a, token_b)?;
+    get_pair_address_by_variant(&e, token_pair, CONCENTRATED_KIND)
+}
 
-    pair::Client::new(&e, &pair_address).initialize(
-        &e.current_contract_address(),
-        &token_pair.token_0(), 
-        &token_pair.token_1()
-    );
+/// Returns the Wasm hash used to deploy new concentrated pairs.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the concentrated pair wasm hash has not been set.
+fn concentrated_pair_wasm_hash(e: Env) -> Result<BytesN<32>, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    get_concentrated_pair_wasm_hash(&e)
+}
 
-    put_pair_address_by_token_pair(&e, token_pair.clone(), &pair_address);
-    add_pair_to_all_pairs(&e, &pair_address);
+/// Returns the kinds of pairs that have a template, in registration order: the constant product kind first,
+/// then the other built-in kinds as their wasm hashes are set and the kinds registered with `set_pool_template`.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn pool_kinds(e: Env) -> Result<Vec<Symbol>, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    Ok(get_pool_kinds(&e))
+}
 
-    event::new_pair(&e, token_pair.token_0().clone(), token_pair.token_1().clone(), pair_address.clone(), get_total_pairs(&e));
+/// Returns the template used to deploy the pairs of the given `kind`. The templates of the built-in kinds
+/// have the wasm hashes set with `initialize` and the `set_*_wasm_hash` functions.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `kind` - The kind of pool.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if `kind` has no template.
+fn pool_template(e: Env, kind: Symbol) -> Result<PoolTemplate, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    get_pool_template(&e, kind)
+}
 
-    Ok(pair_address)
+/// Returns every variant of pair created for `token_a` and `token_b`, each one being the kind of the pair.
+/// The built-in kinds come first, followed by the registered kinds in creation order.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if `token_a` and `token_b` are identical.
+fn pair_variants(e: Env, token_a: Address, token_b: Address) -> Result<Vec<Symbol>, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    let token_pair = Pair::new(token_a, token_b)?;
+    Ok(get_pair_variants(&e, token_pair))
+}
+
+/// Returns the variant and the address of every pair created for `token_a` and `token_b`, in the order
+/// of `pair_variants`.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if `token_a` and `token_b` are identical.
+fn get_pairs(e: Env, token_a: Address, token_b: Address) -> Result<Vec<(Symbol, Address)>, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    let token_pair = Pair::new(token_a, token_b)?;
+    let mut pairs = Vec::new(&e);
+    for variant in get_pair_variants(&e, token_pair.clone()).iter() {
+        let pair_address = get_pair_address_by_variant(&e, token_pair.clone(), variant.clone())?;
+        pairs.push_back((variant, pair_address));
+    }
+    Ok(pairs)
+}
+
+
+/* *** State-Changing Functions: *** */
+
+/// Sets the `fee_to_setter` address and initializes the factory.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `setter` - The address to set as the current `fee_to_setter`.
+/// * `pair_wasm_hash` - The Wasm hash of the pair.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is already initialized.
+fn initialize(e: Env, setter: Address, pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+    if has_total_pairs(&e) {
+        return Err(FactoryError::InitializeAlreadyInitialized);
+    }
+    put_fee_to_setter(&e, &setter);
+    put_fee_to(&e, setter.clone());
+    put_guardian(&e, &setter);
+    put_pool_template(&e, CONSTANT_PRODUCT_KIND, &PoolTemplate { wasm_hash: pair_wasm_hash, init_args: vec![&e, DEFAULT_PAIR_FEE.into_val(&e)] });
+    put_total_pairs(&e, 0);
+    put_storage_version(&e, STORAGE_VERSION);
+    event::initialized(&e, setter);
+    extend_instance_ttl(&e);
+    Ok(())
+}
+
+/// Sets the `fee_to` address.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `to` - The address to set as the `fee_to`.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the current `fee_to_setter`.
+fn set_fee_to(e: Env, to: Address) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    let old = get_fee_to(&e);
+    put_fee_to(&e, to.clone());
+    event::new_fee_to(&e, setter, old, to);
+    Ok(())
+}
+
+/// Sets the `fee_to_setter` address.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `new_setter` - The address to set as the new `fee_to_setter`.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the existing `fee_to_setter`.
+fn set_fee_to_setter(e: Env, new_setter: Address) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    put_fee_to_setter(&e, &new_setter);
+    event::new_setter(&e, setter, new_setter);
+    Ok(())
+}
+
+/// Sets whether fees are enabled or disabled.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `is_enabled` - A boolean indicating whether fees are enabled or disabled.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the current `fee_to_setter`.
+fn set_fees_enabled(e: Env, is_enabled: bool) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    put_fees_enabled(&e, &is_enabled);
+    event::new_fees_enabled(&e, is_enabled);
+    Ok(())
+}
+
+/// Creates a constant product pair for `token_a` and `token_b` if one doesn't exist already.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if `token_a` and `token_b` have identical addresses,
+/// or if the pair already exists.
+fn create_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError> {
+    Self::create_pair_with_kind(e, token_a, token_b, CONSTANT_PRODUCT_KIND)
+}
+
+/// Creates a pair of the given `kind` for `token_a` and `token_b` from the template of that kind, if one doesn't
+/// exist already. Constant product pairs keep the salt they always had, and the pairs of the other kinds add the
+/// kind to it, so each kind gets its own deterministic address.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `kind` - Any kind with a template, built-in or registered with `set_pool_template`.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if `kind` has no template, if the template of a
+/// built-in kind that needs arguments has none, if `token_a` and `token_b` have identical addresses, or if the
+/// pair of that kind already exists.
+fn create_pair_with_kind(e: Env, token_a: Address, token_b: Address, kind: Symbol) -> Result<Address, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let token_pair = Pair::new(token_a, token_b)?;
+    let pair_address = deploy_pair(&e, &token_pair, &kind, None)?;
+
+    if kind == CONSTANT_PRODUCT_KIND {
+        event::new_pair(&e, token_pair.token_0().clone(), token_pair.token_1().clone(), pair_address.clone(), get_total_pairs(&e));
+    } else {
+        event::new_kind_pair(&e, token_pair.token_0().clone(), token_pair.token_1().clone(), pair_address.clone(), kind, get_total_pairs(&e));
+    }
+
+    Ok(pair_address)
+}
+
+/// Creates a StableSwap pair for `token_a` and `token_b` if one doesn't exist already. The stable pair is deployed
+/// at a different address than the constant product pair of the same tokens, and both can exist at the same time.
+/// Only the `fee_to_setter` can create stable pairs, as their amplification coefficient and fee are permanent.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `amp` - The amplification coefficient of the pair, between 1 and 1_000_000.
+/// * `fee` - The swap fee of the pair, in basis points.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the current `fee_to_setter`,
+/// if the stable pair wasm hash has not been set, if `token_a` and `token_b` have identical addresses, if the
+/// stable pair already exists, or if the amplification coefficient or the fee are out of range.
+fn create_stable_pair(e: Env, token_a: Address, token_b: Address, amp: u32, fee: u32) -> Result<Address, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    if amp < MINIMUM_STABLE_AMP || amp > MAXIMUM_STABLE_AMP {
+        return Err(FactoryError::CreateStablePairInvalidAmp);
+    }
+    if fee > MAXIMUM_PAIR_FEE {
+        return Err(FactoryError::SetPairFeeInvalidFee);
+    }
+
+    let token_pair = Pair::new(token_a, token_b)?;
+    let init_args = vec![&e, amp.into_val(&e), fee.into_val(&e)];
+    let pair_address = deploy_pair(&e, &token_pair, &STABLE_KIND, Some(init_args))?;
+
+    event::new_stable_pair(&e, token_pair.token_0().clone(), token_pair.token_1().clone(), pair_address.clone(), amp, fee, get_total_pairs(&e));
+
+    Ok(pair_address)
+}
+
+/// Creates a weighted pair for `token_a` and `token_b` if one doesn't exist already. The weighted pair is deployed
+/// at a different address than the other kinds of pairs of the same tokens, and all of them can exist at the same time.
+/// Only the `fee_to_setter` can create weighted pairs, as their weights and fee are permanent.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `weight_a` - The weight of `token_a`, in basis points between 100 and 9_900. `token_b` gets the rest.
+/// * `fee` - The swap fee of the pair, in basis points.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the current `fee_to_setter`,
+/// if the weighted pair wasm hash has not been set, if `token_a` and `token_b` have identical addresses, if the
+/// weighted pair already exists, or if the weight or the fee are out of range.
+fn create_weighted_pair(e: Env, token_a: Address, token_b: Address, weight_a: u32, fee: u32) -> Result<Address, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    if weight_a < MINIMUM_WEIGHT || weight_a > MAXIMUM_WEIGHT {
+        return Err(FactoryError::CreateWeightedPairInvalidWeights);
+    }
+    if fee > MAXIMUM_PAIR_FEE {
+        return Err(FactoryError::SetPairFeeInvalidFee);
+    }
+
+    let token_pair = Pair::new(token_a.clone(), token_b)?;
+
+    // The pair stores the weight of its first token, which is token_a only if it sorts first
+    let weight_0 = if &token_a == token_pair.token_0() { weight_a } else { WEIGHT_DENOMINATOR - weight_a };
+
+    let init_args = vec![&e, weight_0.into_val(&e), fee.into_val(&e)];
+    let pair_address = deploy_pair(&e, &token_pair, &WEIGHTED_KIND, Some(init_args))?;
+
+    event::new_weighted_pair(&e, token_pair.token_0().clone(), token_pair.token_1().clone(), pair_address.clone(), weight_0, fee, get_total_pairs(&e));
+
+    Ok(pair_address)
+}
+
+/// Creates a concentrated liquidity pair for `token_a` and `token_b` if one doesn't exist already. It lives
+/// alongside the other kinds of pairs of the same tokens. Only the `fee_to_setter` can create concentrated pairs.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `fee` - The swap fee of the pair, in basis points.
+/// * `tick_spacing` - The distance between the ticks where positions can start or end.
+/// * `sqrt_price` - The square root of the initial price of `token_a` in `token_b`, as a Q64.64 number.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the current `fee_to_setter`,
+/// if the concentrated pair wasm hash has not been set, if `token_a` and `token_b` have identical addresses, if the
+/// concentrated pair already exists, or if the fee, the tick spacing or the price are out of range.
+fn create_concentrated_pair(e: Env, token_a: Address, token_b: Address, fee: u32, tick_spacing: u32, sqrt_price: u128) -> Result<Address, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    if fee > MAXIMUM_PAIR_FEE {
+        return Err(FactoryError::SetPairFeeInvalidFee);
+    }
+    if tick_spacing == 0 || tick_spacing > MAXIMUM_TICK_SPACING {
+        return Err(FactoryError::CreateConcentratedPairInvalidTickSpacing);
+    }
+
+    let token_pair = Pair::new(token_a.clone(), token_b)?;
+
+    // The pair stores the price of its first token, so the price of token_a is inverted when it sorts second.
+    // 2^128 / sqrt_price does not fit in a u128, and u128::MAX / sqrt_price is at most one unit below it
+    let sqrt_price_0 = if &token_a == token_pair.token_0() { sqrt_price } else { u128::MAX.checked_div(sqrt_price).unwrap_or(0) };
+    if sqrt_price_0 < MINIMUM_SQRT_PRICE || sqrt_price_0 >= MAXIMUM_SQRT_PRICE {
+        return Err(FactoryError::CreateConcentratedPairInvalidPrice);
+    }
+
+    let init_args = vec![&e, fee.into_val(&e), tick_spacing.into_val(&e), sqrt_price_0.into_val(&e)];
+    let pair_address = deploy_pair(&e, &token_pair, &CONCENTRATED_KIND, Some(init_args))?;
+
+    event::new_concentrated_pair(&e, token_pair.token_0().clone(), token_pair.token_1().clone(), pair_address.clone(), fee, tick_spacing, sqrt_price_0, get_total_pairs(&e));
+
+    Ok(pair_address)
+}
+
+/// Sets the swap fee of the `variant` pair for `token_a` and `token_b`. Only the constant product pairs of the
+/// "cp" variant can change their fee, the other variants, fee tiers included, keep the fee they were created with.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `variant` - The variant of the pair, as returned by `pair_variants`.
+/// * `fee` - The new swap fee, in basis points.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the current `fee_to_setter`,
+/// if the pair does not exist, if the fee is greater than the maximum allowed or if the pair can not change its fee,
+/// which is the case of every variant other than "cp" and of the pairs created before the fee could be set.
+fn set_pair_fee(e: Env, token_a: Address, token_b: Address, variant: Symbol, fee: u32) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    if fee > MAXIMUM_PAIR_FEE {
+        return Err(FactoryError::SetPairFeeInvalidFee);
+    }
+
+    let token_pair = Pair::new(token_a, token_b)?;
+    let pair_address = get_pair_address_by_variant(&e, token_pair.clone(), variant.clone())?;
+    // The fee of the other variants is part of what the variant stands for, like the fee tiers, so it can not change
+    if variant != CONSTANT_PRODUCT_KIND {
+        return Err(FactoryError::SetPairFeeNotSupported);
+    }
+
+    let old = get_fee_of_pair(&e, &pair_address);
+    if pair::Client::new(&e, &pair_address).try_set_fee(&fee).is_err() {
+        return Err(FactoryError::SetPairFeeNotSupported);
+    }
+
+    event::new_pair_fee(&e, token_pair.token_0().clone(), token_pair.token_1().clone(), pair_address, old, fee);
+    Ok(())
+}
+
+/// Sets the fraction of the liquidity providers fees that goes to `fee_to` when fees are enabled.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `numerator` - The numerator of the fraction.
+/// * `denominator` - The denominator of the fraction.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the current `fee_to_setter`,
+/// or if the fraction is not greater than 0 and less than 1.
+fn set_protocol_fee_fraction(e: Env, numerator: u32, denominator: u32) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    if numerator == 0 || numerator >= denominator {
+        return Err(FactoryError::SetProtocolFeeFractionInvalid);
+    }
+
+    let old = get_protocol_fee_fraction(&e);
+    put_protocol_fee_fraction(&e, &(numerator, denominator));
+    event::new_protocol_fee_fraction(&e, old, (numerator, denominator));
+    Ok(())
+}
+
+/// Sets the maximum referral fee that the router lets integrators charge on a swap.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `max_fee` - The new maximum referral fee, in basis points.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the current `fee_to_setter`,
+/// or if `max_fee` is greater than the maximum allowed.
+fn set_max_referral_fee(e: Env, max_fee: u32) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    if max_fee > MAXIMUM_REFERRAL_FEE {
+        return Err(FactoryError::SetMaxReferralFeeInvalid);
+    }
+
+    let old = get_max_referral_fee(&e);
+    put_max_referral_fee(&e, &max_fee);
+    event::new_max_referral_fee(&e, old, max_fee);
+    Ok(())
+}
+
+/// Sets the `guardian` address.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `new_guardian` - The address to set as the new `guardian`.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the current `fee_to_setter`.
+fn set_guardian(e: Env, new_guardian: Address) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    let old = get_guardian(&e);
+    put_guardian(&e, &new_guardian);
+    event::new_guardian(&e, old, new_guardian);
+    Ok(())
+}
+
+/// Pauses or unpauses deposits and swaps in all the pairs. Withdraws are never paused.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `is_paused` - A boolean indicating whether the pairs are paused.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the current `guardian`.
+fn set_paused(e: Env, is_paused: bool) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let guardian = get_guardian(&e);
+    guardian.require_auth();
+
+    put_paused(&e, &is_paused);
+    event::paused(&e, is_paused);
+    Ok(())
+}
+
+/// Pauses or unpauses deposits and swaps in the `variant` pair for `token_a` and `token_b`. Withdraws are never paused.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `variant` - The variant of the pair, as returned by `pair_variants`.
+/// * `is_paused` - A boolean indicating whether the pair is paused.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the current `guardian`,
+/// or if the pair does not exist.
+fn set_pair_paused(e: Env, token_a: Address, token_b: Address, variant: Symbol, is_paused: bool) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let guardian = get_guardian(&e);
+    guardian.require_auth();
+
+    let token_pair = Pair::new(token_a, token_b)?;
+    let pair_address = get_pair_address_by_variant(&e, token_pair.clone(), variant)?;
+    // Every pair type has the same `set_paused`, so the client of SoroswapPair can call all of them
+    pair::Client::new(&e, &pair_address).set_paused(&is_paused);
+
+    event::pair_paused(&e, token_pair.token_0().clone(), token_pair.token_1().clone(), pair_address, is_paused);
+    Ok(())
+}
+
+/// Sets the Wasm hash of the template of the constant product pairs. Existing pairs are not affected, use
+/// `upgrade_pair` for them.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `pair_wasm_hash` - The Wasm hash of the new SoroswapPair contract.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the current `fee_to_setter`.
+fn set_pair_wasm_hash(e: Env, pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    let old = get_pool_template(&e, CONSTANT_PRODUCT_KIND)?;
+    // The template migrated from version 0 has no fee, the new pairs start with the default one
+    let init_args = if old.init_args.is_empty() { vec![&e, DEFAULT_PAIR_FEE.into_val(&e)] } else { old.init_args };
+    put_pool_template(&e, CONSTANT_PRODUCT_KIND, &PoolTemplate { wasm_hash: pair_wasm_hash.clone(), init_args });
+    let old = old.wasm_hash;
+    event::new_pair_wasm_hash(&e, old, pair_wasm_hash);
+    Ok(())
+}
+
+/// Sets the Wasm hash of the template of the stable pairs, keeping its init args. Existing stable pairs are not affected.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `stable_pair_wasm_hash` - The Wasm hash of the SoroswapStablePair contract.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the current `fee_to_setter`.
+fn set_stable_pair_wasm_hash(e: Env, stable_pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    let old = get_stable_pair_wasm_hash(&e).ok();
+    put_pool_template_wasm_hash(&e, STABLE_KIND, stable_pair_wasm_hash.clone());
+    event::new_stable_pair_wasm_hash(&e, old, stable_pair_wasm_hash);
+    Ok(())
+}
+
+/// Sets the Wasm hash of the template of the weighted pairs, keeping its init args. Existing weighted pairs are not affected.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `weighted_pair_wasm_hash` - The Wasm hash of the SoroswapWeightedPair contract.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the current `fee_to_setter`.
+fn set_weighted_pair_wasm_hash(e: Env, weighted_pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    let old = get_weighted_pair_wasm_hash(&e).ok();
+    put_pool_template_wasm_hash(&e, WEIGHTED_KIND, weighted_pair_wasm_hash.clone());
+    event::new_weighted_pair_wasm_hash(&e, old, weighted_pair_wasm_hash);
+    Ok(())
+}
+
+/// Sets the Wasm hash of the template of the concentrated pairs, keeping its init args. Existing concentrated pairs
+/// are not affected.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `concentrated_pair_wasm_hash` - The Wasm hash of the SoroswapConcentratedPair contract.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the current `fee_to_setter`.
+fn set_concentrated_pair_wasm_hash(e: Env, concentrated_pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    let old = get_concentrated_pair_wasm_hash(&e).ok();
+    put_pool_template_wasm_hash(&e, CONCENTRATED_KIND, concentrated_pair_wasm_hash.clone());
+    event::new_concentrated_pair_wasm_hash(&e, old, concentrated_pair_wasm_hash);
+    Ok(())
+}
+
+/// Registers or replaces the template used by `create_pair_with_kind` to deploy the pairs of the given `kind`, so
+/// new kinds of pools can be added without deploying a new factory. The template of a built-in kind can be replaced
+/// too, and its init args are the ones `create_pair_with_kind` uses. Existing pairs of that kind are not affected.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `kind` - The name of the kind of pool, built-in or new.
+/// * `wasm_hash` - The Wasm hash of the pool contract.
+/// * `init_args` - The arguments passed to `initialize` after the factory, `token_0` and `token_1`.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the current `fee_to_setter`, or if
+/// the init args do not fit the `initialize` of a built-in kind, or of the built-in kind whose wasm the template uses.
+fn set_pool_template(e: Env, kind: Symbol, wasm_hash: BytesN<32>, init_args: Vec<Val>) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    let template = PoolTemplate { wasm_hash, init_args };
+    check_init_args(&e, &kind, &template)?;
+    let old = get_pool_template(&e, kind.clone()).ok();
+    put_pool_template(&e, kind.clone(), &template);
+    event::new_pool_template(&e, kind, old, template);
+    Ok(())
+}
+
+/// Upgrades the code of the factory. The stored data is kept, so `migrate` should be called afterwards
+/// if the storage layout changed.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `new_wasm_hash` - The Wasm hash of the new SoroswapFactory contract.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the current `fee_to_setter`.
+fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    e.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+    event::upgrade(&e, new_wasm_hash);
+    Ok(())
+}
+
+/// Migrates the stored data of the factory from the stored version to `STORAGE_VERSION`.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the current `fee_to_setter`,
+/// or if the stored data is newer than the contract code.
+fn migrate(e: Env) -> Result<u32, FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    let version = get_storage_version(&e);
+    if version > STORAGE_VERSION {
+        return Err(FactoryError::MigrateInvalidVersion);
+    }
+
+    // Each change to the DataKey layout adds a step here.
+    // Version 0 -> 1 moves the pair wasm hash to the constant product template. The new pairs are keyed by
+    // variant, the pairs created before are read from PairAddressesByTokens and stay there
+    if version < 1 {
+        migrate_pair_wasm_hash_to_template(&e);
+    }
+
+    if version < STORAGE_VERSION {
+        put_storage_version(&e, STORAGE_VERSION);
+        event::migrate(&e, version, STORAGE_VERSION);
+    }
+    Ok(STORAGE_VERSION)
+}
+
+/// Upgrades the code of an existing pair and migrates the stored data of the constant product pairs. The pair only
+/// accepts upgrades coming from the factory that created it.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `pair` - The address of the pair to upgrade, of any pair type.
+/// * `new_wasm_hash` - The Wasm hash of the new code of the pair.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the current `fee_to_setter`.
+fn upgrade_pair(e: Env, pair: Address, new_wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+    if !has_total_pairs(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+
+    extend_instance_ttl(&e);
+    let setter = get_fee_to_setter(&e);
+    setter.require_auth();
+
+    // Every pair type has the same `upgrade`, so the client of SoroswapPair can call all of them
+    let pair_client = pair::Client::new(&e, &pair);
+    pair_client.upgrade(&new_wasm_hash);
+    // The new code is already active for this call. Only the constant product pairs have a `migrate`, the other pair
+    // types are still on the storage layout they were created with
+    let storage_version = match pair_client.try_migrate() {
+        Ok(Ok(version)) => version,
+        Err(Ok(error)) => panic_with_error!(&e, error),
+        _ => 0,
+    };
+
+    event::upgrade_pair(&e, pair, new_wasm_hash, storage_version);
+    Ok(())
 }
 
 
@@ -1,6 +1,6 @@
 // Import necessary types from the Soroban SDK
 #![allow(unused)]
-use soroban_sdk::{contracttype, contracterror, xdr::ToXdr, Address, Bytes, BytesN, Env};
+use soroban_sdk::{contracttype, contracterror, Address, BytesN, Env, Symbol};
 
 soroban_sdk::contractimport!(
     file = "../pair/target/wasm32-unknown-unknown/release/soroswap_pair.wasm"
@@ -30,15 +30,10 @@ impl Pair {
         }
     }
 
-    pub fn salt(&self, e: &Env) -> BytesN<32> {
-        let mut salt = Bytes::new(e);
-
-        // Append the bytes of token_0 and token_1 to the salt
-        salt.append(&self.0.clone().to_xdr(e)); // can be simplified to salt.append(&self.clone().to_xdr(e)); but changes the hash
-        salt.append(&self.1.clone().to_xdr(e));
-
-        // Hash the salt using SHA256 to generate a new BytesN<32> value
-        e.crypto().sha256(&salt)
+    /// Salt of the pair of the given variant, the same one `pair_for_variant` of the library computes, so each
+    /// variant of the same tokens gets a different address.
+    pub fn salt(&self, e: &Env, variant: &Symbol) -> BytesN<32> {
+        soroswap_library::pair_salt(e, self.0.clone(), self.1.clone(), variant)
     }
 
     pub fn token_0(&self) -> &Address {
@@ -66,12 +61,12 @@ pub fn create_contract(
     */
     e: &Env,                    // Pass in the current environment as an argument
     pair_wasm_hash: BytesN<32>, // Pass in the hash of the token contract's WASM file
-    token_pair: &Pair,
+    salt: BytesN<32>,
 ) -> Address {
     // Return the hash of the newly created contract as a Address value
 
     // Use the deployer() method of the current environment to create a new contract instance
     e.deployer()
-        .with_current_contract(token_pair.salt(&e)) // Use the salt as a unique identifier for the new contract instance
+        .with_current_contract(salt) // Use the salt as a unique identifier for the new contract instance
         .deploy(pair_wasm_hash) // Deploy the new contract instance using the given pair_wasm_hash value
 }
@@ -1,7 +1,7 @@
 use soroban_sdk::{
-    contracttype, Address, BytesN, Env, Val, TryFromVal
+    contracttype, Address, BytesN, Env, Symbol, Val, Vec, TryFromVal
 };
-use soroswap_factory_interface::{FactoryError};
+use soroswap_factory_interface::{FactoryError, PoolTemplate, CONSTANT_PRODUCT_KIND, STABLE_KIND, WEIGHTED_KIND, CONCENTRATED_KIND};
 use crate::pair::{Pair};
 
 
@@ -11,13 +11,34 @@ use crate::pair::{Pair};
 pub enum DataKey {
     FeeTo,      // Address. Instance storage
     FeeToSetter, // Address. Instance storage
-    PairWasmHash, // BytesN<32>. Persistent storage
+    PairWasmHash, // BytesN<32>. Persistent storage. Before version 1, now PoolTemplate(CONSTANT_PRODUCT_KIND)
     FeesEnabled, // Bool. Instance storage
     TotalPairs, // Total pairs created by the Factory. u32, Instance storage
     PairAddressesNIndexed(u32), // Addresses of pairs created by the Factory. Persistent Storage
-    PairAddressesByTokens(Pair)
+    PairAddressesByTokens(Pair), // Pairs created before version 1, now PairAddressesByVariant(Pair, CONSTANT_PRODUCT_KIND)
+    ProtocolFeeFraction, // (u32, u32). Fraction of the LP fees minted to fee_to. Instance storage
+    StorageVersion, // u32. Version of the storage layout. Instance storage
+    Guardian, // Address. Can pause and unpause the pairs. Instance storage
+    Paused, // Bool. All pairs are paused. Instance storage
+    MaxReferralFee, // u32. Maximum referral fee in basis points that the router allows. Instance storage
+    PoolTemplate(Symbol), // PoolTemplate of each kind of pool, built-in or registered with set_pool_template. Persistent Storage
+    PoolKinds, // Vec<Symbol>. Kinds with a template, in registration order. Instance storage
+    PairAddressesByVariant(Pair, Symbol), // Address of the pair of each token pair and variant. Persistent Storage
+    PairVariants(Pair), // Vec<Symbol>. Variants with a pair for each token pair, in creation order. Persistent Storage
+    PairKindsNIndexed(u32), // Kind of each pair in PairAddressesNIndexed, except the constant product ones. Persistent Storage
 }
 
+// Swap fee in basis points of the constant product pairs created with the "cp" template (0.3%)
+pub const DEFAULT_PAIR_FEE: u32 = 30;
+// By default the protocol gets 1/6 of the LP fees
+pub const DEFAULT_PROTOCOL_FEE_FRACTION: (u32, u32) = (1, 6);
+// By default integrators can not charge a referral fee until the fee_to_setter allows it
+pub const DEFAULT_MAX_REFERRAL_FEE: u32 = 0;
+
+// Version of the storage layout used by this code. Factories deployed before versioning was added have version 0.
+// Bump it whenever the DataKey layout changes and add the corresponding step to `migrate`
+pub const STORAGE_VERSION: u32 = 1;
+
 
 const DAY_IN_LEDGERS: u32 = 17280;
 const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
@@ -63,34 +84,67 @@ pub fn has_total_pairs(e: &Env) -> bool {
 }
 
 
-// PairAddressesByTokens(Address, Address)
-pub fn put_pair_address_by_token_pair(e: &Env, token_pair: Pair, pair_address: &Address) {
-    let key = DataKey::PairAddressesByTokens(token_pair); 
+// PairAddressesByVariant(Pair, Symbol)
+pub fn put_pair_address_by_variant(e: &Env, token_pair: Pair, variant: Symbol, pair_address: &Address) {
+    let key = DataKey::PairAddressesByVariant(token_pair.clone(), variant.clone());
     e.storage()
         .persistent()
         .set(&key, &pair_address);
     e.storage()
         .persistent()
-        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT)
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+    let mut variants = get_created_pair_variants(e, token_pair.clone());
+    variants.push_back(variant);
+    let variants_key = DataKey::PairVariants(token_pair);
+    e.storage()
+        .persistent()
+        .set(&variants_key, &variants);
+    e.storage()
+        .persistent()
+        .extend_ttl(&variants_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT)
 }
-pub fn get_pair_address_by_token_pair(e: &Env, token_pair: Pair) -> Result<Address, FactoryError> {
-    let key = DataKey::PairAddressesByTokens(token_pair);
+pub fn get_pair_address_by_variant(e: &Env, token_pair: Pair, variant: Symbol) -> Result<Address, FactoryError> {
+    let key = DataKey::PairAddressesByVariant(token_pair.clone(), variant.clone());
+    if variant == CONSTANT_PRODUCT_KIND && !e.storage().persistent().has(&key) {
+        // Constant product pairs created before version 1 keep their key
+        return get_persistent_extend_or_error(&e, &DataKey::PairAddressesByTokens(token_pair), FactoryError::PairDoesNotExist);
+    }
     get_persistent_extend_or_error(&e, &key, FactoryError::PairDoesNotExist)
 }
 
-pub fn get_pair_exists(e: &Env, token_pair: Pair) -> bool {
-    let key:DataKey = DataKey::PairAddressesByTokens(token_pair);
-    if e.storage().persistent().has(&key) {
+fn get_baseline_pair_exists(e: &Env, token_pair: Pair) -> bool {
+    e.storage().persistent().has(&DataKey::PairAddressesByTokens(token_pair))
+}
+
+pub fn get_pair_exists_by_variant(e: &Env, token_pair: Pair, variant: Symbol) -> bool {
+    e.storage().persistent().has(&DataKey::PairAddressesByVariant(token_pair.clone(), variant.clone()))
+        || (variant == CONSTANT_PRODUCT_KIND && get_baseline_pair_exists(e, token_pair))
+}
+
+// PairVariants(Pair)
+fn get_created_pair_variants(e: &Env, token_pair: Pair) -> Vec<Symbol> {
+    let key = DataKey::PairVariants(token_pair);
+    if let Some(variants) = e.storage().persistent().get(&key) {
         e.storage()
             .persistent()
             .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
-        true
+        variants
     } else {
-        false
+        Vec::new(e)
     }
-    
 }
 
+// Every variant with a pair for the token pair: the constant product pair created before version 1 first, then the
+// rest in creation order
+pub fn get_pair_variants(e: &Env, token_pair: Pair) -> Vec<Symbol> {
+    let mut variants = Vec::new(e);
+    if get_baseline_pair_exists(e, token_pair.clone()) {
+        variants.push_back(CONSTANT_PRODUCT_KIND);
+    }
+    variants.append(&get_created_pair_variants(e, token_pair));
+    variants
+}
 
 pub fn get_fee_to(e: &Env) -> Address {
     e.storage().instance().get(&DataKey::FeeTo).unwrap()
@@ -106,14 +160,38 @@ pub fn get_fees_enabled(e: &Env) -> bool {
     }
 }
 
+pub fn get_protocol_fee_fraction(e: &Env) -> (u32, u32) {
+    let key = DataKey::ProtocolFeeFraction;
+    if let Some(fraction) = e.storage().instance().get(&key) {
+        fraction
+    } else {
+        DEFAULT_PROTOCOL_FEE_FRACTION
+    }
+}
+
+pub fn get_max_referral_fee(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::MaxReferralFee).unwrap_or(DEFAULT_MAX_REFERRAL_FEE)
+}
+
 pub fn get_fee_to_setter(e: &Env) -> Address {
     e.storage().instance().get(&DataKey::FeeToSetter).unwrap()
 }
 
+pub fn get_guardian(e: &Env) -> Address {
+    if let Some(guardian) = e.storage().instance().get(&DataKey::Guardian) {
+        guardian
+    } else {
+        get_fee_to_setter(e) // Factories deployed before the guardian existed
+    }
+}
 
-pub fn get_pair_wasm_hash(e: &Env) -> Result<BytesN<32>, FactoryError>{
-    let key = DataKey::PairWasmHash;
-    get_persistent_extend_or_error(&e, &key, FactoryError::NotInitialized)
+pub fn get_paused(e: &Env) -> bool {
+    e.storage().instance().get(&DataKey::Paused).unwrap_or(false)
+}
+
+
+pub fn get_pair_wasm_hash(e: &Env) -> Result<BytesN<32>, FactoryError> {
+    Ok(get_pool_template(e, CONSTANT_PRODUCT_KIND)?.wasm_hash)
 }
 
 pub fn put_fee_to(e: &Env, to: Address) {
@@ -124,19 +202,107 @@ pub fn put_fee_to_setter(e: &Env, setter: &Address) {
     e.storage().instance().set(&DataKey::FeeToSetter, setter);
 }
 
+pub fn put_guardian(e: &Env, guardian: &Address) {
+    e.storage().instance().set(&DataKey::Guardian, guardian);
+}
+
+pub fn put_paused(e: &Env, is_paused: &bool) {
+    e.storage().instance().set(&DataKey::Paused, is_paused);
+}
+
 pub fn put_fees_enabled(e: &Env, is_enabled: &bool) {
     e.storage().instance().set(&DataKey::FeesEnabled, is_enabled);
 }
 
-pub fn put_pair_wasm_hash(e: &Env, pair_wasm_hash: BytesN<32>) {
-    let key = DataKey::PairWasmHash;
-    e.storage().persistent().set(&key, &pair_wasm_hash);
+pub fn get_storage_version(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::StorageVersion).unwrap_or(0)
+}
+
+pub fn put_storage_version(e: &Env, version: u32) {
+    e.storage().instance().set(&DataKey::StorageVersion, &version);
+}
+
+pub fn put_protocol_fee_fraction(e: &Env, fraction: &(u32, u32)) {
+    e.storage().instance().set(&DataKey::ProtocolFeeFraction, fraction);
+}
+
+pub fn put_max_referral_fee(e: &Env, max_fee: &u32) {
+    e.storage().instance().set(&DataKey::MaxReferralFee, max_fee);
+}
+
+pub fn get_stable_pair_wasm_hash(e: &Env) -> Result<BytesN<32>, FactoryError> {
+    Ok(get_pool_template(e, STABLE_KIND)?.wasm_hash)
+}
+
+pub fn get_weighted_pair_wasm_hash(e: &Env) -> Result<BytesN<32>, FactoryError> {
+    Ok(get_pool_template(e, WEIGHTED_KIND)?.wasm_hash)
+}
+
+pub fn get_concentrated_pair_wasm_hash(e: &Env) -> Result<BytesN<32>, FactoryError> {
+    Ok(get_pool_template(e, CONCENTRATED_KIND)?.wasm_hash)
+}
+
+// PoolTemplate(Symbol)
+pub fn get_pool_template(e: &Env, kind: Symbol) -> Result<PoolTemplate, FactoryError> {
+    // The built-in kinds keep the errors they had when their wasm hashes had their own keys
+    let error = if kind == CONSTANT_PRODUCT_KIND {
+        FactoryError::NotInitialized
+    } else if kind == STABLE_KIND {
+        FactoryError::StablePairWasmHashNotSet
+    } else if kind == WEIGHTED_KIND {
+        FactoryError::WeightedPairWasmHashNotSet
+    } else if kind == CONCENTRATED_KIND {
+        FactoryError::ConcentratedPairWasmHashNotSet
+    } else {
+        FactoryError::PoolTemplateDoesNotExist
+    };
+    let key = DataKey::PoolTemplate(kind);
+    get_persistent_extend_or_error(&e, &key, error)
+}
+
+// Registers the kind in PoolKinds the first time it gets a template
+pub fn put_pool_template(e: &Env, kind: Symbol, template: &PoolTemplate) {
+    let key = DataKey::PoolTemplate(kind.clone());
+    if !e.storage().persistent().has(&key) {
+        let mut kinds = get_pool_kinds(e);
+        kinds.push_back(kind);
+        put_pool_kinds(e, &kinds);
+    }
+    e.storage().persistent().set(&key, template);
     e.storage()
             .persistent()
             .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT)
 }
 
-pub fn add_pair_to_all_pairs(e: &Env, pair_address: &Address) {
+// Replaces the wasm hash of the template of the kind and keeps its init args
+pub fn put_pool_template_wasm_hash(e: &Env, kind: Symbol, wasm_hash: BytesN<32>) {
+    let init_args = match get_pool_template(e, kind.clone()) {
+        Ok(template) => template.init_args,
+        Err(_) => Vec::new(e),
+    };
+    put_pool_template(e, kind, &PoolTemplate { wasm_hash, init_args });
+}
+
+// PoolKinds
+pub fn get_pool_kinds(e: &Env) -> Vec<Symbol> {
+    e.storage().instance().get(&DataKey::PoolKinds).unwrap_or(Vec::new(e))
+}
+
+fn put_pool_kinds(e: &Env, kinds: &Vec<Symbol>) {
+    e.storage().instance().set(&DataKey::PoolKinds, kinds);
+}
+
+// Version 0 -> 1: the pair wasm hash becomes the template of the constant product kind. Its init args stay empty,
+// as the pairs of that wasm take no fee in `initialize`, until `set_pair_wasm_hash` replaces it
+pub fn migrate_pair_wasm_hash_to_template(e: &Env) {
+    let wasm_hash: Option<BytesN<32>> = e.storage().persistent().get(&DataKey::PairWasmHash);
+    if let Some(wasm_hash) = wasm_hash {
+        put_pool_template(e, CONSTANT_PRODUCT_KIND, &PoolTemplate { wasm_hash, init_args: Vec::new(e) });
+        e.storage().persistent().remove(&DataKey::PairWasmHash);
+    }
+}
+
+pub fn add_pair_to_all_pairs(e: &Env, pair_address: &Address, kind: &Symbol) {
     // total_pairs is the total amount of pairs created by the Factory
     let mut total_pairs = get_total_pairs(e);
     // Because PairAddressesNIndexed is 0-indexed, we start with 0, default value of total_pairs
@@ -148,11 +314,33 @@ pub fn add_pair_to_all_pairs(e: &Env, pair_address: &Address) {
             .persistent()
             .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
+    // Pairs created before kinds existed were all constant product pairs, so that kind is not stored
+    if kind != &CONSTANT_PRODUCT_KIND {
+        let kind_key = DataKey::PairKindsNIndexed(total_pairs);
+        e.storage().persistent().set(&kind_key, kind);
+        e.storage()
+                .persistent()
+                .extend_ttl(&kind_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
     total_pairs = total_pairs.checked_add(1).unwrap();
     put_total_pairs(&e, total_pairs);
 }
 
-pub fn get_all_pairs(e: Env, n: u32) -> Result<Address, FactoryError> {
+pub fn get_all_pairs(e: &Env, n: u32) -> Result<Address, FactoryError> {
     let key = DataKey::PairAddressesNIndexed(n);
     get_persistent_extend_or_error(&e, &key, FactoryError::IndexDoesNotExist)
-}
\ No newline at end of file
+}
+
+pub fn get_all_pairs_with_kind(e: &Env, n: u32) -> Result<(Address, Symbol), FactoryError> {
+    let pair_address = get_all_pairs(e, n)?;
+    let kind_key = DataKey::PairKindsNIndexed(n);
+    if let Some(kind) = e.storage().persistent().get(&kind_key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&kind_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Ok((pair_address, kind))
+    } else {
+        Ok((pair_address, CONSTANT_PRODUCT_KIND))
+    }
+}
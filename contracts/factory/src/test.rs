@@ -37,6 +37,39 @@ mod pair {
 }
 use pair::SoroswapPairClient;
 
+//  **** STABLE PAIR WASM ****
+fn stable_pair_wasm(e: &Env) -> BytesN<32> {
+    e.deployer().upload_contract_wasm(stable_pair::WASM)
+}
+
+mod stable_pair {
+    soroban_sdk::contractimport!(file = "../stable-pair/target/wasm32-unknown-unknown/release/soroswap_stable_pair.wasm");
+    pub type SoroswapStablePairClient<'a> = Client<'a>;
+}
+use stable_pair::SoroswapStablePairClient;
+
+//  **** WEIGHTED PAIR WASM ****
+fn weighted_pair_wasm(e: &Env) -> BytesN<32> {
+    e.deployer().upload_contract_wasm(weighted_pair::WASM)
+}
+
+mod weighted_pair {
+    soroban_sdk::contractimport!(file = "../weighted-pair/target/wasm32-unknown-unknown/release/soroswap_weighted_pair.wasm");
+    pub type SoroswapWeightedPairClient<'a> = Client<'a>;
+}
+use weighted_pair::SoroswapWeightedPairClient;
+
+//  **** CONCENTRATED PAIR WASM ****
+fn concentrated_pair_wasm(e: &Env) -> BytesN<32> {
+    e.deployer().upload_contract_wasm(concentrated_pair::WASM)
+}
+
+mod concentrated_pair {
+    soroban_sdk::contractimport!(file = "../concentrated-pair/target/wasm32-unknown-unknown/release/soroswap_concentrated_pair.wasm");
+    pub type SoroswapConcentratedPairClient<'a> = Client<'a>;
+}
+use concentrated_pair::SoroswapConcentratedPairClient;
+
 
 //  **** FACTORY CONTRACT (TO BE TESTED) **** 
 fn create_factory_contract<'a>(e: & Env) -> SoroswapFactoryClient<'a> {
@@ -55,6 +88,9 @@ pub struct SoroswapFactoryTest<'a> {
     token_2: TokenClient<'a>,
     token_3: TokenClient<'a>,
     pair_wasm: BytesN<32>,
+    stable_pair_wasm: BytesN<32>,
+    weighted_pair_wasm: BytesN<32>,
+    concentrated_pair_wasm: BytesN<32>,
     contract: SoroswapFactoryClient<'a>,
 }
 
@@ -84,6 +120,9 @@ impl<'a> SoroswapFactoryTest<'a> {
 
         
         let pair_wasm = pair_token_wasm(&env);  
+        let stable_pair_wasm = stable_pair_wasm(&env);
+        let weighted_pair_wasm = weighted_pair_wasm(&env);
+        let concentrated_pair_wasm = concentrated_pair_wasm(&env);
         let contract = create_factory_contract(&env);
 
         // TODO: Get rid of this hack?
@@ -99,6 +138,9 @@ impl<'a> SoroswapFactoryTest<'a> {
             token_2,
             token_3,
             pair_wasm,
+            stable_pair_wasm,
+            weighted_pair_wasm,
+            concentrated_pair_wasm,
             contract,
         }
     }
@@ -108,5 +150,15 @@ mod initialize;
 mod fee_to_setter;
 mod pairs;
 mod events;
+mod pair_fee;
+mod protocol_fee;
+mod upgrade;
+mod pause;
+mod referral_fee;
+mod stable_pairs;
+mod weighted_pairs;
+mod concentrated_pairs;
+mod pool_templates;
+mod variants;
 
 pub mod deterministic;
\ No newline at end of file
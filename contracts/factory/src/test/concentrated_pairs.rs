@@ -0,0 +1,115 @@
+use crate::test::{SoroswapFactoryTest, SoroswapConcentratedPairClient};
+use soroban_sdk::{xdr::{ToXdr},
+    Bytes,
+};
+use soroswap_factory_interface::{FactoryError, CONCENTRATED_KIND};
+
+const FEE: u32 = 30;
+const TICK_SPACING: u32 = 60;
+// Price of 1.0
+const SQRT_PRICE: u128 = 1 << 64;
+
+#[test]
+fn create_concentrated_pair_not_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let result = test.contract.try_create_concentrated_pair(&test.token_0.address, &test.token_1.address, &FEE, &TICK_SPACING, &SQRT_PRICE);
+    assert_eq!(result, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn create_concentrated_pair_wasm_hash_not_set() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    assert_eq!(test.contract.try_concentrated_pair_wasm_hash(), Err(Ok(FactoryError::ConcentratedPairWasmHashNotSet)));
+    let result = test.contract.try_create_concentrated_pair(&test.token_0.address, &test.token_1.address, &FEE, &TICK_SPACING, &SQRT_PRICE);
+    assert_eq!(result, Err(Ok(FactoryError::ConcentratedPairWasmHashNotSet)));
+}
+
+#[test]
+fn create_concentrated_pair_invalid_parameters() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_concentrated_pair_wasm_hash(&test.concentrated_pair_wasm);
+
+    let result = test.contract.try_create_concentrated_pair(&test.token_0.address, &test.token_1.address, &1_001, &TICK_SPACING, &SQRT_PRICE);
+    assert_eq!(result, Err(Ok(FactoryError::SetPairFeeInvalidFee)));
+    let result = test.contract.try_create_concentrated_pair(&test.token_0.address, &test.token_1.address, &FEE, &0, &SQRT_PRICE);
+    assert_eq!(result, Err(Ok(FactoryError::CreateConcentratedPairInvalidTickSpacing)));
+    let result = test.contract.try_create_concentrated_pair(&test.token_0.address, &test.token_1.address, &FEE, &16_384, &SQRT_PRICE);
+    assert_eq!(result, Err(Ok(FactoryError::CreateConcentratedPairInvalidTickSpacing)));
+    let result = test.contract.try_create_concentrated_pair(&test.token_0.address, &test.token_1.address, &FEE, &TICK_SPACING, &4_295_048_015);
+    assert_eq!(result, Err(Ok(FactoryError::CreateConcentratedPairInvalidPrice)));
+    let result = test.contract.try_create_concentrated_pair(&test.token_1.address, &test.token_0.address, &FEE, &TICK_SPACING, &0);
+    assert_eq!(result, Err(Ok(FactoryError::CreateConcentratedPairInvalidPrice)));
+    let result = test.contract.try_create_concentrated_pair(&test.token_0.address, &test.token_0.address, &FEE, &TICK_SPACING, &SQRT_PRICE);
+    assert_eq!(result, Err(Ok(FactoryError::CreatePairIdenticalTokens)));
+}
+
+#[test]
+fn create_concentrated_pair() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_concentrated_pair_wasm_hash(&test.concentrated_pair_wasm);
+    assert_eq!(test.contract.concentrated_pair_wasm_hash(), test.concentrated_pair_wasm);
+
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    // The concentrated pair of the same tokens lives alongside the constant product pair
+    let concentrated_pair_address = test.contract.create_concentrated_pair(&test.token_0.address, &test.token_1.address, &FEE, &TICK_SPACING, &SQRT_PRICE);
+    assert_ne!(concentrated_pair_address, pair_address);
+
+    assert_eq!(test.contract.all_pairs_length(), 2);
+    assert_eq!(test.contract.all_pairs_with_kind(&1), (concentrated_pair_address.clone(), CONCENTRATED_KIND));
+    assert_eq!(test.contract.get_concentrated_pair(&test.token_0.address, &test.token_1.address), concentrated_pair_address);
+    assert_eq!(test.contract.get_concentrated_pair(&test.token_1.address, &test.token_0.address), concentrated_pair_address);
+
+    // The salt of a concentrated pair is the salt of the pair followed by "concentrated"
+    let mut salt = Bytes::new(&test.env);
+    salt.append(&test.token_0.address.clone().to_xdr(&test.env));
+    salt.append(&test.token_1.address.clone().to_xdr(&test.env));
+    salt.append(&Bytes::from_slice(&test.env, b"concentrated"));
+    let deterministic_address = test.env.deployer().with_address(test.contract.address.clone(), test.env.crypto().sha256(&salt)).deployed_address();
+    assert_eq!(concentrated_pair_address, deterministic_address);
+
+    let concentrated_pair_client = SoroswapConcentratedPairClient::new(&test.env, &concentrated_pair_address);
+    assert_eq!(concentrated_pair_client.factory(), test.contract.address);
+    assert_eq!(concentrated_pair_client.token_0(), test.token_0.address);
+    assert_eq!(concentrated_pair_client.token_1(), test.token_1.address);
+    assert_eq!(concentrated_pair_client.fee(), FEE);
+    assert_eq!(concentrated_pair_client.tick_spacing(), TICK_SPACING);
+    assert_eq!(concentrated_pair_client.get_price(), (SQRT_PRICE, 0));
+}
+
+#[test]
+fn create_concentrated_pair_reversed_tokens() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_concentrated_pair_wasm_hash(&test.concentrated_pair_wasm);
+
+    // The price is given for token_a, so the pair gets its inverse for its token 0: 1/4 instead of 4
+    let concentrated_pair_address = test.contract.create_concentrated_pair(&test.token_1.address, &test.token_0.address, &FEE, &TICK_SPACING, &(2 * SQRT_PRICE));
+    let concentrated_pair_client = SoroswapConcentratedPairClient::new(&test.env, &concentrated_pair_address);
+    assert_eq!(concentrated_pair_client.token_0(), test.token_0.address);
+    assert_eq!(concentrated_pair_client.get_price().0, SQRT_PRICE / 2 - 1);
+}
+
+#[test]
+fn double_concentrated_pair_creation() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_concentrated_pair_wasm_hash(&test.concentrated_pair_wasm);
+
+    test.contract.create_concentrated_pair(&test.token_0.address, &test.token_1.address, &FEE, &TICK_SPACING, &SQRT_PRICE);
+    let result = test.contract.try_create_concentrated_pair(&test.token_1.address, &test.token_0.address, &FEE, &TICK_SPACING, &SQRT_PRICE);
+    assert_eq!(result, Err(Ok(FactoryError::CreatePairAlreadyExists)));
+}
+
+#[test]
+fn get_concentrated_pair_does_not_exist() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    let result = test.contract.try_get_concentrated_pair(&test.token_0.address, &test.token_1.address);
+    assert_eq!(result, Err(Ok(FactoryError::PairDoesNotExist)));
+}
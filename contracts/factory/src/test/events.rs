@@ -1,12 +1,15 @@
 use soroban_sdk::{testutils::{Events}, vec, IntoVal, symbol_short};
 use soroban_sdk::{xdr::{ToXdr}, Bytes}; // For determinisitic address
 use crate::test::{SoroswapFactoryTest};
+use soroswap_factory_interface::CONSTANT_PRODUCT_KIND;
 use crate::event::{
     InitializedEvent,
     NewPairEvent,
     FeeToSettedEvent,
     NewSetterEvent,
-    NewFeesEnabledEvent};
+    NewFeesEnabledEvent,
+    NewPairFeeEvent,
+    NewProtocolFeeFractionEvent};
 
 
 #[test]
@@ -403,4 +406,85 @@ fn fees_enabled_event() {
         ]
     );
 
-}
\ No newline at end of file
+}
+
+
+#[test]
+fn pair_fee_event() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let pair_address = test.contract.get_pair(&test.token_0.address, &test.token_1.address);
+    test.contract.set_pair_fee(&test.token_1.address, &test.token_0.address, &CONSTANT_PRODUCT_KIND, &5);
+
+    let pair_fee_event = test.env.events().all().last().unwrap();
+
+    let expected_pair_fee_event: NewPairFeeEvent = NewPairFeeEvent {
+        token_0: test.token_0.address.clone(),
+        token_1: test.token_1.address.clone(),
+        pair: pair_address.clone(),
+        old: 30,
+        new: 5,
+    };
+
+    assert_eq!(
+        vec![&test.env, pair_fee_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("pair_fee")).into_val(&test.env),
+                (expected_pair_fee_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    let false_pair_fee_event: NewPairFeeEvent = NewPairFeeEvent {
+        token_0: test.token_0.address.clone(),
+        token_1: test.token_1.address.clone(),
+        pair: pair_address,
+        old: 5,
+        new: 30,
+    };
+
+    assert_ne!(
+        vec![&test.env, pair_fee_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("pair_fee")).into_val(&test.env),
+                (false_pair_fee_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+
+#[test]
+fn protocol_fee_fraction_event() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_protocol_fee_fraction(&1, &4);
+
+    let protocol_fee_fraction_event = test.env.events().all().last().unwrap();
+
+    let expected_protocol_fee_fraction_event: NewProtocolFeeFractionEvent = NewProtocolFeeFractionEvent {
+        old_numerator: 1,
+        old_denominator: 6,
+        new_numerator: 1,
+        new_denominator: 4,
+    };
+
+    assert_eq!(
+        vec![&test.env, protocol_fee_fraction_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("prot_fee")).into_val(&test.env),
+                (expected_protocol_fee_fraction_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
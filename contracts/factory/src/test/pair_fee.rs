@@ -0,0 +1,150 @@
+use crate::test::{SoroswapFactoryTest, SoroswapPairClient};
+use soroswap_factory_interface::{FactoryError, CONSTANT_PRODUCT_KIND, STABLE_KIND};
+use soroban_sdk::{
+    vec,
+    IntoVal,
+    Symbol,
+    symbol_short,
+    testutils::{
+        MockAuth,
+        MockAuthInvoke,
+    },
+};
+
+// Constant product pairs with a 0.05% fee, deployed from a template of the same wasm
+const CP_5_VARIANT: Symbol = symbol_short!("cp_5");
+
+#[test]
+fn pair_fee_default() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    let pair_address = test.contract.get_pair(&test.token_0.address, &test.token_1.address);
+    let pair_client = SoroswapPairClient::new(&test.env, &pair_address);
+
+    assert_eq!(test.contract.pair_fee(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND), 30);
+    assert_eq!(test.contract.pair_fee(&test.token_1.address, &test.token_0.address, &CONSTANT_PRODUCT_KIND), 30);
+    assert_eq!(pair_client.fee(), 30);
+}
+
+#[test]
+fn pair_fee_does_not_exist() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    let res = test.contract.try_pair_fee(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND);
+    assert_eq!(res, Err(Ok(FactoryError::PairDoesNotExist)));
+
+    let res = test.contract.try_set_pair_fee(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND, &5);
+    assert_eq!(res, Err(Ok(FactoryError::PairDoesNotExist)));
+}
+
+#[test]
+fn set_pair_fee() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    test.contract.create_pair(&test.token_2.address, &test.token_3.address);
+
+    let pair_address = test.contract.get_pair(&test.token_0.address, &test.token_1.address);
+    let pair_client = SoroswapPairClient::new(&test.env, &pair_address);
+
+    // 0.05%
+    test.contract.set_pair_fee(&test.token_1.address, &test.token_0.address, &CONSTANT_PRODUCT_KIND, &5);
+    assert_eq!(test.contract.pair_fee(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND), 5);
+    assert_eq!(pair_client.fee(), 5);
+
+    // 1%
+    test.contract.set_pair_fee(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND, &100);
+    assert_eq!(test.contract.pair_fee(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND), 100);
+    assert_eq!(pair_client.fee(), 100);
+
+    // Other pairs are not affected
+    assert_eq!(test.contract.pair_fee(&test.token_2.address, &test.token_3.address, &CONSTANT_PRODUCT_KIND), 30);
+}
+
+#[test]
+fn pair_fee_tiers() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_pool_template(&CP_5_VARIANT, &test.pair_wasm, &vec![&test.env, 5_u32.into_val(&test.env)]);
+
+    // Each fee tier of the same tokens has its own pair
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let pair_5_address = test.contract.create_pair_with_kind(&test.token_1.address, &test.token_0.address, &CP_5_VARIANT);
+    assert_ne!(pair_address, pair_5_address);
+    assert_eq!(test.contract.get_pair(&test.token_0.address, &test.token_1.address), pair_address);
+    assert_eq!(test.contract.get_pair_with_kind(&test.token_0.address, &test.token_1.address, &CP_5_VARIANT), pair_5_address);
+
+    assert_eq!(test.contract.pair_fee(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND), 30);
+    assert_eq!(test.contract.pair_fee(&test.token_0.address, &test.token_1.address, &CP_5_VARIANT), 5);
+    assert_eq!(SoroswapPairClient::new(&test.env, &pair_5_address).fee(), 5);
+
+    // The fee of a tier can not change, even though its pair has the same code as the "cp" one
+    let res = test.contract.try_set_pair_fee(&test.token_0.address, &test.token_1.address, &CP_5_VARIANT, &100);
+    assert_eq!(res, Err(Ok(FactoryError::SetPairFeeNotSupported)));
+    assert_eq!(test.contract.pair_fee(&test.token_0.address, &test.token_1.address, &CP_5_VARIANT), 5);
+
+    // Setting the fee of the "cp" pair does not change the tier
+    test.contract.set_pair_fee(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND, &10);
+    assert_eq!(test.contract.pair_fee(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND), 10);
+    assert_eq!(test.contract.pair_fee(&test.token_0.address, &test.token_1.address, &CP_5_VARIANT), 5);
+}
+
+#[test]
+fn set_pair_fee_not_supported() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    test.contract.create_stable_pair(&test.token_0.address, &test.token_1.address, &400, &4);
+
+    // The stable pairs keep the fee they were created with
+    assert_eq!(test.contract.pair_fee(&test.token_0.address, &test.token_1.address, &STABLE_KIND), 4);
+    let res = test.contract.try_set_pair_fee(&test.token_0.address, &test.token_1.address, &STABLE_KIND, &5);
+    assert_eq!(res, Err(Ok(FactoryError::SetPairFeeNotSupported)));
+    assert_eq!(test.contract.pair_fee(&test.token_0.address, &test.token_1.address, &STABLE_KIND), 4);
+}
+
+#[test]
+fn set_pair_fee_invalid_fee() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    // The maximum fee is 10%
+    test.contract.set_pair_fee(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND, &1_000);
+    let res = test.contract.try_set_pair_fee(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND, &1_001);
+    assert_eq!(res, Err(Ok(FactoryError::SetPairFeeInvalidFee)));
+    assert_eq!(test.contract.pair_fee(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND), 1_000);
+}
+
+#[test]
+fn set_pair_fee_not_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let res = test.contract.try_set_pair_fee(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND, &5);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+#[should_panic]
+fn set_pair_fee_with_mock_auth_not_allowed() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.user.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "set_pair_fee",
+                    args: (test.token_0.address.clone(), test.token_1.address.clone(), CONSTANT_PRODUCT_KIND, 5_u32).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .set_pair_fee(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND, &5);
+}
@@ -0,0 +1,187 @@
+use crate::test::{SoroswapFactoryTest, SoroswapPairClient, SoroswapStablePairClient};
+use crate::event::{NewGuardianEvent, PausedEvent, PairPausedEvent};
+use soroswap_factory_interface::{FactoryError, CONSTANT_PRODUCT_KIND, STABLE_KIND};
+use soroban_sdk::{
+    IntoVal,
+    symbol_short,
+    vec,
+    testutils::{
+        Events,
+        MockAuth,
+        MockAuthInvoke,
+    },
+};
+
+#[test]
+fn guardian_not_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let res = test.contract.try_guardian();
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+
+    let res = test.contract.try_paused();
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+
+    let res = test.contract.try_set_paused(&true);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+
+    let res = test.contract.try_set_guardian(&test.user);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn set_guardian() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    // By default the guardian is the setter
+    assert_eq!(test.contract.guardian(), test.admin);
+    assert_eq!(test.contract.paused(), false);
+
+    test.contract.set_guardian(&test.user);
+    assert_eq!(test.contract.guardian(), test.user);
+    assert_eq!(test.contract.fee_to_setter(), test.admin);
+
+    let guardian_event = test.env.events().all().last().unwrap();
+    let expected_guardian_event: NewGuardianEvent = NewGuardianEvent {
+        old: test.admin.clone(),
+        new: test.user.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, guardian_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("guardian")).into_val(&test.env),
+                (expected_guardian_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn set_paused() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let pair_address = test.contract.get_pair(&test.token_0.address, &test.token_1.address);
+    let pair_client = SoroswapPairClient::new(&test.env, &pair_address);
+
+    test.contract.set_paused(&true);
+    assert_eq!(test.contract.paused(), true);
+    assert_eq!(pair_client.paused(), true);
+
+    let paused_event = test.env.events().all().last().unwrap();
+    let expected_paused_event: PausedEvent = PausedEvent {
+        is_paused: true,
+    };
+    assert_eq!(
+        vec![&test.env, paused_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("paused")).into_val(&test.env),
+                (expected_paused_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    test.contract.set_paused(&false);
+    assert_eq!(test.contract.paused(), false);
+    assert_eq!(pair_client.paused(), false);
+}
+
+#[test]
+fn set_pair_paused() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    test.contract.create_pair(&test.token_2.address, &test.token_3.address);
+    let pair_address = test.contract.get_pair(&test.token_0.address, &test.token_1.address);
+    let pair_client = SoroswapPairClient::new(&test.env, &pair_address);
+    let other_pair_client = SoroswapPairClient::new(&test.env, &test.contract.get_pair(&test.token_2.address, &test.token_3.address));
+
+    test.contract.set_pair_paused(&test.token_1.address, &test.token_0.address, &CONSTANT_PRODUCT_KIND, &true);
+    assert_eq!(pair_client.paused(), true);
+    assert_eq!(other_pair_client.paused(), false);
+    assert_eq!(test.contract.paused(), false);
+
+    let pair_paused_event = test.env.events().all().last().unwrap();
+    let expected_pair_paused_event: PairPausedEvent = PairPausedEvent {
+        token_0: test.token_0.address.clone(),
+        token_1: test.token_1.address.clone(),
+        pair: pair_address.clone(),
+        is_paused: true,
+    };
+    assert_eq!(
+        vec![&test.env, pair_paused_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("pair_paus")).into_val(&test.env),
+                (expected_pair_paused_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    test.contract.set_pair_paused(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND, &false);
+    assert_eq!(pair_client.paused(), false);
+}
+
+#[test]
+fn set_pair_paused_does_not_exist() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let res = test.contract.try_set_pair_paused(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND, &true);
+    assert_eq!(res, Err(Ok(FactoryError::PairDoesNotExist)));
+
+    // Only the constant product pair exists, so its stable variant cannot be paused
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let res = test.contract.try_set_pair_paused(&test.token_0.address, &test.token_1.address, &STABLE_KIND, &true);
+    assert_eq!(res, Err(Ok(FactoryError::PairDoesNotExist)));
+}
+
+#[test]
+fn set_pair_paused_stable_variant() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    let stable_pair_address = test.contract.create_stable_pair(&test.token_0.address, &test.token_1.address, &400, &4);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let stable_pair_client = SoroswapStablePairClient::new(&test.env, &stable_pair_address);
+    let pair_client = SoroswapPairClient::new(&test.env, &test.contract.get_pair(&test.token_0.address, &test.token_1.address));
+
+    // Only the pair of the given variant is paused
+    test.contract.set_pair_paused(&test.token_0.address, &test.token_1.address, &STABLE_KIND, &true);
+    assert_eq!(stable_pair_client.paused(), true);
+    assert_eq!(pair_client.paused(), false);
+
+    test.contract.set_pair_paused(&test.token_0.address, &test.token_1.address, &STABLE_KIND, &false);
+    assert_eq!(stable_pair_client.paused(), false);
+}
+
+#[test]
+#[should_panic]
+fn set_paused_with_mock_auth_not_allowed() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_guardian(&test.user);
+
+    // The setter is not the guardian anymore
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.admin.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "set_paused",
+                    args: (true,).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .set_paused(&true);
+}
@@ -0,0 +1,201 @@
+use crate::test::{SoroswapFactoryTest, SoroswapStablePairClient, token};
+use soroban_sdk::{xdr::{ToXdr},
+    vec,
+    Bytes,
+    IntoVal,
+    Symbol,
+    symbol_short,
+};
+use soroswap_factory_interface::{FactoryError, PoolTemplate, CONSTANT_PRODUCT_KIND, STABLE_KIND, WEIGHTED_KIND, CONCENTRATED_KIND};
+
+const AMP: u32 = 400;
+const FEE: u32 = 4;
+// Stable pairs with a 0.04% fee, deployed from a template instead of `create_stable_pair`
+const STABLE_4_KIND: Symbol = symbol_short!("stable_4");
+
+fn set_stable_4_template(test: &SoroswapFactoryTest) {
+    test.contract.set_pool_template(&STABLE_4_KIND, &test.stable_pair_wasm, &vec![&test.env, AMP.into_val(&test.env), FEE.into_val(&test.env)]);
+}
+
+#[test]
+fn pool_templates_not_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    assert_eq!(test.contract.try_pool_kinds(), Err(Ok(FactoryError::NotInitialized)));
+    assert_eq!(test.contract.try_pool_template(&STABLE_4_KIND), Err(Ok(FactoryError::NotInitialized)));
+    let result = test.contract.try_set_pool_template(&STABLE_4_KIND, &test.stable_pair_wasm, &vec![&test.env]);
+    assert_eq!(result, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn pool_template_constant_product() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    assert_eq!(test.contract.pool_kinds(), vec![&test.env, CONSTANT_PRODUCT_KIND]);
+    assert_eq!(test.contract.pool_template(&CONSTANT_PRODUCT_KIND), PoolTemplate { wasm_hash: test.pair_wasm.clone(), init_args: vec![&test.env, 30_u32.into_val(&test.env)] });
+    assert_eq!(test.contract.try_pool_template(&STABLE_4_KIND), Err(Ok(FactoryError::PoolTemplateDoesNotExist)));
+    assert_eq!(test.contract.try_pool_template(&STABLE_KIND), Err(Ok(FactoryError::StablePairWasmHashNotSet)));
+    assert_eq!(test.contract.try_pool_template(&WEIGHTED_KIND), Err(Ok(FactoryError::WeightedPairWasmHashNotSet)));
+    assert_eq!(test.contract.try_pool_template(&CONCENTRATED_KIND), Err(Ok(FactoryError::ConcentratedPairWasmHashNotSet)));
+}
+
+#[test]
+fn pool_templates_built_in_kinds() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_weighted_pair_wasm_hash(&test.weighted_pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    test.contract.set_concentrated_pair_wasm_hash(&test.concentrated_pair_wasm);
+
+    // The built-in kinds are listed as their wasm hashes are set
+    assert_eq!(test.contract.pool_kinds(), vec![&test.env, CONSTANT_PRODUCT_KIND, WEIGHTED_KIND, STABLE_KIND, CONCENTRATED_KIND]);
+    assert_eq!(test.contract.pool_template(&STABLE_KIND), PoolTemplate { wasm_hash: test.stable_pair_wasm.clone(), init_args: vec![&test.env] });
+    assert_eq!(test.contract.pool_template(&WEIGHTED_KIND), PoolTemplate { wasm_hash: test.weighted_pair_wasm.clone(), init_args: vec![&test.env] });
+    assert_eq!(test.contract.pool_template(&CONCENTRATED_KIND), PoolTemplate { wasm_hash: test.concentrated_pair_wasm.clone(), init_args: vec![&test.env] });
+
+    // Setting a wasm hash again keeps the init args of the template
+    test.contract.set_pool_template(&STABLE_KIND, &test.stable_pair_wasm, &vec![&test.env, AMP.into_val(&test.env), FEE.into_val(&test.env)]);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    assert_eq!(test.contract.pool_template(&STABLE_KIND).init_args.len(), 2);
+    assert_eq!(test.contract.pool_kinds(), vec![&test.env, CONSTANT_PRODUCT_KIND, WEIGHTED_KIND, STABLE_KIND, CONCENTRATED_KIND]);
+
+    test.contract.set_pair_wasm_hash(&test.stable_pair_wasm);
+    assert_eq!(test.contract.pool_template(&CONSTANT_PRODUCT_KIND).wasm_hash, test.stable_pair_wasm);
+    assert_eq!(test.contract.pair_wasm_hash(), test.stable_pair_wasm);
+}
+
+#[test]
+fn set_pool_template() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    set_stable_4_template(&test);
+
+    assert_eq!(test.contract.pool_kinds(), vec![&test.env, CONSTANT_PRODUCT_KIND, STABLE_4_KIND]);
+    let template = test.contract.pool_template(&STABLE_4_KIND);
+    assert_eq!(template.wasm_hash, test.stable_pair_wasm);
+    assert_eq!(template.init_args.len(), 2);
+
+    // Updating a template does not list its kind twice
+    test.contract.set_pool_template(&STABLE_4_KIND, &test.stable_pair_wasm, &vec![&test.env, 100_u32.into_val(&test.env), FEE.into_val(&test.env)]);
+    assert_eq!(test.contract.pool_kinds(), vec![&test.env, CONSTANT_PRODUCT_KIND, STABLE_4_KIND]);
+}
+
+#[test]
+fn set_pool_template_invalid_init_args() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    test.contract.set_weighted_pair_wasm_hash(&test.weighted_pair_wasm);
+    test.contract.set_concentrated_pair_wasm_hash(&test.concentrated_pair_wasm);
+    let e = &test.env;
+    let cp_5_kind = symbol_short!("cp_5");
+
+    let invalid = [
+        // The constant product pairs only take their fee
+        (CONSTANT_PRODUCT_KIND, test.pair_wasm.clone(), vec![e], FactoryError::PoolTemplateInvalidInitArgs),
+        (CONSTANT_PRODUCT_KIND, test.pair_wasm.clone(), vec![e, 30_u32.into_val(e), 30_u32.into_val(e)], FactoryError::PoolTemplateInvalidInitArgs),
+        (CONSTANT_PRODUCT_KIND, test.pair_wasm.clone(), vec![e, 30_i128.into_val(e)], FactoryError::PoolTemplateInvalidInitArgs),
+        (CONSTANT_PRODUCT_KIND, test.pair_wasm.clone(), vec![e, 1_001_u32.into_val(e)], FactoryError::SetPairFeeInvalidFee),
+        (STABLE_KIND, test.stable_pair_wasm.clone(), vec![e, AMP.into_val(e)], FactoryError::PoolTemplateInvalidInitArgs),
+        (STABLE_KIND, test.stable_pair_wasm.clone(), vec![e, 0_u32.into_val(e), FEE.into_val(e)], FactoryError::CreateStablePairInvalidAmp),
+        (STABLE_KIND, test.stable_pair_wasm.clone(), vec![e, AMP.into_val(e), 10_000_u32.into_val(e)], FactoryError::SetPairFeeInvalidFee),
+        (WEIGHTED_KIND, test.weighted_pair_wasm.clone(), vec![e, 9_901_u32.into_val(e), FEE.into_val(e)], FactoryError::CreateWeightedPairInvalidWeights),
+        (WEIGHTED_KIND, test.weighted_pair_wasm.clone(), vec![e, 99_u32.into_val(e), FEE.into_val(e)], FactoryError::CreateWeightedPairInvalidWeights),
+        (CONCENTRATED_KIND, test.concentrated_pair_wasm.clone(), vec![e, FEE.into_val(e), 0_u32.into_val(e), (1_u128 << 64).into_val(e)], FactoryError::CreateConcentratedPairInvalidTickSpacing),
+        (CONCENTRATED_KIND, test.concentrated_pair_wasm.clone(), vec![e, FEE.into_val(e), 60_u32.into_val(e), 1_u128.into_val(e)], FactoryError::CreateConcentratedPairInvalidPrice),
+        // A registered kind is checked like the built-in kind whose wasm it uses
+        (cp_5_kind.clone(), test.pair_wasm.clone(), vec![e], FactoryError::PoolTemplateInvalidInitArgs),
+        (cp_5_kind.clone(), test.pair_wasm.clone(), vec![e, 10_000_u32.into_val(e)], FactoryError::SetPairFeeInvalidFee),
+        (STABLE_4_KIND, test.stable_pair_wasm.clone(), vec![e], FactoryError::PoolTemplateInvalidInitArgs),
+    ];
+    for (kind, wasm_hash, init_args, error) in invalid {
+        assert_eq!(test.contract.try_set_pool_template(&kind, &wasm_hash, &init_args), Err(Ok(error)));
+    }
+
+    // The built-in templates are unchanged
+    assert_eq!(test.contract.pool_template(&CONSTANT_PRODUCT_KIND).init_args, vec![e, 30_u32.into_val(e)]);
+    assert_eq!(test.contract.pool_template(&STABLE_KIND).init_args, vec![e]);
+    assert_eq!(test.contract.pool_kinds(), vec![e, CONSTANT_PRODUCT_KIND, STABLE_KIND, WEIGHTED_KIND, CONCENTRATED_KIND]);
+
+    // The templates of the other built-in kinds can have no init args, they are then given to the create functions
+    test.contract.set_pool_template(&STABLE_KIND, &test.stable_pair_wasm, &vec![e]);
+    test.contract.set_pool_template(&cp_5_kind, &test.pair_wasm, &vec![e, 5_u32.into_val(e)]);
+    // The init args of a template with another wasm are not checked
+    let other_wasm = e.deployer().upload_contract_wasm(token::WASM);
+    test.contract.set_pool_template(&symbol_short!("other"), &other_wasm, &vec![e]);
+}
+
+#[test]
+fn create_pair_missing_template_or_init_args() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    let result = test.contract.try_create_pair_with_kind(&test.token_0.address, &test.token_1.address, &STABLE_KIND);
+    assert_eq!(result, Err(Ok(FactoryError::StablePairWasmHashNotSet)));
+    // The stable pairs can not be initialized without an amplification coefficient and a fee
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    let result = test.contract.try_create_pair_with_kind(&test.token_0.address, &test.token_1.address, &STABLE_KIND);
+    assert_eq!(result, Err(Ok(FactoryError::PoolTemplateMissingInitArgs)));
+    let result = test.contract.try_create_pair_with_kind(&test.token_0.address, &test.token_1.address, &STABLE_4_KIND);
+    assert_eq!(result, Err(Ok(FactoryError::PoolTemplateDoesNotExist)));
+    let result = test.contract.try_get_pair_with_kind(&test.token_0.address, &test.token_1.address, &STABLE_4_KIND);
+    assert_eq!(result, Err(Ok(FactoryError::PairDoesNotExist)));
+}
+
+#[test]
+fn create_pair_from_template() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    set_stable_4_template(&test);
+
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    // The pair of a registered kind lives alongside the constant product pair
+    let stable_4_pair_address = test.contract.create_pair_with_kind(&test.token_1.address, &test.token_0.address, &STABLE_4_KIND);
+    assert_ne!(stable_4_pair_address, pair_address);
+
+    assert_eq!(test.contract.all_pairs_length(), 2);
+    assert_eq!(test.contract.all_pairs_with_kind(&0), (pair_address.clone(), CONSTANT_PRODUCT_KIND));
+    assert_eq!(test.contract.all_pairs_with_kind(&1), (stable_4_pair_address.clone(), STABLE_4_KIND));
+    assert_eq!(test.contract.get_pair_with_kind(&test.token_0.address, &test.token_1.address, &STABLE_4_KIND), stable_4_pair_address);
+    assert_eq!(test.contract.get_pair_with_kind(&test.token_1.address, &test.token_0.address, &STABLE_4_KIND), stable_4_pair_address);
+    assert_eq!(test.contract.get_pair(&test.token_0.address, &test.token_1.address), pair_address);
+
+    // The salt of a pair of a registered kind is the salt of the pair followed by the XDR of the kind
+    let mut salt = Bytes::new(&test.env);
+    salt.append(&test.token_0.address.clone().to_xdr(&test.env));
+    salt.append(&test.token_1.address.clone().to_xdr(&test.env));
+    salt.append(&STABLE_4_KIND.to_xdr(&test.env));
+    let deterministic_address = test.env.deployer().with_address(test.contract.address.clone(), test.env.crypto().sha256(&salt)).deployed_address();
+    assert_eq!(stable_4_pair_address, deterministic_address);
+
+    // The template init args follow the factory and the sorted tokens
+    let stable_pair_client = SoroswapStablePairClient::new(&test.env, &stable_4_pair_address);
+    assert_eq!(stable_pair_client.factory(), test.contract.address);
+    assert_eq!(stable_pair_client.token_0(), test.token_0.address);
+    assert_eq!(stable_pair_client.token_1(), test.token_1.address);
+    assert_eq!(stable_pair_client.amp(), AMP);
+    assert_eq!(stable_pair_client.fee(), FEE);
+
+    let result = test.contract.try_create_pair_with_kind(&test.token_0.address, &test.token_1.address, &STABLE_4_KIND);
+    assert_eq!(result, Err(Ok(FactoryError::CreatePairAlreadyExists)));
+}
+
+#[test]
+fn create_pair_with_built_in_template() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_pool_template(&STABLE_KIND, &test.stable_pair_wasm, &vec![&test.env, AMP.into_val(&test.env), FEE.into_val(&test.env)]);
+
+    // The pair is deployed where create_stable_pair would have deployed it
+    let stable_pair_address = test.contract.create_pair_with_kind(&test.token_1.address, &test.token_0.address, &STABLE_KIND);
+    assert_eq!(test.contract.get_stable_pair(&test.token_0.address, &test.token_1.address), stable_pair_address);
+    assert_eq!(test.contract.all_pairs(&0), stable_pair_address);
+    assert_eq!(test.contract.all_pairs_with_kind(&0), (stable_pair_address.clone(), STABLE_KIND));
+
+    let stable_pair_client = SoroswapStablePairClient::new(&test.env, &stable_pair_address);
+    assert_eq!(stable_pair_client.amp(), AMP);
+    assert_eq!(stable_pair_client.fee(), FEE);
+
+    let result = test.contract.try_create_stable_pair(&test.token_0.address, &test.token_1.address, &AMP, &FEE);
+    assert_eq!(result, Err(Ok(FactoryError::CreatePairAlreadyExists)));
+}
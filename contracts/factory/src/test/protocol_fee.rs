@@ -0,0 +1,77 @@
+use crate::test::{SoroswapFactoryTest};
+use soroswap_factory_interface::{FactoryError};
+use soroban_sdk::{
+    IntoVal,
+    testutils::{
+        MockAuth,
+        MockAuthInvoke,
+    },
+};
+
+#[test]
+fn protocol_fee_fraction_default() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    assert_eq!(test.contract.protocol_fee_fraction(), (1, 6));
+}
+
+#[test]
+fn protocol_fee_fraction_not_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let res = test.contract.try_protocol_fee_fraction();
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+
+    let res = test.contract.try_set_protocol_fee_fraction(&1, &4);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn set_protocol_fee_fraction() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    test.contract.set_protocol_fee_fraction(&1, &4);
+    assert_eq!(test.contract.protocol_fee_fraction(), (1, 4));
+
+    test.contract.set_protocol_fee_fraction(&1, &10);
+    assert_eq!(test.contract.protocol_fee_fraction(), (1, 10));
+}
+
+#[test]
+fn set_protocol_fee_fraction_invalid() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    let res = test.contract.try_set_protocol_fee_fraction(&0, &4);
+    assert_eq!(res, Err(Ok(FactoryError::SetProtocolFeeFractionInvalid)));
+
+    let res = test.contract.try_set_protocol_fee_fraction(&4, &4);
+    assert_eq!(res, Err(Ok(FactoryError::SetProtocolFeeFractionInvalid)));
+
+    let res = test.contract.try_set_protocol_fee_fraction(&1, &0);
+    assert_eq!(res, Err(Ok(FactoryError::SetProtocolFeeFractionInvalid)));
+
+    assert_eq!(test.contract.protocol_fee_fraction(), (1, 6));
+}
+
+#[test]
+#[should_panic]
+fn set_protocol_fee_fraction_with_mock_auth_not_allowed() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.user.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "set_protocol_fee_fraction",
+                    args: (1_u32, 4_u32).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .set_protocol_fee_fraction(&1, &4);
+}
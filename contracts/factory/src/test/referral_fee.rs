@@ -0,0 +1,92 @@
+use crate::test::{SoroswapFactoryTest};
+use crate::event::NewMaxReferralFeeEvent;
+use soroswap_factory_interface::{FactoryError};
+use soroban_sdk::{
+    IntoVal,
+    symbol_short,
+    vec,
+    testutils::{
+        Events,
+        MockAuth,
+        MockAuthInvoke,
+    },
+};
+
+#[test]
+fn max_referral_fee_default() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    assert_eq!(test.contract.max_referral_fee(), 0);
+}
+
+#[test]
+fn max_referral_fee_not_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let res = test.contract.try_max_referral_fee();
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+
+    let res = test.contract.try_set_max_referral_fee(&50);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn set_max_referral_fee() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    test.contract.set_max_referral_fee(&50);
+    assert_eq!(test.contract.max_referral_fee(), 50);
+
+    let max_referral_fee_event = test.env.events().all().last().unwrap();
+    let expected_max_referral_fee_event: NewMaxReferralFeeEvent = NewMaxReferralFeeEvent {
+        old: 0,
+        new: 50,
+    };
+    assert_eq!(
+        vec![&test.env, max_referral_fee_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("ref_fee")).into_val(&test.env),
+                (expected_max_referral_fee_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    // The maximum allowed is 10%
+    test.contract.set_max_referral_fee(&1_000);
+    assert_eq!(test.contract.max_referral_fee(), 1_000);
+}
+
+#[test]
+fn set_max_referral_fee_invalid() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    let res = test.contract.try_set_max_referral_fee(&1_001);
+    assert_eq!(res, Err(Ok(FactoryError::SetMaxReferralFeeInvalid)));
+    assert_eq!(test.contract.max_referral_fee(), 0);
+}
+
+#[test]
+#[should_panic]
+fn set_max_referral_fee_with_mock_auth_not_allowed() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.user.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "set_max_referral_fee",
+                    args: (50_u32,).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .set_max_referral_fee(&50);
+}
@@ -0,0 +1,96 @@
+use crate::test::{SoroswapFactoryTest, SoroswapStablePairClient};
+use soroban_sdk::{xdr::{ToXdr},
+    Bytes,
+};
+use soroswap_factory_interface::{FactoryError, STABLE_KIND};
+
+const AMP: u32 = 200;
+const FEE: u32 = 4;
+
+#[test]
+fn create_stable_pair_not_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let result = test.contract.try_create_stable_pair(&test.token_0.address, &test.token_1.address, &AMP, &FEE);
+    assert_eq!(result, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn create_stable_pair_wasm_hash_not_set() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    assert_eq!(test.contract.try_stable_pair_wasm_hash(), Err(Ok(FactoryError::StablePairWasmHashNotSet)));
+    let result = test.contract.try_create_stable_pair(&test.token_0.address, &test.token_1.address, &AMP, &FEE);
+    assert_eq!(result, Err(Ok(FactoryError::StablePairWasmHashNotSet)));
+}
+
+#[test]
+fn create_stable_pair_invalid_parameters() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+
+    let result = test.contract.try_create_stable_pair(&test.token_0.address, &test.token_1.address, &0, &FEE);
+    assert_eq!(result, Err(Ok(FactoryError::CreateStablePairInvalidAmp)));
+    let result = test.contract.try_create_stable_pair(&test.token_0.address, &test.token_1.address, &1_000_001, &FEE);
+    assert_eq!(result, Err(Ok(FactoryError::CreateStablePairInvalidAmp)));
+    let result = test.contract.try_create_stable_pair(&test.token_0.address, &test.token_1.address, &AMP, &1_001);
+    assert_eq!(result, Err(Ok(FactoryError::SetPairFeeInvalidFee)));
+    let result = test.contract.try_create_stable_pair(&test.token_0.address, &test.token_0.address, &AMP, &FEE);
+    assert_eq!(result, Err(Ok(FactoryError::CreatePairIdenticalTokens)));
+}
+
+#[test]
+fn create_stable_pair() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    assert_eq!(test.contract.stable_pair_wasm_hash(), test.stable_pair_wasm);
+
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    // The stable pair of the same tokens lives alongside the constant product pair
+    let stable_pair_address = test.contract.create_stable_pair(&test.token_1.address, &test.token_0.address, &AMP, &FEE);
+    assert_ne!(stable_pair_address, pair_address);
+
+    assert_eq!(test.contract.all_pairs_length(), 2);
+    assert_eq!(test.contract.all_pairs_with_kind(&1), (stable_pair_address.clone(), STABLE_KIND));
+    assert_eq!(test.contract.get_pair(&test.token_0.address, &test.token_1.address), pair_address);
+    assert_eq!(test.contract.get_stable_pair(&test.token_0.address, &test.token_1.address), stable_pair_address);
+    assert_eq!(test.contract.get_stable_pair(&test.token_1.address, &test.token_0.address), stable_pair_address);
+
+    // The salt of a stable pair is the salt of the pair followed by "stable"
+    let mut salt = Bytes::new(&test.env);
+    salt.append(&test.token_0.address.clone().to_xdr(&test.env));
+    salt.append(&test.token_1.address.clone().to_xdr(&test.env));
+    salt.append(&Bytes::from_slice(&test.env, b"stable"));
+    let deterministic_address = test.env.deployer().with_address(test.contract.address.clone(), test.env.crypto().sha256(&salt)).deployed_address();
+    assert_eq!(stable_pair_address, deterministic_address);
+
+    let stable_pair_client = SoroswapStablePairClient::new(&test.env, &stable_pair_address);
+    assert_eq!(stable_pair_client.factory(), test.contract.address);
+    assert_eq!(stable_pair_client.token_0(), test.token_0.address);
+    assert_eq!(stable_pair_client.token_1(), test.token_1.address);
+    assert_eq!(stable_pair_client.amp(), AMP);
+    assert_eq!(stable_pair_client.fee(), FEE);
+}
+
+#[test]
+fn double_stable_pair_creation() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+
+    test.contract.create_stable_pair(&test.token_0.address, &test.token_1.address, &AMP, &FEE);
+    let result = test.contract.try_create_stable_pair(&test.token_1.address, &test.token_0.address, &AMP, &FEE);
+    assert_eq!(result, Err(Ok(FactoryError::CreatePairAlreadyExists)));
+}
+
+#[test]
+fn get_stable_pair_does_not_exist() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    let result = test.contract.try_get_stable_pair(&test.token_0.address, &test.token_1.address);
+    assert_eq!(result, Err(Ok(FactoryError::PairDoesNotExist)));
+}
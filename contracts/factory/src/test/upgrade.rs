@@ -0,0 +1,247 @@
+use crate::test::{SoroswapFactoryTest, SoroswapPairClient, SoroswapStablePairClient, token};
+use crate::event::{NewPairWasmHashEvent, UpgradePairEvent};
+use crate::storage::DataKey;
+use crate::pair::Pair;
+use soroswap_factory_interface::{FactoryError, PoolTemplate, CONSTANT_PRODUCT_KIND, STABLE_KIND};
+use soroban_sdk::{
+    IntoVal,
+    Symbol,
+    symbol_short,
+    vec,
+    testutils::{
+        Events,
+        MockAuth,
+        MockAuthInvoke,
+    },
+};
+
+const STABLE_4_KIND: Symbol = symbol_short!("stable_4");
+
+#[test]
+fn upgrade_not_initialized() {
+    let test = SoroswapFactoryTest::setup();
+
+    let res = test.contract.try_storage_version();
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+
+    let res = test.contract.try_set_pair_wasm_hash(&test.pair_wasm);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+
+    let res = test.contract.try_upgrade(&test.pair_wasm);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+
+    let res = test.contract.try_migrate();
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+
+    let res = test.contract.try_upgrade_pair(&test.user, &test.pair_wasm);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn storage_version() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    assert_eq!(test.contract.storage_version(), 1);
+
+    // Nothing to migrate
+    assert_eq!(test.contract.migrate(), 1);
+    assert_eq!(test.contract.storage_version(), 1);
+}
+
+#[test]
+fn migrate_from_version_0() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    // Layout of version 0: no storage version, the pair wasm hash has its own key and the pairs are keyed by
+    // their tokens
+    test.env.as_contract(&test.contract.address, || {
+        let token_pair = Pair::new(test.token_0.address.clone(), test.token_1.address.clone()).unwrap();
+        test.env.storage().instance().remove(&DataKey::StorageVersion);
+        test.env.storage().instance().remove(&DataKey::PoolKinds);
+        test.env.storage().persistent().remove(&DataKey::PoolTemplate(CONSTANT_PRODUCT_KIND));
+        test.env.storage().persistent().set(&DataKey::PairWasmHash, &test.pair_wasm);
+        test.env.storage().persistent().remove(&DataKey::PairAddressesByVariant(token_pair.clone(), CONSTANT_PRODUCT_KIND));
+        test.env.storage().persistent().remove(&DataKey::PairVariants(token_pair.clone()));
+        test.env.storage().persistent().set(&DataKey::PairAddressesByTokens(token_pair), &pair_address);
+    });
+    assert_eq!(test.contract.storage_version(), 0);
+
+    assert_eq!(test.contract.migrate(), 1);
+    assert_eq!(test.contract.storage_version(), 1);
+    assert_eq!(test.contract.pool_kinds(), vec![&test.env, CONSTANT_PRODUCT_KIND]);
+    // The template keeps the wasm of version 0, whose pairs take no fee in `initialize`
+    assert_eq!(
+        test.contract.pool_template(&CONSTANT_PRODUCT_KIND),
+        PoolTemplate { wasm_hash: test.pair_wasm.clone(), init_args: vec![&test.env] }
+    );
+    test.env.as_contract(&test.contract.address, || {
+        assert!(!test.env.storage().persistent().has(&DataKey::PairWasmHash));
+    });
+
+    // The pairs created before are still found through their old keys
+    assert_eq!(test.contract.get_pair(&test.token_0.address, &test.token_1.address), pair_address);
+    assert!(test.contract.pair_exists(&test.token_1.address, &test.token_0.address));
+    assert_eq!(test.contract.pair_variants(&test.token_0.address, &test.token_1.address), vec![&test.env, CONSTANT_PRODUCT_KIND]);
+    let res = test.contract.try_create_pair(&test.token_0.address, &test.token_1.address);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairAlreadyExists)));
+
+    // Replacing the wasm hash gives the template the default fee
+    test.contract.set_pair_wasm_hash(&test.pair_wasm);
+    assert_eq!(test.contract.pool_template(&CONSTANT_PRODUCT_KIND).init_args, vec![&test.env, 30_u32.into_val(&test.env)]);
+    let new_pair_address = test.contract.create_pair(&test.token_2.address, &test.token_3.address);
+    assert_eq!(SoroswapPairClient::new(&test.env, &new_pair_address).fee(), 30);
+
+    // The other kinds are registered after the migration
+    test.contract.set_pool_template(&STABLE_4_KIND, &test.stable_pair_wasm, &vec![&test.env, 400_u32.into_val(&test.env), 4_u32.into_val(&test.env)]);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    assert_eq!(test.contract.pool_kinds(), vec![&test.env, CONSTANT_PRODUCT_KIND, STABLE_4_KIND, STABLE_KIND]);
+}
+
+#[test]
+fn set_pair_wasm_hash() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    assert_eq!(test.contract.pair_wasm_hash(), test.pair_wasm);
+
+    let new_wasm_hash = test.env.deployer().upload_contract_wasm(token::WASM);
+    test.contract.set_pair_wasm_hash(&new_wasm_hash);
+    assert_eq!(test.contract.pair_wasm_hash(), new_wasm_hash);
+
+    let pair_wasm_event = test.env.events().all().last().unwrap();
+    let expected_pair_wasm_event: NewPairWasmHashEvent = NewPairWasmHashEvent {
+        old: test.pair_wasm.clone(),
+        new: new_wasm_hash.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, pair_wasm_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("pair_wasm")).into_val(&test.env),
+                (expected_pair_wasm_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn upgrade_pair() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let pair_address = test.contract.get_pair(&test.token_0.address, &test.token_1.address);
+    let pair_client = SoroswapPairClient::new(&test.env, &pair_address);
+    test.contract.set_pair_fee(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND, &5);
+
+    test.contract.upgrade_pair(&pair_address, &test.pair_wasm);
+
+    let upgrade_pair_event = test.env.events().all().last().unwrap();
+    let expected_upgrade_pair_event: UpgradePairEvent = UpgradePairEvent {
+        pair: pair_address.clone(),
+        new_wasm_hash: test.pair_wasm.clone(),
+        storage_version: 1,
+    };
+    assert_eq!(
+        vec![&test.env, upgrade_pair_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("upg_pair")).into_val(&test.env),
+                (expected_upgrade_pair_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    // The pair keeps its data
+    assert_eq!(pair_client.storage_version(), 1);
+    assert_eq!(pair_client.token_0(), test.token_0.address);
+    assert_eq!(pair_client.token_1(), test.token_1.address);
+    assert_eq!(pair_client.factory(), test.contract.address);
+    assert_eq!(pair_client.fee(), 5);
+}
+
+#[test]
+fn upgrade_stable_pair() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    let stable_pair_address = test.contract.create_stable_pair(&test.token_0.address, &test.token_1.address, &400, &4);
+    let stable_pair_client = SoroswapStablePairClient::new(&test.env, &stable_pair_address);
+
+    test.contract.upgrade_pair(&stable_pair_address, &test.stable_pair_wasm);
+
+    // The stable pair has no storage version, so the event reports 0
+    let upgrade_pair_event = test.env.events().all().last().unwrap();
+    let expected_upgrade_pair_event: UpgradePairEvent = UpgradePairEvent {
+        pair: stable_pair_address.clone(),
+        new_wasm_hash: test.stable_pair_wasm.clone(),
+        storage_version: 0,
+    };
+    assert_eq!(
+        vec![&test.env, upgrade_pair_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("upg_pair")).into_val(&test.env),
+                (expected_upgrade_pair_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    // The stable pair keeps its data
+    assert_eq!(stable_pair_client.token_0(), test.token_0.address);
+    assert_eq!(stable_pair_client.token_1(), test.token_1.address);
+    assert_eq!(stable_pair_client.amp(), 400);
+    assert_eq!(stable_pair_client.fee(), 4);
+}
+
+#[test]
+#[should_panic]
+fn upgrade_pair_with_mock_auth_not_allowed() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let pair_address = test.contract.get_pair(&test.token_0.address, &test.token_1.address);
+
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.user.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "upgrade_pair",
+                    args: (pair_address.clone(), test.pair_wasm.clone()).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .upgrade_pair(&pair_address, &test.pair_wasm);
+}
+
+#[test]
+#[should_panic]
+fn upgrade_with_mock_auth_not_allowed() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.user.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "upgrade",
+                    args: (test.pair_wasm.clone(),).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .upgrade(&test.pair_wasm);
+}
@@ -0,0 +1,98 @@
+use crate::test::{SoroswapFactoryTest};
+use soroban_sdk::{
+    vec,
+    IntoVal,
+    Symbol,
+    symbol_short,
+};
+use soroswap_factory_interface::{FactoryError, CONSTANT_PRODUCT_KIND, STABLE_KIND, WEIGHTED_KIND, CONCENTRATED_KIND};
+
+const AMP: u32 = 400;
+// Fee tiers of the stable pair, deployed from templates
+const STABLE_1_VARIANT: Symbol = symbol_short!("stable_1");
+const STABLE_50_VARIANT: Symbol = symbol_short!("stable_50");
+
+fn set_stable_template(test: &SoroswapFactoryTest, variant: &Symbol, fee: u32) {
+    test.contract.set_pool_template(variant, &test.stable_pair_wasm, &vec![&test.env, AMP.into_val(&test.env), fee.into_val(&test.env)]);
+}
+
+#[test]
+fn pair_variants_not_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    assert_eq!(test.contract.try_pair_variants(&test.token_0.address, &test.token_1.address), Err(Ok(FactoryError::NotInitialized)));
+    assert_eq!(test.contract.try_get_pairs(&test.token_0.address, &test.token_1.address), Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn pair_variants_identical_tokens() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    assert_eq!(test.contract.try_pair_variants(&test.token_0.address, &test.token_0.address), Err(Ok(FactoryError::CreatePairIdenticalTokens)));
+    assert_eq!(test.contract.try_get_pairs(&test.token_0.address, &test.token_0.address), Err(Ok(FactoryError::CreatePairIdenticalTokens)));
+}
+
+#[test]
+fn pair_variants_no_pairs() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    assert_eq!(test.contract.pair_variants(&test.token_0.address, &test.token_1.address), vec![&test.env]);
+    assert_eq!(test.contract.get_pairs(&test.token_0.address, &test.token_1.address), vec![&test.env]);
+}
+
+#[test]
+fn pair_variants() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    set_stable_template(&test, &STABLE_50_VARIANT, 50);
+    set_stable_template(&test, &STABLE_1_VARIANT, 1);
+
+    // The variants are listed in creation order
+    let stable_50_pair = test.contract.create_pair_with_kind(&test.token_0.address, &test.token_1.address, &STABLE_50_VARIANT);
+    let stable_1_pair = test.contract.create_pair_with_kind(&test.token_1.address, &test.token_0.address, &STABLE_1_VARIANT);
+    let stable_pair = test.contract.create_stable_pair(&test.token_0.address, &test.token_1.address, &AMP, &4);
+    let pair = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    let variants = vec![&test.env, STABLE_50_VARIANT, STABLE_1_VARIANT, STABLE_KIND, CONSTANT_PRODUCT_KIND];
+    assert_eq!(test.contract.pair_variants(&test.token_0.address, &test.token_1.address), variants);
+    assert_eq!(test.contract.pair_variants(&test.token_1.address, &test.token_0.address), variants);
+
+    let pairs = vec![
+        &test.env,
+        (STABLE_50_VARIANT, stable_50_pair),
+        (STABLE_1_VARIANT, stable_1_pair),
+        (STABLE_KIND, stable_pair),
+        (CONSTANT_PRODUCT_KIND, pair),
+    ];
+    assert_eq!(test.contract.get_pairs(&test.token_0.address, &test.token_1.address), pairs);
+    assert_eq!(test.contract.get_pairs(&test.token_1.address, &test.token_0.address), pairs);
+    for (variant, pair_address) in pairs.iter() {
+        assert_eq!(test.contract.get_pair_with_kind(&test.token_0.address, &test.token_1.address, &variant), pair_address);
+    }
+}
+
+#[test]
+fn pair_addresses_match_library() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    test.contract.set_weighted_pair_wasm_hash(&test.weighted_pair_wasm);
+    test.contract.set_concentrated_pair_wasm_hash(&test.concentrated_pair_wasm);
+    set_stable_template(&test, &STABLE_1_VARIANT, 1);
+
+    let pairs = [
+        (CONSTANT_PRODUCT_KIND, test.contract.create_pair(&test.token_1.address, &test.token_0.address)),
+        (STABLE_KIND, test.contract.create_stable_pair(&test.token_0.address, &test.token_1.address, &AMP, &4)),
+        (WEIGHTED_KIND, test.contract.create_weighted_pair(&test.token_1.address, &test.token_0.address, &8_000, &30)),
+        (CONCENTRATED_KIND, test.contract.create_concentrated_pair(&test.token_0.address, &test.token_1.address, &30, &60, &(1_u128 << 64))),
+        (STABLE_1_VARIANT, test.contract.create_pair_with_kind(&test.token_1.address, &test.token_0.address, &STABLE_1_VARIANT)),
+    ];
+
+    // The library computes the address of every variant without calling the factory
+    for (variant, pair_address) in pairs {
+        let expected = soroswap_library::pair_for_variant(test.env.clone(), test.contract.address.clone(), test.token_0.address.clone(), test.token_1.address.clone(), variant.clone()).unwrap();
+        assert_eq!(pair_address, expected);
+        let expected = soroswap_library::pair_for_variant(test.env.clone(), test.contract.address.clone(), test.token_1.address.clone(), test.token_0.address.clone(), variant).unwrap();
+        assert_eq!(pair_address, expected);
+    }
+}
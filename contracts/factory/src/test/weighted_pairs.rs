@@ -0,0 +1,108 @@
+use crate::test::{SoroswapFactoryTest, SoroswapWeightedPairClient};
+use soroban_sdk::{xdr::{ToXdr},
+    Bytes,
+};
+use soroswap_factory_interface::{FactoryError, WEIGHTED_KIND};
+
+const WEIGHT: u32 = 8_000;
+const FEE: u32 = 30;
+
+#[test]
+fn create_weighted_pair_not_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let result = test.contract.try_create_weighted_pair(&test.token_0.address, &test.token_1.address, &WEIGHT, &FEE);
+    assert_eq!(result, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn create_weighted_pair_wasm_hash_not_set() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    assert_eq!(test.contract.try_weighted_pair_wasm_hash(), Err(Ok(FactoryError::WeightedPairWasmHashNotSet)));
+    let result = test.contract.try_create_weighted_pair(&test.token_0.address, &test.token_1.address, &WEIGHT, &FEE);
+    assert_eq!(result, Err(Ok(FactoryError::WeightedPairWasmHashNotSet)));
+}
+
+#[test]
+fn create_weighted_pair_invalid_parameters() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_weighted_pair_wasm_hash(&test.weighted_pair_wasm);
+
+    let result = test.contract.try_create_weighted_pair(&test.token_0.address, &test.token_1.address, &99, &FEE);
+    assert_eq!(result, Err(Ok(FactoryError::CreateWeightedPairInvalidWeights)));
+    let result = test.contract.try_create_weighted_pair(&test.token_0.address, &test.token_1.address, &9_901, &FEE);
+    assert_eq!(result, Err(Ok(FactoryError::CreateWeightedPairInvalidWeights)));
+    let result = test.contract.try_create_weighted_pair(&test.token_0.address, &test.token_1.address, &WEIGHT, &1_001);
+    assert_eq!(result, Err(Ok(FactoryError::SetPairFeeInvalidFee)));
+    let result = test.contract.try_create_weighted_pair(&test.token_0.address, &test.token_0.address, &WEIGHT, &FEE);
+    assert_eq!(result, Err(Ok(FactoryError::CreatePairIdenticalTokens)));
+}
+
+#[test]
+fn create_weighted_pair() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_weighted_pair_wasm_hash(&test.weighted_pair_wasm);
+    assert_eq!(test.contract.weighted_pair_wasm_hash(), test.weighted_pair_wasm);
+
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    // The weighted pair of the same tokens lives alongside the constant product pair
+    let weighted_pair_address = test.contract.create_weighted_pair(&test.token_0.address, &test.token_1.address, &WEIGHT, &FEE);
+    assert_ne!(weighted_pair_address, pair_address);
+
+    assert_eq!(test.contract.all_pairs_length(), 2);
+    assert_eq!(test.contract.all_pairs_with_kind(&1), (weighted_pair_address.clone(), WEIGHTED_KIND));
+    assert_eq!(test.contract.get_weighted_pair(&test.token_0.address, &test.token_1.address), weighted_pair_address);
+    assert_eq!(test.contract.get_weighted_pair(&test.token_1.address, &test.token_0.address), weighted_pair_address);
+
+    // The salt of a weighted pair is the salt of the pair followed by "weighted"
+    let mut salt = Bytes::new(&test.env);
+    salt.append(&test.token_0.address.clone().to_xdr(&test.env));
+    salt.append(&test.token_1.address.clone().to_xdr(&test.env));
+    salt.append(&Bytes::from_slice(&test.env, b"weighted"));
+    let deterministic_address = test.env.deployer().with_address(test.contract.address.clone(), test.env.crypto().sha256(&salt)).deployed_address();
+    assert_eq!(weighted_pair_address, deterministic_address);
+
+    let weighted_pair_client = SoroswapWeightedPairClient::new(&test.env, &weighted_pair_address);
+    assert_eq!(weighted_pair_client.factory(), test.contract.address);
+    assert_eq!(weighted_pair_client.token_0(), test.token_0.address);
+    assert_eq!(weighted_pair_client.token_1(), test.token_1.address);
+    assert_eq!(weighted_pair_client.weights(), (WEIGHT, 10_000 - WEIGHT));
+    assert_eq!(weighted_pair_client.fee(), FEE);
+}
+
+#[test]
+fn create_weighted_pair_reversed_tokens() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_weighted_pair_wasm_hash(&test.weighted_pair_wasm);
+
+    // The weight is given for token_a, so the pair gets the rest for its token 0
+    let weighted_pair_address = test.contract.create_weighted_pair(&test.token_1.address, &test.token_0.address, &WEIGHT, &FEE);
+    let weighted_pair_client = SoroswapWeightedPairClient::new(&test.env, &weighted_pair_address);
+    assert_eq!(weighted_pair_client.token_0(), test.token_0.address);
+    assert_eq!(weighted_pair_client.weights(), (10_000 - WEIGHT, WEIGHT));
+}
+
+#[test]
+fn double_weighted_pair_creation() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_weighted_pair_wasm_hash(&test.weighted_pair_wasm);
+
+    test.contract.create_weighted_pair(&test.token_0.address, &test.token_1.address, &WEIGHT, &FEE);
+    let result = test.contract.try_create_weighted_pair(&test.token_1.address, &test.token_0.address, &WEIGHT, &FEE);
+    assert_eq!(result, Err(Ok(FactoryError::CreatePairAlreadyExists)));
+}
+
+#[test]
+fn get_weighted_pair_does_not_exist() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    let result = test.contract.try_get_weighted_pair(&test.token_0.address, &test.token_1.address);
+    assert_eq!(result, Err(Ok(FactoryError::PairDoesNotExist)));
+}
@@ -21,4 +21,19 @@ pub enum SoroswapLibraryError {
 
     /// SoroswapLibrary: token_a and token_b have identical addresses
     SortIdenticalTokens = 306,
+
+    /// SoroswapLibrary: fee must be less than 10000 basis points
+    InvalidFee = 307,
+
+    /// SoroswapLibrary: arithmetic overflow
+    Overflow = 308,
+
+    /// SoroswapLibrary: price must be positive
+    InvalidPrice = 309,
+
+    /// SoroswapLibrary: amplification coefficient must be positive
+    InvalidAmp = 310,
+
+    /// SoroswapLibrary: weights must be positive and add up to 10000 basis points
+    InvalidWeights = 311,
 }
\ No newline at end of file
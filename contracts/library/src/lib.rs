@@ -1,7 +1,7 @@
 #![no_std] 
 use soroban_sdk::{
     contract, contractimpl,
-    Address, Env, Vec, 
+    Address, Env, Symbol, Vec, 
 };
 
 mod test;
@@ -10,22 +10,56 @@ mod reserves;
 mod quotes;
 mod error;
 mod math;
+mod stableswap;
+pub mod fixed_point;
+#[cfg(any(test, feature = "std"))]
+pub mod routes;
 
 
 pub use tokens::{
     sort_tokens,
-    pair_for
+    pair_salt,
+    pair_for,
+    stable_pair_for,
+    weighted_pair_for,
+    concentrated_pair_for,
+    pair_for_kind,
+    pair_for_variant,
+    PairKind,
+    RouteHop,
+    CONSTANT_PRODUCT_KIND,
+    STABLE_KIND,
+    WEIGHTED_KIND,
+    CONCENTRATED_KIND,
 };
 pub use reserves::{
     get_reserves_with_factory,
-    get_reserves_with_pair
+    get_reserves_with_pair,
+    get_fee_with_factory,
+    get_fee_with_pair,
+    get_amp_with_pair,
+    get_weights_with_pair
 };
 pub use quotes::{
     quote, 
     get_amount_out, 
     get_amount_in, 
+    get_amount_out_with_fee,
+    get_amount_in_with_fee,
     get_amounts_out, 
-    get_amounts_in
+    get_amounts_in,
+    quote_detailed,
+    get_amount_in_for_price_with_fee,
+    get_amount_in_for_price,
+    get_amount_out_stable,
+    get_amount_in_stable,
+    get_amount_out_weighted,
+    get_amount_in_weighted,
+    get_amounts_out_route,
+    get_amounts_in_route,
+    check_hop,
+    HopQuote,
+    PRICE_PRECISION
 };
 pub use error::SoroswapLibraryError;
 
@@ -90,6 +124,32 @@ pub trait SoroswapLibraryTrait {
     /// Returns `Result<(i128, i128), SoroswapLibraryError>` where `Ok` contains a tuple of sorted reserves, and `Err` indicates an error such as identical tokens or an issue with sorting.
     fn get_reserves_with_pair(e: Env, pair: Address, token_a: Address, token_b: Address) -> Result<(i128, i128), SoroswapLibraryError>;
 
+    /// Fetches the swap fee of a pair, in basis points, using the factory address.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<u32, SoroswapLibraryError>` where `Ok` contains the swap fee of the pair, and `Err` indicates an error such as identical tokens.
+    fn get_fee_with_factory(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<u32, SoroswapLibraryError>;
+
+    /// Fetches the swap fee of a pair, in basis points, knowing the pair address.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `pair` - The pair address.
+    ///
+    /// # Returns
+    ///
+    /// Returns the swap fee of the pair, or the default 0.3% if the pair was deployed before the fee could be configured.
+    fn get_fee_with_pair(e: Env, pair: Address) -> u32;
+
     /// Given some amount of an asset and pair reserves, returns an equivalent amount of the other asset.
     ///
     /// # Arguments
@@ -129,6 +189,34 @@ pub trait SoroswapLibraryTrait {
     /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount or liquidity.
     fn get_amount_in(amount_out: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, SoroswapLibraryError>;
 
+    /// Given an input amount of an asset, pair reserves and the pair swap fee, returns the maximum output amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount, liquidity or an invalid fee.
+    fn get_amount_out_with_fee(amount_in: i128, reserve_in: i128, reserve_out: i128, fee: u32) -> Result<i128, SoroswapLibraryError>;
+
+    /// Given an output amount of an asset, pair reserves and the pair swap fee, returns a required input amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount, liquidity or an invalid fee.
+    fn get_amount_in_with_fee(amount_out: i128, reserve_in: i128, reserve_out: i128, fee: u32) -> Result<i128, SoroswapLibraryError>;
+
     /// Performs chained get_amount_out calculations on any number of pairs.
     ///
     /// # Arguments
@@ -156,6 +244,199 @@ pub trait SoroswapLibraryTrait {
     ///
     /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
     fn get_amounts_in(e: Env, factory: Address, amount_out: i128, path: Vec<Address>) -> Result<Vec<i128>, SoroswapLibraryError>;
+
+    /// Quotes a trade along a path and returns the breakdown of every hop.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `amount` - The exact input amount if `exact_in` is true, otherwise the exact output amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `exact_in` - Whether `amount` is the input or the output of the trade.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<HopQuote>, SoroswapLibraryError>` where `Ok` contains the pair, reserves before and after, fee, prices and price impact of each hop, and `Err` indicates an error such as an invalid path.
+    fn quote_detailed(e: Env, factory: Address, amount: i128, path: Vec<Address>, exact_in: bool) -> Result<Vec<HopQuote>, SoroswapLibraryError>;
+
+    /// Given pair reserves, the pair swap fee and a price, returns the largest input amount whose execution price is at least that price, without rounding.
+    ///
+    /// # Arguments
+    ///
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    /// * `price` - The minimum price of the input asset in the output asset, with `PRICE_PRECISION`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the input amount, and `Err` indicates an error such as insufficient liquidity, an invalid fee or an invalid price.
+    fn get_amount_in_for_price_with_fee(reserve_in: i128, reserve_out: i128, fee: u32, price: i128) -> Result<i128, SoroswapLibraryError>;
+
+    /// Returns the largest input amount, up to a maximum, whose exact execution price along a path is at least a price.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `amount_in_max` - The maximum input amount.
+    /// * `price` - The minimum price of the first token of the path in the last one, with `PRICE_PRECISION`.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the input amount, 0 if no trade reaches the price, and `Err` indicates an error such as an invalid path or an invalid price.
+    fn get_amount_in_for_price(e: Env, factory: Address, amount_in_max: i128, price: i128, path: Vec<Address>) -> Result<i128, SoroswapLibraryError>;
+
+    /// Calculates the deterministic address for a stable pair without making any external calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the stable pair, and `Err` indicates an error such as identical tokens.
+    fn stable_pair_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError>;
+
+    /// Given an input amount of an asset and the reserves, amplification coefficient and swap fee of a stable pair, returns the maximum output amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `amp` - The amplification coefficient of the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount, liquidity, an invalid amplification coefficient or an invalid fee.
+    fn get_amount_out_stable(amount_in: i128, reserve_in: i128, reserve_out: i128, amp: u32, fee: u32) -> Result<i128, SoroswapLibraryError>;
+
+    /// Given an output amount of an asset and the reserves, amplification coefficient and swap fee of a stable pair, returns the required input amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `amp` - The amplification coefficient of the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount, liquidity, an invalid amplification coefficient or an invalid fee.
+    fn get_amount_in_stable(amount_out: i128, reserve_in: i128, reserve_out: i128, amp: u32, fee: u32) -> Result<i128, SoroswapLibraryError>;
+
+    /// Calculates the deterministic address for a weighted pair without making any external calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the weighted pair, and `Err` indicates an error such as identical tokens.
+    fn weighted_pair_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError>;
+
+    /// Calculates the deterministic address for a concentrated liquidity pair without making any external calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the concentrated pair, and `Err` indicates an error such as identical tokens.
+    fn concentrated_pair_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError>;
+
+    /// Calculates the deterministic address for the pair of the given variant, the kind of the pair in the factory.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    /// * `variant` - The variant of the pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` with the deterministic address of the pair.
+    fn pair_for_variant(e: Env, factory: Address, token_a: Address, token_b: Address, variant: Symbol) -> Result<Address, SoroswapLibraryError>;
+
+    /// Given an input amount of an asset and the reserves, weights and swap fee of a weighted pair, returns the maximum output amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `weight_in` - Weight of the input asset, in basis points.
+    /// * `weight_out` - Weight of the output asset, in basis points.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount, liquidity, invalid weights or an invalid fee.
+    fn get_amount_out_weighted(amount_in: i128, reserve_in: i128, reserve_out: i128, weight_in: u32, weight_out: u32, fee: u32) -> Result<i128, SoroswapLibraryError>;
+
+    /// Given an output amount of an asset and the reserves, weights and swap fee of a weighted pair, returns the required input amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `weight_in` - Weight of the input asset, in basis points.
+    /// * `weight_out` - Weight of the output asset, in basis points.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount, liquidity, invalid weights or an invalid fee.
+    fn get_amount_in_weighted(amount_out: i128, reserve_in: i128, reserve_out: i128, weight_in: u32, weight_out: u32, fee: u32) -> Result<i128, SoroswapLibraryError>;
+
+    /// Performs chained getAmountOut calculations along a path where every hop goes through a constant product, a stable or a weighted pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `hops` - The pair of each hop, with one element less than `path`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn get_amounts_out_route(e: Env, factory: Address, amount_in: i128, path: Vec<Address>, hops: Vec<RouteHop>) -> Result<Vec<i128>, SoroswapLibraryError>;
+
+    /// Performs chained getAmountIn calculations along a path where every hop goes through a constant product, a stable or a weighted pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `amount_out` - The output amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `hops` - The pair of each hop, with one element less than `path`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn get_amounts_in_route(e: Env, factory: Address, amount_out: i128, path: Vec<Address>, hops: Vec<RouteHop>) -> Result<Vec<i128>, SoroswapLibraryError>;
     
 
 
@@ -235,6 +516,36 @@ impl SoroswapLibraryTrait for SoroswapLibrary {
 
     }
 
+    /// Fetches the swap fee of a pair, in basis points, using the factory address.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<u32, SoroswapLibraryError>` where `Ok` contains the swap fee of the pair, and `Err` indicates an error such as identical tokens.
+    fn get_fee_with_factory(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<u32, SoroswapLibraryError> {
+        get_fee_with_factory(e, factory, token_a, token_b)
+    }
+
+    /// Fetches the swap fee of a pair, in basis points, using the pair address.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `pair` - The pair address.
+    ///
+    /// # Returns
+    ///
+    /// Returns the swap fee of the pair.
+    fn get_fee_with_pair(e: Env, pair: Address) -> u32 {
+        get_fee_with_pair(e, pair)
+    }
+
     /// Given some amount of an asset and pair reserves, returns an equivalent amount of the other asset.
     ///
     /// # Arguments
@@ -281,6 +592,38 @@ impl SoroswapLibraryTrait for SoroswapLibrary {
         get_amount_in(amount_out, reserve_in, reserve_out)
     }
 
+    /// Given an input amount of an asset, pair reserves and the pair swap fee, returns the maximum output amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount, liquidity or an invalid fee.
+    fn get_amount_out_with_fee(amount_in: i128, reserve_in: i128, reserve_out: i128, fee: u32) -> Result<i128, SoroswapLibraryError> {
+        get_amount_out_with_fee(amount_in, reserve_in, reserve_out, fee)
+    }
+
+    /// Given an output amount of an asset, pair reserves and the pair swap fee, returns a required input amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount, liquidity or an invalid fee.
+    fn get_amount_in_with_fee(amount_out: i128, reserve_in: i128, reserve_out: i128, fee: u32) -> Result<i128, SoroswapLibraryError> {
+        get_amount_in_with_fee(amount_out, reserve_in, reserve_out, fee)
+    }
+
     /// Performs chained get_amount_out calculations on any number of pairs.
     ///
     /// # Arguments
@@ -313,6 +656,225 @@ impl SoroswapLibraryTrait for SoroswapLibrary {
         get_amounts_in(e, factory, amount_out, path)
     }
 
+    /// Quotes a trade along a path and returns the breakdown of every hop.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `amount` - The exact input amount if `exact_in` is true, otherwise the exact output amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `exact_in` - Whether `amount` is the input or the output of the trade.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<HopQuote>, SoroswapLibraryError>` where `Ok` contains the pair, reserves before and after, fee, prices and price impact of each hop, and `Err` indicates an error such as an invalid path.
+    fn quote_detailed(e: Env, factory: Address, amount: i128, path: Vec<Address>, exact_in: bool) -> Result<Vec<HopQuote>, SoroswapLibraryError> {
+        quote_detailed(e, factory, amount, path, exact_in)
+    }
+
+    /// Given pair reserves, the pair swap fee and a price, returns the largest input amount whose execution price is at least that price, without rounding.
+    ///
+    /// # Arguments
+    ///
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    /// * `price` - The minimum price of the input asset in the output asset, with `PRICE_PRECISION`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the input amount, and `Err` indicates an error such as insufficient liquidity, an invalid fee or an invalid price.
+    fn get_amount_in_for_price_with_fee(reserve_in: i128, reserve_out: i128, fee: u32, price: i128) -> Result<i128, SoroswapLibraryError> {
+        get_amount_in_for_price_with_fee(reserve_in, reserve_out, fee, price)
+    }
+
+    /// Returns the largest input amount, up to a maximum, whose exact execution price along a path is at least a price.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `amount_in_max` - The maximum input amount.
+    /// * `price` - The minimum price of the first token of the path in the last one, with `PRICE_PRECISION`.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the input amount, 0 if no trade reaches the price, and `Err` indicates an error such as an invalid path or an invalid price.
+    fn get_amount_in_for_price(e: Env, factory: Address, amount_in_max: i128, price: i128, path: Vec<Address>) -> Result<i128, SoroswapLibraryError> {
+        get_amount_in_for_price(e, factory, amount_in_max, price, path)
+    }
+
+    /// Calculates the deterministic address for a stable pair without making any external calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the stable pair, and `Err` indicates an error such as identical tokens.
+    fn stable_pair_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError> {
+        stable_pair_for(e, factory, token_a, token_b)
+    }
+
+    /// Given an input amount of an asset and the reserves, amplification coefficient and swap fee of a stable pair, returns the maximum output amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `amp` - The amplification coefficient of the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount, liquidity, an invalid amplification coefficient or an invalid fee.
+    fn get_amount_out_stable(amount_in: i128, reserve_in: i128, reserve_out: i128, amp: u32, fee: u32) -> Result<i128, SoroswapLibraryError> {
+        get_amount_out_stable(amount_in, reserve_in, reserve_out, amp, fee)
+    }
+
+    /// Given an output amount of an asset and the reserves, amplification coefficient and swap fee of a stable pair, returns the required input amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `amp` - The amplification coefficient of the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount, liquidity, an invalid amplification coefficient or an invalid fee.
+    fn get_amount_in_stable(amount_out: i128, reserve_in: i128, reserve_out: i128, amp: u32, fee: u32) -> Result<i128, SoroswapLibraryError> {
+        get_amount_in_stable(amount_out, reserve_in, reserve_out, amp, fee)
+    }
+
+    /// Calculates the deterministic address for a weighted pair without making any external calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the weighted pair, and `Err` indicates an error such as identical tokens.
+    fn weighted_pair_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError> {
+        weighted_pair_for(e, factory, token_a, token_b)
+    }
+
+    /// Calculates the deterministic address for a concentrated liquidity pair without making any external calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the concentrated pair, and `Err` indicates an error such as identical tokens.
+    fn concentrated_pair_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError> {
+        concentrated_pair_for(e, factory, token_a, token_b)
+    }
+
+    /// Calculates the deterministic address for the pair of the given variant, the kind of the pair in the factory.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    /// * `variant` - The variant of the pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` with the deterministic address of the pair.
+    fn pair_for_variant(e: Env, factory: Address, token_a: Address, token_b: Address, variant: Symbol) -> Result<Address, SoroswapLibraryError> {
+        pair_for_variant(e, factory, token_a, token_b, variant)
+    }
+
+    /// Given an input amount of an asset and the reserves, weights and swap fee of a weighted pair, returns the maximum output amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `weight_in` - Weight of the input asset, in basis points.
+    /// * `weight_out` - Weight of the output asset, in basis points.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount, liquidity, invalid weights or an invalid fee.
+    fn get_amount_out_weighted(amount_in: i128, reserve_in: i128, reserve_out: i128, weight_in: u32, weight_out: u32, fee: u32) -> Result<i128, SoroswapLibraryError> {
+        get_amount_out_weighted(amount_in, reserve_in, reserve_out, weight_in, weight_out, fee)
+    }
+
+    /// Given an output amount of an asset and the reserves, weights and swap fee of a weighted pair, returns the required input amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `weight_in` - Weight of the input asset, in basis points.
+    /// * `weight_out` - Weight of the output asset, in basis points.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount, liquidity, invalid weights or an invalid fee.
+    fn get_amount_in_weighted(amount_out: i128, reserve_in: i128, reserve_out: i128, weight_in: u32, weight_out: u32, fee: u32) -> Result<i128, SoroswapLibraryError> {
+        get_amount_in_weighted(amount_out, reserve_in, reserve_out, weight_in, weight_out, fee)
+    }
+
+    /// Performs chained getAmountOut calculations along a path where every hop goes through a constant product, a stable or a weighted pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `hops` - The pair of each hop, with one element less than `path`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn get_amounts_out_route(e: Env, factory: Address, amount_in: i128, path: Vec<Address>, hops: Vec<RouteHop>) -> Result<Vec<i128>, SoroswapLibraryError> {
+        get_amounts_out_route(e, factory, amount_in, path, hops)
+    }
+
+    /// Performs chained getAmountIn calculations along a path where every hop goes through a constant product, a stable or a weighted pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `amount_out` - The output amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `hops` - The pair of each hop, with one element less than `path`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn get_amounts_in_route(e: Env, factory: Address, amount_out: i128, path: Vec<Address>, hops: Vec<RouteHop>) -> Result<Vec<i128>, SoroswapLibraryError> {
+        get_amounts_in_route(e, factory, amount_out, path, hops)
+    }
+
 
 
 }
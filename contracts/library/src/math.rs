@@ -1,14 +1,145 @@
-pub trait CheckedCeilingDiv {
-    fn checked_ceiling_div(self, divisor: i128) -> Option<i128>;
+// The library functions are pure and have no Env, so they cannot use the host U256.
+// This is the small part of an unsigned 256-bit integer that the quotes need.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct U256 {
+    // Field order matters: the derived Ord compares hi first
+    hi: u128,
+    lo: u128,
 }
 
-impl CheckedCeilingDiv for i128 {
-    fn checked_ceiling_div(self, divisor: i128) -> Option<i128> {
-        let result = self.checked_div(divisor)?;
-        if self % divisor != 0 {
-            result.checked_add(1)
+const LOW_64_BITS: u128 = u64::MAX as u128;
+
+impl U256 {
+    pub(crate) const ZERO: U256 = U256 { hi: 0, lo: 0 };
+    pub(crate) const ONE: U256 = U256 { hi: 0, lo: 1 };
+
+    pub(crate) fn from_u128(value: u128) -> U256 {
+        U256 { hi: 0, lo: value }
+    }
+
+    pub(crate) fn to_u128(self) -> Option<u128> {
+        if self.hi == 0 {
+            Some(self.lo)
         } else {
-            Some(result)
+            None
+        }
+    }
+
+    pub(crate) fn to_i128(self) -> Option<i128> {
+        i128::try_from(self.to_u128()?).ok()
+    }
+
+    /// Full product of two u128 values, which always fits in 256 bits.
+    pub(crate) fn mul(a: u128, b: u128) -> U256 {
+        let (a_hi, a_lo) = (a >> 64, a & LOW_64_BITS);
+        let (b_hi, b_lo) = (b >> 64, b & LOW_64_BITS);
+
+        let lo_lo = a_lo * b_lo;
+        let hi_lo = a_hi * b_lo;
+        let lo_hi = a_lo * b_hi;
+        let hi_hi = a_hi * b_hi;
+
+        let middle = (lo_lo >> 64) + (hi_lo & LOW_64_BITS) + (lo_hi & LOW_64_BITS);
+        U256 {
+            hi: hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (middle >> 64),
+            lo: (lo_lo & LOW_64_BITS) | (middle << 64),
         }
     }
-}
\ No newline at end of file
+
+    pub(crate) fn checked_mul_u128(self, other: u128) -> Option<U256> {
+        let high_part = U256::mul(self.hi, other);
+        if high_part.hi != 0 {
+            return None;
+        }
+        let low_part = U256::mul(self.lo, other);
+        Some(U256 {
+            hi: low_part.hi.checked_add(high_part.lo)?,
+            lo: low_part.lo,
+        })
+    }
+
+    pub(crate) fn checked_add(self, other: U256) -> Option<U256> {
+        let (lo, carry) = self.lo.overflowing_add(other.lo);
+        let hi = self.hi.checked_add(other.hi)?.checked_add(carry as u128)?;
+        Some(U256 { hi, lo })
+    }
+
+    pub(crate) fn checked_sub(self, other: U256) -> Option<U256> {
+        if self < other {
+            None
+        } else {
+            Some(self.wrapping_sub(other))
+        }
+    }
+
+    fn wrapping_sub(self, other: U256) -> U256 {
+        let (lo, borrow) = self.lo.overflowing_sub(other.lo);
+        let hi = self.hi.wrapping_sub(other.hi).wrapping_sub(borrow as u128);
+        U256 { hi, lo }
+    }
+
+    /// Quotient and remainder of the division, or None when dividing by zero.
+    pub(crate) fn checked_div_rem(self, divisor: U256) -> Option<(U256, U256)> {
+        if divisor == U256::ZERO {
+            return None;
+        }
+        let mut quotient = U256::ZERO;
+        let mut remainder = U256::ZERO;
+        for bit in (0..256u32).rev() {
+            // A remainder with its top bit set is already larger than any divisor once shifted,
+            // and the wrapping subtraction below gives the right result for it
+            let overflows = remainder.hi >> 127 == 1;
+            remainder = U256 {
+                hi: (remainder.hi << 1) | (remainder.lo >> 127),
+                lo: (remainder.lo << 1) | ((if bit >= 128 { self.hi >> (bit - 128) } else { self.lo >> bit }) & 1),
+            };
+            if overflows || remainder >= divisor {
+                remainder = remainder.wrapping_sub(divisor);
+                if bit >= 128 {
+                    quotient.hi |= 1 << (bit - 128);
+                } else {
+                    quotient.lo |= 1 << bit;
+                }
+            }
+        }
+        Some((quotient, remainder))
+    }
+
+    pub(crate) fn checked_div(self, divisor: U256) -> Option<U256> {
+        let (quotient, _) = self.checked_div_rem(divisor)?;
+        Some(quotient)
+    }
+
+    pub(crate) fn checked_ceiling_div(self, divisor: U256) -> Option<U256> {
+        let (quotient, remainder) = self.checked_div_rem(divisor)?;
+        if remainder != U256::ZERO {
+            quotient.checked_add(U256::ONE)
+        } else {
+            Some(quotient)
+        }
+    }
+}
+
+/// a * b / c rounded towards zero, like the i128 division, with a 256-bit intermediate product.
+/// Returns None if c is zero or the result does not fit in an i128.
+pub(crate) fn checked_mul_div(a: i128, b: i128, c: i128) -> Option<i128> {
+    let negative = (a < 0) ^ (b < 0) ^ (c < 0);
+    let (quotient, _) = U256::mul(a.unsigned_abs(), b.unsigned_abs()).checked_div_rem(U256::from_u128(c.unsigned_abs()))?;
+    to_signed(quotient.to_u128()?, negative)
+}
+
+/// a * b / c rounded up, for non-negative a and b and a positive c.
+pub(crate) fn checked_mul_div_ceil(a: i128, b: i128, c: i128) -> Option<i128> {
+    if a < 0 || b < 0 || c <= 0 {
+        return None;
+    }
+    U256::mul(a as u128, b as u128).checked_ceiling_div(U256::from_u128(c as u128))?.to_i128()
+}
+
+fn to_signed(magnitude: u128, negative: bool) -> Option<i128> {
+    if negative {
+        0i128.checked_sub_unsigned(magnitude)
+    } else {
+        i128::try_from(magnitude).ok()
+    }
+}
@@ -1,7 +1,46 @@
-use soroban_sdk::{Address, Env, Vec};
-use crate::reserves::{get_reserves_with_factory};
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+use crate::reserves::{get_reserves_with_pair, get_fee_with_pair, get_amp_with_pair, get_weights_with_pair};
+use crate::tokens::{pair_for, pair_for_variant, PairKind, RouteHop, CONSTANT_PRODUCT_KIND, STABLE_KIND, WEIGHTED_KIND, CONCENTRATED_KIND};
 use crate::error::SoroswapLibraryError;
-use crate::math::CheckedCeilingDiv;
+use crate::math::{checked_mul_div, checked_mul_div_ceil, U256};
+use crate::stableswap::{compute_d, get_y};
+use crate::fixed_point::{pow_up, ONE};
+
+// Swap fees are expressed in basis points
+const FEE_DENOMINATOR: i128 = 10_000;
+/// Swap fee of the pairs that have not been configured by the factory (0.3%)
+pub const DEFAULT_FEE: u32 = 30;
+/// Prices in `HopQuote` are fixed point numbers with 14 decimals
+pub const PRICE_PRECISION: i128 = 100_000_000_000_000;
+// Price impacts are expressed in basis points
+const PRICE_IMPACT_DENOMINATOR: i128 = 10_000;
+// Weights of the weighted pairs are expressed in basis points
+const WEIGHT_DENOMINATOR: i128 = 10_000;
+// Weighted pairs reject swaps that take in or out more than 30% of a reserve, where pow loses precision
+const MAX_WEIGHTED_RATIO: i128 = 3_000;
+
+/// Breakdown of a single hop of a quoted trade.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HopQuote {
+    pub pair: Address,
+    pub token_in: Address,
+    pub token_out: Address,
+    pub amount_in: i128,
+    pub amount_out: i128,
+    pub reserve_in_before: i128,
+    pub reserve_out_before: i128,
+    pub reserve_in_after: i128,
+    pub reserve_out_after: i128,
+    /// Part of `amount_in` charged as swap fee. It stays in the pair
+    pub fee_amount: i128,
+    /// Price of `token_in` in `token_out` before the trade: `reserve_out_before / reserve_in_before`
+    pub mid_price: i128,
+    /// Price actually paid in this hop: `amount_out / amount_in`
+    pub execution_price: i128,
+    /// How much worse the execution price is than the mid price, in basis points. Includes the swap fee
+    pub price_impact: i128,
+}
 
 
 /// Given some amount of an asset and pair reserves, returns an equivalent amount of the other asset.
@@ -22,10 +61,11 @@ pub fn quote(amount_a: i128, reserve_a: i128, reserve_b: i128) -> Result<i128, S
     if reserve_a <= 0 || reserve_b <= 0 {
         return Err(SoroswapLibraryError::InsufficientLiquidity);
     }
-    Ok(amount_a.checked_mul(reserve_b).ok_or(SoroswapLibraryError::InsufficientLiquidity)?.checked_div(reserve_a).ok_or(SoroswapLibraryError::InsufficientLiquidity)?)
+    checked_mul_div(amount_a, reserve_b, reserve_a).ok_or(SoroswapLibraryError::Overflow)
 }
 
 /// Given an input amount of an asset and pair reserves, returns the maximum output amount of the other asset.
+/// Uses the default swap fee of 0.3%.
 ///
 /// # Arguments
 ///
@@ -37,24 +77,43 @@ pub fn quote(amount_a: i128, reserve_a: i128, reserve_b: i128) -> Result<i128, S
 ///
 /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount or liquidity.
 pub fn get_amount_out(amount_in: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, SoroswapLibraryError> {
+    get_amount_out_with_fee(amount_in, reserve_in, reserve_out, DEFAULT_FEE)
+}
+
+/// Given an input amount of an asset, pair reserves and the pair swap fee, returns the maximum output amount of the other asset.
+///
+/// # Arguments
+///
+/// * `amount_in` - The input amount of the asset.
+/// * `reserve_in` - Reserves of the input asset in the pair.
+/// * `reserve_out` - Reserves of the output asset in the pair.
+/// * `fee` - The swap fee of the pair, in basis points.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount, liquidity or an invalid fee.
+pub fn get_amount_out_with_fee(amount_in: i128, reserve_in: i128, reserve_out: i128, fee: u32) -> Result<i128, SoroswapLibraryError> {
     if amount_in <= 0 {
         return Err(SoroswapLibraryError::InsufficientInputAmount);
     }
     if reserve_in <= 0 || reserve_out <= 0 {
         return Err(SoroswapLibraryError::InsufficientLiquidity);
     }
+    if fee as i128 >= FEE_DENOMINATOR {
+        return Err(SoroswapLibraryError::InvalidFee);
+    }
 
-    let fee = (amount_in.checked_mul(3).unwrap()).checked_ceiling_div(1000).unwrap();
-
-    let amount_in_less_fee = amount_in.checked_sub(fee).unwrap();
-    let numerator = amount_in_less_fee.checked_mul(reserve_out).unwrap();
+    let fee = checked_mul_div_ceil(amount_in, fee as i128, FEE_DENOMINATOR).ok_or(SoroswapLibraryError::Overflow)?;
 
-    let denominator = reserve_in.checked_add(amount_in_less_fee).unwrap();
+    let amount_in_less_fee = amount_in.checked_sub(fee).ok_or(SoroswapLibraryError::Overflow)?;
+    let denominator = reserve_in.checked_add(amount_in_less_fee).ok_or(SoroswapLibraryError::Overflow)?;
 
-    Ok(numerator.checked_div(denominator).unwrap())
+    // amount_in_less_fee * reserve_out is computed in 256 bits
+    checked_mul_div(amount_in_less_fee, reserve_out, denominator).ok_or(SoroswapLibraryError::Overflow)
 }
 
 /// Given an output amount of an asset and pair reserves, returns a required input amount of the other asset.
+/// Uses the default swap fee of 0.3%.
 ///
 /// # Arguments
 ///
@@ -66,18 +125,40 @@ pub fn get_amount_out(amount_in: i128, reserve_in: i128, reserve_out: i128) -> R
 ///
 /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount or liquidity.
 pub fn get_amount_in(amount_out: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, SoroswapLibraryError> {
+    get_amount_in_with_fee(amount_out, reserve_in, reserve_out, DEFAULT_FEE)
+}
+
+/// Given an output amount of an asset, pair reserves and the pair swap fee, returns a required input amount of the other asset.
+///
+/// # Arguments
+///
+/// * `amount_out` - The output amount of the asset.
+/// * `reserve_in` - Reserves of the input asset in the pair.
+/// * `reserve_out` - Reserves of the output asset in the pair.
+/// * `fee` - The swap fee of the pair, in basis points.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount, liquidity or an invalid fee.
+pub fn get_amount_in_with_fee(amount_out: i128, reserve_in: i128, reserve_out: i128, fee: u32) -> Result<i128, SoroswapLibraryError> {
     if amount_out <= 0 {
         return Err(SoroswapLibraryError::InsufficientOutputAmount);
     }
-    if reserve_in <= 0 || reserve_out <= 0 {
+    // The whole reserve can never be bought
+    if reserve_in <= 0 || reserve_out <= amount_out {
         return Err(SoroswapLibraryError::InsufficientLiquidity);
     }
-    let numerator = reserve_in.checked_mul(amount_out).unwrap().checked_mul(1000).unwrap();
-    let denominator = reserve_out.checked_sub(amount_out).unwrap().checked_mul(997).unwrap();
-    Ok(numerator.checked_ceiling_div(denominator).unwrap().checked_add(1).unwrap())
+    if fee as i128 >= FEE_DENOMINATOR {
+        return Err(SoroswapLibraryError::InvalidFee);
+    }
+    // Both sides are computed in 256 bits. Only a numerator above 2^256 or a result above i128::MAX overflows
+    let numerator = U256::mul(reserve_in as u128, amount_out as u128).checked_mul_u128(FEE_DENOMINATOR as u128).ok_or(SoroswapLibraryError::Overflow)?;
+    let denominator = U256::mul((reserve_out - amount_out) as u128, (FEE_DENOMINATOR - fee as i128) as u128);
+    let amount_in = numerator.checked_ceiling_div(denominator).and_then(U256::to_i128).ok_or(SoroswapLibraryError::Overflow)?;
+    amount_in.checked_add(1).ok_or(SoroswapLibraryError::Overflow)
 }
 
-/// Performs chained getAmountOut calculations on any number of pairs.
+/// Performs chained getAmountOut calculations on any number of pairs, using the swap fee of each pair.
 ///
 /// # Arguments
 ///
@@ -98,14 +179,16 @@ pub fn get_amounts_out(e: Env, factory: Address, amount_in: i128, path: Vec<Addr
     amounts.push_back(amount_in);
 
     for i in 0..path.len() - 1 {
-        let (reserve_in, reserve_out) = get_reserves_with_factory(e.clone(), factory.clone(), path.get(i).unwrap(), path.get(i+1).unwrap())?;
-        amounts.push_back(get_amount_out(amounts.get(i).unwrap(), reserve_in, reserve_out)?);
+        let pair = pair_for(e.clone(), factory.clone(), path.get(i).unwrap(), path.get(i+1).unwrap())?;
+        let (reserve_in, reserve_out) = get_reserves_with_pair(e.clone(), pair.clone(), path.get(i).unwrap(), path.get(i+1).unwrap())?;
+        let fee = get_fee_with_pair(e.clone(), pair);
+        amounts.push_back(get_amount_out_with_fee(amounts.get(i).unwrap(), reserve_in, reserve_out, fee)?);
     }
 
     Ok(amounts)
 }
 
-/// Performs chained getAmountIn calculations on any number of pairs.
+/// Performs chained getAmountIn calculations on any number of pairs, using the swap fee of each pair.
 ///
 /// # Arguments
 ///
@@ -126,10 +209,515 @@ pub fn get_amounts_in(e: Env, factory: Address, amount_out: i128, path: Vec<Addr
     amounts.push_front(amount_out);
 
     for i in (1..path.len()).rev() {
-        let (reserve_in, reserve_out) = get_reserves_with_factory(e.clone(), factory.clone(), path.get(i-1).unwrap(), path.get(i).unwrap())?;
-        let new_amount = get_amount_in(amounts.get(0).unwrap(), reserve_in, reserve_out)?;
+        let pair = pair_for(e.clone(), factory.clone(), path.get(i-1).unwrap(), path.get(i).unwrap())?;
+        let (reserve_in, reserve_out) = get_reserves_with_pair(e.clone(), pair.clone(), path.get(i-1).unwrap(), path.get(i).unwrap())?;
+        let fee = get_fee_with_pair(e.clone(), pair);
+        let new_amount = get_amount_in_with_fee(amounts.get(0).unwrap(), reserve_in, reserve_out, fee)?;
         amounts.push_front(new_amount);
     }
 
     Ok(amounts)
 }
+
+/// Given an input amount of an asset and the reserves, amplification coefficient and swap fee of a stable pair,
+/// returns the maximum output amount of the other asset. The swap fee is taken from the input, and the output
+/// is the amount that keeps the balances on the StableSwap curve of the current reserves.
+///
+/// # Arguments
+///
+/// * `amount_in` - The input amount of the asset.
+/// * `reserve_in` - Reserves of the input asset in the pair.
+/// * `reserve_out` - Reserves of the output asset in the pair.
+/// * `amp` - The amplification coefficient of the pair.
+/// * `fee` - The swap fee of the pair, in basis points.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount, liquidity, an invalid amplification coefficient or an invalid fee.
+pub fn get_amount_out_stable(amount_in: i128, reserve_in: i128, reserve_out: i128, amp: u32, fee: u32) -> Result<i128, SoroswapLibraryError> {
+    if amount_in <= 0 {
+        return Err(SoroswapLibraryError::InsufficientInputAmount);
+    }
+    if reserve_in <= 0 || reserve_out <= 0 {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+    if amp == 0 {
+        return Err(SoroswapLibraryError::InvalidAmp);
+    }
+    if fee as i128 >= FEE_DENOMINATOR {
+        return Err(SoroswapLibraryError::InvalidFee);
+    }
+
+    let fee = checked_mul_div_ceil(amount_in, fee as i128, FEE_DENOMINATOR).ok_or(SoroswapLibraryError::Overflow)?;
+    let balance_in = reserve_in.checked_add(amount_in - fee).ok_or(SoroswapLibraryError::Overflow)?;
+
+    let d = compute_d(reserve_in as u128, reserve_out as u128, amp).ok_or(SoroswapLibraryError::Overflow)?;
+    let balance_out = get_y(balance_in as u128, d, amp).ok_or(SoroswapLibraryError::Overflow)?;
+    // balance_out is rounded up, so tiny inputs can get nothing
+    Ok((reserve_out as u128).saturating_sub(balance_out) as i128)
+}
+
+/// Given an output amount of an asset and the reserves, amplification coefficient and swap fee of a stable pair,
+/// returns the required input amount of the other asset.
+///
+/// # Arguments
+///
+/// * `amount_out` - The output amount of the asset.
+/// * `reserve_in` - Reserves of the input asset in the pair.
+/// * `reserve_out` - Reserves of the output asset in the pair.
+/// * `amp` - The amplification coefficient of the pair.
+/// * `fee` - The swap fee of the pair, in basis points.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount, liquidity, an invalid amplification coefficient or an invalid fee.
+pub fn get_amount_in_stable(amount_out: i128, reserve_in: i128, reserve_out: i128, amp: u32, fee: u32) -> Result<i128, SoroswapLibraryError> {
+    if amount_out <= 0 {
+        return Err(SoroswapLibraryError::InsufficientOutputAmount);
+    }
+    // The whole reserve can never be bought
+    if reserve_in <= 0 || reserve_out <= amount_out {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+    if amp == 0 {
+        return Err(SoroswapLibraryError::InvalidAmp);
+    }
+    if fee as i128 >= FEE_DENOMINATOR {
+        return Err(SoroswapLibraryError::InvalidFee);
+    }
+
+    let d = compute_d(reserve_in as u128, reserve_out as u128, amp).ok_or(SoroswapLibraryError::Overflow)?;
+    let balance_in = get_y((reserve_out - amount_out) as u128, d, amp).ok_or(SoroswapLibraryError::Overflow)?;
+    let amount_in_less_fee = balance_in.saturating_sub(reserve_in as u128);
+
+    // The fee is charged on the whole input, so it is added back rounding up
+    U256::mul(amount_in_less_fee, FEE_DENOMINATOR as u128)
+        .checked_ceiling_div(U256::from_u128((FEE_DENOMINATOR - fee as i128) as u128))
+        .and_then(U256::to_i128)
+        .ok_or(SoroswapLibraryError::Overflow)
+}
+
+/// Given an input amount of an asset and the reserves, weights and swap fee of a weighted pair, returns the
+/// maximum output amount of the other asset. The swap fee is taken from the input, and the output keeps
+/// `reserve_in^weight_in · reserve_out^weight_out` constant, rounding in favor of the pair.
+///
+/// # Arguments
+///
+/// * `amount_in` - The input amount of the asset.
+/// * `reserve_in` - Reserves of the input asset in the pair.
+/// * `reserve_out` - Reserves of the output asset in the pair.
+/// * `weight_in` - Weight of the input asset, in basis points.
+/// * `weight_out` - Weight of the output asset, in basis points.
+/// * `fee` - The swap fee of the pair, in basis points.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount, liquidity, invalid weights or an invalid fee. Amounts in or out above 30% of the reserves are rejected as insufficient liquidity.
+pub fn get_amount_out_weighted(amount_in: i128, reserve_in: i128, reserve_out: i128, weight_in: u32, weight_out: u32, fee: u32) -> Result<i128, SoroswapLibraryError> {
+    if amount_in <= 0 {
+        return Err(SoroswapLibraryError::InsufficientInputAmount);
+    }
+    if reserve_in <= 0 || reserve_out <= 0 {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+    check_weights(weight_in, weight_out)?;
+    if fee as i128 >= FEE_DENOMINATOR {
+        return Err(SoroswapLibraryError::InvalidFee);
+    }
+    if exceeds_weighted_ratio(amount_in, reserve_in) {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+
+    let fee = checked_mul_div_ceil(amount_in, fee as i128, FEE_DENOMINATOR).ok_or(SoroswapLibraryError::Overflow)?;
+    let balance_in = reserve_in.checked_add(amount_in - fee).ok_or(SoroswapLibraryError::Overflow)?;
+
+    // reserve_out - amount_out = reserve_out · (reserve_in / balance_in)^(weight_in / weight_out)
+    let base = checked_mul_div_ceil(reserve_in, ONE, balance_in).ok_or(SoroswapLibraryError::Overflow)?;
+    let exponent = checked_mul_div_ceil(weight_in as i128, ONE, weight_out as i128).ok_or(SoroswapLibraryError::Overflow)?;
+    let power = pow_up(base, exponent).ok_or(SoroswapLibraryError::Overflow)?;
+    if power >= ONE {
+        // Tiny inputs can get nothing
+        return Ok(0);
+    }
+
+    let amount_out = checked_mul_div(reserve_out, ONE - power, ONE).ok_or(SoroswapLibraryError::Overflow)?;
+    if exceeds_weighted_ratio(amount_out, reserve_out) {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+    Ok(amount_out)
+}
+
+/// Given an output amount of an asset and the reserves, weights and swap fee of a weighted pair, returns the
+/// required input amount of the other asset.
+///
+/// # Arguments
+///
+/// * `amount_out` - The output amount of the asset.
+/// * `reserve_in` - Reserves of the input asset in the pair.
+/// * `reserve_out` - Reserves of the output asset in the pair.
+/// * `weight_in` - Weight of the input asset, in basis points.
+/// * `weight_out` - Weight of the output asset, in basis points.
+/// * `fee` - The swap fee of the pair, in basis points.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount, liquidity, invalid weights or an invalid fee. Amounts in or out above 30% of the reserves are rejected as insufficient liquidity.
+pub fn get_amount_in_weighted(amount_out: i128, reserve_in: i128, reserve_out: i128, weight_in: u32, weight_out: u32, fee: u32) -> Result<i128, SoroswapLibraryError> {
+    if amount_out <= 0 {
+        return Err(SoroswapLibraryError::InsufficientOutputAmount);
+    }
+    if reserve_in <= 0 || reserve_out <= amount_out {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+    check_weights(weight_in, weight_out)?;
+    if fee as i128 >= FEE_DENOMINATOR {
+        return Err(SoroswapLibraryError::InvalidFee);
+    }
+    if exceeds_weighted_ratio(amount_out, reserve_out) {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+
+    // balance_in = reserve_in · (reserve_out / (reserve_out - amount_out))^(weight_out / weight_in)
+    let base = checked_mul_div_ceil(reserve_out, ONE, reserve_out - amount_out).ok_or(SoroswapLibraryError::Overflow)?;
+    let exponent = checked_mul_div_ceil(weight_out as i128, ONE, weight_in as i128).ok_or(SoroswapLibraryError::Overflow)?;
+    let power = pow_up(base, exponent).ok_or(SoroswapLibraryError::Overflow)?;
+    let amount_in_less_fee = checked_mul_div_ceil(reserve_in, power - ONE, ONE).ok_or(SoroswapLibraryError::Overflow)?;
+
+    // The fee is charged on the whole input, so it is added back rounding up
+    let amount_in = checked_mul_div_ceil(amount_in_less_fee, FEE_DENOMINATOR, FEE_DENOMINATOR - fee as i128).ok_or(SoroswapLibraryError::Overflow)?;
+    if exceeds_weighted_ratio(amount_in, reserve_in) {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+    Ok(amount_in)
+}
+
+fn check_weights(weight_in: u32, weight_out: u32) -> Result<(), SoroswapLibraryError> {
+    if weight_in == 0 || weight_out == 0 || weight_in as i128 + weight_out as i128 != WEIGHT_DENOMINATOR {
+        return Err(SoroswapLibraryError::InvalidWeights);
+    }
+    Ok(())
+}
+
+fn exceeds_weighted_ratio(amount: i128, reserve: i128) -> bool {
+    U256::mul(amount as u128, WEIGHT_DENOMINATOR as u128) > U256::mul(reserve as u128, MAX_WEIGHTED_RATIO as u128)
+}
+
+/// Checks a hop of a route and returns the kind and the variant of its pair. The built-in variants are only
+/// reached through `RouteHop::Kind`, so a registered variant can not name one of them with a different kind.
+///
+/// # Arguments
+///
+/// * `hop` - The hop of the route.
+///
+/// # Returns
+///
+/// Returns `Result<(PairKind, Symbol), SoroswapLibraryError>` where `Ok` contains the kind and the variant of the pair, and `Err` indicates an invalid path if a `RouteHop::Variant` names a built-in variant.
+pub fn check_hop(hop: RouteHop) -> Result<(PairKind, Symbol), SoroswapLibraryError> {
+    match hop {
+        RouteHop::Kind(PairKind::ConstantProduct) => Ok((PairKind::ConstantProduct, CONSTANT_PRODUCT_KIND)),
+        RouteHop::Kind(PairKind::Stable) => Ok((PairKind::Stable, STABLE_KIND)),
+        RouteHop::Kind(PairKind::Weighted) => Ok((PairKind::Weighted, WEIGHTED_KIND)),
+        RouteHop::Variant(kind, variant) => {
+            if variant == CONSTANT_PRODUCT_KIND || variant == STABLE_KIND || variant == WEIGHTED_KIND || variant == CONCENTRATED_KIND {
+                return Err(SoroswapLibraryError::InvalidPath);
+            }
+            Ok((kind, variant))
+        }
+    }
+}
+
+/// Output amount of a single hop through the pair of the given variant, priced with the math of its kind.
+fn get_amount_out_with_kind(e: &Env, factory: &Address, amount_in: i128, token_in: Address, token_out: Address, kind: PairKind, variant: Symbol) -> Result<i128, SoroswapLibraryError> {
+    let pair = pair_for_variant(e.clone(), factory.clone(), token_in.clone(), token_out.clone(), variant)?;
+    let (reserve_in, reserve_out) = get_reserves_with_pair(e.clone(), pair.clone(), token_in.clone(), token_out.clone())?;
+    let fee = get_fee_with_pair(e.clone(), pair.clone());
+    match kind {
+        PairKind::ConstantProduct => get_amount_out_with_fee(amount_in, reserve_in, reserve_out, fee),
+        PairKind::Stable => get_amount_out_stable(amount_in, reserve_in, reserve_out, get_amp_with_pair(e.clone(), pair), fee),
+        PairKind::Weighted => {
+            let (weight_in, weight_out) = get_weights_with_pair(e.clone(), pair, token_in, token_out)?;
+            get_amount_out_weighted(amount_in, reserve_in, reserve_out, weight_in, weight_out, fee)
+        }
+    }
+}
+
+/// Input amount of a single hop through the pair of the given variant, priced with the math of its kind.
+fn get_amount_in_with_kind(e: &Env, factory: &Address, amount_out: i128, token_in: Address, token_out: Address, kind: PairKind, variant: Symbol) -> Result<i128, SoroswapLibraryError> {
+    let pair = pair_for_variant(e.clone(), factory.clone(), token_in.clone(), token_out.clone(), variant)?;
+    let (reserve_in, reserve_out) = get_reserves_with_pair(e.clone(), pair.clone(), token_in.clone(), token_out.clone())?;
+    let fee = get_fee_with_pair(e.clone(), pair.clone());
+    match kind {
+        PairKind::ConstantProduct => get_amount_in_with_fee(amount_out, reserve_in, reserve_out, fee),
+        PairKind::Stable => get_amount_in_stable(amount_out, reserve_in, reserve_out, get_amp_with_pair(e.clone(), pair), fee),
+        PairKind::Weighted => {
+            let (weight_in, weight_out) = get_weights_with_pair(e.clone(), pair, token_in, token_out)?;
+            get_amount_in_weighted(amount_out, reserve_in, reserve_out, weight_in, weight_out, fee)
+        }
+    }
+}
+
+/// Performs chained getAmountOut calculations along a path where every hop can go through a constant product,
+/// a stable or a weighted pair.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `amount_in` - The input amount.
+/// * `path` - Vector of token addresses representing the path.
+/// * `hops` - The pair of each hop, with one element less than `path`. The kind of the hop selects the math used
+///   to price it.
+///
+/// # Returns
+///
+/// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+pub fn get_amounts_out_route(e: Env, factory: Address, amount_in: i128, path: Vec<Address>, hops: Vec<RouteHop>) -> Result<Vec<i128>, SoroswapLibraryError> {
+    if path.len() < 2 || hops.len() != path.len() - 1 {
+        return Err(SoroswapLibraryError::InvalidPath);
+    }
+
+    let mut amounts = Vec::new(&e);
+    amounts.push_back(amount_in);
+
+    for i in 0..path.len() - 1 {
+        let (kind, variant) = check_hop(hops.get(i).unwrap())?;
+        let amount_out = get_amount_out_with_kind(&e, &factory, amounts.get(i).unwrap(), path.get(i).unwrap(), path.get(i+1).unwrap(), kind, variant)?;
+        amounts.push_back(amount_out);
+    }
+
+    Ok(amounts)
+}
+
+/// Performs chained getAmountIn calculations along a path where every hop can go through a constant product,
+/// a stable or a weighted pair.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `amount_out` - The output amount.
+/// * `path` - Vector of token addresses representing the path.
+/// * `hops` - The pair of each hop, with one element less than `path`. The kind of the hop selects the math used
+///   to price it.
+///
+/// # Returns
+///
+/// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+pub fn get_amounts_in_route(e: Env, factory: Address, amount_out: i128, path: Vec<Address>, hops: Vec<RouteHop>) -> Result<Vec<i128>, SoroswapLibraryError> {
+    if path.len() < 2 || hops.len() != path.len() - 1 {
+        return Err(SoroswapLibraryError::InvalidPath);
+    }
+
+    let mut amounts = Vec::new(&e);
+    amounts.push_front(amount_out);
+
+    for i in (1..path.len()).rev() {
+        let (kind, variant) = check_hop(hops.get(i-1).unwrap())?;
+        let amount_in = get_amount_in_with_kind(&e, &factory, amounts.get(0).unwrap(), path.get(i-1).unwrap(), path.get(i).unwrap(), kind, variant)?;
+        amounts.push_front(amount_in);
+    }
+
+    Ok(amounts)
+}
+
+/// Quotes a trade along a path and returns the breakdown of every hop: the pair, its reserves before and after
+/// the trade, the fee charged, the mid and execution prices and the price impact.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `amount` - The exact input amount if `exact_in` is true, otherwise the exact output amount.
+/// * `path` - Vector of token addresses representing the path.
+/// * `exact_in` - Whether `amount` is the input or the output of the trade.
+///
+/// # Returns
+///
+/// Returns `Result<Vec<HopQuote>, SoroswapLibraryError>` where `Ok` contains the breakdown of each hop, and `Err` indicates an error such as an invalid path or insufficient liquidity.
+pub fn quote_detailed(e: Env, factory: Address, amount: i128, path: Vec<Address>, exact_in: bool) -> Result<Vec<HopQuote>, SoroswapLibraryError> {
+    if path.len() < 2 {
+        return Err(SoroswapLibraryError::InvalidPath);
+    }
+
+    // (pair, reserve_in, reserve_out, fee) of each hop
+    let mut pairs: Vec<(Address, i128, i128, u32)> = Vec::new(&e);
+    for i in 0..path.len() - 1 {
+        let pair = pair_for(e.clone(), factory.clone(), path.get(i).unwrap(), path.get(i+1).unwrap())?;
+        let (reserve_in, reserve_out) = get_reserves_with_pair(e.clone(), pair.clone(), path.get(i).unwrap(), path.get(i+1).unwrap())?;
+        let fee = get_fee_with_pair(e.clone(), pair.clone());
+        pairs.push_back((pair, reserve_in, reserve_out, fee));
+    }
+
+    let mut amounts: Vec<i128> = Vec::new(&e);
+    if exact_in {
+        amounts.push_back(amount);
+        for i in 0..pairs.len() {
+            let (_pair, reserve_in, reserve_out, fee) = pairs.get(i).unwrap();
+            amounts.push_back(get_amount_out_with_fee(amounts.get(i).unwrap(), reserve_in, reserve_out, fee)?);
+        }
+    } else {
+        amounts.push_front(amount);
+        for i in (0..pairs.len()).rev() {
+            let (_pair, reserve_in, reserve_out, fee) = pairs.get(i).unwrap();
+            amounts.push_front(get_amount_in_with_fee(amounts.get(0).unwrap(), reserve_in, reserve_out, fee)?);
+        }
+    }
+
+    let mut hops: Vec<HopQuote> = Vec::new(&e);
+    for i in 0..pairs.len() {
+        let (pair, reserve_in, reserve_out, fee) = pairs.get(i).unwrap();
+        let (amount_in, amount_out) = (amounts.get(i).unwrap(), amounts.get(i + 1).unwrap());
+
+        let mid_price = checked_mul_div(reserve_out, PRICE_PRECISION, reserve_in).ok_or(SoroswapLibraryError::Overflow)?;
+        let execution_price = checked_mul_div(amount_out, PRICE_PRECISION, amount_in).ok_or(SoroswapLibraryError::Overflow)?;
+        let price_impact = checked_mul_div(
+            mid_price.checked_sub(execution_price).ok_or(SoroswapLibraryError::Overflow)?,
+            PRICE_IMPACT_DENOMINATOR,
+            mid_price,
+        ).ok_or(SoroswapLibraryError::Overflow)?;
+
+        hops.push_back(HopQuote {
+            pair,
+            token_in: path.get(i).unwrap(),
+            token_out: path.get(i + 1).unwrap(),
+            amount_in,
+            amount_out,
+            reserve_in_before: reserve_in,
+            reserve_out_before: reserve_out,
+            // The fee stays in the pair, so the whole input is added to the reserve
+            reserve_in_after: reserve_in.checked_add(amount_in).ok_or(SoroswapLibraryError::Overflow)?,
+            reserve_out_after: reserve_out.checked_sub(amount_out).ok_or(SoroswapLibraryError::Overflow)?,
+            fee_amount: checked_mul_div_ceil(amount_in, fee as i128, FEE_DENOMINATOR).ok_or(SoroswapLibraryError::Overflow)?,
+            mid_price,
+            execution_price,
+            price_impact,
+        });
+    }
+
+    Ok(hops)
+}
+
+/// Given pair reserves, the pair swap fee and a price, returns the largest input amount whose execution price
+/// (`amount_out / amount_in`, with `PRICE_PRECISION`) is at least `price`. This is the inverse of the price
+/// of `get_amount_out_with_fee`, computed without its rounding: the price falls as the input grows, and equals
+/// `price` at `reserve_out / price - reserve_in / (1 - fee)`. Because the output is rounded down, the exact
+/// execution price of the returned amount can be slightly lower than `price`.
+///
+/// # Arguments
+///
+/// * `reserve_in` - Reserves of the input asset in the pair.
+/// * `reserve_out` - Reserves of the output asset in the pair.
+/// * `fee` - The swap fee of the pair, in basis points.
+/// * `price` - The minimum price of the input asset in the output asset, with `PRICE_PRECISION`.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the input amount, 0 if even the smallest trade is below `price`, and `Err` indicates an error such as insufficient liquidity, an invalid fee or an invalid price.
+pub fn get_amount_in_for_price_with_fee(reserve_in: i128, reserve_out: i128, fee: u32, price: i128) -> Result<i128, SoroswapLibraryError> {
+    if reserve_in <= 0 || reserve_out <= 0 {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+    if fee as i128 >= FEE_DENOMINATOR {
+        return Err(SoroswapLibraryError::InvalidFee);
+    }
+    if price <= 0 {
+        return Err(SoroswapLibraryError::InvalidPrice);
+    }
+
+    // amount_in <= (reserve_out * PRICE_PRECISION * (1 - fee) - reserve_in * price) / (price * (1 - fee)),
+    // with the fee factor scaled by FEE_DENOMINATOR. Both sides are computed in 256 bits
+    let fee_factor = (FEE_DENOMINATOR - fee as i128) as u128;
+    let max_value = U256::mul(reserve_out as u128, PRICE_PRECISION as u128).checked_mul_u128(fee_factor).ok_or(SoroswapLibraryError::Overflow)?;
+    let min_value = U256::mul(reserve_in as u128, price as u128).checked_mul_u128(FEE_DENOMINATOR as u128).ok_or(SoroswapLibraryError::Overflow)?;
+    let numerator = match max_value.checked_sub(min_value) {
+        Some(numerator) => numerator,
+        // The mid price less the fee is already below price
+        None => return Ok(0),
+    };
+    let (amount_in, _) = numerator.checked_div_rem(U256::mul(price as u128, fee_factor)).ok_or(SoroswapLibraryError::Overflow)?;
+    amount_in.to_i128().ok_or(SoroswapLibraryError::Overflow)
+}
+
+/// Returns the largest input amount, up to `amount_in_max`, whose exact execution price along the path is at
+/// least `price`, using the swap fee of each pair. The execution price of a trade is the final output of
+/// `get_amounts_out` over its input, with `PRICE_PRECISION`.
+///
+/// The amount is bounded with `get_amount_in_for_price_with_fee` on the first pair, at `price` divided by the
+/// mid price less fee of the later pairs, as no hop can do better than that. It is then found by bisection
+/// with the exact amounts of `get_amounts_out`. Their rounding makes the price slightly uneven between nearby
+/// amounts, so the amount found can be a few units below the largest one.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `amount_in_max` - The maximum input amount.
+/// * `price` - The minimum price of the first token of the path in the last one, with `PRICE_PRECISION`.
+/// * `path` - Vector of token addresses representing the path.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the input amount, 0 if no trade reaches `price`, and `Err` indicates an error such as an invalid path or an invalid price.
+pub fn get_amount_in_for_price(e: Env, factory: Address, amount_in_max: i128, price: i128, path: Vec<Address>) -> Result<i128, SoroswapLibraryError> {
+    if path.len() < 2 {
+        return Err(SoroswapLibraryError::InvalidPath);
+    }
+    if amount_in_max <= 0 {
+        return Err(SoroswapLibraryError::InsufficientInputAmount);
+    }
+    if price <= 0 {
+        return Err(SoroswapLibraryError::InvalidPrice);
+    }
+
+    // (reserve_in, reserve_out, fee) of each hop. They are read once for all the amounts tried below
+    let mut hops: Vec<(i128, i128, u32)> = Vec::new(&e);
+    for i in 0..path.len() - 1 {
+        let pair = pair_for(e.clone(), factory.clone(), path.get(i).unwrap(), path.get(i+1).unwrap())?;
+        let (reserve_in, reserve_out) = get_reserves_with_pair(e.clone(), pair.clone(), path.get(i).unwrap(), path.get(i+1).unwrap())?;
+        let fee = get_fee_with_pair(e.clone(), pair);
+        hops.push_back((reserve_in, reserve_out, fee));
+    }
+
+    // Price the first hop needs for the whole path to reach price. Rounded down, so the bound is never too low
+    let mut first_hop_price = price;
+    for i in 1..hops.len() {
+        let (reserve_in, reserve_out, fee) = hops.get(i).unwrap();
+        first_hop_price = checked_mul_div(first_hop_price, reserve_in, reserve_out).ok_or(SoroswapLibraryError::Overflow)?;
+        first_hop_price = checked_mul_div(first_hop_price, FEE_DENOMINATOR, FEE_DENOMINATOR - fee as i128).ok_or(SoroswapLibraryError::Overflow)?;
+    }
+    let mut upper = amount_in_max;
+    if first_hop_price > 0 {
+        let (reserve_in, reserve_out, fee) = hops.get(0).unwrap();
+        // A bound too large for an i128 is no bound
+        if let Ok(bound) = get_amount_in_for_price_with_fee(reserve_in, reserve_out, fee, first_hop_price) {
+            upper = upper.min(bound);
+        }
+    }
+
+    if upper == 0 || reaches_price(&hops, upper, price)? {
+        return Ok(upper);
+    }
+    // lower always reaches price (0 trivially), and upper never does
+    let mut lower = 0;
+    while upper - lower > 1 {
+        let middle = lower + (upper - lower) / 2;
+        if reaches_price(&hops, middle, price)? {
+            lower = middle;
+        } else {
+            upper = middle;
+        }
+    }
+    Ok(lower)
+}
+
+/// Whether swapping `amount_in` through `hops` gets an execution price of at least `price`.
+fn reaches_price(hops: &Vec<(i128, i128, u32)>, amount_in: i128, price: i128) -> Result<bool, SoroswapLibraryError> {
+    let mut amount = amount_in;
+    for (reserve_in, reserve_out, fee) in hops.iter() {
+        amount = get_amount_out_with_fee(amount, reserve_in, reserve_out, fee)?;
+        if amount == 0 {
+            return Ok(false);
+        }
+    }
+    Ok(U256::mul(amount as u128, PRICE_PRECISION as u128) >= U256::mul(price as u128, amount_in as u128))
+}
@@ -1,13 +1,54 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, TryFromVal};
 use crate::tokens::{sort_tokens, pair_for};
 use crate::error::SoroswapLibraryError;
+use crate::quotes::DEFAULT_FEE;
 
+// The views of the pairs used by the library. Every pair type has them, whatever its curve
 mod pair {
-    soroban_sdk::contractimport!(
-        file = "./src/soroswap_pair.wasm"
-    );
+    use soroban_sdk::{contractclient, Env, Val, Vec};
+
+    #[contractclient(name = "SoroswapPairClient")]
+    #[allow(dead_code)]
+    pub trait SoroswapPairInterface {
+        // (reserve_0, reserve_1, block_timestamp_last), or (reserve_0, reserve_1) in the pairs deployed before
+        // get_reserves returned the timestamp, so it is read as a vector
+        fn get_reserves(e: Env) -> Vec<Val>;
+        fn fee(e: Env) -> u32;
+    }
+}
+use pair::SoroswapPairClient;
+
+// Reads the first two values of get_reserves, which are the reserves in every version of the pairs
+fn get_pair_reserves(e: &Env, pair: &Address) -> (i128, i128) {
+    let reserves = SoroswapPairClient::new(e, pair).get_reserves();
+    let reserve_0 = i128::try_from_val(e, &reserves.get(0).unwrap()).unwrap();
+    let reserve_1 = i128::try_from_val(e, &reserves.get(1).unwrap()).unwrap();
+    (reserve_0, reserve_1)
+}
+
+// Stable pairs have the same get_reserves and fee as the constant product pairs, and also an amplification coefficient
+mod stable_pair {
+    use soroban_sdk::{contractclient, Env};
+
+    #[contractclient(name = "SoroswapStablePairClient")]
+    #[allow(dead_code)]
+    pub trait SoroswapStablePairInterface {
+        fn amp(e: Env) -> u32;
+    }
+}
+use stable_pair::SoroswapStablePairClient;
+
+// Weighted pairs also have the same get_reserves and fee, and the weights of their tokens
+mod weighted_pair {
+    use soroban_sdk::{contractclient, Env};
+
+    #[contractclient(name = "SoroswapWeightedPairClient")]
+    #[allow(dead_code)]
+    pub trait SoroswapWeightedPairInterface {
+        fn weights(e: Env) -> (u32, u32);
+    }
 }
-use pair::Client as SoroswapPairClient;
+use weighted_pair::SoroswapWeightedPairClient;
 
 
 /// Fetches and sorts the reserves for a pair of tokens.
@@ -25,8 +66,7 @@ use pair::Client as SoroswapPairClient;
 pub fn get_reserves_with_factory(e: Env,factory: Address, token_a: Address, token_b: Address) -> Result<(i128,i128), SoroswapLibraryError>{
     let (token_0,token_1) = sort_tokens(token_a.clone(), token_b.clone())?;
     let pair_address = pair_for(e.clone(), factory, token_0.clone(), token_1.clone())?;
-    let pair_client = SoroswapPairClient::new(&e, &pair_address);
-    let (reserve_0, reserve_1) = pair_client.get_reserves();
+    let (reserve_0, reserve_1) = get_pair_reserves(&e, &pair_address);
     
     let (reserve_a, reseve_b) =
         if token_a == token_0 {
@@ -51,8 +91,7 @@ pub fn get_reserves_with_factory(e: Env,factory: Address, token_a: Address, toke
 /// Returns `Result<(i128, i128), SoroswapLibraryError>` where `Ok` contains a tuple of sorted reserves, and `Err` indicates an error such as identical tokens or an issue with sorting.
 pub fn get_reserves_with_pair(e: Env, pair: Address, token_a: Address, token_b: Address) -> Result<(i128,i128), SoroswapLibraryError>{
     let (token_0,token_1) = sort_tokens(token_a.clone(), token_b.clone())?;
-    let pair_client = SoroswapPairClient::new(&e, &pair);
-    let (reserve_0, reserve_1) = pair_client.get_reserves();
+    let (reserve_0, reserve_1) = get_pair_reserves(&e, &pair);
     
     let (reserve_a, reseve_b) =
         if token_a == token_0 {
@@ -61,4 +100,76 @@ pub fn get_reserves_with_pair(e: Env, pair: Address, token_a: Address, token_b:
             (reserve_1, reserve_0) };
 
     Ok((reserve_a, reseve_b))
-}
\ No newline at end of file
+}
+
+/// Fetches the swap fee of a pair, in basis points, given the pair address.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `pair` - The pair address.
+///
+/// # Returns
+///
+/// Returns the swap fee of the pair in basis points. The pairs deployed before the fee could be configured have no
+/// `fee` and charge `DEFAULT_FEE`.
+pub fn get_fee_with_pair(e: Env, pair: Address) -> u32 {
+    match SoroswapPairClient::new(&e, &pair).try_fee() {
+        Ok(Ok(fee)) => fee,
+        _ => DEFAULT_FEE,
+    }
+}
+
+/// Fetches the swap fee of a pair, in basis points, using the factory address.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `token_a` - The address of the first token.
+/// * `token_b` - The address of the second token.
+///
+/// # Returns
+///
+/// Returns `Result<u32, SoroswapLibraryError>` where `Ok` contains the swap fee of the pair in basis points, and `Err` indicates an error such as identical tokens.
+pub fn get_fee_with_factory(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<u32, SoroswapLibraryError> {
+    let pair_address = pair_for(e.clone(), factory, token_a, token_b)?;
+    Ok(get_fee_with_pair(e, pair_address))
+}
+
+/// Fetches the amplification coefficient of a stable pair given the pair address.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `pair` - The stable pair address.
+///
+/// # Returns
+///
+/// Returns the amplification coefficient of the stable pair.
+pub fn get_amp_with_pair(e: Env, pair: Address) -> u32 {
+    SoroswapStablePairClient::new(&e, &pair).amp()
+}
+
+/// Fetches and sorts the weights of a weighted pair given the pair address.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `pair` - The weighted pair address.
+/// * `token_a` - The address of the first token.
+/// * `token_b` - The address of the second token.
+///
+/// # Returns
+///
+/// Returns `Result<(u32, u32), SoroswapLibraryError>` where `Ok` contains a tuple of sorted weights in basis points, and `Err` indicates an error such as identical tokens.
+pub fn get_weights_with_pair(e: Env, pair: Address, token_a: Address, token_b: Address) -> Result<(u32, u32), SoroswapLibraryError> {
+    let (token_0, _token_1) = sort_tokens(token_a.clone(), token_b.clone())?;
+    let (weight_0, weight_1) = SoroswapWeightedPairClient::new(&e, &pair).weights();
+
+    if token_a == token_0 {
+        Ok((weight_0, weight_1))
+    } else {
+        Ok((weight_1, weight_0))
+    }
+}
@@ -0,0 +1,199 @@
+//! Off-chain route finding over a snapshot of pair reserves.
+//!
+//! This module is not part of the contract. It is compiled with the `std` feature so backends can
+//! find the best path between two tokens without calling the network for every candidate route.
+extern crate std;
+
+use std::collections::BTreeMap;
+use std::vec::Vec;
+use crate::error::SoroswapLibraryError;
+use crate::quotes::{get_amount_in_with_fee, get_amount_out_with_fee};
+
+/// Reserves of a single pair at the time of the snapshot.
+///
+/// `token_0` and `token_1` do not need to be sorted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PairSnapshot<T> {
+    pub token_0: T,
+    pub token_1: T,
+    pub reserve_0: i128,
+    pub reserve_1: i128,
+    /// Swap fee of the pair, in basis points.
+    pub fee: u32,
+}
+
+/// A path between two tokens together with the amounts at each step, as `get_amounts_out` or `get_amounts_in` would return them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Route<T> {
+    pub path: Vec<T>,
+    pub amounts: Vec<i128>,
+}
+
+/// Finds routes between tokens over a snapshot of pair reserves.
+pub struct RouteFinder<T> {
+    // (token_a, token_b) -> (reserve_a, reserve_b, fee). Each pair is stored in both directions
+    reserves: BTreeMap<(T, T), (i128, i128, u32)>,
+    // token -> tokens it has a pair with
+    neighbors: BTreeMap<T, Vec<T>>,
+}
+
+impl<T: Clone + Ord> RouteFinder<T> {
+    /// Builds the token graph from a snapshot of pairs. If the same two tokens appear more than once, the last snapshot is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `pairs` - The pairs with their reserves and fees.
+    pub fn new(pairs: &[PairSnapshot<T>]) -> Self {
+        let mut reserves = BTreeMap::new();
+        let mut neighbors: BTreeMap<T, Vec<T>> = BTreeMap::new();
+
+        for pair in pairs {
+            if pair.token_0 == pair.token_1 {
+                continue;
+            }
+            let key = (pair.token_0.clone(), pair.token_1.clone());
+            if !reserves.contains_key(&key) {
+                neighbors.entry(pair.token_0.clone()).or_default().push(pair.token_1.clone());
+                neighbors.entry(pair.token_1.clone()).or_default().push(pair.token_0.clone());
+            }
+            reserves.insert(key, (pair.reserve_0, pair.reserve_1, pair.fee));
+            reserves.insert((pair.token_1.clone(), pair.token_0.clone()), (pair.reserve_1, pair.reserve_0, pair.fee));
+        }
+
+        RouteFinder { reserves, neighbors }
+    }
+
+    /// Returns the reserves of `token_in` and `token_out` and the fee of their pair, if it is in the snapshot.
+    pub fn get_reserves(&self, token_in: &T, token_out: &T) -> Option<(i128, i128, u32)> {
+        self.reserves.get(&(token_in.clone(), token_out.clone())).copied()
+    }
+
+    /// Enumerates every path from `token_in` to `token_out` with at most `max_hops` pairs. Paths never visit a token twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_in` - The first token of the paths.
+    /// * `token_out` - The last token of the paths.
+    /// * `max_hops` - The maximum number of pairs in a path.
+    pub fn paths(&self, token_in: &T, token_out: &T, max_hops: usize) -> Vec<Vec<T>> {
+        let mut paths = Vec::new();
+        if token_in == token_out || max_hops == 0 {
+            return paths;
+        }
+        let mut current = std::vec![token_in.clone()];
+        self.extend_paths(token_out, max_hops, &mut current, &mut paths);
+        paths
+    }
+
+    fn extend_paths(&self, token_out: &T, max_hops: usize, current: &mut Vec<T>, paths: &mut Vec<Vec<T>>) {
+        let last = current.last().unwrap().clone();
+        let Some(neighbors) = self.neighbors.get(&last) else {
+            return;
+        };
+        for next in neighbors {
+            if current.contains(next) {
+                continue;
+            }
+            current.push(next.clone());
+            if next == token_out {
+                paths.push(current.clone());
+            } else if current.len() <= max_hops {
+                self.extend_paths(token_out, max_hops, current, paths);
+            }
+            current.pop();
+        }
+    }
+
+    /// Performs chained get_amount_out calculations over a path using the snapshot reserves.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains the amounts at each step, and `Err` indicates an error such as an invalid path or insufficient liquidity.
+    pub fn get_amounts_out(&self, amount_in: i128, path: &[T]) -> Result<Vec<i128>, SoroswapLibraryError> {
+        if path.len() < 2 {
+            return Err(SoroswapLibraryError::InvalidPath);
+        }
+        let mut amounts = std::vec![amount_in];
+        for i in 0..path.len() - 1 {
+            let (reserve_in, reserve_out, fee) = self.get_reserves(&path[i], &path[i + 1]).ok_or(SoroswapLibraryError::InvalidPath)?;
+            amounts.push(get_amount_out_with_fee(amounts[i], reserve_in, reserve_out, fee)?);
+        }
+        Ok(amounts)
+    }
+
+    /// Performs chained get_amount_in calculations over a path using the snapshot reserves.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains the amounts at each step, and `Err` indicates an error such as an invalid path or insufficient liquidity.
+    pub fn get_amounts_in(&self, amount_out: i128, path: &[T]) -> Result<Vec<i128>, SoroswapLibraryError> {
+        if path.len() < 2 {
+            return Err(SoroswapLibraryError::InvalidPath);
+        }
+        let mut amounts = std::vec![0; path.len()];
+        amounts[path.len() - 1] = amount_out;
+        for i in (1..path.len()).rev() {
+            let (reserve_in, reserve_out, fee) = self.get_reserves(&path[i - 1], &path[i]).ok_or(SoroswapLibraryError::InvalidPath)?;
+            amounts[i - 1] = get_amount_in_with_fee(amounts[i], reserve_in, reserve_out, fee)?;
+        }
+        Ok(amounts)
+    }
+
+    /// Finds the path with at most `max_hops` pairs that gives the biggest output for an exact input.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_in` - The token to sell.
+    /// * `token_out` - The token to buy.
+    /// * `amount_in` - The exact amount of `token_in` to sell.
+    /// * `max_hops` - The maximum number of pairs in the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Route<T>, SoroswapLibraryError>` where `Ok` contains the best route, and `Err` is `InvalidPath` if no path can be quoted.
+    pub fn best_route_out(&self, token_in: &T, token_out: &T, amount_in: i128, max_hops: usize) -> Result<Route<T>, SoroswapLibraryError> {
+        let mut best: Option<Route<T>> = None;
+        for path in self.paths(token_in, token_out, max_hops) {
+            let Ok(amounts) = self.get_amounts_out(amount_in, &path) else {
+                continue;
+            };
+            let is_better = match &best {
+                Some(route) => amounts.last() > route.amounts.last(),
+                None => true,
+            };
+            if is_better {
+                best = Some(Route { path, amounts });
+            }
+        }
+        best.ok_or(SoroswapLibraryError::InvalidPath)
+    }
+
+    /// Finds the path with at most `max_hops` pairs that needs the smallest input for an exact output.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_in` - The token to sell.
+    /// * `token_out` - The token to buy.
+    /// * `amount_out` - The exact amount of `token_out` to buy.
+    /// * `max_hops` - The maximum number of pairs in the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Route<T>, SoroswapLibraryError>` where `Ok` contains the best route, and `Err` is `InvalidPath` if no path can be quoted.
+    pub fn best_route_in(&self, token_in: &T, token_out: &T, amount_out: i128, max_hops: usize) -> Result<Route<T>, SoroswapLibraryError> {
+        let mut best: Option<Route<T>> = None;
+        for path in self.paths(token_in, token_out, max_hops) {
+            let Ok(amounts) = self.get_amounts_in(amount_out, &path) else {
+                continue;
+            };
+            let is_better = match &best {
+                Some(route) => amounts[0] < route.amounts[0],
+                None => true,
+            };
+            if is_better {
+                best = Some(Route { path, amounts });
+            }
+        }
+        best.ok_or(SoroswapLibraryError::InvalidPath)
+    }
+}
@@ -1,6 +1,8 @@
-use soroban_sdk::{Address, vec, Vec};
+use soroban_sdk::{contract, contractimpl, Address, Env, vec, Vec};
 use crate::test::{SoroswapLibraryTest};
+use crate::CONSTANT_PRODUCT_KIND;
 use crate::error::SoroswapLibraryError;
+use crate::quotes::{HopQuote, PRICE_PRECISION};
 
 
 #[test]
@@ -27,7 +29,7 @@ fn get_amount_out() {
     test.token_0.transfer(&test.user, &test.pair.address, &amount_0);
     test.token_1.transfer(&test.user, &test.pair.address, &amount_1);
     test.pair.deposit(&test.user);
-    assert_eq!(test.pair.get_reserves(), (amount_0, amount_1));
+    assert_eq!(test.pair.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
 
     let initial_0: i128 = test.token_0.balance(&test.user);
     let initial_1: i128 = test.token_1.balance(&test.user);
@@ -82,7 +84,7 @@ fn get_amount_in() {
     test.token_0.transfer(&test.user, &test.pair.address, &amount_0);
     test.token_1.transfer(&test.user, &test.pair.address, &amount_1);
     test.pair.deposit(&test.user);
-    assert_eq!(test.pair.get_reserves(), (amount_0, amount_1));
+    assert_eq!(test.pair.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
 
     let initial_0: i128 = test.token_0.balance(&test.user);
     let initial_1: i128 = test.token_1.balance(&test.user);
@@ -180,5 +182,286 @@ fn get_reserves_with_pair() {
     test.token_0.transfer(&test.user, &test.pair.address, &amount_0);
     test.token_1.transfer(&test.user, &test.pair.address, &amount_1);
     test.pair.deposit(&test.user);
-    assert_eq!(test.pair.get_reserves(), (amount_0, amount_1));
-}
\ No newline at end of file
+    assert_eq!(test.pair.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
+    assert_eq!(test.contract.get_reserves_with_pair(&test.pair.address, &test.token_0.address, &test.token_1.address), (amount_0, amount_1));
+    assert_eq!(test.contract.get_reserves_with_pair(&test.pair.address, &test.token_1.address, &test.token_0.address), (amount_1, amount_0));
+}
+
+// A pair deployed before get_reserves returned the last timestamp and before the fee could be configured
+#[contract]
+struct LegacyPair;
+
+#[contractimpl]
+impl LegacyPair {
+    pub fn get_reserves(_e: Env) -> (i128, i128) {
+        (123456789, 987654321)
+    }
+}
+
+#[test]
+fn get_with_legacy_pair() {
+    let test = SoroswapLibraryTest::setup();
+    let legacy_pair = test.env.register_contract(None, LegacyPair);
+    assert_eq!(test.contract.get_reserves_with_pair(&legacy_pair, &test.token_0.address, &test.token_1.address), (123456789, 987654321));
+    assert_eq!(test.contract.get_reserves_with_pair(&legacy_pair, &test.token_1.address, &test.token_0.address), (987654321, 123456789));
+    // It charges the default fee
+    assert_eq!(test.contract.get_fee_with_pair(&legacy_pair), 30);
+}
+
+#[test]
+fn get_amount_with_fee() {
+    let test = SoroswapLibraryTest::setup();
+
+    // The default fee gives the same results as get_amount_out and get_amount_in
+    assert_eq!(test.contract.get_amount_out(&1000, &10000, &10000), test.contract.get_amount_out_with_fee(&1000, &10000, &10000, &30));
+    assert_eq!(test.contract.get_amount_in(&1000, &10000, &10000), test.contract.get_amount_in_with_fee(&1000, &10000, &10000, &30));
+
+    // fee = ceil(1000*100/10000) = 10
+    // 990*10000/10990 = 900.8 = 900
+    assert_eq!(900, test.contract.get_amount_out_with_fee(&1000, &10000, &10000, &100));
+    // Without fees: 1000*10000/10990 = 909.9 = 909
+    assert_eq!(909, test.contract.get_amount_out_with_fee(&1000, &10000, &10000, &0));
+
+    // ceil(10000*900*10000/(9100*9900)) + 1 = 1000 + 1
+    assert_eq!(1001, test.contract.get_amount_in_with_fee(&900, &10000, &10000, &100));
+}
+
+#[test]
+fn get_amount_with_fee_invalid_fee() {
+    let test = SoroswapLibraryTest::setup();
+    let result = test.contract.try_get_amount_out_with_fee(&1000, &10000, &10000, &10000);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InvalidFee)));
+    let result = test.contract.try_get_amount_in_with_fee(&1000, &10000, &10000, &10000);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InvalidFee)));
+}
+
+#[test]
+fn get_amounts_with_pair_fee() {
+    let test = SoroswapLibraryTest::setup();
+
+    let path: Vec<Address> =  vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+
+    test.token_0.transfer(&test.user, &test.pair.address, &10000);
+    test.token_1.transfer(&test.user, &test.pair.address, &10000);
+    test.pair.deposit(&test.user);
+
+    assert_eq!(30, test.contract.get_fee_with_pair(&test.pair.address));
+    assert_eq!(vec![&test.env, 1000, 906], test.contract.get_amounts_out(&test.factory.address, &1000, &path));
+
+    test.factory.set_pair_fee(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND, &100);
+    assert_eq!(100, test.contract.get_fee_with_pair(&test.pair.address));
+    assert_eq!(100, test.contract.get_fee_with_factory(&test.factory.address, &test.token_1.address, &test.token_0.address));
+    assert_eq!(vec![&test.env, 1000, 900], test.contract.get_amounts_out(&test.factory.address, &1000, &path));
+    assert_eq!(vec![&test.env, 1001, 900], test.contract.get_amounts_in(&test.factory.address, &900, &path));
+}
+
+#[test]
+fn quote_detailed_exact_in() {
+    let test = SoroswapLibraryTest::setup();
+
+    let path: Vec<Address> =  vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+
+    test.token_0.transfer(&test.user, &test.pair.address, &10000);
+    test.token_1.transfer(&test.user, &test.pair.address, &10000);
+    test.pair.deposit(&test.user);
+
+    // fee = ceil(1000*30/10000) = 3
+    // 997*10000/10997 = 906.6 = 906
+    let expected_hop = HopQuote {
+        pair: test.pair.address.clone(),
+        token_in: test.token_0.address.clone(),
+        token_out: test.token_1.address.clone(),
+        amount_in: 1000,
+        amount_out: 906,
+        reserve_in_before: 10000,
+        reserve_out_before: 10000,
+        reserve_in_after: 11000,
+        reserve_out_after: 9094,
+        fee_amount: 3,
+        mid_price: PRICE_PRECISION,
+        execution_price: 90_600_000_000_000,
+        // (1 - 0.906) * 10000
+        price_impact: 940,
+    };
+    assert_eq!(vec![&test.env, expected_hop], test.contract.quote_detailed(&test.factory.address, &1000, &path, &true));
+}
+
+#[test]
+fn quote_detailed_exact_out() {
+    let test = SoroswapLibraryTest::setup();
+
+    let path: Vec<Address> =  vec![&test.env, test.token_1.address.clone(), test.token_0.address.clone()];
+
+    test.token_0.transfer(&test.user, &test.pair.address, &10000);
+    test.token_1.transfer(&test.user, &test.pair.address, &10000);
+    test.pair.deposit(&test.user);
+
+    // ceil(10000*900*10000/(9100*9970)) + 1 = 993
+    let hops = test.contract.quote_detailed(&test.factory.address, &900, &path, &false);
+    assert_eq!(hops.len(), 1);
+    let hop = hops.get(0).unwrap();
+    assert_eq!(hop.token_in, test.token_1.address);
+    assert_eq!(hop.token_out, test.token_0.address);
+    assert_eq!((hop.amount_in, hop.amount_out), (993, 900));
+    assert_eq!((hop.reserve_in_after, hop.reserve_out_after), (10993, 9100));
+    assert_eq!(hop.fee_amount, 3);
+    // 900/993 = 0.90634441087613
+    assert_eq!(hop.execution_price, 90_634_441_087_613);
+    assert_eq!(hop.price_impact, 936);
+    // Same amounts as get_amounts_in
+    assert_eq!(vec![&test.env, 993, 900], test.contract.get_amounts_in(&test.factory.address, &900, &path));
+}
+
+#[test]
+fn quote_detailed_invalid_path() {
+    let test = SoroswapLibraryTest::setup();
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone()];
+    let result = test.contract.try_quote_detailed(&test.factory.address, &1000, &path, &true);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InvalidPath)));
+}
+
+#[test]
+fn get_amount_in_whole_reserve() {
+    let test = SoroswapLibraryTest::setup();
+    let result = test.contract.try_get_amount_in(&100, &100, &100);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+    let result = test.contract.try_get_amount_in(&101, &100, &100);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+}
+
+#[test]
+fn get_amount_out_large_amounts() {
+    let test = SoroswapLibraryTest::setup();
+    // amount_in_less_fee * reserve_out is about 1.7 * 10^39, and is computed in 256 bits
+    let result = test.contract.get_amount_out(&(i128::MAX / 10), &100, &100);
+    assert_eq!(result, 99);
+    // amount_in_less_fee * reserve_out is about 10^39
+    let result = test.contract.get_amount_out(&100_000_000_000_000_000_000, &10_000_000_000_000_000_000, &10_000_000_000_000_000_000);
+    assert_eq!(result, 9_088_422_971_741_112_123);
+    // Reserves of 10^37
+    let result = test.contract.get_amount_out(
+        &1_000_000_000_000_000_000_000_000_000_000_000_000,
+        &10_000_000_000_000_000_000_000_000_000_000_000_000,
+        &10_000_000_000_000_000_000_000_000_000_000_000_000);
+    assert_eq!(result, 906_610_893_880_149_131_581_340_365_554_242_066);
+}
+
+#[test]
+fn get_amount_out_overflow() {
+    let test = SoroswapLibraryTest::setup();
+    // reserve_in + amount_in_less_fee does not fit in an i128
+    let result = test.contract.try_get_amount_out(&i128::MAX, &i128::MAX, &100);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::Overflow)));
+}
+
+#[test]
+fn get_amount_in_large_amounts() {
+    let test = SoroswapLibraryTest::setup();
+    // reserve_in * amount_out * 10000 is about 10^41, and is computed in 256 bits
+    let result = test.contract.get_amount_in(&1_000_000_000_000_000_000, &10_000_000_000_000_000_000, &10_000_000_000_000_000_000);
+    assert_eq!(result, 1_114_454_474_534_715_258);
+}
+
+#[test]
+fn get_amount_in_overflow() {
+    let test = SoroswapLibraryTest::setup();
+    // The required input does not fit in an i128
+    let result = test.contract.try_get_amount_in(&(10_000_000_000_000_000_000 - 1), &i128::MAX, &10_000_000_000_000_000_000);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::Overflow)));
+    // reserve_in * amount_out * 10000 is about 1.7 * 10^78, above 2^256
+    let result = test.contract.try_get_amount_in(&1_000_000_000_000_000_000_000_000_000_000_000_000, &i128::MAX, &i128::MAX);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::Overflow)));
+}
+
+#[test]
+fn quote_large_amounts() {
+    let test = SoroswapLibraryTest::setup();
+    // amount_a * reserve_b = 10^40
+    let result = test.contract.quote(&100_000_000_000_000_000_000, &10_000_000_000_000_000_000, &100_000_000_000_000_000_000);
+    assert_eq!(result, 1_000_000_000_000_000_000_000);
+}
+
+#[test]
+fn quote_overflow() {
+    let test = SoroswapLibraryTest::setup();
+    let result = test.contract.try_quote(&100_000_000_000_000_000_000, &1, &100_000_000_000_000_000_000);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::Overflow)));
+}
+
+#[test]
+fn get_amounts_out_large_amount_in() {
+    let test = SoroswapLibraryTest::setup();
+
+    let path: Vec<Address> =  vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+
+    test.token_0.transfer(&test.user, &test.pair.address, &10000);
+    test.token_1.transfer(&test.user, &test.pair.address, &10000);
+    test.pair.deposit(&test.user);
+
+    // (i128::MAX - fee) * reserve_out does not fit in an i128, but the quote does
+    let result = test.contract.get_amounts_out(&test.factory.address, &i128::MAX, &path);
+    assert_eq!(result, vec![&test.env, i128::MAX, 9_999]);
+}
+
+#[test]
+fn get_amount_in_for_price_with_fee() {
+    let test = SoroswapLibraryTest::setup();
+    // 10000/0.9 - 10000/0.997 = 1081.02
+    let result = test.contract.get_amount_in_for_price_with_fee(&10000, &10000, &30, &90_000_000_000_000);
+    assert_eq!(result, 1081);
+    // No trade reaches the mid price less the fee
+    let result = test.contract.get_amount_in_for_price_with_fee(&10000, &10000, &30, &99_700_000_000_000);
+    assert_eq!(result, 0);
+    let result = test.contract.get_amount_in_for_price_with_fee(&10000, &10000, &30, &PRICE_PRECISION);
+    assert_eq!(result, 0);
+}
+
+#[test]
+fn get_amount_in_for_price_with_fee_errors() {
+    let test = SoroswapLibraryTest::setup();
+    let result = test.contract.try_get_amount_in_for_price_with_fee(&10000, &10000, &30, &0);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InvalidPrice)));
+    let result = test.contract.try_get_amount_in_for_price_with_fee(&10000, &10000, &10000, &PRICE_PRECISION);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InvalidFee)));
+    let result = test.contract.try_get_amount_in_for_price_with_fee(&0, &10000, &30, &PRICE_PRECISION);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+}
+
+#[test]
+fn get_amount_in_for_price() {
+    let test = SoroswapLibraryTest::setup();
+
+    let path: Vec<Address> =  vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+
+    test.token_0.transfer(&test.user, &test.pair.address, &10000);
+    test.token_1.transfer(&test.user, &test.pair.address, &10000);
+    test.pair.deposit(&test.user);
+
+    // 960/1066 = 0.9005, while 1067 also gets 960, which is below 0.9
+    let amount_in = test.contract.get_amount_in_for_price(&test.factory.address, &1_000_000, &90_000_000_000_000, &path);
+    assert_eq!(amount_in, 1066);
+    assert_eq!(vec![&test.env, 1066, 960], test.contract.get_amounts_out(&test.factory.address, &1066, &path));
+    assert_eq!(vec![&test.env, 1067, 960], test.contract.get_amounts_out(&test.factory.address, &1067, &path));
+
+    // Bounded by amount_in_max
+    let amount_in = test.contract.get_amount_in_for_price(&test.factory.address, &500, &90_000_000_000_000, &path);
+    assert_eq!(amount_in, 500);
+
+    // The price is never reached
+    let amount_in = test.contract.get_amount_in_for_price(&test.factory.address, &1_000_000, &PRICE_PRECISION, &path);
+    assert_eq!(amount_in, 0);
+}
+
+#[test]
+fn get_amount_in_for_price_errors() {
+    let test = SoroswapLibraryTest::setup();
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone()];
+    let result = test.contract.try_get_amount_in_for_price(&test.factory.address, &1000, &PRICE_PRECISION, &path);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InvalidPath)));
+
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let result = test.contract.try_get_amount_in_for_price(&test.factory.address, &1000, &0, &path);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InvalidPrice)));
+    let result = test.contract.try_get_amount_in_for_price(&test.factory.address, &0, &PRICE_PRECISION, &path);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientInputAmount)));
+}
@@ -0,0 +1,87 @@
+extern crate std;
+use crate::error::SoroswapLibraryError;
+use crate::routes::{PairSnapshot, Route, RouteFinder};
+use std::vec;
+
+fn pair(token_0: u32, token_1: u32, reserve_0: i128, reserve_1: i128, fee: u32) -> PairSnapshot<u32> {
+    PairSnapshot { token_0, token_1, reserve_0, reserve_1, fee }
+}
+
+// Token 1 and token 2 can be swapped directly, through token 3 or through token 4.
+// The direct pair is shallow and the pairs with token 4 have a 5% fee
+fn route_finder() -> RouteFinder<u32> {
+    RouteFinder::new(&[
+        pair(1, 2, 1_000, 1_000, 30),
+        pair(1, 3, 10_000, 20_000, 30),
+        pair(2, 3, 10_000, 20_000, 30),
+        pair(1, 4, 50_000, 50_000, 500),
+        pair(4, 2, 50_000, 50_000, 500),
+        pair(5, 6, 1_000, 1_000, 30),
+    ])
+}
+
+#[test]
+fn routes_get_reserves_both_directions() {
+    let finder = route_finder();
+    assert_eq!(finder.get_reserves(&1, &3), Some((10_000, 20_000, 30)));
+    assert_eq!(finder.get_reserves(&3, &1), Some((20_000, 10_000, 30)));
+    assert_eq!(finder.get_reserves(&2, &4), Some((50_000, 50_000, 500)));
+    assert_eq!(finder.get_reserves(&1, &5), None);
+}
+
+#[test]
+fn routes_paths() {
+    let finder = route_finder();
+    assert_eq!(finder.paths(&1, &2, 1), vec![vec![1, 2]]);
+    assert_eq!(finder.paths(&1, &2, 2), vec![vec![1, 2], vec![1, 3, 2], vec![1, 4, 2]]);
+    // With 3 hops the paths through both 3 and 4 are not possible, as 3 and 4 have no pair
+    assert_eq!(finder.paths(&1, &2, 3).len(), 3);
+    assert_eq!(finder.paths(&1, &3, 3), vec![vec![1, 2, 3], vec![1, 3], vec![1, 4, 2, 3]]);
+    assert!(finder.paths(&1, &5, 3).is_empty());
+    assert!(finder.paths(&1, &1, 3).is_empty());
+    assert!(finder.paths(&1, &2, 0).is_empty());
+}
+
+#[test]
+fn routes_get_amounts_out() {
+    let finder = route_finder();
+    assert_eq!(finder.get_amounts_out(100, &[1, 2]), Ok(vec![100, 90]));
+    assert_eq!(finder.get_amounts_out(100, &[1, 3, 2]), Ok(vec![100, 196, 96]));
+    assert_eq!(finder.get_amounts_out(100, &[1]), Err(SoroswapLibraryError::InvalidPath));
+    assert_eq!(finder.get_amounts_out(100, &[1, 5]), Err(SoroswapLibraryError::InvalidPath));
+    assert_eq!(finder.get_amounts_out(0, &[1, 2]), Err(SoroswapLibraryError::InsufficientInputAmount));
+}
+
+#[test]
+fn routes_get_amounts_in() {
+    let finder = route_finder();
+    assert_eq!(finder.get_amounts_in(50, &[1, 2]), Ok(vec![54, 50]));
+    assert_eq!(finder.get_amounts_in(50, &[1, 3, 2]), Ok(vec![53, 102, 50]));
+    assert_eq!(finder.get_amounts_in(1_000, &[1, 2]), Err(SoroswapLibraryError::InsufficientLiquidity));
+    assert_eq!(finder.get_amounts_in(50, &[1, 5]), Err(SoroswapLibraryError::InvalidPath));
+}
+
+#[test]
+fn routes_best_route_out() {
+    let finder = route_finder();
+    // Through token 3: 96. Direct: 90. Through token 4: 88
+    assert_eq!(finder.best_route_out(&1, &2, 100, 2), Ok(Route { path: vec![1, 3, 2], amounts: vec![100, 196, 96] }));
+    assert_eq!(finder.best_route_out(&1, &2, 100, 1), Ok(Route { path: vec![1, 2], amounts: vec![100, 90] }));
+}
+
+#[test]
+fn routes_best_route_in() {
+    let finder = route_finder();
+    // Through token 3: 53. Direct: 54. Through token 4: 58
+    assert_eq!(finder.best_route_in(&1, &2, 50, 2), Ok(Route { path: vec![1, 3, 2], amounts: vec![53, 102, 50] }));
+    // The direct pair does not have enough liquidity for this amount
+    assert_eq!(finder.best_route_in(&1, &2, 1_000, 2), Ok(Route { path: vec![1, 3, 2], amounts: vec![1_260, 2_230, 1_000] }));
+    assert_eq!(finder.best_route_in(&1, &2, 1_000, 1), Err(SoroswapLibraryError::InvalidPath));
+}
+
+#[test]
+fn routes_no_route() {
+    let finder = route_finder();
+    assert_eq!(finder.best_route_out(&1, &5, 100, 3), Err(SoroswapLibraryError::InvalidPath));
+    assert_eq!(finder.best_route_in(&1, &5, 100, 3), Err(SoroswapLibraryError::InvalidPath));
+}
@@ -0,0 +1,134 @@
+use soroban_sdk::{vec, IntoVal, Vec, symbol_short};
+use crate::test::{SoroswapLibraryTest, stable_pair};
+use crate::test::stable_pair::SoroswapStablePairClient;
+use crate::error::SoroswapLibraryError;
+use crate::tokens::{PairKind, RouteHop, CONSTANT_PRODUCT_KIND, STABLE_KIND, WEIGHTED_KIND, CONCENTRATED_KIND};
+
+const AMP: u32 = 100;
+const FEE: u32 = 4;
+
+fn create_stable_pair<'a>(test: &SoroswapLibraryTest<'a>) -> SoroswapStablePairClient<'a> {
+    let stable_pair_hash = test.env.deployer().upload_contract_wasm(stable_pair::WASM);
+    test.factory.set_stable_pair_wasm_hash(&stable_pair_hash);
+    let address = test.factory.create_stable_pair(&test.token_0.address, &test.token_1.address, &AMP, &FEE);
+    SoroswapStablePairClient::new(&test.env, &address)
+}
+
+#[test]
+fn get_amount_out_stable() {
+    let test = SoroswapLibraryTest::setup();
+    assert_eq!(test.contract.get_amount_out_stable(&1_000_000_000, &10_000_000_000, &10_000_000_000, &AMP, &FEE), 999_098_173);
+    // A constant product pair with the same reserves and fee gives much less
+    assert_eq!(test.contract.get_amount_out_with_fee(&1_000_000_000, &10_000_000_000, &10_000_000_000, &FEE), 908_760_318);
+    // The output is rounded down, so a single unit gets nothing
+    assert_eq!(test.contract.get_amount_out_stable(&1, &10_000_000_000, &10_000_000_000, &AMP, &FEE), 0);
+}
+
+#[test]
+fn get_amount_in_stable() {
+    let test = SoroswapLibraryTest::setup();
+    assert_eq!(test.contract.get_amount_in_stable(&999_098_173, &10_000_000_000, &10_000_000_000, &AMP, &FEE), 1_000_000_000);
+}
+
+#[test]
+fn get_amount_stable_errors() {
+    let test = SoroswapLibraryTest::setup();
+    assert_eq!(test.contract.try_get_amount_out_stable(&0, &100, &100, &AMP, &FEE), Err(Ok(SoroswapLibraryError::InsufficientInputAmount)));
+    assert_eq!(test.contract.try_get_amount_out_stable(&1, &0, &100, &AMP, &FEE), Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+    assert_eq!(test.contract.try_get_amount_out_stable(&1, &100, &100, &0, &FEE), Err(Ok(SoroswapLibraryError::InvalidAmp)));
+    assert_eq!(test.contract.try_get_amount_out_stable(&1, &100, &100, &AMP, &10_000), Err(Ok(SoroswapLibraryError::InvalidFee)));
+
+    assert_eq!(test.contract.try_get_amount_in_stable(&0, &100, &100, &AMP, &FEE), Err(Ok(SoroswapLibraryError::InsufficientOutputAmount)));
+    assert_eq!(test.contract.try_get_amount_in_stable(&100, &100, &100, &AMP, &FEE), Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+    assert_eq!(test.contract.try_get_amount_in_stable(&1, &100, &100, &0, &FEE), Err(Ok(SoroswapLibraryError::InvalidAmp)));
+    assert_eq!(test.contract.try_get_amount_in_stable(&1, &100, &100, &AMP, &10_000), Err(Ok(SoroswapLibraryError::InvalidFee)));
+}
+
+#[test]
+fn stable_pair_for() {
+    let test = SoroswapLibraryTest::setup();
+    let stable_pair = create_stable_pair(&test);
+    assert_eq!(stable_pair.address, test.contract.stable_pair_for(&test.factory.address, &test.token_0.address, &test.token_1.address));
+    assert_eq!(stable_pair.address, test.contract.stable_pair_for(&test.factory.address, &test.token_1.address, &test.token_0.address));
+    assert_ne!(stable_pair.address, test.pair.address);
+}
+
+#[test]
+fn get_amounts_route() {
+    let test = SoroswapLibraryTest::setup();
+    let stable_pair = create_stable_pair(&test);
+    test.token_0.transfer(&test.user, &stable_pair.address, &5_000_000_000);
+    test.token_1.transfer(&test.user, &stable_pair.address, &5_000_000_000);
+    stable_pair.deposit(&test.user);
+    test.token_0.transfer(&test.user, &test.pair.address, &5_000_000_000);
+    test.token_1.transfer(&test.user, &test.pair.address, &5_000_000_000);
+    test.pair.deposit(&test.user);
+
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+
+    let hops = vec![&test.env, RouteHop::Kind(PairKind::Stable)];
+    let amounts = test.contract.get_amounts_out_route(&test.factory.address, &100_000_000, &path, &hops);
+    assert_eq!(amounts, vec![&test.env, 100_000_000, 99_950_054]);
+    let amounts = test.contract.get_amounts_in_route(&test.factory.address, &100_000_000, &path, &hops);
+    assert_eq!(amounts, vec![&test.env, 100_049_976, 100_000_000]);
+
+    // The constant product kind quotes like get_amounts_out and get_amounts_in
+    let hops = vec![&test.env, RouteHop::Kind(PairKind::ConstantProduct)];
+    assert_eq!(test.contract.get_amounts_out_route(&test.factory.address, &100_000_000, &path, &hops), test.contract.get_amounts_out(&test.factory.address, &100_000_000, &path));
+    assert_eq!(test.contract.get_amounts_in_route(&test.factory.address, &100_000_000, &path, &hops), test.contract.get_amounts_in(&test.factory.address, &100_000_000, &path));
+
+    // The quote is what the stable pair accepts
+    test.token_0.transfer(&test.user, &stable_pair.address, &100_000_000);
+    stable_pair.swap(&0, &99_950_054, &test.user);
+}
+
+#[test]
+fn get_amounts_route_invalid_path() {
+    let test = SoroswapLibraryTest::setup();
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let no_hops: Vec<RouteHop> = Vec::new(&test.env);
+    let two_hops = vec![&test.env, RouteHop::Kind(PairKind::Stable), RouteHop::Kind(PairKind::Stable)];
+
+    // Every hop needs a pair
+    assert_eq!(test.contract.try_get_amounts_out_route(&test.factory.address, &100, &path, &no_hops), Err(Ok(SoroswapLibraryError::InvalidPath)));
+    assert_eq!(test.contract.try_get_amounts_in_route(&test.factory.address, &100, &path, &two_hops), Err(Ok(SoroswapLibraryError::InvalidPath)));
+    // The built-in variants are only reached through their kind, so they can not be priced with another invariant
+    for variant in [CONSTANT_PRODUCT_KIND, STABLE_KIND, WEIGHTED_KIND, CONCENTRATED_KIND] {
+        for kind in [PairKind::ConstantProduct, PairKind::Stable, PairKind::Weighted] {
+            let hops = vec![&test.env, RouteHop::Variant(kind, variant.clone())];
+            assert_eq!(test.contract.try_get_amounts_out_route(&test.factory.address, &100, &path, &hops), Err(Ok(SoroswapLibraryError::InvalidPath)));
+            assert_eq!(test.contract.try_get_amounts_in_route(&test.factory.address, &100, &path, &hops), Err(Ok(SoroswapLibraryError::InvalidPath)));
+        }
+    }
+    // Every hop of a longer route is checked
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), test.token_0.address.clone()];
+    let hops = vec![&test.env, RouteHop::Kind(PairKind::ConstantProduct), RouteHop::Variant(PairKind::ConstantProduct, STABLE_KIND)];
+    assert_eq!(test.contract.try_get_amounts_out_route(&test.factory.address, &100, &path, &hops), Err(Ok(SoroswapLibraryError::InvalidPath)));
+    let hops = vec![&test.env, RouteHop::Variant(PairKind::Stable, CONSTANT_PRODUCT_KIND), RouteHop::Kind(PairKind::ConstantProduct)];
+    assert_eq!(test.contract.try_get_amounts_in_route(&test.factory.address, &100, &path, &hops), Err(Ok(SoroswapLibraryError::InvalidPath)));
+}
+
+#[test]
+fn get_amounts_route_variant() {
+    let test = SoroswapLibraryTest::setup();
+    let stable_pair = create_stable_pair(&test);
+    // A 0.01% fee tier of the stable pair, deployed from a template of the factory
+    let variant = symbol_short!("stable_1");
+    let stable_pair_hash = test.env.deployer().upload_contract_wasm(stable_pair::WASM);
+    test.factory.set_pool_template(&variant, &stable_pair_hash, &vec![&test.env, AMP.into_val(&test.env), 1_u32.into_val(&test.env)]);
+    let stable_1_pair = SoroswapStablePairClient::new(&test.env, &test.factory.create_pair_with_kind(&test.token_0.address, &test.token_1.address, &variant));
+    assert_ne!(stable_1_pair.address, stable_pair.address);
+
+    test.token_0.transfer(&test.user, &stable_1_pair.address, &5_000_000_000);
+    test.token_1.transfer(&test.user, &stable_1_pair.address, &5_000_000_000);
+    stable_1_pair.deposit(&test.user);
+
+    // The hop goes through the pair of the variant, quoted with the math of its kind and its own fee
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let amounts = test.contract.get_amounts_out_route(&test.factory.address, &100_000_000, &path, &vec![&test.env, RouteHop::Variant(PairKind::Stable, variant)]);
+    let amount_out = test.contract.get_amount_out_stable(&100_000_000, &5_000_000_000, &5_000_000_000, &AMP, &1);
+    assert_eq!(amounts, vec![&test.env, 100_000_000, amount_out]);
+
+    test.token_0.transfer(&test.user, &stable_1_pair.address, &100_000_000);
+    stable_1_pair.swap(&0, &amount_out, &test.user);
+}
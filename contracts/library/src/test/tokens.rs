@@ -1,5 +1,6 @@
-use soroban_sdk::{Address, String};
-use crate::test::{SoroswapLibraryTest};
+use soroban_sdk::{symbol_short, vec, Address, IntoVal, String};
+use crate::test::{SoroswapLibraryTest, pair_contract_wasm};
+use crate::CONSTANT_PRODUCT_KIND;
 use crate::error::SoroswapLibraryError;
 
 
@@ -27,4 +28,18 @@ fn pair_for() {
     let test = SoroswapLibraryTest::setup();
     assert_eq!(test.pair.address,test.contract.pair_for(&test.factory.address, &test.token_0.address, &test.token_1.address));
     assert_eq!(test.pair.address,test.contract.pair_for(&test.factory.address, &test.token_1.address, &test.token_0.address));
-}
\ No newline at end of file
+}
+
+#[test]
+fn pair_for_variant() {
+    let test = SoroswapLibraryTest::setup();
+    assert_eq!(test.pair.address, test.contract.pair_for_variant(&test.factory.address, &test.token_1.address, &test.token_0.address, &CONSTANT_PRODUCT_KIND));
+
+    // A pair deployed from a registered template of the factory, with a 0.05% fee
+    let variant = symbol_short!("cp_5");
+    test.factory.set_pool_template(&variant, &pair_contract_wasm(&test.env), &vec![&test.env, 5_u32.into_val(&test.env)]);
+    let pair_address = test.factory.create_pair_with_kind(&test.token_0.address, &test.token_1.address, &variant);
+    assert_ne!(pair_address, test.pair.address);
+    assert_eq!(pair_address, test.contract.pair_for_variant(&test.factory.address, &test.token_0.address, &test.token_1.address, &variant));
+    assert_eq!(pair_address, test.contract.pair_for_variant(&test.factory.address, &test.token_1.address, &test.token_0.address, &variant));
+}
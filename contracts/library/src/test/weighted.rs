@@ -0,0 +1,33 @@
+use crate::test::SoroswapLibraryTest;
+use crate::error::SoroswapLibraryError;
+
+const WEIGHT_0: u32 = 8_000;
+const WEIGHT_1: u32 = 2_000;
+const FEE: u32 = 30;
+
+#[test]
+fn get_amount_out_weighted() {
+    let test = SoroswapLibraryTest::setup();
+    assert_eq!(test.contract.get_amount_out_weighted(&1_000_000_000, &80_000_000_000, &20_000_000_000, &WEIGHT_0, &WEIGHT_1, &FEE), 966_694_903);
+    assert_eq!(test.contract.get_amount_out_weighted(&1_000_000_000, &20_000_000_000, &80_000_000_000, &WEIGHT_1, &WEIGHT_0, &FEE), 967_053_449);
+}
+
+#[test]
+fn get_amount_in_weighted() {
+    let test = SoroswapLibraryTest::setup();
+    assert_eq!(test.contract.get_amount_in_weighted(&966_694_903, &80_000_000_000, &20_000_000_000, &WEIGHT_0, &WEIGHT_1, &FEE), 1_000_000_000);
+}
+
+#[test]
+fn get_amount_weighted_invalid_weights() {
+    let test = SoroswapLibraryTest::setup();
+    assert_eq!(test.contract.try_get_amount_out_weighted(&1_000_000_000, &80_000_000_000, &20_000_000_000, &WEIGHT_0, &1_000, &FEE), Err(Ok(SoroswapLibraryError::InvalidWeights)));
+    assert_eq!(test.contract.try_get_amount_in_weighted(&1_000_000_000, &80_000_000_000, &20_000_000_000, &10_000, &0, &FEE), Err(Ok(SoroswapLibraryError::InvalidWeights)));
+}
+
+#[test]
+fn get_amount_weighted_max_ratio() {
+    let test = SoroswapLibraryTest::setup();
+    assert_eq!(test.contract.try_get_amount_out_weighted(&30_000_000_000, &80_000_000_000, &20_000_000_000, &WEIGHT_0, &WEIGHT_1, &FEE), Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+    assert_eq!(test.contract.try_get_amount_in_weighted(&7_000_000_000, &80_000_000_000, &20_000_000_000, &WEIGHT_0, &WEIGHT_1, &FEE), Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+}
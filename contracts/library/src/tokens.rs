@@ -1,25 +1,69 @@
-use soroban_sdk::{Address, Env, xdr::ToXdr, BytesN, Bytes};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, xdr::ToXdr, BytesN, Bytes, Symbol};
 use crate::error::SoroswapLibraryError;
 
+/// Kind of pair a hop of a route goes through.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PairKind {
+    /// Constant product pair, created with `create_pair`
+    ConstantProduct,
+    /// StableSwap pair, created with `create_stable_pair`
+    Stable,
+    /// Weighted constant mean pair, created with `create_weighted_pair`
+    Weighted,
+}
+
+/// Pair a hop of a route goes through.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RouteHop {
+    /// The pair of a built-in kind, whose variant is the name of the kind
+    Kind(PairKind),
+    /// The pair of a variant registered with a pool template in the factory, priced with the math of the kind
+    Variant(PairKind, Symbol),
+}
+
+/// Pool kind of the constant product pairs, as registered in the factory
+pub const CONSTANT_PRODUCT_KIND: Symbol = symbol_short!("cp");
+/// Pool kind of the StableSwap pairs
+pub const STABLE_KIND: Symbol = symbol_short!("stable");
+/// Pool kind of the weighted constant mean pairs
+pub const WEIGHTED_KIND: Symbol = symbol_short!("weighted");
+/// Pool kind of the concentrated liquidity pairs
+pub const CONCENTRATED_KIND: Symbol = symbol_short!("conc");
+
 
-/// Generates a unique cryptographic salt value for a pair of token addresses.
+/// Generates a unique cryptographic salt value for a pair of token addresses and a variant. The factory deploys
+/// every pair with this salt: the constant product pairs use the salt of the two tokens, the other built-in
+/// kinds append their name and the kinds registered with a pool template append the XDR of the kind.
 ///
 /// # Arguments
 ///
 /// * `e` - The environment.
-/// * `token_a` - The address of the first token.
+/// * `token_a` - The address of the first token, sorted before `token_b`.
 /// * `token_b` - The address of the second token.
+/// * `variant` - The variant (pool kind) of the pair.
 ///
 /// # Returns
 ///
-/// Returns a `BytesN<32>` representing the salt for the given token pair.
-fn pair_salt(e: &Env, token_a: Address, token_b: Address) -> BytesN<32> {
+/// Returns a `BytesN<32>` representing the salt for the given token pair and variant.
+pub fn pair_salt(e: &Env, token_a: Address, token_b: Address, variant: &Symbol) -> BytesN<32> {
     let mut salt = Bytes::new(e);
 
     // Append the bytes of token_a and token_b to the salt
     salt.append(&token_a.clone().to_xdr(e)); // can be simplified to salt.append(&self.clone().to_xdr(e)); but changes the hash
     salt.append(&token_b.clone().to_xdr(e));
 
+    if *variant == STABLE_KIND {
+        salt.append(&Bytes::from_slice(e, b"stable"));
+    } else if *variant == WEIGHTED_KIND {
+        salt.append(&Bytes::from_slice(e, b"weighted"));
+    } else if *variant == CONCENTRATED_KIND {
+        salt.append(&Bytes::from_slice(e, b"concentrated"));
+    } else if *variant != CONSTANT_PRODUCT_KIND {
+        salt.append(&variant.clone().to_xdr(e));
+    }
+
     // Hash the salt using SHA256 to generate a new BytesN<32> value
     e.crypto().sha256(&salt).into()
 }
@@ -60,9 +104,97 @@ pub fn sort_tokens(token_a: Address, token_b: Address) -> Result<(Address, Addre
 ///
 /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the pair, and `Err` indicates an error such as identical tokens or an issue with sorting.
 pub fn pair_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError> {
+    pair_for_variant(e, factory, token_a, token_b, CONSTANT_PRODUCT_KIND)
+}
+
+/// Calculates the deterministic address for a stable pair without making any external calls.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `token_a` - The address of the first token.
+/// * `token_b` - The address of the second token.
+///
+/// # Returns
+///
+/// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the stable pair, and `Err` indicates an error such as identical tokens.
+pub fn stable_pair_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError> {
+    pair_for_variant(e, factory, token_a, token_b, STABLE_KIND)
+}
+
+/// Calculates the deterministic address for a weighted pair without making any external calls.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `token_a` - The address of the first token.
+/// * `token_b` - The address of the second token.
+///
+/// # Returns
+///
+/// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the weighted pair, and `Err` indicates an error such as identical tokens.
+pub fn weighted_pair_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError> {
+    pair_for_variant(e, factory, token_a, token_b, WEIGHTED_KIND)
+}
+
+/// Calculates the deterministic address for a concentrated liquidity pair without making any external calls.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `token_a` - The address of the first token.
+/// * `token_b` - The address of the second token.
+///
+/// # Returns
+///
+/// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the concentrated pair, and `Err` indicates an error such as identical tokens.
+pub fn concentrated_pair_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError> {
+    pair_for_variant(e, factory, token_a, token_b, CONCENTRATED_KIND)
+}
+
+/// Calculates the deterministic address for the pair of the given kind.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `token_a` - The address of the first token.
+/// * `token_b` - The address of the second token.
+/// * `kind` - The kind of pair.
+///
+/// # Returns
+///
+/// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the pair, and `Err` indicates an error such as identical tokens.
+pub fn pair_for_kind(e: Env, factory: Address, token_a: Address, token_b: Address, kind: PairKind) -> Result<Address, SoroswapLibraryError> {
+    match kind {
+        PairKind::ConstantProduct => pair_for(e, factory, token_a, token_b),
+        PairKind::Stable => stable_pair_for(e, factory, token_a, token_b),
+        PairKind::Weighted => weighted_pair_for(e, factory, token_a, token_b),
+    }
+}
+
+/// Calculates the deterministic address for the pair of the given variant without making any external calls.
+/// The variant is the kind of the pair in the factory, either a built-in kind or a kind registered with a
+/// pool template, so every variant of the same tokens gets its own address.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `token_a` - The address of the first token.
+/// * `token_b` - The address of the second token.
+/// * `variant` - The variant of the pair, as listed by the factory `pair_variants`.
+///
+/// # Returns
+///
+/// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the pair, and `Err` indicates an error such as identical tokens.
+pub fn pair_for_variant(e: Env, factory: Address, token_a: Address, token_b: Address, variant: Symbol) -> Result<Address, SoroswapLibraryError> {
     let (token_0, token_1) = sort_tokens(token_a, token_b)?;
-    let salt = pair_salt(&e, token_0, token_1);
+    let salt = pair_salt(&e, token_0, token_1, &variant);
     let deployer_with_address = e.deployer().with_address(factory.clone(), salt);
     let deterministic_address = deployer_with_address.deployed_address();
     Ok(deterministic_address)
-}
\ No newline at end of file
+}
@@ -0,0 +1,40 @@
+use soroban_sdk::{token, Address, Env};
+use crate::event;
+use crate::storage::*;
+
+pub fn get_balance(e: &Env, contract_id: Address) -> i128 {
+    token::Client::new(e, &contract_id).balance(&e.current_contract_address())
+}
+
+pub fn get_balance_0(e: &Env) -> i128 {
+    get_balance(e, get_token_0(e))
+}
+
+pub fn get_balance_1(e: &Env) -> i128 {
+    get_balance(e, get_token_1(e))
+}
+
+pub fn transfer_from_pair(e: &Env, token: Address, to: &Address, amount: i128) {
+    token::Client::new(e, &token).transfer(&e.current_contract_address(), to, &amount);
+}
+
+pub fn transfer_to_pair(e: &Env, token: Address, from: &Address, amount: i128) {
+    token::Client::new(e, &token).transfer(from, &e.current_contract_address(), &amount);
+}
+
+pub fn transfer_token_0_from_pair(e: &Env, to: &Address, amount: i128) {
+    transfer_from_pair(e, get_token_0(e), to, amount);
+}
+
+pub fn transfer_token_1_from_pair(e: &Env, to: &Address, amount: i128) {
+    transfer_from_pair(e, get_token_1(e), to, amount);
+}
+
+/// Stores the balances as the new reserves, with the current ledger timestamp, and publishes the sync event.
+/// The prices of the reserves before the update should be accumulated first, see `accumulate_prices`.
+pub fn update_reserves(e: &Env, pair_name: &'static str, balance_0: i128, balance_1: i128) {
+    put_reserve_0(e, balance_0);
+    put_reserve_1(e, balance_1);
+    put_block_timestamp_last(e, e.ledger().timestamp());
+    event::sync(e, pair_name, balance_0, balance_1);
+}
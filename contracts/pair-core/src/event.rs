@@ -0,0 +1,172 @@
+//! Definition of the Events every pool type publishes. Their first topic is the name of the pool type,
+//! such as "SoroswapStablePair"
+use soroban_sdk::{contracttype, symbol_short, Env, Address, BytesN};
+
+// DEPOSIT EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositEvent {
+    pub to: Address,
+    pub amount_0: i128,
+    pub amount_1: i128,
+    pub liquidity: i128,
+    pub new_reserve_0: i128,
+    pub new_reserve_1: i128,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn deposit(
+    e: &Env,
+    pair_name: &'static str,
+    to: Address,
+    amount_0: i128,
+    amount_1: i128,
+    liquidity: i128,
+    new_reserve_0: i128,
+    new_reserve_1: i128) {
+    
+    let event: DepositEvent = DepositEvent {
+        to,
+        amount_0,
+        amount_1,
+        liquidity,
+        new_reserve_0,
+        new_reserve_1
+    };
+    e.events().publish((pair_name, symbol_short!("deposit")), event);
+}
+
+
+// SWAP EVENT
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapEvent {
+    pub to: Address,
+    pub amount_0_in: i128,
+    pub amount_1_in: i128,
+    pub amount_0_out: i128,
+    pub amount_1_out: i128,
+}
+
+pub fn swap(
+    e: &Env,
+    pair_name: &'static str,
+    to: Address,
+    amount_0_in: i128,
+    amount_1_in: i128,
+    amount_0_out: i128,
+    amount_1_out: i128,
+) {
+    let event: SwapEvent = SwapEvent {
+        to,
+        amount_0_in,
+        amount_1_in,
+        amount_0_out,
+        amount_1_out,
+    };
+    e.events().publish((pair_name, symbol_short!("swap")), event);
+}
+
+// WITHDRAW EVENT
+
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawEvent {
+    pub to: Address,
+    pub liquidity: i128,
+    pub amount_0: i128,
+    pub amount_1: i128,
+    pub new_reserve_0: i128,
+    pub new_reserve_1: i128,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn withdraw(
+    e: &Env,
+    pair_name: &'static str,
+    to: Address,
+    liquidity: i128,
+    amount_0: i128,
+    amount_1: i128,
+    new_reserve_0: i128,
+    new_reserve_1: i128,
+) {
+    let event: WithdrawEvent = WithdrawEvent {
+        to,
+        liquidity,
+        amount_0,
+        amount_1,
+        new_reserve_0,
+        new_reserve_1,
+    };
+    e.events().publish((pair_name, symbol_short!("withdraw")), event);
+}
+
+// SYNC EVENT
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SyncEvent {
+    pub new_reserve_0: i128,
+    pub new_reserve_1: i128,
+}
+
+pub fn sync(e: &Env, pair_name: &'static str, new_reserve_0: i128, new_reserve_1: i128) {
+    let event: SyncEvent = SyncEvent {
+        new_reserve_0,
+        new_reserve_1,
+    };
+    e.events().publish((pair_name, symbol_short!("sync")), event);
+}
+
+
+// SKIM EVENT
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SkimEvent {
+    pub skimmed_0: i128,
+    pub skimmed_1: i128,
+}
+
+pub fn skim(e: &Env, pair_name: &'static str, skimmed_0: i128, skimmed_1: i128) {
+    let event: SkimEvent = SkimEvent {
+        skimmed_0,
+        skimmed_1,
+    };
+    e.events().publish((pair_name, symbol_short!("skim")), event);
+}
+
+
+// UPGRADE EVENT
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeEvent {
+    pub new_wasm_hash: BytesN<32>,
+}
+
+pub(crate) fn upgrade(e: &Env, pair_name: &'static str, new_wasm_hash: BytesN<32>) {
+    let event: UpgradeEvent = UpgradeEvent {
+        new_wasm_hash,
+    };
+    e.events().publish((pair_name, symbol_short!("upgrade")), event);
+}
+
+
+// PAUSED EVENT
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PausedEvent {
+    pub is_paused: bool,
+}
+
+pub(crate) fn paused(e: &Env, pair_name: &'static str, is_paused: bool) {
+    let event: PausedEvent = PausedEvent {
+        is_paused,
+    };
+    e.events().publish((pair_name, symbol_short!("paused")), event);
+}
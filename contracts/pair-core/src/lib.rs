@@ -0,0 +1,18 @@
+#![no_std]
+
+//! Plumbing shared by the stable, weighted and concentrated pairs: the storage of their tokens, factory, fee and
+//! reserves, the token balances, the price accumulators, pausing, upgrades and the events they have in common.
+//! Each pool crate keeps its invariant math and its entry points on top of it.
+
+mod storage;
+mod balances;
+pub mod event;
+mod oracle;
+mod pause;
+mod upgrade;
+
+pub use storage::*;
+pub use balances::*;
+pub use oracle::accumulate_prices;
+pub use pause::{is_paused, set_paused};
+pub use upgrade::upgrade;
@@ -0,0 +1,44 @@
+use soroban_sdk::{Env, U256};
+use crate::storage::*;
+
+/// Adds the prices of the current reserves, multiplied by the seconds since the last update of the reserves, to the
+/// price accumulators. Like in `SoroswapPair`, the prices are unsigned 128.128 fixed point numbers and the
+/// accumulators wrap around at 2^256, so consumers should compute differences with wrapping arithmetic.
+///
+/// `prices` returns the prices of token 0 and token 1 for the reserves. It is only called when time passed and both
+/// reserves are set, and its `None` is returned as is.
+pub fn accumulate_prices<F>(e: &Env, prices: F) -> Option<()>
+where
+    F: FnOnce(i128, i128) -> Option<(U256, U256)>,
+{
+    let time_elapsed = e.ledger().timestamp().checked_sub(get_block_timestamp_last(e))?;
+    let (reserve_0, reserve_1) = (get_reserve_0(e), get_reserve_1(e));
+
+    if time_elapsed > 0 && reserve_0 != 0 && reserve_1 != 0 {
+        let (price_0, price_1) = prices(reserve_0, reserve_1)?;
+        put_price_0_cumulative_last(e, &wrapping_accumulate(e, &get_price_0_cumulative_last(e), &price_0, time_elapsed));
+        put_price_1_cumulative_last(e, &wrapping_accumulate(e, &get_price_1_cumulative_last(e), &price_1, time_elapsed));
+    }
+    Some(())
+}
+
+// a + b, wrapping around at 2^256
+fn wrapping_add_u256(e: &Env, a: &U256, b: &U256) -> U256 {
+    let room = U256::from_parts(e, u64::MAX, u64::MAX, u64::MAX, u64::MAX).sub(a);
+    if *b > room {
+        b.sub(&room).sub(&U256::from_u32(e, 1))
+    } else {
+        a.add(b)
+    }
+}
+
+// accumulator + price * time_elapsed, wrapping around at 2^256 like the accumulators of SoroswapPair.
+// The price is split at bit 192, so that each partial product fits in a U256 before it is added
+fn wrapping_accumulate(e: &Env, accumulator: &U256, price: &U256, time_elapsed: u64) -> U256 {
+    let price_low = price.rem_euclid(&U256::from_u32(e, 1).shl(192));
+    let price_high = price.shr(192).to_u128().unwrap() as u64;
+
+    let product_low = price_low.mul(&U256::from_u128(e, time_elapsed as u128));
+    let product_high = U256::from_u128(e, price_high.wrapping_mul(time_elapsed) as u128).shl(192);
+    wrapping_add_u256(e, &wrapping_add_u256(e, accumulator, &product_low), &product_high)
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::Env;
+use soroswap_factory_interface::SoroswapFactoryClient;
+use crate::event;
+use crate::storage::*;
+
+/// The pair is paused when it was paused by itself or when the factory paused all the pairs.
+pub fn is_paused(e: &Env) -> bool {
+    get_paused(e) || SoroswapFactoryClient::new(e, &get_factory(e)).paused()
+}
+
+/// Pauses or unpauses the pair. Only the factory can do it, on behalf of its guardian.
+pub fn set_paused(e: &Env, pair_name: &'static str, is_paused: bool) {
+    get_factory(e).require_auth();
+
+    put_paused(e, is_paused);
+    event::paused(e, pair_name, is_paused);
+}
@@ -0,0 +1,120 @@
+use soroban_sdk::{contracttype, Address, Env, U256};
+
+// Keys of the data every pool type has. The pool crates keep their own keys, which must use other names
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    Token0, // Address. Instance storage
+    Token1, // Address. Instance storage
+    Factory, // Address. Instance storage
+    Fee, // u32. Swap fee in basis points, set by the factory at creation. Instance storage
+    Paused, // bool. Whether this pair has been paused by the factory guardian. Instance storage
+    Reserve0, // i128. Instance storage
+    Reserve1, // i128. Instance storage
+    BlockTimestampLast, // u64. Ledger timestamp of the last update of the reserves. Instance storage
+    Price0CumulativeLast, // U256. Last cumulative price of token 0. Instance storage
+    Price1CumulativeLast, // U256. Last cumulative price of token 1. Instance storage
+}
+
+// We will follow the token standar for instance bumping
+
+const DAY_IN_LEDGERS: u32 = 17280;
+const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+// Helper function in order to know if the contract has been initialized or not
+pub fn has_token_0(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::Token0)
+}
+
+pub fn get_token_0(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Token0).unwrap()
+}
+
+pub fn get_token_1(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Token1).unwrap()
+}
+
+pub fn get_factory(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Factory).unwrap()
+}
+
+pub fn get_fee(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::Fee).unwrap()
+}
+
+pub fn get_paused(e: &Env) -> bool {
+    e.storage().instance().get(&DataKey::Paused).unwrap_or(false)
+}
+
+pub fn get_reserve_0(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::Reserve0).unwrap()
+}
+
+pub fn get_reserve_1(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::Reserve1).unwrap()
+}
+
+pub fn get_block_timestamp_last(e: &Env) -> u64 {
+    e.storage().instance().get(&DataKey::BlockTimestampLast).unwrap_or(0)
+}
+
+pub fn get_price_0_cumulative_last(e: &Env) -> U256 {
+    e.storage().instance().get(&DataKey::Price0CumulativeLast).unwrap_or(U256::from_u32(e, 0))
+}
+
+pub fn get_price_1_cumulative_last(e: &Env) -> U256 {
+    e.storage().instance().get(&DataKey::Price1CumulativeLast).unwrap_or(U256::from_u32(e, 0))
+}
+
+pub fn put_token_0(e: &Env, token_0: Address) {
+    e.storage().instance().set(&DataKey::Token0, &token_0);
+}
+
+pub fn put_token_1(e: &Env, token_1: Address) {
+    e.storage().instance().set(&DataKey::Token1, &token_1);
+}
+
+pub fn put_factory(e: &Env, factory: Address) {
+    e.storage().instance().set(&DataKey::Factory, &factory);
+}
+
+pub fn put_fee(e: &Env, fee: u32) {
+    e.storage().instance().set(&DataKey::Fee, &fee);
+}
+
+pub fn put_paused(e: &Env, is_paused: bool) {
+    e.storage().instance().set(&DataKey::Paused, &is_paused);
+}
+
+pub fn put_reserve_0(e: &Env, amount: i128) {
+    if amount < 0 {
+        panic!("put_reserve_0: amount cannot be negative")
+    }
+    e.storage().instance().set(&DataKey::Reserve0, &amount)
+}
+
+pub fn put_reserve_1(e: &Env, amount: i128) {
+    if amount < 0 {
+        panic!("put_reserve_1: amount cannot be negative")
+    }
+    e.storage().instance().set(&DataKey::Reserve1, &amount)
+}
+
+pub fn put_block_timestamp_last(e: &Env, block_timestamp_last: u64) {
+    e.storage().instance().set(&DataKey::BlockTimestampLast, &block_timestamp_last);
+}
+
+pub fn put_price_0_cumulative_last(e: &Env, price_0_cumulative_last: &U256) {
+    e.storage().instance().set(&DataKey::Price0CumulativeLast, price_0_cumulative_last);
+}
+
+pub fn put_price_1_cumulative_last(e: &Env, price_1_cumulative_last: &U256) {
+    e.storage().instance().set(&DataKey::Price1CumulativeLast, price_1_cumulative_last);
+}
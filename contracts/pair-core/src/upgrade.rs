@@ -0,0 +1,11 @@
+use soroban_sdk::{BytesN, Env};
+use crate::event;
+use crate::storage::get_factory;
+
+/// Upgrades the code of the pair to the given wasm hash, keeping its stored data. Only the factory can do it.
+pub fn upgrade(e: &Env, pair_name: &'static str, new_wasm_hash: BytesN<32>) {
+    get_factory(e).require_auth();
+
+    e.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+    event::upgrade(e, pair_name, new_wasm_hash);
+}
@@ -1,13 +1,13 @@
 //! This contract demonstrates a sample implementation of the Soroban token
 //! interface.
-use crate::soroswap_pair_token::allowance::{read_allowance, spend_allowance, write_allowance};
-use crate::soroswap_pair_token::balance::{read_balance, receive_balance, spend_balance};
-use crate::soroswap_pair_token::metadata::{read_decimal, read_name, read_symbol};
-use crate::soroswap_pair_token::total_supply::{read_total_supply, increase_total_supply, decrease_total_supply};
-
-#[cfg(test)]
-use crate::soroswap_pair_token::storage_types::{AllowanceDataKey, AllowanceValue, DataKey};
-use crate::soroswap_pair_token::storage_types::{INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD};
+use crate::allowance::{read_allowance, spend_allowance, write_allowance};
+use crate::balance::{read_balance, receive_balance, spend_balance};
+use crate::metadata::{read_decimal, read_name, read_symbol};
+use crate::total_supply::{read_total_supply, increase_total_supply, decrease_total_supply};
+
+#[cfg(any(test, feature = "testutils"))]
+use crate::storage_types::{AllowanceDataKey, AllowanceValue, DataKey};
+use crate::storage_types::{INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD};
 use soroban_sdk::token::{self, Interface as _};
 use soroban_sdk::{contract, contractimpl, Address, Env, String};
 use soroban_token_sdk::TokenUtils;
@@ -55,7 +55,7 @@ impl SoroswapPairToken {
         read_total_supply(&e)
     }
 
-    #[cfg(test)]
+    #[cfg(any(test, feature = "testutils"))]
     pub fn get_allowance(e: Env, from: Address, spender: Address) -> Option<AllowanceValue> {
         let key = DataKey::Allowance(AllowanceDataKey { from, spender });
         let allowance = e.storage().temporary().get::<_, AllowanceValue>(&key);
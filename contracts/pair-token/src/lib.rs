@@ -1,4 +1,7 @@
-//#![no_std]
+#![no_std]
+
+//! LP token shared by the Soroswap pairs. Every pair crate includes it, so the pairs expose the same
+//! token interface and share one implementation of it.
 
 mod allowance;
 mod balance;
@@ -6,8 +9,10 @@ mod contract;
 mod metadata;
 mod storage_types;
 mod total_supply;
+mod strings;
 
 pub use contract::SoroswapPairTokenClient; 
 pub use contract::SoroswapPairToken;
 pub use contract::{internal_mint, internal_burn};
 pub use metadata::write_metadata;
+pub use strings::TakeFirstNCharsAndConcat;
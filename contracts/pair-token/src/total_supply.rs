@@ -1,6 +1,6 @@
 use soroban_sdk::{Env};
 
-use crate::soroswap_pair_token::storage_types::DataKey;
+use crate::storage_types::DataKey;
 
 pub fn read_total_supply(e: &Env) -> i128 {
     let key = DataKey::TotalSupply;
@@ -1,5 +1,6 @@
 use soroban_sdk::{Address, Env}; 
-use crate::{soroswap_pair_token::{SoroswapPairToken}, any_token};
+use crate::any_token;
+use soroswap_pair_token::SoroswapPairToken;
 use crate::storage::*;
 use soroban_sdk::token::Interface;
 
@@ -43,8 +43,20 @@ pub enum SoroswapPairError {
     /// SoroswapPair: insufficient liquidity burned while doing withdraw
     WithdrawInsufficientLiquidityBurned = 117,
 
-    /// SoroswapPair: OVERFLOW while updating
+    /// SoroswapPair: OVERFLOW while doing the math of a deposit, swap, withdraw or protocol fee
     UpdateOverflow = 118,
+
+    /// SoroswapPair: fee is greater than the maximum allowed while setting the fee
+    SetFeeInvalidFee = 119,
+
+    /// SoroswapPair: the stored data is newer than the contract code while migrating
+    MigrateInvalidVersion = 120,
+
+    /// SoroswapPair: the pair is paused, only withdraws are allowed
+    Paused = 121,
+
+    /// SoroswapPair: fee is greater than the maximum allowed while initializing
+    InitializeInvalidFee = 122,
 }
 
 
@@ -1,5 +1,5 @@
 //! Definition of the Events used in the contract
-use soroban_sdk::{contracttype, symbol_short, Env, Address};
+use soroban_sdk::{contracttype, symbol_short, Env, Address, BytesN};
 
 // DEPOSIT EVENT
 #[contracttype]
@@ -131,4 +131,55 @@ pub(crate) fn skim(e: &Env, skimmed_0: i128, skimmed_1: i128) {
         skimmed_1: skimmed_1,
     };
     e.events().publish(("SoroswapPair", symbol_short!("skim")), event);
-}
\ No newline at end of file
+}
+
+
+// UPGRADE EVENT
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeEvent {
+    pub new_wasm_hash: BytesN<32>,
+}
+
+pub(crate) fn upgrade(e: &Env, new_wasm_hash: BytesN<32>) {
+    let event: UpgradeEvent = UpgradeEvent {
+        new_wasm_hash: new_wasm_hash,
+    };
+    e.events().publish(("SoroswapPair", symbol_short!("upgrade")), event);
+}
+
+
+// MIGRATE EVENT
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrateEvent {
+    pub old_version: u32,
+    pub new_version: u32,
+}
+
+pub(crate) fn migrate(e: &Env, old_version: u32, new_version: u32) {
+    let event: MigrateEvent = MigrateEvent {
+        old_version: old_version,
+        new_version: new_version,
+    };
+    e.events().publish(("SoroswapPair", symbol_short!("migrate")), event);
+}
+
+
+
+// PAUSED EVENT
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PausedEvent {
+    pub is_paused: bool,
+}
+
+pub(crate) fn paused(e: &Env, is_paused: bool) {
+    let event: PausedEvent = PausedEvent {
+        is_paused: is_paused,
+    };
+    e.events().publish(("SoroswapPair", symbol_short!("paused")), event);
+}
@@ -1,18 +1,16 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contractmeta, Address, Env, String}; 
-use num_integer::Roots; 
+use soroban_sdk::{contract, contractimpl, contractmeta, Address, Bytes, BytesN, Env, String, I256, U256}; 
 use soroswap_factory_interface::SoroswapFactoryClient;
+use soroswap_callee_interface::SoroswapCalleeClient;
 use soroban_token_sdk::metadata::TokenMetadata;
 
 
-mod soroswap_pair_token;
 mod storage;
 mod balances;
 mod event;
 mod error; 
 mod test;
 mod math;
-mod strings;
 
 // ANY TOKEN CONTRACT
 // TODO: Simplify this and use a any_token_interface
@@ -23,12 +21,14 @@ pub mod any_token {
 
 use storage::*;
 use balances::*;
-use soroswap_pair_token::{SoroswapPairToken, internal_mint, internal_burn, write_metadata};
+use soroswap_pair_token::{SoroswapPairToken, internal_mint, internal_burn, write_metadata, TakeFirstNCharsAndConcat};
 use error::SoroswapPairError;
-use math::CheckedCeilingDiv;
-use strings::TakeFirstNCharsAndConcat;
+use math::{mul_i256, checked_mul_div, checked_mul_div_ceil, checked_sqrt_mul, sqrt_i256, uq128x128_div, wrapping_accumulate};
 
 static MINIMUM_LIQUIDITY: i128 = 1000;
+// Fees are expressed in basis points. The factory cannot set a fee greater than 10%
+static FEE_DENOMINATOR: i128 = 10_000;
+static MAXIMUM_FEE: u32 = 1_000;
 
 fn create_symbol(e: &Env, symbol_0: &String, symbol_1: &String) -> String {
     let symbol_0_short = symbol_0.take_first_n_chars(&e, 6);
@@ -49,37 +49,63 @@ fn create_name(e: &Env, symbol_0: &String, symbol_1: &String) -> String {
 // Metadata that is added on to the WASM custom section
 contractmeta!(
     key = "Description",
-    val = "Soroswap.Finance Protocol - Constant product AMM with a .3% swap fee"
+    val = "Soroswap.Finance Protocol - Constant product AMM with a configurable swap fee (.3% by default)"
 );
 
 pub trait SoroswapPairTrait{
     // Sets the token contract addresses for this pool
-    fn initialize(e: Env, factory: Address, token_0: Address, token_1: Address)-> Result<(), SoroswapPairError>;
+    fn initialize(e: Env, factory: Address, token_0: Address, token_1: Address, fee: u32)-> Result<(), SoroswapPairError>;
 
     fn deposit(e:Env, to: Address)  -> Result<i128, SoroswapPairError>;
 
     // Swaps. This function should be called from another contract that has already sent tokens to the pair contract
     fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address) -> Result<(), SoroswapPairError>;
 
+    // Flash swaps. Sends the amounts out to "to" first, and then calls "soroswap_call" on "to" so it can
+    // pay back the pair (in any of both tokens) before the K constant is checked
+    fn flash_swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address, data: Bytes) -> Result<(), SoroswapPairError>;
+
     fn withdraw(e: Env, to: Address) -> Result<(i128, i128), SoroswapPairError>;
 
     // transfers the excess token balances from the pair to the specified to address, 
     // ensuring that the balances match the reserves by subtracting the reserve amounts 
     // from the current balances.
-    fn skim(e: Env, to: Address);
+    fn skim(e: Env, to: Address) -> Result<(), SoroswapPairError>;
 
     // updates the reserves of the pair to match the current token balances.
     // It retrieves the balances and reserves from the environment, then calls the update
     // function to synchronize the reserves with the balances.
-    fn sync(e: Env);
+    fn sync(e: Env) -> Result<(), SoroswapPairError>;
 
     fn token_0(e: Env) -> Address;
     fn token_1(e: Env) -> Address;
     fn factory(e: Env) -> Address;
 
-    fn k_last(e: Env) -> i128;
+    fn k_last(e: Env) -> I256;
+
+    // Swap fee in basis points. Only the factory can change it
+    fn fee(e: Env) -> u32;
+    fn set_fee(e: Env, fee: u32) -> Result<(), SoroswapPairError>;
+
+    fn get_reserves(e: Env) -> (i128, i128, u64);
+
+    // Accumulated price of token 0 in terms of token 1, weighted by the seconds it was active.
+    // Used to build time-weighted average prices (TWAP).
+    fn price_0_cumulative_last(e: Env) -> U256;
+    fn price_1_cumulative_last(e: Env) -> U256;
+
+    // Upgrades the code of the pair to the given wasm hash. Only the factory can upgrade a pair
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), SoroswapPairError>;
+
+    // Migrates the stored data to the storage layout of the current code. Only the factory can migrate a pair
+    fn migrate(e: Env) -> Result<u32, SoroswapPairError>;
 
-    fn get_reserves(e: Env) -> (i128, i128);
+    fn storage_version(e: Env) -> u32;
+
+    // Returns true if the pair has been paused, either by itself or because the factory paused all the pairs.
+    // While paused, deposits and swaps are rejected but withdraws still work
+    fn paused(e: Env) -> bool;
+    fn set_paused(e: Env, is_paused: bool) -> Result<(), SoroswapPairError>;
 
 }
 
@@ -96,7 +122,8 @@ impl SoroswapPairTrait for SoroswapPair {
     /// * `factory` - The address of the Soroswap factory contract.
     /// * `token_0` - The address of the first token in the pair.
     /// * `token_1` - The address of the second token in the pair.
-    fn initialize(e: Env, factory: Address, token_0: Address, token_1: Address) -> Result<(), SoroswapPairError> {
+    /// * `fee` - The swap fee, in basis points.
+    fn initialize(e: Env, factory: Address, token_0: Address, token_1: Address, fee: u32) -> Result<(), SoroswapPairError> {
         if has_token_0(&e) {
             return Err(SoroswapPairError::InitializeAlreadyInitialized);
         }
@@ -105,7 +132,12 @@ impl SoroswapPairTrait for SoroswapPair {
             return Err(SoroswapPairError::InitializeTokenOrderInvalid);
         }
 
+        if fee > MAXIMUM_FEE {
+            return Err(SoroswapPairError::InitializeInvalidFee);
+        }
+
         put_factory(&e, factory);
+        put_fee(&e, fee);
 
         let symbol_0: String = any_token::TokenClient::new(&e, &token_0).symbol();
         let symbol_1: String = any_token::TokenClient::new(&e, &token_1).symbol();
@@ -127,6 +159,7 @@ impl SoroswapPairTrait for SoroswapPair {
         put_token_1(&e, token_1);
         put_reserve_0(&e, 0);
         put_reserve_1(&e, 0);
+        put_storage_version(&e, STORAGE_VERSION);
         extend_instance_ttl(&e);
 
         Ok(())
@@ -172,6 +205,10 @@ impl SoroswapPairTrait for SoroswapPair {
             return Err(SoroswapPairError::NotInitialized)
         }
 
+        if is_paused(&e) {
+            return Err(SoroswapPairError::Paused);
+        }
+
         let (mut reserve_0, mut reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
         let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
         let amount_0 = balance_0.checked_sub(reserve_0).ok_or(SoroswapPairError::DepositInsufficientAmountToken0)?;
@@ -185,20 +222,20 @@ impl SoroswapPairTrait for SoroswapPair {
             return Err(SoroswapPairError::DepositInsufficientAmountToken1);
         }
 
-        let fee_on: bool = mint_fee(&e, reserve_0, reserve_1);
+        let fee_on: bool = mint_fee(&e, reserve_0, reserve_1)?;
         let total_supply = SoroswapPairToken::total_supply(e.clone());
 
         let liquidity = if total_supply == 0 {
             // When the liquidity pool is being initialized, we block the minimum liquidity forever in this contract
             internal_mint(e.clone(), e.current_contract_address(), MINIMUM_LIQUIDITY);
-            let previous_liquidity = (amount_0.checked_mul(amount_1).unwrap()).sqrt();
+            let previous_liquidity = checked_sqrt_mul(&e, amount_0, amount_1).ok_or(SoroswapPairError::UpdateOverflow)?;
             if previous_liquidity <= MINIMUM_LIQUIDITY {
                 return Err(SoroswapPairError::DepositInsufficientFirstLiquidity);
             }
-            (previous_liquidity).checked_sub(MINIMUM_LIQUIDITY).unwrap()
+            (previous_liquidity).checked_sub(MINIMUM_LIQUIDITY).ok_or(SoroswapPairError::UpdateOverflow)?
         } else {
-            let shares_0 = (amount_0.checked_mul(total_supply).unwrap()).checked_div(reserve_0).unwrap();
-            let shares_1 = (amount_1.checked_mul(total_supply).unwrap()).checked_div(reserve_1).unwrap();
+            let shares_0 = checked_mul_div(&e, amount_0, total_supply, reserve_0).ok_or(SoroswapPairError::UpdateOverflow)?;
+            let shares_1 = checked_mul_div(&e, amount_1, total_supply, reserve_1).ok_or(SoroswapPairError::UpdateOverflow)?;
             shares_0.min(shares_1)
         };
 
@@ -207,11 +244,11 @@ impl SoroswapPairTrait for SoroswapPair {
         }
 
         internal_mint(e.clone(), to.clone(), liquidity.clone());
-        update(&e, balance_0, balance_1);
+        update(&e, balance_0, balance_1)?;
 
         (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
         if fee_on {
-            put_klast(&e, reserve_0.checked_mul(reserve_1).unwrap());
+            put_klast(&e, mul_i256(&e, reserve_0, reserve_1));
         }
 
         event::deposit(&e, to, amount_0, amount_1, liquidity, reserve_0, reserve_1);
@@ -236,71 +273,28 @@ impl SoroswapPairTrait for SoroswapPair {
     /// - `SoroswapPairError::SwapInsufficientInputAmount`
     /// - `SoroswapPairError::SwapNegativesInNotSupported`
     /// - `SoroswapPairError::SwapKConstantNotMet`: If the K constant condition is not met after the swap.
+    /// - `SoroswapPairError::UpdateOverflow`: If any of the amounts overflows.
     fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address) -> Result<(), SoroswapPairError> {
-        extend_instance_ttl(&e);
-
-        if !has_token_0(&e) {
-            return Err(SoroswapPairError::NotInitialized);
-        }
-    
-        let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
-    
-        if amount_0_out == 0 && amount_1_out == 0 {
-            return Err(SoroswapPairError::SwapInsufficientOutputAmount);
-        }
-        if amount_0_out < 0 || amount_1_out < 0 {
-            return Err(SoroswapPairError::SwapNegativesOutNotSupported);
-        }
-        if amount_0_out >= reserve_0 || amount_1_out >= reserve_1 {
-            return Err(SoroswapPairError::SwapInsufficientLiquidity);
-        }
-        if to == get_token_0(&e) || to == get_token_1(&e) {
-            return Err(SoroswapPairError::SwapInvalidTo);
-        }
-
-        if amount_0_out > 0 {
-            transfer_token_0_from_pair(&e, &to, amount_0_out);
-        }
-        if amount_1_out > 0 {
-            transfer_token_1_from_pair(&e, &to, amount_1_out);
-        }
-
-        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
-
-        let amount_0_in = if balance_0 > reserve_0.checked_sub(amount_0_out).unwrap() {
-            balance_0.checked_sub(reserve_0.checked_sub(amount_0_out).unwrap()).unwrap()
-        } else {
-            0
-        };
-        let amount_1_in = if balance_1 > reserve_1.checked_sub(amount_1_out).unwrap() {
-            balance_1.checked_sub(reserve_1.checked_sub(amount_1_out).unwrap()).unwrap()
-        } else {
-            0
-        };
-
-        if amount_0_in == 0 && amount_1_in == 0 {
-            return Err(SoroswapPairError::SwapInsufficientInputAmount);
-        }
-        if amount_0_in < 0 || amount_1_in < 0 {
-            return Err(SoroswapPairError::SwapNegativesInNotSupported);
-        }
-
-        let fee_0 = (amount_0_in.checked_mul(3).unwrap()).checked_ceiling_div(1000).unwrap();
-        let fee_1 = (amount_1_in.checked_mul(3).unwrap()).checked_ceiling_div(1000).unwrap();
-
-        let balance_0_minus_fee = balance_0.checked_sub(fee_0).unwrap();
-        let balance_1_minus_fee = balance_1.checked_sub(fee_1).unwrap();
-
-        if balance_0_minus_fee.checked_mul(balance_1_minus_fee).unwrap() <
-            reserve_0.checked_mul(reserve_1).unwrap() {
-            return Err(SoroswapPairError::SwapKConstantNotMet);
-        }
-
-        update(&e, balance_0, balance_1);
-        
-        event::swap(&e, to, amount_0_in, amount_1_in, amount_0_out, amount_1_out);
+        execute_swap(&e, amount_0_out, amount_1_out, to, None)
+    }
 
-        Ok(())
+    /// Executes a flash swap within the Soroswap pair.
+    ///
+    /// The requested amounts are sent to `to` before any payment is received. Then `to` is called through
+    /// the `SoroswapCallee` interface with the given `data`, and it must pay back the pair before the
+    /// K constant is checked. The payment can be done in any of both tokens.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `amount_0_out` - The desired amount of the first token to receive.
+    /// * `amount_1_out` - The desired amount of the second token to receive.
+    /// * `to` - The contract that receives the tokens and implements `soroswap_call`.
+    /// * `data` - Opaque payload forwarded to `soroswap_call`.
+    ///
+    /// # Errors
+    /// Returns the same errors as `swap`.
+    fn flash_swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address, data: Bytes) -> Result<(), SoroswapPairError> {
+        execute_swap(&e, amount_0_out, amount_1_out, to, Some(data))
     }
 
 
@@ -326,18 +320,18 @@ impl SoroswapPairTrait for SoroswapPair {
 
         let (mut reserve_0, mut reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
         let (mut balance_0, mut balance_1) = (get_balance_0(&e), get_balance_1(&e));
-        let user_sent_shares = balance_shares.checked_sub(MINIMUM_LIQUIDITY).unwrap();
+        let user_sent_shares = balance_shares.checked_sub(MINIMUM_LIQUIDITY).ok_or(SoroswapPairError::UpdateOverflow)?;
 
         if user_sent_shares <= 0 {
             return Err(SoroswapPairError::WithdrawInsufficientSentShares);
         }
     
 
-        let fee_on: bool = mint_fee(&e, reserve_0, reserve_1);
+        let fee_on: bool = mint_fee(&e, reserve_0, reserve_1)?;
         let total_supply = SoroswapPairToken::total_supply(e.clone());
 
-        let amount_0 = (balance_0.checked_mul(user_sent_shares).unwrap()).checked_div(total_supply).unwrap();
-        let amount_1 = (balance_1.checked_mul(user_sent_shares).unwrap()).checked_div(total_supply).unwrap();
+        let amount_0 = checked_mul_div(&e, balance_0, user_sent_shares, total_supply).ok_or(SoroswapPairError::UpdateOverflow)?;
+        let amount_1 = checked_mul_div(&e, balance_1, user_sent_shares, total_supply).ok_or(SoroswapPairError::UpdateOverflow)?;
 
         if amount_0 <= 0 || amount_1 <= 0 {
             return Err(SoroswapPairError::WithdrawInsufficientLiquidityBurned);
@@ -350,11 +344,11 @@ impl SoroswapPairTrait for SoroswapPair {
 
         (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
 
-        update(&e, balance_0, balance_1);
+        update(&e, balance_0, balance_1)?;
 
         (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
         if fee_on {
-            put_klast(&e, reserve_0.checked_mul(reserve_1).unwrap());
+            put_klast(&e, mul_i256(&e, reserve_0, reserve_1));
         }
 
         event::withdraw(&e, to, user_sent_shares, amount_0, amount_1, reserve_0, reserve_1);
@@ -366,27 +360,28 @@ impl SoroswapPairTrait for SoroswapPair {
     /// # Arguments
     /// * `e` - The runtime environment.
     /// * `to` - The address where the excess tokens will be sent.
-    fn skim(e: Env, to: Address) {
+    fn skim(e: Env, to: Address) -> Result<(), SoroswapPairError> {
         extend_instance_ttl(&e);
 
         let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
         let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
-        let skimmed_0 = balance_0.checked_sub(reserve_0).unwrap();
-        let skimmed_1 = balance_1.checked_sub(reserve_1).unwrap();
+        let skimmed_0 = balance_0.checked_sub(reserve_0).ok_or(SoroswapPairError::UpdateOverflow)?;
+        let skimmed_1 = balance_1.checked_sub(reserve_1).ok_or(SoroswapPairError::UpdateOverflow)?;
         transfer_token_0_from_pair(&e, &to, skimmed_0);
         transfer_token_1_from_pair(&e, &to, skimmed_1);
         event::skim(&e, skimmed_0, skimmed_1);
+        Ok(())
     }
 
     /// Forces reserves to match current balances.
     ///
     /// # Arguments
     /// * `e` - The runtime environment.
-    fn sync(e: Env) {
+    fn sync(e: Env) -> Result<(), SoroswapPairError> {
         extend_instance_ttl(&e);
 
         let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
-        update(&e, balance_0, balance_1);
+        update(&e, balance_0, balance_1)
     }
 
     /// Returns the current reserves and the last block timestamp.
@@ -395,11 +390,39 @@ impl SoroswapPairTrait for SoroswapPair {
     /// * `e` - The runtime environment.
     ///
     /// # Returns
-    /// A tuple containing the reserves of token 0 and token 1.
-    fn get_reserves(e: Env) -> (i128, i128) {
+    /// A tuple containing the reserves of token 0 and token 1, and the ledger timestamp of the last reserves update.
+    fn get_reserves(e: Env) -> (i128, i128, u64) {
+        extend_instance_ttl(&e);
+
+        (get_reserve_0(&e), get_reserve_1(&e), get_block_timestamp_last(&e))
+    }
+
+    /// Returns the cumulative price of token 0 (priced in token 1) as of the last reserves update.
+    ///
+    /// The price is an unsigned 128.128 fixed point number (the price times 2^128), multiplied by the
+    /// seconds it was valid. The accumulator wraps around at 2^256, so consumers should compute
+    /// differences between two observations with wrapping arithmetic.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    fn price_0_cumulative_last(e: Env) -> U256 {
+        extend_instance_ttl(&e);
+
+        get_price_0_cumulative_last(&e)
+    }
+
+    /// Returns the cumulative price of token 1 (priced in token 0) as of the last reserves update.
+    ///
+    /// The price is an unsigned 128.128 fixed point number (the price times 2^128), multiplied by the
+    /// seconds it was valid. The accumulator wraps around at 2^256, so consumers should compute
+    /// differences between two observations with wrapping arithmetic.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    fn price_1_cumulative_last(e: Env) -> U256 {
         extend_instance_ttl(&e);
 
-        (get_reserve_0(&e), get_reserve_1(&e))
+        get_price_1_cumulative_last(&e)
     }
 
 
@@ -410,13 +433,226 @@ impl SoroswapPairTrait for SoroswapPair {
     ///
     /// # Returns
     /// The value of the last product of reserves (`K`).
-    fn k_last(e: Env) -> i128 {
+    fn k_last(e: Env) -> I256 {
         extend_instance_ttl(&e);
         
         get_klast(&e)
     }
 
+    /// Returns the swap fee of the pair, in basis points.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    fn fee(e: Env) -> u32 {
+        extend_instance_ttl(&e);
+
+        get_fee(&e)
+    }
+
+    /// Sets the swap fee of the pair. Can only be called by the factory.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `fee` - The new swap fee, in basis points.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`: The Soroswap pair has not been initialized.
+    /// - `SoroswapPairError::SetFeeInvalidFee`: The fee is greater than the maximum allowed.
+    fn set_fee(e: Env, fee: u32) -> Result<(), SoroswapPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        get_factory(&e).require_auth();
+
+        if fee > MAXIMUM_FEE {
+            return Err(SoroswapPairError::SetFeeInvalidFee);
+        }
+
+        put_fee(&e, fee);
+        Ok(())
+    }
+
+    /// Upgrades the code of the pair. Can only be called by the factory.
+    /// The stored data is kept, so `migrate` should be called afterwards if the storage layout changed.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `new_wasm_hash` - The hash of the new wasm code, already uploaded to the network.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`: The Soroswap pair has not been initialized.
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), SoroswapPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        get_factory(&e).require_auth();
+
+        e.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+        event::upgrade(&e, new_wasm_hash);
+        Ok(())
+    }
+
+    /// Migrates the stored data from the stored version to `STORAGE_VERSION`. Can only be called by the factory.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    ///
+    /// # Returns
+    /// The storage version after the migration.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`: The Soroswap pair has not been initialized.
+    /// - `SoroswapPairError::MigrateInvalidVersion`: The stored data is newer than the contract code.
+    fn migrate(e: Env) -> Result<u32, SoroswapPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        get_factory(&e).require_auth();
+
+        let version = get_storage_version(&e);
+        if version > STORAGE_VERSION {
+            return Err(SoroswapPairError::MigrateInvalidVersion);
+        }
+
+        // Each change to the DataKey layout adds a step here, for example:
+        // if version < 2 { move the data of the old keys to the new ones }
+        if version < 1 {
+            migrate_klast_to_i256(&e);
+            init_price_accumulators(&e);
+        }
+
+        if version < STORAGE_VERSION {
+            put_storage_version(&e, STORAGE_VERSION);
+            event::migrate(&e, version, STORAGE_VERSION);
+        }
+        Ok(STORAGE_VERSION)
+    }
+
+    /// Returns the version of the storage layout of the pair.
+    fn storage_version(e: Env) -> u32 {
+        extend_instance_ttl(&e);
+        get_storage_version(&e)
+    }
+
+    /// Returns true if deposits and swaps are currently blocked, either because this pair was paused
+    /// or because the factory paused all the pairs.
+    fn paused(e: Env) -> bool {
+        extend_instance_ttl(&e);
+        has_token_0(&e) && is_paused(&e)
+    }
+
+    /// Pauses or unpauses this pair. Can only be called by the factory, on behalf of its guardian.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `is_paused` - Whether deposits and swaps should be blocked.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`: The Soroswap pair has not been initialized.
+    fn set_paused(e: Env, is_paused: bool) -> Result<(), SoroswapPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        get_factory(&e).require_auth();
+
+        put_paused(&e, is_paused);
+        event::paused(&e, is_paused);
+        Ok(())
+    }
+
+    
+}
+
+/// The pair is paused when it was paused by itself or when the factory paused all the pairs.
+fn is_paused(e: &Env) -> bool {
+    get_paused(e) || SoroswapFactoryClient::new(e, &get_factory(e)).paused()
+}
+
+/// Executes a swap, calling `soroswap_call` on `to` before checking the K constant when `data` is given.
+fn execute_swap(e: &Env, amount_0_out: i128, amount_1_out: i128, to: Address, data: Option<Bytes>) -> Result<(), SoroswapPairError> {
+    extend_instance_ttl(e);
+
+    if !has_token_0(e) {
+        return Err(SoroswapPairError::NotInitialized);
+    }
+
+    if is_paused(e) {
+        return Err(SoroswapPairError::Paused);
+    }
+
+    let (reserve_0, reserve_1) = (get_reserve_0(e), get_reserve_1(e));
+
+    if amount_0_out == 0 && amount_1_out == 0 {
+        return Err(SoroswapPairError::SwapInsufficientOutputAmount);
+    }
+    if amount_0_out < 0 || amount_1_out < 0 {
+        return Err(SoroswapPairError::SwapNegativesOutNotSupported);
+    }
+    if amount_0_out >= reserve_0 || amount_1_out >= reserve_1 {
+        return Err(SoroswapPairError::SwapInsufficientLiquidity);
+    }
+    if to == get_token_0(e) || to == get_token_1(e) {
+        return Err(SoroswapPairError::SwapInvalidTo);
+    }
+
+    if amount_0_out > 0 {
+        transfer_token_0_from_pair(e, &to, amount_0_out);
+    }
+    if amount_1_out > 0 {
+        transfer_token_1_from_pair(e, &to, amount_1_out);
+    }
+
+    // Flash swap: "to" has the tokens and needs to pay the pair back before the K constant is checked.
+    // Soroban does not allow reentrancy, so "to" cannot call this pair while doing so.
+    if let Some(data) = data {
+        SoroswapCalleeClient::new(e, &to).soroswap_call(&e.current_contract_address(), &amount_0_out, &amount_1_out, &data);
+    }
+
+    let (balance_0, balance_1) = (get_balance_0(e), get_balance_1(e));
+
+    let amount_0_in = if balance_0 > reserve_0.checked_sub(amount_0_out).ok_or(SoroswapPairError::UpdateOverflow)? {
+        balance_0.checked_sub(reserve_0.checked_sub(amount_0_out).ok_or(SoroswapPairError::UpdateOverflow)?).ok_or(SoroswapPairError::UpdateOverflow)?
+    } else {
+        0
+    };
+    let amount_1_in = if balance_1 > reserve_1.checked_sub(amount_1_out).ok_or(SoroswapPairError::UpdateOverflow)? {
+        balance_1.checked_sub(reserve_1.checked_sub(amount_1_out).ok_or(SoroswapPairError::UpdateOverflow)?).ok_or(SoroswapPairError::UpdateOverflow)?
+    } else {
+        0
+    };
+
+    if amount_0_in == 0 && amount_1_in == 0 {
+        return Err(SoroswapPairError::SwapInsufficientInputAmount);
+    }
+    if amount_0_in < 0 || amount_1_in < 0 {
+        return Err(SoroswapPairError::SwapNegativesInNotSupported);
+    }
+
+    let fee = get_fee(e) as i128;
+    let fee_0 = checked_mul_div_ceil(e, amount_0_in, fee, FEE_DENOMINATOR).ok_or(SoroswapPairError::UpdateOverflow)?;
+    let fee_1 = checked_mul_div_ceil(e, amount_1_in, fee, FEE_DENOMINATOR).ok_or(SoroswapPairError::UpdateOverflow)?;
+
+    let balance_0_minus_fee = balance_0.checked_sub(fee_0).ok_or(SoroswapPairError::UpdateOverflow)?;
+    let balance_1_minus_fee = balance_1.checked_sub(fee_1).ok_or(SoroswapPairError::UpdateOverflow)?;
+
+    // Both products are computed in 256 bits, so pools with large reserves can still be checked
+    if mul_i256(e, balance_0_minus_fee, balance_1_minus_fee) < mul_i256(e, reserve_0, reserve_1) {
+        return Err(SoroswapPairError::SwapKConstantNotMet);
+    }
+
+    update(e, balance_0, balance_1)?;
     
+    event::swap(e, to, amount_0_in, amount_1_in, amount_0_out, amount_1_out);
+
+    Ok(())
 }
 
 fn transfer(e: &Env, contract_id: Address, to: &Address, amount: i128) {
@@ -432,7 +668,7 @@ fn transfer_token_1_from_pair(e: &Env, to: &Address, amount: i128) {
     transfer(e, get_token_1(e), &to, amount);
 }
 
-fn mint_fee(e: &Env, reserve_0: i128, reserve_1: i128) -> bool{
+fn mint_fee(e: &Env, reserve_0: i128, reserve_1: i128) -> Result<bool, SoroswapPairError> {
 
     /*
             accumulated fees are collected only when liquidity is deposited 
@@ -444,33 +680,59 @@ fn mint_fee(e: &Env, reserve_0: i128, reserve_1: i128) -> bool{
     let factory_client = SoroswapFactoryClient::new(&e, &factory);
     let fee_on = factory_client.fees_enabled();
     let klast = get_klast(&e);
+    let zero = I256::from_i32(&e, 0);
      
     if fee_on{
         let fee_to: Address = factory_client.fee_to();
 
-        if klast != 0 {
-            let root_k = (reserve_0.checked_mul(reserve_1).unwrap()).sqrt();
-            let root_klast = (klast).sqrt();
+        if klast != zero {
+            let root_k = sqrt_i256(&e, &mul_i256(&e, reserve_0, reserve_1));
+            let root_klast = sqrt_i256(&e, &klast);
             if root_k > root_klast{
-                let total_supply = SoroswapPairToken::total_supply(e.clone());
-                let numerator = total_supply.checked_mul(root_k.checked_sub(root_klast).unwrap()).unwrap();
-                let denominator = root_k.checked_mul(5_i128).unwrap().checked_add(root_klast).unwrap();
-                let liquidity_pool_shares_fees = numerator.checked_div(denominator).unwrap();
+                /*
+                    The protocol gets a fraction phi = fee_numerator/fee_denominator of the growth in sqrt(k):
+                    shares = total_supply * (root_k - root_klast) / ((1/phi - 1) * root_k + root_klast)
+                    With phi = 1/6 this is total_supply * (root_k - root_klast) / (5 * root_k + root_klast)
+                */
+                let (fee_numerator, fee_denominator) = factory_client.protocol_fee_fraction();
+                let fee_numerator = I256::from_i128(&e, fee_numerator as i128);
+                let fee_denominator = I256::from_i128(&e, fee_denominator as i128);
+                let total_supply = I256::from_i128(&e, SoroswapPairToken::total_supply(e.clone()));
+                let numerator = total_supply.mul(&root_k.sub(&root_klast));
+                let denominator = root_k.mul(&fee_denominator.sub(&fee_numerator)).add(&root_klast.mul(&fee_numerator));
+                // numerator * fee_numerator / denominator, split so that no product needs more than 256 bits
+                let liquidity_pool_shares_fees = numerator.div(&denominator).mul(&fee_numerator)
+                    .add(&numerator.rem_euclid(&denominator).mul(&fee_numerator).div(&denominator))
+                    .to_i128().ok_or(SoroswapPairError::UpdateOverflow)?;
 
                 if liquidity_pool_shares_fees > 0 {
                     internal_mint(e.clone(), fee_to, liquidity_pool_shares_fees);
                 }
             }
         }
-    } else if klast != 0{
-        put_klast(&e, 0);
+    } else if klast != zero{
+        put_klast(&e, zero);
     }
 
-    fee_on
+    Ok(fee_on)
 }
 
-fn update(e: &Env, balance_0: i128, balance_1: i128) {
+fn update(e: &Env, balance_0: i128, balance_1: i128) -> Result<(), SoroswapPairError> {
+    let block_timestamp = e.ledger().timestamp();
+    let time_elapsed = block_timestamp.checked_sub(get_block_timestamp_last(&e)).ok_or(SoroswapPairError::UpdateOverflow)?;
+    let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+
+    if time_elapsed > 0 && reserve_0 != 0 && reserve_1 != 0 {
+        // prices are computed with the reserves prior to this update, the accumulators overflow is desired
+        let price_0 = uq128x128_div(&e, reserve_1, reserve_0);
+        let price_1 = uq128x128_div(&e, reserve_0, reserve_1);
+        put_price_0_cumulative_last(&e, &wrapping_accumulate(&e, &get_price_0_cumulative_last(&e), &price_0, time_elapsed));
+        put_price_1_cumulative_last(&e, &wrapping_accumulate(&e, &get_price_1_cumulative_last(&e), &price_1, time_elapsed));
+    }
+
     put_reserve_0(&e, balance_0);
     put_reserve_1(&e, balance_1);
+    put_block_timestamp_last(&e, block_timestamp);
     event::sync(&e, balance_0, balance_1);
+    Ok(())
 }
\ No newline at end of file
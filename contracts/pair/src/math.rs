@@ -1,14 +1,86 @@
-pub trait CheckedCeilingDiv {
-    fn checked_ceiling_div(self, divisor: i128) -> Option<i128>;
+use soroban_sdk::{Env, I256, U256};
+
+// The product of two i128 values always fits in an I256, so none of these helpers can overflow
+// before the final conversion back to i128
+pub fn mul_i256(e: &Env, a: i128, b: i128) -> I256 {
+    I256::from_i128(e, a).mul(&I256::from_i128(e, b))
+}
+
+// a * b / c rounded down, or None if c is zero or the result does not fit in an i128
+pub fn checked_mul_div(e: &Env, a: i128, b: i128, c: i128) -> Option<i128> {
+    if c == 0 {
+        return None;
+    }
+    mul_i256(e, a, b).div(&I256::from_i128(e, c)).to_i128()
+}
+
+// a * b / c rounded up, for non-negative a and b and a positive c
+pub fn checked_mul_div_ceil(e: &Env, a: i128, b: i128, c: i128) -> Option<i128> {
+    if c <= 0 {
+        return None;
+    }
+    let c = I256::from_i128(e, c);
+    let one = I256::from_i32(e, 1);
+    mul_i256(e, a, b).add(&c).sub(&one).div(&c).to_i128()
 }
 
-impl CheckedCeilingDiv for i128 {
-    fn checked_ceiling_div(self, divisor: i128) -> Option<i128> {
-        let result = self.checked_div(divisor)?;
-        if self % divisor != 0 {
-            result.checked_add(1)
+// Integer square root (rounded down) of a non-negative I256, using Newton's method
+pub fn sqrt_i256(e: &Env, n: &I256) -> I256 {
+    let zero = I256::from_i32(e, 0);
+    if *n <= zero {
+        return zero;
+    }
+
+    let mut bits: u32 = 256;
+    for byte in n.to_be_bytes().iter() {
+        if byte == 0 {
+            bits -= 8;
         } else {
-            Some(result)
+            bits -= byte.leading_zeros();
+            break;
+        }
+    }
+
+    // 2^ceil(bits / 2) is not below the root, so the iterations decrease until they reach it
+    let mut x = I256::from_i32(e, 1).shl((bits + 1) / 2);
+    loop {
+        let y = x.add(&n.div(&x)).shr(1);
+        if y >= x {
+            return x;
         }
+        x = y;
     }
-}
\ No newline at end of file
+}
+
+// Square root of a * b rounded down. For non-negative inputs it is at most the larger of the two,
+// so it always fits in an i128
+pub fn checked_sqrt_mul(e: &Env, a: i128, b: i128) -> Option<i128> {
+    sqrt_i256(e, &mul_i256(e, a, b)).to_i128()
+}
+
+// numerator / denominator as an unsigned 128.128 fixed point number (UQ128x128), for positive inputs.
+// Reserves are below 2^127, so the shifted numerator and the quotient always fit in a U256
+pub fn uq128x128_div(e: &Env, numerator: i128, denominator: i128) -> U256 {
+    U256::from_u128(e, numerator as u128).shl(128).div(&U256::from_u128(e, denominator as u128))
+}
+
+// a + b, wrapping around at 2^256
+fn wrapping_add_u256(e: &Env, a: &U256, b: &U256) -> U256 {
+    let room = U256::from_parts(e, u64::MAX, u64::MAX, u64::MAX, u64::MAX).sub(a);
+    if *b > room {
+        b.sub(&room).sub(&U256::from_u32(e, 1))
+    } else {
+        a.add(b)
+    }
+}
+
+// accumulator + price * time_elapsed, wrapping around at 2^256 like the Uniswap V2 price accumulators.
+// The price is split at bit 192, so that each partial product fits in a U256 before it is added
+pub fn wrapping_accumulate(e: &Env, accumulator: &U256, price: &U256, time_elapsed: u64) -> U256 {
+    let price_low = price.rem_euclid(&U256::from_u32(e, 1).shl(192));
+    let price_high = price.shr(192).to_u128().unwrap() as u64;
+
+    let product_low = price_low.mul(&U256::from_u128(e, time_elapsed as u128));
+    let product_high = U256::from_u128(e, price_high.wrapping_mul(time_elapsed) as u128).shl(192);
+    wrapping_add_u256(e, &wrapping_add_u256(e, accumulator, &product_low), &product_high)
+}
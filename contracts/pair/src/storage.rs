@@ -1,5 +1,5 @@
 use soroban_sdk::{ Env, Address, ConversionError,
-    TryFromVal, Val}; 
+    TryFromVal, Val, I256, U256}; 
 
 #[derive(Clone, Copy)] 
 #[repr(u32)]
@@ -10,10 +10,23 @@ pub enum DataKey {
     Reserve0 = 2, // reserve0, instance type of data;
     Reserve1 = 3, // reserve1, instance type of data;
     Factory = 4, // factory, instance type of data;
-    KLast = 5 // last k, instance type of data;
+    KLast = 5, // last k, instance type of data;
+    BlockTimestampLast = 6, // last block timestamp, instance type of data;
+    Price0CumulativeLast = 7, // last cumulative price of token 0, instance type of data;
+    Price1CumulativeLast = 8, // last cumulative price of token 1, instance type of data;
+    Fee = 9, // swap fee in basis points, set by the factory, instance type of data;
+    StorageVersion = 10, // version of the storage layout, instance type of data;
+    Paused = 11, // whether this pair has been paused by the factory guardian, instance type of data;
 
 }
 
+// Swap fee in basis points of the pairs initialized before the factory passed one to `initialize` (0.3%)
+pub const DEFAULT_FEE: u32 = 30;
+
+// Version of the storage layout used by this code. Pairs deployed before versioning was added have version 0.
+// Bump it whenever the DataKey layout changes and add the corresponding step to `migrate`
+pub const STORAGE_VERSION: u32 = 1;
+
 // We will follow the token standar for instance bumping
 
 const DAY_IN_LEDGERS: u32 = 17280;
@@ -66,15 +79,67 @@ get(&DataKey::Reserve1).unwrap()
 
 
 
-pub fn get_klast(e: &Env) -> i128 {
+pub fn get_klast(e: &Env) -> I256 {
     if let Some(klast) = e.storage().instance().
 get(&DataKey::KLast) {
         klast
+    } else {
+        I256::from_i32(e, 0)
+    }
+}
+
+// Storage version 0 kept KLast as an i128. Since version 1 it is an I256, so that K can be
+// stored for reserves whose product does not fit in an i128
+pub fn migrate_klast_to_i256(e: &Env) {
+    if let Some(klast) = e.storage().instance().get::<DataKey, i128>(&DataKey::KLast) {
+        put_klast(e, I256::from_i128(e, klast));
+    }
+}
+
+// Storage version 0 had no price accumulators. They start from the migration, otherwise the first update would
+// weight the current price by every second since timestamp 0
+pub fn init_price_accumulators(e: &Env) {
+    if !e.storage().instance().has(&DataKey::BlockTimestampLast) {
+        put_block_timestamp_last(e, e.ledger().timestamp());
+    }
+}
+
+pub fn get_block_timestamp_last(e: &Env) -> u64 {
+    if let Some(block_timestamp_last) = e.storage().instance().
+get(&DataKey::BlockTimestampLast) {
+        block_timestamp_last
     } else {
         0
     }
 }
 
+pub fn get_price_0_cumulative_last(e: &Env) -> U256 {
+    if let Some(price_0_cumulative_last) = e.storage().instance().
+get(&DataKey::Price0CumulativeLast) {
+        price_0_cumulative_last
+    } else {
+        U256::from_u32(e, 0)
+    }
+}
+
+pub fn get_price_1_cumulative_last(e: &Env) -> U256 {
+    if let Some(price_1_cumulative_last) = e.storage().instance().
+get(&DataKey::Price1CumulativeLast) {
+        price_1_cumulative_last
+    } else {
+        U256::from_u32(e, 0)
+    }
+}
+
+pub fn get_fee(e: &Env) -> u32 {
+    if let Some(fee) = e.storage().instance().
+get(&DataKey::Fee) {
+        fee
+    } else {
+        DEFAULT_FEE
+    }
+}
+
 pub fn put_factory(e: &Env, factory: Address) {
     e.storage().instance().
 set(&DataKey::Factory, &factory);
@@ -107,7 +172,55 @@ set(&DataKey::Reserve1, &amount)
 }
 
 
-pub fn put_klast(e: &Env, klast: i128) {
+pub fn put_klast(e: &Env, klast: I256) {
     e.storage().instance().
 set(&DataKey::KLast, &klast);
+}
+
+pub fn put_block_timestamp_last(e: &Env, block_timestamp_last: u64) {
+    e.storage().instance().
+set(&DataKey::BlockTimestampLast, &block_timestamp_last);
+}
+
+pub fn put_price_0_cumulative_last(e: &Env, price_0_cumulative_last: &U256) {
+    e.storage().instance().
+set(&DataKey::Price0CumulativeLast, price_0_cumulative_last);
+}
+
+pub fn put_price_1_cumulative_last(e: &Env, price_1_cumulative_last: &U256) {
+    e.storage().instance().
+set(&DataKey::Price1CumulativeLast, price_1_cumulative_last);
+}
+
+pub fn put_fee(e: &Env, fee: u32) {
+    e.storage().instance().
+set(&DataKey::Fee, &fee);
+}
+
+pub fn get_storage_version(e: &Env) -> u32 {
+    if let Some(version) = e.storage().instance().
+get(&DataKey::StorageVersion) {
+        version
+    } else {
+        0
+    }
+}
+
+pub fn put_storage_version(e: &Env, version: u32) {
+    e.storage().instance().
+set(&DataKey::StorageVersion, &version);
+}
+
+pub fn get_paused(e: &Env) -> bool {
+    if let Some(paused) = e.storage().instance().
+get(&DataKey::Paused) {
+        paused
+    } else {
+        false
+    }
+}
+
+pub fn put_paused(e: &Env, is_paused: bool) {
+    e.storage().instance().
+set(&DataKey::Paused, &is_paused);
 }
\ No newline at end of file
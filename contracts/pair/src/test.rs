@@ -134,6 +134,11 @@ mod fee;
 mod skim;
 mod sync;
 mod events;
+mod oracle;
+mod flash_swap;
+mod upgrade;
+mod pause;
+mod overflow;
 // mod decode; // wont be used for now
 
 // Test forked by stellar/soroban-examples
@@ -1,7 +1,7 @@
 extern crate std;
 use crate::test::{SoroswapPairTest};
 use crate::event::{DepositEvent, SwapEvent, WithdrawEvent, SyncEvent, SkimEvent};
-use crate::soroswap_pair_token::{SoroswapPairTokenClient};
+use soroswap_pair_token::{SoroswapPairTokenClient};
 use crate::test::deposit::add_liquidity;
 use soroban_sdk::{testutils::{Ledger, Events}, vec, IntoVal, symbol_short};
 
@@ -12,7 +12,7 @@ fn deposit_event() {
     let amount_0: i128 = 1_001; //
     let amount_1: i128 = 1_001; //
     let expected_liquidity: i128 = 1;
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     test.token_0.transfer(&test.user, &test.contract.address, &amount_0);
     test.token_1.transfer(&test.user, &test.contract.address, &amount_1);
     let executed_liquidity = test.contract.deposit(&test.user);
@@ -73,7 +73,7 @@ fn swap_event() {
 
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     add_liquidity(&test, &amount_0, &amount_1);
 
     let init_time = 12345;
@@ -136,7 +136,7 @@ fn swap_event() {
 fn withdraw_event() {
     let test = SoroswapPairTest::setup();    
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let amount_0: i128 = 3_000_000;
     let amount_1: i128 = 3_000_000;
     let expected_liquidity: i128 =  3_000_000;
@@ -205,7 +205,7 @@ fn withdraw_event() {
 fn sync_event() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
 
     let original_0: i128 = test.token_0.balance(&test.user);
     let original_1: i128 = test.token_1.balance(&test.user);
@@ -218,7 +218,7 @@ fn sync_event() {
     assert_eq!(test.token_1.balance(&test.user), original_1.checked_sub(amount_1).unwrap());
     assert_eq!(test.token_0.balance(&test.contract.address), amount_0);
     assert_eq!(test.token_1.balance(&test.contract.address), amount_1);
-    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
 
     //extra tokens sent to skim:
     let amount_0_extra: i128 = 123_000_000;
@@ -227,7 +227,7 @@ fn sync_event() {
     test.token_1.transfer(&test.user, &test.contract.address, &amount_1_extra);
     assert_eq!(test.token_0.balance(&test.contract.address), amount_0 + amount_0_extra);
     assert_eq!(test.token_1.balance(&test.contract.address), amount_1 + amount_1_extra);
-    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
 
     test.contract.sync();
 
@@ -276,7 +276,7 @@ fn skim_event() {
     // zero tokens are being sent
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
 
     let original_0: i128 = test.token_0.balance(&test.user);
     let original_1: i128 = test.token_1.balance(&test.user);
@@ -289,7 +289,7 @@ fn skim_event() {
     assert_eq!(test.token_1.balance(&test.user), original_1.checked_sub(amount_1).unwrap());
     assert_eq!(test.token_0.balance(&test.contract.address), amount_0);
     assert_eq!(test.token_1.balance(&test.contract.address), amount_1);
-    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
 
     //extra tokens sent to skim:
     let amount_0_extra: i128 = 123_000_000;
@@ -298,7 +298,7 @@ fn skim_event() {
     test.token_1.transfer(&test.user, &test.contract.address, &amount_1_extra);
     assert_eq!(test.token_0.balance(&test.contract.address), amount_0 + amount_0_extra);
     assert_eq!(test.token_1.balance(&test.contract.address), amount_1 + amount_1_extra);
-    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
 
     test.contract.skim(&test.admin);
 
@@ -1,6 +1,7 @@
 use crate::test::{SoroswapPairTest};
 use crate::test::deposit::add_liquidity;
 use num_integer::Roots; 
+use soroban_sdk::I256;
 
 
 
@@ -8,35 +9,35 @@ use num_integer::Roots;
 fn fee_off() {
     let test = SoroswapPairTest::setup();    
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
     let expected_liquidity: i128 =  70_710_678;
     let minimum_liquidity: i128 = 1_000;
 
-    assert_eq!(test.contract.k_last(), 0);
+    assert_eq!(test.contract.k_last(), I256::from_i32(&test.env, 0));
     add_liquidity(&test, &amount_0, &amount_1);
-    assert_eq!(test.contract.get_reserves(), (amount_0,amount_1,));
-    assert_eq!(test.contract.k_last(), 0);
+    assert_eq!(test.contract.get_reserves(), (amount_0,amount_1, test.env.ledger().timestamp()));
+    assert_eq!(test.contract.k_last(), I256::from_i32(&test.env, 0));
 
     let swap_amount_0 = 10_000_000;
     let expected_output_amount_1 = 16624979;
 
     test.token_0.transfer(&test.user, &test.contract.address, &swap_amount_0);
     test.contract.swap(&0, &expected_output_amount_1, &test.user);
-    assert_eq!(test.contract.get_reserves(), (amount_0+swap_amount_0,amount_1-expected_output_amount_1,));
-    assert_eq!(test.contract.k_last(), 0);
+    assert_eq!(test.contract.get_reserves(), (amount_0+swap_amount_0,amount_1-expected_output_amount_1, test.env.ledger().timestamp()));
+    assert_eq!(test.contract.k_last(), I256::from_i32(&test.env, 0));
 
     test.contract.transfer(&test.user, &test.contract.address, &expected_liquidity.checked_sub(minimum_liquidity).unwrap());
 
     test.contract.withdraw(&test.user);
-    assert_eq!(test.contract.k_last(), 0);
+    assert_eq!(test.contract.k_last(), I256::from_i32(&test.env, 0));
     assert_eq!(test.contract.balance(&test.user), 0);
         assert_eq!(test.contract.total_supply(), minimum_liquidity);
         assert_eq!(test.contract.balance(&test.contract.address), minimum_liquidity);
         assert_eq!(test.token_0.balance(&test.contract.address), 849);
         assert_eq!(test.token_1.balance(&test.contract.address), 1180);
-        assert_eq!(test.contract.get_reserves(), (849,1180,));
+        assert_eq!(test.contract.get_reserves(), (849,1180, test.env.ledger().timestamp()));
 
 }
 
@@ -48,19 +49,19 @@ fn fee_on_add_swap_remove() {
     test.factory.set_fees_enabled(&true);
     assert_eq!(test.factory.fees_enabled(), true);
     assert_eq!(test.factory.fee_to(), test.admin);
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
 
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
     let minimum_liquidity: i128 = 1_000;
     let expected_liquidity: i128 =  70_710_678;
 
-    assert_eq!(test.contract.k_last(), 0);
+    assert_eq!(test.contract.k_last(), I256::from_i32(&test.env, 0));
     add_liquidity(&test, &amount_0, &amount_1);
 
     // If we deposit with fee on, we should see a change in the klast paramenter
     //klast should be the new reserves (amount0 and amount1)
-    assert_eq!(test.contract.k_last(), amount_0.checked_mul(amount_1).unwrap());
+    assert_eq!(test.contract.k_last(), I256::from_i128(&test.env, amount_0).mul(&I256::from_i128(&test.env, amount_1)));
     assert_eq!(test.contract.total_supply(), expected_liquidity);
 
 
@@ -71,10 +72,10 @@ fn fee_on_add_swap_remove() {
     test.token_0.transfer(&test.user, &test.contract.address, &swap_amount_0);
     test.contract.swap(&0, &expected_output_amount_1, &test.user);
     //klast does not gets updated in swaps
-    assert_eq!(test.contract.k_last(), amount_0.checked_mul(amount_1).unwrap());
+    assert_eq!(test.contract.k_last(), I256::from_i128(&test.env, amount_0).mul(&I256::from_i128(&test.env, amount_1)));
     let new_expected_reserve_0= amount_0+swap_amount_0; // 60000000
     let new_expected_reserve_1= amount_1-expected_output_amount_1; // 83375021
-    assert_eq!(test.contract.get_reserves(), (new_expected_reserve_0,new_expected_reserve_1));
+    assert_eq!(test.contract.get_reserves(), (new_expected_reserve_0,new_expected_reserve_1, test.env.ledger().timestamp()));
     
     let k2_root=70728362; // new_expected_reserve_0.checked_mul(new_expected_reserve_1).unwrap().sqrt();
     assert_eq!(new_expected_reserve_0.checked_mul(new_expected_reserve_1).unwrap().sqrt(), k2_root);
@@ -134,8 +135,8 @@ fn fee_on_add_swap_remove() {
     let after_withdraw_expected_reserve_1= 4653; //amount_1-expected_output_amount_1-expected_user_out_token_1; // 4653
     assert_eq!(after_withdraw_expected_reserve_0, amount_0+swap_amount_0-expected_user_out_token_0);
     assert_eq!(after_withdraw_expected_reserve_1, amount_1-expected_output_amount_1-expected_user_out_token_1);
-    assert_eq!(test.contract.get_reserves(), (after_withdraw_expected_reserve_0,after_withdraw_expected_reserve_1,));
-    assert_eq!(test.contract.k_last(), after_withdraw_expected_reserve_0.checked_mul(after_withdraw_expected_reserve_1).unwrap());
+    assert_eq!(test.contract.get_reserves(), (after_withdraw_expected_reserve_0,after_withdraw_expected_reserve_1, test.env.ledger().timestamp()));
+    assert_eq!(test.contract.k_last(), I256::from_i128(&test.env, after_withdraw_expected_reserve_0).mul(&I256::from_i128(&test.env, after_withdraw_expected_reserve_1)));
     // assert_eq!(test.contract.balance(&test.user), 0);
     
 
@@ -164,7 +165,7 @@ fn fee_on_add_swap_add() {
     test.factory.set_fees_enabled(&true);
     assert_eq!(test.factory.fees_enabled(), true);
     assert_eq!(test.factory.fee_to(), test.admin);
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
 
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
@@ -174,12 +175,12 @@ fn fee_on_add_swap_add() {
     let original_1: i128 = test.token_1.balance(&test.user);
 
     // ***************** DEPOSIT *****************
-    assert_eq!(test.contract.k_last(), 0);
+    assert_eq!(test.contract.k_last(), I256::from_i32(&test.env, 0));
     add_liquidity(&test, &amount_0, &amount_1);
 
     // If we deposit with fee on, we should see a change in the klast paramenter
     //klast should be the new reserves (amount0 and amount1)
-    assert_eq!(test.contract.k_last(), amount_0.checked_mul(amount_1).unwrap());
+    assert_eq!(test.contract.k_last(), I256::from_i128(&test.env, amount_0).mul(&I256::from_i128(&test.env, amount_1)));
     assert_eq!(test.contract.total_supply(), expected_liquidity);
     assert_eq!(test.token_0.balance(&test.user), original_0.checked_sub(amount_0).unwrap());
     assert_eq!(test.token_1.balance(&test.user), original_1.checked_sub(amount_1).unwrap());
@@ -193,7 +194,7 @@ fn fee_on_add_swap_add() {
     test.token_0.transfer(&test.user, &test.contract.address, &swap_amount_0);
     test.contract.swap(&0, &expected_output_amount_1, &test.user);
     //klast does not gets updated in swaps
-    assert_eq!(test.contract.k_last(), amount_0.checked_mul(amount_1).unwrap());
+    assert_eq!(test.contract.k_last(), I256::from_i128(&test.env, amount_0).mul(&I256::from_i128(&test.env, amount_1)));
     
     let new_expected_reserve_0= 60000000; //amount_0+swap_amount_0
     assert_eq!(new_expected_reserve_0, amount_0+swap_amount_0);
@@ -201,7 +202,7 @@ fn fee_on_add_swap_add() {
     let new_expected_reserve_1= 83375021; // amount_1-expected_output_amount_1; // 83375021
     assert_eq!(new_expected_reserve_1, amount_1-expected_output_amount_1);
 
-    assert_eq!(test.contract.get_reserves(), (new_expected_reserve_0,new_expected_reserve_1));
+    assert_eq!(test.contract.get_reserves(), (new_expected_reserve_0,new_expected_reserve_1, test.env.ledger().timestamp()));
     assert_eq!(test.token_0.balance(&test.user), original_0-amount_0-swap_amount_0);
     assert_eq!(test.token_1.balance(&test.user), original_1-amount_1+expected_output_amount_1);
     
@@ -218,7 +219,7 @@ fn fee_on_add_swap_add() {
 
     // ***************** DEPOSIT AGAIN! *****************
     assert_eq!(test.contract.total_supply(), expected_liquidity);
-    assert_eq!(test.contract.get_reserves(), (new_expected_reserve_0,new_expected_reserve_1));
+    assert_eq!(test.contract.get_reserves(), (new_expected_reserve_0,new_expected_reserve_1, test.env.ledger().timestamp()));
     let new_amount_0: i128 = 1_000_000;
     let new_amount_1: i128 = 1389583; //(new_amount_0*new_expected_reserve_1)/new_expected_reserve_0);
     assert_eq!(new_amount_1, (new_amount_0*new_expected_reserve_1)/new_expected_reserve_0);
@@ -226,7 +227,7 @@ fn fee_on_add_swap_add() {
     assert_eq!(test.token_0.balance(&test.user), original_0-amount_0-swap_amount_0-new_amount_0);
     assert_eq!(test.token_1.balance(&test.user), original_1-amount_1+expected_output_amount_1-new_amount_1);
 
-    assert_eq!(test.contract.k_last(), (new_expected_reserve_0+new_amount_0).checked_mul(new_expected_reserve_1+new_amount_1).unwrap());
+    assert_eq!(test.contract.k_last(), I256::from_i128(&test.env, new_expected_reserve_0+new_amount_0).mul(&I256::from_i128(&test.env, new_expected_reserve_1+new_amount_1)));
     
     // We have the new liquidity minted to the admin = n
     // n = expected_liquidity*(k2_root-k1_root)/(5k2_root + k1_root)
@@ -271,3 +272,62 @@ fn fee_on_add_swap_add() {
 
 
 }
+
+
+// Mints the fee shares on a withdraw for a given protocol fee fraction and returns them
+fn fee_on_with_fraction(numerator: u32, denominator: u32) -> i128 {
+    let test = SoroswapPairTest::setup();    
+    test.env.budget().reset_unlimited();
+    test.factory.set_fees_enabled(&true);
+    test.factory.set_protocol_fee_fraction(&numerator, &denominator);
+    assert_eq!(test.factory.protocol_fee_fraction(), (numerator, denominator));
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    let minimum_liquidity: i128 = 1_000;
+    let expected_liquidity: i128 =  70_710_678;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    let swap_amount_0 = 10_000_000;
+    let expected_output_amount_1 = 16624979;
+    test.token_0.transfer(&test.user, &test.contract.address, &swap_amount_0);
+    test.contract.swap(&0, &expected_output_amount_1, &test.user);
+
+    test.contract.transfer(&test.user, &test.contract.address, &expected_liquidity.checked_sub(minimum_liquidity).unwrap());
+    test.contract.withdraw(&test.user);
+
+    let n = test.contract.balance(&test.admin);
+    assert_eq!(test.contract.total_supply(), minimum_liquidity.checked_add(n).unwrap());
+    n
+}
+
+#[test]
+fn fee_on_default_protocol_fee_fraction() {
+    // Same as fee_on_add_swap_remove: the default fraction is 1/6
+    assert_eq!(fee_on_with_fraction(1, 6), 2946);
+}
+
+#[test]
+fn fee_on_protocol_fee_fraction_quarter() {
+    let expected_liquidity: i128 =  70_710_678;
+    let k2_root = 70728362;
+    let k1_root = 70_710_678;
+    // n = expected_liquidity*(k2_root-k1_root)/(3k2_root + k1_root)
+    // = 4420,02... --> 4420
+    let n = 4420;
+    assert_eq!(n, expected_liquidity*(k2_root-k1_root)/(3*k2_root + k1_root));
+    assert_eq!(fee_on_with_fraction(1, 4), n);
+}
+
+#[test]
+fn fee_on_protocol_fee_fraction_tenth() {
+    let expected_liquidity: i128 =  70_710_678;
+    let k2_root = 70728362;
+    let k1_root = 70_710_678;
+    // n = expected_liquidity*(k2_root-k1_root)/(9k2_root + k1_root)
+    // = 1768,1... --> 1768
+    let n = 1768;
+    assert_eq!(n, expected_liquidity*(k2_root-k1_root)/(9*k2_root + k1_root));
+    assert_eq!(fee_on_with_fraction(1, 10), n);
+}
@@ -0,0 +1,168 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapPairTest};
+use crate::test::pair::SoroswapPairError;
+use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Bytes, Env};
+use soroswap_callee_interface::SoroswapCalleeTrait;
+
+// MOCK CALLEE CONTRACT
+// Pays back the pair with a preconfigured amount of a preconfigured token
+#[derive(Clone)]
+#[contracttype]
+enum MockCalleeDataKey {
+    Repayment, // (Address, i128). The token and amount sent back to the pair
+    LastCall, // (Address, i128, i128, Bytes). The arguments received in the last soroswap_call
+}
+
+#[contract]
+pub struct MockCallee;
+
+#[contractimpl]
+impl MockCallee {
+    pub fn set_repayment(e: Env, token: Address, amount: i128) {
+        e.storage().instance().set(&MockCalleeDataKey::Repayment, &(token, amount));
+    }
+
+    pub fn last_call(e: Env) -> (Address, i128, i128, Bytes) {
+        e.storage().instance().get(&MockCalleeDataKey::LastCall).unwrap()
+    }
+}
+
+#[contractimpl]
+impl SoroswapCalleeTrait for MockCallee {
+    fn soroswap_call(e: Env, pair: Address, amount_0_out: i128, amount_1_out: i128, data: Bytes) {
+        pair.require_auth();
+        let (token, amount): (Address, i128) = e.storage().instance().get(&MockCalleeDataKey::Repayment).unwrap();
+        token::Client::new(&e, &token).transfer(&e.current_contract_address(), &pair, &amount);
+        e.storage().instance().set(&MockCalleeDataKey::LastCall, &(pair, amount_0_out, amount_1_out, data));
+    }
+}
+
+fn create_mock_callee<'a>(test: &SoroswapPairTest) -> MockCalleeClient<'a> {
+    let callee = MockCalleeClient::new(&test.env, &test.env.register_contract(None, MockCallee {}));
+    // The callee needs some tokens in order to pay the fees
+    test.token_0.transfer(&test.user, &callee.address, &100_000_000);
+    test.token_1.transfer(&test.user, &callee.address, &100_000_000);
+    callee
+}
+
+#[test]
+fn flash_swap_repay_other_token() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    let callee = create_mock_callee(&test);
+    let data = Bytes::from_slice(&test.env, &[1, 2, 3]);
+
+    // Borrow token 1 and pay with token 0, same amounts as a normal swap
+    let repayment_0: i128 = 10_000_000;
+    let amount_1_out: i128 = 16624979;
+    callee.set_repayment(&test.token_0.address, &repayment_0);
+    test.contract.flash_swap(&0, &amount_1_out, &callee.address, &data);
+
+    assert_eq!(callee.last_call(), (test.contract.address.clone(), 0, amount_1_out, data));
+    assert_eq!(test.contract.get_reserves(), (amount_0 + repayment_0, amount_1 - amount_1_out, test.env.ledger().timestamp()));
+    assert_eq!(test.token_0.balance(&callee.address), 100_000_000 - repayment_0);
+    assert_eq!(test.token_1.balance(&callee.address), 100_000_000 + amount_1_out);
+}
+
+#[test]
+fn flash_swap_repay_same_token_1() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    let callee = create_mock_callee(&test);
+
+    // Borrow token 1 and pay it back with the 0.3% fee
+    // (1_003_010 - ceil(1_003_010*3/1000)) = 1_000_000
+    let amount_1_out: i128 = 1_000_000;
+    let repayment_1: i128 = 1_003_010;
+    callee.set_repayment(&test.token_1.address, &repayment_1);
+    test.contract.flash_swap(&0, &amount_1_out, &callee.address, &Bytes::new(&test.env));
+
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1 - amount_1_out + repayment_1, test.env.ledger().timestamp()));
+    assert_eq!(test.token_1.balance(&callee.address), 100_000_000 + amount_1_out - repayment_1);
+}
+
+#[test]
+fn flash_swap_repay_same_token_0() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    let callee = create_mock_callee(&test);
+
+    let amount_0_out: i128 = 1_000_000;
+    let repayment_0: i128 = 1_003_010;
+    callee.set_repayment(&test.token_0.address, &repayment_0);
+    test.contract.flash_swap(&amount_0_out, &0, &callee.address, &Bytes::new(&test.env));
+
+    assert_eq!(test.contract.get_reserves(), (amount_0 - amount_0_out + repayment_0, amount_1, test.env.ledger().timestamp()));
+    assert_eq!(test.token_0.balance(&callee.address), 100_000_000 + amount_0_out - repayment_0);
+}
+
+#[test]
+fn flash_swap_short_repayment_other_token() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    let callee = create_mock_callee(&test);
+
+    callee.set_repayment(&test.token_0.address, &9_999_999);
+    let result = test.contract.try_flash_swap(&0, &16624979, &callee.address, &Bytes::new(&test.env));
+    assert_eq!(result, Err(Ok(SoroswapPairError::SwapKConstantNotMet)));
+
+    // Nothing changed
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
+    assert_eq!(test.token_0.balance(&callee.address), 100_000_000);
+    assert_eq!(test.token_1.balance(&callee.address), 100_000_000);
+}
+
+#[test]
+fn flash_swap_short_repayment_same_token() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    let callee = create_mock_callee(&test);
+
+    // The fee is not being paid
+    callee.set_repayment(&test.token_1.address, &1_003_009);
+    let result = test.contract.try_flash_swap(&0, &1_000_000, &callee.address, &Bytes::new(&test.env));
+    assert_eq!(result, Err(Ok(SoroswapPairError::SwapKConstantNotMet)));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn flash_swap_no_repayment() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    let callee = create_mock_callee(&test);
+
+    callee.set_repayment(&test.token_1.address, &0);
+    let result = test.contract.try_flash_swap(&0, &1_000_000, &callee.address, &Bytes::new(&test.env));
+    assert_eq!(result, Err(Ok(SoroswapPairError::SwapInsufficientInputAmount)));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
+}
@@ -0,0 +1,204 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapPairTest};
+use soroban_sdk::{testutils::{Ledger}, Env, U256};
+
+// Cumulative prices are UQ128x128 fixed point numbers: the price shifted left by 128 bits
+fn uq128x128(e: &Env, numerator: i128, denominator: i128) -> U256 {
+    U256::from_u128(e, numerator as u128).shl(128).div(&U256::from_u128(e, denominator as u128))
+}
+
+fn seconds(e: &Env, time_elapsed: u64) -> U256 {
+    U256::from_u128(e, time_elapsed as u128)
+}
+
+#[test]
+fn cumulative_prices_not_initialized() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    assert_eq!(test.contract.price_0_cumulative_last(), U256::from_u32(&test.env, 0));
+    assert_eq!(test.contract.price_1_cumulative_last(), U256::from_u32(&test.env, 0));
+    assert_eq!(test.contract.get_reserves(), (0, 0, 0));
+}
+
+#[test]
+fn cumulative_prices_first_deposit() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+
+    let init_time = 12345;
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = init_time;
+    });
+
+    let amount_0: i128 = 1_000_000;
+    let amount_1: i128 = 4_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    // There were no reserves before the deposit, so there is no price to accumulate
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, init_time));
+    assert_eq!(test.contract.price_0_cumulative_last(), U256::from_u32(&test.env, 0));
+    assert_eq!(test.contract.price_1_cumulative_last(), U256::from_u32(&test.env, 0));
+}
+
+#[test]
+fn cumulative_prices_same_timestamp() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+
+    let init_time = 12345;
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = init_time;
+    });
+
+    let amount_0: i128 = 1_000_000;
+    let amount_1: i128 = 4_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    // No time has elapsed between both updates
+    assert_eq!(test.contract.get_reserves(), (2*amount_0, 2*amount_1, init_time));
+    assert_eq!(test.contract.price_0_cumulative_last(), U256::from_u32(&test.env, 0));
+    assert_eq!(test.contract.price_1_cumulative_last(), U256::from_u32(&test.env, 0));
+}
+
+#[test]
+fn cumulative_prices_sync() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+
+    let init_time = 12345;
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = init_time;
+    });
+
+    let amount_0: i128 = 1_000_000;
+    let amount_1: i128 = 4_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    let time_elapsed: u64 = 10;
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = init_time + time_elapsed;
+    });
+    test.contract.sync();
+
+    // price_0 = 4_000_000 / 1_000_000 = 4
+    // price_1 = 1_000_000 / 4_000_000 = 0.25
+    let expected_price_0_cumulative = uq128x128(&test.env, 4, 1).mul(&seconds(&test.env, time_elapsed));
+    let expected_price_1_cumulative = uq128x128(&test.env, 1, 4).mul(&seconds(&test.env, time_elapsed));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, init_time + time_elapsed));
+    assert_eq!(test.contract.price_0_cumulative_last(), expected_price_0_cumulative);
+    assert_eq!(test.contract.price_1_cumulative_last(), expected_price_1_cumulative);
+}
+
+#[test]
+fn cumulative_prices_swap() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+
+    let init_time = 12345;
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = init_time;
+    });
+
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    // The swap uses the reserves that were active during the last 100 seconds
+    let first_time_elapsed: u64 = 100;
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = init_time + first_time_elapsed;
+    });
+
+    let swap_amount_0: i128 = 10_000_000;
+    let expected_output_amount_1: i128 = 16624979;
+    test.token_0.transfer(&test.user, &test.contract.address, &swap_amount_0);
+    test.contract.swap(&0, &expected_output_amount_1, &test.user);
+
+    // price_0 = 100_000_000 / 50_000_000 = 2
+    // price_1 = 50_000_000 / 100_000_000 = 0.5
+    let mut expected_price_0_cumulative = uq128x128(&test.env, 2, 1).mul(&seconds(&test.env, first_time_elapsed));
+    let mut expected_price_1_cumulative = uq128x128(&test.env, 1, 2).mul(&seconds(&test.env, first_time_elapsed));
+    assert_eq!(test.contract.price_0_cumulative_last(), expected_price_0_cumulative);
+    assert_eq!(test.contract.price_1_cumulative_last(), expected_price_1_cumulative);
+
+    // Now the new reserves are active during 50 seconds
+    let second_time_elapsed: u64 = 50;
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = init_time + first_time_elapsed + second_time_elapsed;
+    });
+    test.contract.sync();
+
+    let new_reserve_0 = amount_0 + swap_amount_0;
+    let new_reserve_1 = amount_1 - expected_output_amount_1;
+    let price_0 = uq128x128(&test.env, new_reserve_1, new_reserve_0);
+    let price_1 = uq128x128(&test.env, new_reserve_0, new_reserve_1);
+    expected_price_0_cumulative = expected_price_0_cumulative.add(&price_0.mul(&seconds(&test.env, second_time_elapsed)));
+    expected_price_1_cumulative = expected_price_1_cumulative.add(&price_1.mul(&seconds(&test.env, second_time_elapsed)));
+
+    assert_eq!(test.contract.get_reserves(), (new_reserve_0, new_reserve_1, init_time + first_time_elapsed + second_time_elapsed));
+    assert_eq!(test.contract.price_0_cumulative_last(), expected_price_0_cumulative);
+    assert_eq!(test.contract.price_1_cumulative_last(), expected_price_1_cumulative);
+}
+
+#[test]
+fn cumulative_prices_skewed_reserves() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+
+    // A reserve ratio of 1e30 does not fit in an i128 with 14 decimals, but it does as a UQ128x128
+    let amount_0: i128 = 1_000;
+    let amount_1: i128 = 1_000_000_000_000_000_000_000_000_000_000_000;
+    test.token_1.mint(&test.user, &amount_1);
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    let time_elapsed: u64 = 10;
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = time_elapsed;
+    });
+    test.contract.sync();
+
+    let expected_price_0_cumulative = uq128x128(&test.env, amount_1, amount_0).mul(&seconds(&test.env, time_elapsed));
+    let expected_price_1_cumulative = uq128x128(&test.env, amount_0, amount_1).mul(&seconds(&test.env, time_elapsed));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, time_elapsed));
+    assert_eq!(test.contract.price_0_cumulative_last(), expected_price_0_cumulative);
+    assert_eq!(test.contract.price_1_cumulative_last(), expected_price_1_cumulative);
+}
+
+#[test]
+fn cumulative_prices_wrap_around() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+
+    let amount_0: i128 = 1_000_000;
+    let amount_1: i128 = 4_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    // Both accumulators (keys 7 and 8) are 10 units below 2^256
+    let max = U256::from_parts(&test.env, u64::MAX, u64::MAX, u64::MAX, u64::MAX);
+    let almost_max = max.sub(&U256::from_u32(&test.env, 9));
+    test.env.as_contract(&test.contract.address, || {
+        test.env.storage().instance().set(&7_u32, &almost_max);
+        test.env.storage().instance().set(&8_u32, &almost_max);
+    });
+
+    let time_elapsed: u64 = 10;
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = time_elapsed;
+    });
+    test.contract.sync();
+
+    // The accumulators wrap around instead of failing, so the difference between observations is still the price
+    let ten = U256::from_u32(&test.env, 10);
+    let expected_price_0_cumulative = uq128x128(&test.env, 4, 1).mul(&seconds(&test.env, time_elapsed)).sub(&ten);
+    let expected_price_1_cumulative = uq128x128(&test.env, 1, 4).mul(&seconds(&test.env, time_elapsed)).sub(&ten);
+    assert_eq!(test.contract.price_0_cumulative_last(), expected_price_0_cumulative);
+    assert_eq!(test.contract.price_1_cumulative_last(), expected_price_1_cumulative);
+}
@@ -0,0 +1,112 @@
+use soroban_sdk::I256;
+use crate::test::{SoroswapPairTest};
+use crate::test::deposit::add_liquidity;
+use crate::test::pair::SoroswapPairError;
+
+// i128::MAX is about 1.7 * 10^38. Products of reserves of this size need 256 bits,
+// so these pools could not be used while the pair math was done in i128
+const LARGE_AMOUNT: i128 = 10_000_000_000_000_000_000_000_000_000_000_000_000; // 10^37
+
+fn setup_large_balances<'a>() -> SoroswapPairTest<'a> {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.token_0.mint(&test.user, &(3 * LARGE_AMOUNT));
+    test.token_1.mint(&test.user, &(3 * LARGE_AMOUNT));
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    test
+}
+
+#[test]
+fn deposit_first_liquidity_large_amounts() {
+    let test = setup_large_balances();
+
+    // amount_0 * amount_1 = 10^74, and its square root is 10^37
+    let liquidity = add_liquidity(&test, &LARGE_AMOUNT, &LARGE_AMOUNT);
+    assert_eq!(liquidity, LARGE_AMOUNT - 1_000);
+    assert_eq!(test.contract.total_supply(), LARGE_AMOUNT);
+    assert_eq!(test.contract.get_reserves(), (LARGE_AMOUNT, LARGE_AMOUNT, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn deposit_shares_large_amounts() {
+    let test = setup_large_balances();
+    add_liquidity(&test, &LARGE_AMOUNT, &LARGE_AMOUNT);
+
+    // amount_0 * total_supply = 10^74
+    let liquidity = add_liquidity(&test, &LARGE_AMOUNT, &LARGE_AMOUNT);
+    assert_eq!(liquidity, LARGE_AMOUNT);
+    assert_eq!(test.contract.total_supply(), 2 * LARGE_AMOUNT);
+}
+
+#[test]
+fn deposit_shares_result_overflow() {
+    let test = setup_large_balances();
+
+    // Minted liquidity is sqrt(1 * 1_100_000) = 1048 for a reserve_0 of 1
+    add_liquidity(&test, &1, &1_100_000);
+    assert_eq!(test.contract.total_supply(), 1_048);
+
+    // amount_0 * total_supply / reserve_0 is about 1.05 * 10^40, which does not fit in an i128
+    let amount_0: i128 = 1_000_000_000_000_000_000_000_000_000_000_000_000; // 10^36
+    test.token_0.transfer(&test.user, &test.contract.address, &amount_0);
+    test.token_1.transfer(&test.user, &test.contract.address, &1_100_000);
+    let result = test.contract.try_deposit(&test.user);
+    assert_eq!(result, Err(Ok(SoroswapPairError::UpdateOverflow)));
+}
+
+#[test]
+fn swap_k_constant_large_reserves() {
+    let test = setup_large_balances();
+    add_liquidity(&test, &LARGE_AMOUNT, &LARGE_AMOUNT);
+
+    // fee = ceil(10^36 * 30 / 10000) = 3 * 10^33
+    // The largest output keeping (1.1 * 10^37 - fee) * (10^37 - amount_out) >= 10^74
+    let amount_in: i128 = 1_000_000_000_000_000_000_000_000_000_000_000_000; // 10^36
+    let amount_out: i128 = 906_610_893_880_149_131_581_340_365_554_242_066;
+    test.token_0.transfer(&test.user, &test.contract.address, &amount_in);
+
+    let result = test.contract.try_swap(&0, &(amount_out + 1), &test.user);
+    assert_eq!(result, Err(Ok(SoroswapPairError::SwapKConstantNotMet)));
+
+    test.contract.swap(&0, &amount_out, &test.user);
+    assert_eq!(test.contract.get_reserves(), (LARGE_AMOUNT + amount_in, LARGE_AMOUNT - amount_out, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn withdraw_large_amounts() {
+    let test = setup_large_balances();
+    let liquidity = add_liquidity(&test, &LARGE_AMOUNT, &LARGE_AMOUNT);
+
+    // balance_0 * user_sent_shares = 10^37 * (10^37 - 1000)
+    test.contract.transfer(&test.user, &test.contract.address, &liquidity);
+    let (amount_0, amount_1) = test.contract.withdraw(&test.user);
+    assert_eq!(amount_0, LARGE_AMOUNT - 1_000);
+    assert_eq!(amount_1, LARGE_AMOUNT - 1_000);
+    assert_eq!(test.contract.get_reserves(), (1_000, 1_000, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn mint_fee_large_reserves() {
+    let test = setup_large_balances();
+    test.factory.set_fees_enabled(&true);
+    add_liquidity(&test, &LARGE_AMOUNT, &LARGE_AMOUNT);
+
+    // k_last = 10^74 does not fit in an i128
+    let large_amount = I256::from_i128(&test.env, LARGE_AMOUNT);
+    assert_eq!(test.contract.k_last(), large_amount.mul(&large_amount));
+
+    let amount_in: i128 = 1_000_000_000_000_000_000_000_000_000_000_000_000; // 10^36
+    let amount_out: i128 = 906_610_893_880_149_131_581_340_365_554_242_066;
+    test.token_0.transfer(&test.user, &test.contract.address, &amount_in);
+    test.contract.swap(&0, &amount_out, &test.user);
+
+    // root_k = sqrt(1.1 * 10^37 * (10^37 - amount_out)) = 10001363915352663516068092419939597188
+    // fees = 10^37 * (root_k - 10^37) / (5 * root_k + 10^37)
+    let amount_0: i128 = 11_000_000_000_000_000_000_000_000_000_000;
+    let amount_1: i128 = 10_000_000_000_000_000_000_000_000_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+    assert_eq!(test.contract.balance(&test.admin), 227_293_391_365_082_528_589_167_348_649_440);
+
+    let (reserve_0, reserve_1, _) = test.contract.get_reserves();
+    assert_eq!(test.contract.k_last(), I256::from_i128(&test.env, reserve_0).mul(&I256::from_i128(&test.env, reserve_1)));
+}
@@ -0,0 +1,90 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapPairTest};
+use crate::test::pair::SoroswapPairError;
+use soroban_sdk::{Bytes};
+
+#[test]
+fn not_paused_by_default() {
+    let test = SoroswapPairTest::setup();
+    assert_eq!(test.contract.paused(), false);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    assert_eq!(test.contract.paused(), false);
+}
+
+#[test]
+fn set_paused_not_initialized() {
+    let test = SoroswapPairTest::setup();
+    let res = test.contract.try_set_paused(&true);
+    assert_eq!(res, Err(Ok(SoroswapPairError::NotInitialized)));
+}
+
+#[test]
+fn paused_blocks_deposit_and_swap() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    test.contract.set_paused(&true);
+    assert_eq!(test.contract.paused(), true);
+
+    test.token_0.transfer(&test.user, &test.contract.address, &10_000_000);
+    let res = test.contract.try_swap(&0, &16624979, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapPairError::Paused)));
+
+    let res = test.contract.try_flash_swap(&0, &16624979, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapPairError::Paused)));
+
+    test.token_1.transfer(&test.user, &test.contract.address, &20_000_000);
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapPairError::Paused)));
+
+    // Once unpaused everything works again
+    test.contract.set_paused(&false);
+    assert_eq!(test.contract.paused(), false);
+    test.contract.swap(&0, &16624979, &test.user);
+}
+
+#[test]
+fn paused_allows_withdraw() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let amount_0: i128 = 3_000_000;
+    let amount_1: i128 = 3_000_000;
+    let expected_liquidity: i128 = 3_000_000;
+    let minimum_liquidity: i128 = 1_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    test.contract.set_paused(&true);
+
+    test.contract.transfer(&test.user, &test.contract.address, &(expected_liquidity - minimum_liquidity));
+    test.contract.withdraw(&test.user);
+    assert_eq!(test.contract.balance(&test.user), 0);
+    assert_eq!(test.contract.total_supply(), minimum_liquidity);
+    assert_eq!(test.contract.get_reserves(), (minimum_liquidity, minimum_liquidity, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn factory_paused_blocks_swap() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    // The guardian pauses all the pairs through the factory
+    test.factory.set_paused(&true);
+    assert_eq!(test.contract.paused(), true);
+
+    test.token_0.transfer(&test.user, &test.contract.address, &10_000_000);
+    let res = test.contract.try_swap(&0, &16624979, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapPairError::Paused)));
+
+    test.factory.set_paused(&false);
+    assert_eq!(test.contract.paused(), false);
+    test.contract.swap(&0, &16624979, &test.user);
+}
@@ -14,7 +14,7 @@ fn skim_with_liquidity_nothing_to_skim() {
     // zero tokens are being sent
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
 
     let original_0: i128 = test.token_0.balance(&test.user);
     let original_1: i128 = test.token_1.balance(&test.user);
@@ -27,7 +27,7 @@ fn skim_with_liquidity_nothing_to_skim() {
     assert_eq!(test.token_1.balance(&test.user), original_1.checked_sub(amount_1).unwrap());
     assert_eq!(test.token_0.balance(&test.contract.address), amount_0);
     assert_eq!(test.token_1.balance(&test.contract.address), amount_1);
-    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
 
     test.contract.skim(&test.user);
     //no tokens where sent to the user, nothing changed
@@ -35,7 +35,7 @@ fn skim_with_liquidity_nothing_to_skim() {
     assert_eq!(test.token_1.balance(&test.user), original_1.checked_sub(amount_1).unwrap());
     assert_eq!(test.token_0.balance(&test.contract.address), amount_0);
     assert_eq!(test.token_1.balance(&test.contract.address), amount_1);
-    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
 }
 
 
@@ -45,7 +45,7 @@ fn skim() {
     // zero tokens are being sent
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
 
     let original_0: i128 = test.token_0.balance(&test.user);
     let original_1: i128 = test.token_1.balance(&test.user);
@@ -58,7 +58,7 @@ fn skim() {
     assert_eq!(test.token_1.balance(&test.user), original_1.checked_sub(amount_1).unwrap());
     assert_eq!(test.token_0.balance(&test.contract.address), amount_0);
     assert_eq!(test.token_1.balance(&test.contract.address), amount_1);
-    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
 
     //extra tokens sent to skim:
     let amount_0_extra: i128 = 123_000_000;
@@ -67,7 +67,7 @@ fn skim() {
     test.token_1.transfer(&test.user, &test.contract.address, &amount_1_extra);
     assert_eq!(test.token_0.balance(&test.contract.address), amount_0 + amount_0_extra);
     assert_eq!(test.token_1.balance(&test.contract.address), amount_1 + amount_1_extra);
-    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
 
     test.contract.skim(&test.admin);
     assert_eq!(test.token_0.balance(&test.user), original_0 - amount_0 - amount_0_extra);
@@ -76,5 +76,5 @@ fn skim() {
     assert_eq!(test.token_1.balance(&test.admin), amount_1_extra);
     assert_eq!(test.token_0.balance(&test.contract.address), amount_0);
     assert_eq!(test.token_1.balance(&test.contract.address), amount_1);
-    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
 }
@@ -18,7 +18,7 @@ fn try_swap_not_yet_initialized() {
 fn try_swap_amounts_zero() {
     let test = SoroswapPairTest::setup();    
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let result = test.contract.try_swap(&0, &0, &test.user);
     assert_eq!(result, Err(Ok(SoroswapPairError::SwapInsufficientOutputAmount)));
 }
@@ -28,7 +28,7 @@ fn try_swap_amounts_zero() {
 fn try_swap_amount_0_negative() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let result = test.contract.try_swap(&-1, &1, &test.user);
     assert_eq!(result, Err(Ok(SoroswapPairError::SwapNegativesOutNotSupported)));
 }
@@ -38,7 +38,7 @@ fn try_swap_amount_0_negative() {
 fn try_swap_amount_1_negative() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let result = test.contract.try_swap(&1, &-1, &test.user);
     assert_eq!(result, Err(Ok(SoroswapPairError::SwapNegativesOutNotSupported)));
 }
@@ -48,7 +48,7 @@ fn try_swap_amount_1_negative() {
 fn try_swap_no_liquidity() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let result = test.contract.try_swap(&1, &1, &test.user);
     assert_eq!(result, Err(Ok(SoroswapPairError::SwapInsufficientLiquidity)));
 }
@@ -58,7 +58,7 @@ fn try_swap_no_liquidity() {
 fn try_swap_to_token_0() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
     add_liquidity(&test, &amount_0, &amount_1);
@@ -71,7 +71,7 @@ fn try_swap_to_token_0() {
 fn try_swap_to_token_1() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
     add_liquidity(&test, &amount_0, &amount_1);
@@ -85,7 +85,7 @@ fn try_swap_to_token_1() {
 fn try_swap_token_0_insufficient_input() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
     add_liquidity(&test, &amount_0, &amount_1);
@@ -98,7 +98,7 @@ fn try_swap_token_0_insufficient_input() {
 fn try_swap_token_1_insufficient_input() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
     add_liquidity(&test, &amount_0, &amount_1);
@@ -111,7 +111,7 @@ fn try_swap_token_1_insufficient_input() {
 fn try_swap_token_0_low_sent() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
     add_liquidity(&test, &amount_0, &amount_1);
@@ -125,7 +125,7 @@ fn try_swap_token_0_low_sent() {
 fn try_swap_token_1_low_sent() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
     add_liquidity(&test, &amount_0, &amount_1);
@@ -147,7 +147,7 @@ fn swap_token_0() {
 
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     add_liquidity(&test, &amount_0, &amount_1);
 
     let init_time = 12345;
@@ -165,7 +165,8 @@ fn swap_token_0() {
     
     assert_eq!(test.contract.get_reserves(),
         (amount_0.checked_add(swap_amount_0).unwrap(),
-        amount_1.checked_sub(expected_output_amount_1).unwrap()));
+        amount_1.checked_sub(expected_output_amount_1).unwrap(),
+        init_time));
 
     assert_eq!(test.token_0.balance(&test.contract.address), amount_0.checked_add(swap_amount_0).unwrap());
     assert_eq!(test.token_1.balance(&test.contract.address), amount_1.checked_sub(expected_output_amount_1).unwrap());
@@ -185,7 +186,7 @@ fn swap_token_1() {
 
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     add_liquidity(&test, &amount_0, &amount_1);
     let init_time = 12345;
     test.env.ledger().with_mut(|li| {
@@ -202,7 +203,8 @@ fn swap_token_1() {
     
     assert_eq!(test.contract.get_reserves(),
         (amount_0.checked_sub(expected_output_amount_0).unwrap(),
-        amount_1.checked_add(swap_amount_1).unwrap()));
+        amount_1.checked_add(swap_amount_1).unwrap(),
+        init_time));
 
     assert_eq!(test.token_0.balance(&test.contract.address), amount_0.checked_sub(expected_output_amount_0).unwrap());
     assert_eq!(test.token_1.balance(&test.contract.address), amount_1.checked_add(swap_amount_1).unwrap());
@@ -219,7 +221,7 @@ fn try_swap_token_1_optimal_plus_1() {
     
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     add_liquidity(&test, &amount_0, &amount_1);
     let init_time = 12345;
     test.env.ledger().with_mut(|li| {
@@ -235,3 +237,73 @@ fn try_swap_token_1_optimal_plus_1() {
     let result = test.contract.try_swap(&expected_output_amount_0, &0, &test.user);
     assert_eq!(result, Err(Ok(SoroswapPairError::SwapKConstantNotMet)));
 }
+
+#[test]
+fn swap_token_0_custom_fee() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    assert_eq!(test.contract.fee(), 30);
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    // 0.05%
+    test.contract.set_fee(&5);
+    assert_eq!(test.contract.fee(), 5);
+
+    let swap_amount_0: i128 = 10_000_000;
+    // fee = ceil(10_000_000*5/10_000) = 5_000
+    // (10_000_000 - 5_000)*100_000_000/(50_000_000 + 10_000_000 - 5_000) = 16659721
+    let expected_output_amount_1: i128 = 16659721;
+
+    test.token_0.transfer(&test.user, &test.contract.address, &swap_amount_0);
+    let result = test.contract.try_swap(&0, &(expected_output_amount_1 + 1), &test.user);
+    assert_eq!(result, Err(Ok(SoroswapPairError::SwapKConstantNotMet)));
+
+    test.contract.swap(&0, &expected_output_amount_1, &test.user);
+    assert_eq!(test.contract.get_reserves(), (amount_0 + swap_amount_0, amount_1 - expected_output_amount_1, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn swap_token_0_higher_fee() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    // 1%
+    test.contract.set_fee(&100);
+
+    let swap_amount_0: i128 = 10_000_000;
+    // With the default fee we would get 16624979
+    let result = test.contract.try_swap(&0, &16624979, &test.user);
+    assert_eq!(result, Err(Ok(SoroswapPairError::SwapInsufficientInputAmount)));
+
+    test.token_0.transfer(&test.user, &test.contract.address, &swap_amount_0);
+    let result = test.contract.try_swap(&0, &16624979, &test.user);
+    assert_eq!(result, Err(Ok(SoroswapPairError::SwapKConstantNotMet)));
+
+    // (10_000_000 - 100_000)*100_000_000/(50_000_000 + 10_000_000 - 100_000) = 16527545
+    let expected_output_amount_1: i128 = 16527545;
+    test.contract.swap(&0, &expected_output_amount_1, &test.user);
+    assert_eq!(test.contract.get_reserves(), (amount_0 + swap_amount_0, amount_1 - expected_output_amount_1, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn set_fee_invalid_fee() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+
+    let result = test.contract.try_set_fee(&5);
+    assert_eq!(result, Err(Ok(SoroswapPairError::NotInitialized)));
+
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let result = test.contract.try_set_fee(&1_001);
+    assert_eq!(result, Err(Ok(SoroswapPairError::SetFeeInvalidFee)));
+    assert_eq!(test.contract.fee(), 30);
+}
@@ -13,7 +13,7 @@ fn sync_not_initialized() {
 fn sync_with_liquidity_nothing_to_sync() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
 
     let original_0: i128 = test.token_0.balance(&test.user);
     let original_1: i128 = test.token_1.balance(&test.user);
@@ -26,7 +26,7 @@ fn sync_with_liquidity_nothing_to_sync() {
     assert_eq!(test.token_1.balance(&test.user), original_1.checked_sub(amount_1).unwrap());
     assert_eq!(test.token_0.balance(&test.contract.address), amount_0);
     assert_eq!(test.token_1.balance(&test.contract.address), amount_1);
-    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
 
     test.contract.sync();
     //no tokens where sent to the user, nothing changed
@@ -34,7 +34,7 @@ fn sync_with_liquidity_nothing_to_sync() {
     assert_eq!(test.token_1.balance(&test.user), original_1.checked_sub(amount_1).unwrap());
     assert_eq!(test.token_0.balance(&test.contract.address), amount_0);
     assert_eq!(test.token_1.balance(&test.contract.address), amount_1);
-    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
 }
 
 
@@ -43,7 +43,7 @@ fn sync_with_liquidity_nothing_to_sync() {
 fn sync() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
 
     let original_0: i128 = test.token_0.balance(&test.user);
     let original_1: i128 = test.token_1.balance(&test.user);
@@ -56,7 +56,7 @@ fn sync() {
     assert_eq!(test.token_1.balance(&test.user), original_1.checked_sub(amount_1).unwrap());
     assert_eq!(test.token_0.balance(&test.contract.address), amount_0);
     assert_eq!(test.token_1.balance(&test.contract.address), amount_1);
-    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
 
     //extra tokens sent to skim:
     let amount_0_extra: i128 = 123_000_000;
@@ -65,7 +65,7 @@ fn sync() {
     test.token_1.transfer(&test.user, &test.contract.address, &amount_1_extra);
     assert_eq!(test.token_0.balance(&test.contract.address), amount_0 + amount_0_extra);
     assert_eq!(test.token_1.balance(&test.contract.address), amount_1 + amount_1_extra);
-    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
 
     test.contract.sync();
     //no tokens where sent to the user, nothing changed -- only reserves!
@@ -73,5 +73,5 @@ fn sync() {
     assert_eq!(test.token_1.balance(&test.user), original_1 - amount_1 - amount_1_extra);
     assert_eq!(test.token_0.balance(&test.contract.address), amount_0 + amount_0_extra);
     assert_eq!(test.token_1.balance(&test.contract.address), amount_1 + amount_1_extra);
-    assert_eq!(test.contract.get_reserves(), (amount_0 + amount_0_extra, amount_1 + amount_1_extra,));
+    assert_eq!(test.contract.get_reserves(), (amount_0 + amount_0_extra, amount_1 + amount_1_extra, test.env.ledger().timestamp()));
 }
@@ -0,0 +1,106 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapPairTest, pair_token_wasm};
+use crate::test::pair::SoroswapPairError;
+use soroban_sdk::{
+    IntoVal,
+    I256,
+    U256,
+    testutils::{
+        Ledger,
+        MockAuth,
+        MockAuthInvoke,
+    },
+};
+
+#[test]
+fn upgrade_not_initialized() {
+    let test = SoroswapPairTest::setup();
+    let res = test.contract.try_upgrade(&pair_token_wasm(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapPairError::NotInitialized)));
+
+    let res = test.contract.try_migrate();
+    assert_eq!(res, Err(Ok(SoroswapPairError::NotInitialized)));
+}
+
+#[test]
+fn upgrade_keeps_data() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    assert_eq!(test.contract.storage_version(), 1);
+
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    test.contract.upgrade(&pair_token_wasm(&test.env));
+    assert_eq!(test.contract.migrate(), 1);
+
+    assert_eq!(test.contract.storage_version(), 1);
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, test.env.ledger().timestamp()));
+    assert_eq!(test.contract.token_0(), test.token_0.address);
+    assert_eq!(test.contract.token_1(), test.token_1.address);
+    assert_eq!(test.contract.factory(), test.factory.address);
+}
+
+#[test]
+#[should_panic]
+fn upgrade_with_mock_auth_not_allowed() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let new_wasm_hash = pair_token_wasm(&test.env);
+
+    // Only the factory can upgrade the pair
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.admin.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "upgrade",
+                    args: (new_wasm_hash.clone(),).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .upgrade(&new_wasm_hash);
+}
+
+#[test]
+fn migrate_from_version_0() {
+    let test = SoroswapPairTest::setup();
+    test.contract.initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    // Version 0 stored KLast (key 5) as an i128 and had no storage version (key 10), timestamp (key 6) or
+    // cumulative prices (keys 7 and 8)
+    test.env.as_contract(&test.contract.address, || {
+        test.env.storage().instance().set(&5_u32, &5_000_000_000_000_000_i128);
+        for key in [6_u32, 7, 8, 10] {
+            test.env.storage().instance().remove(&key);
+        }
+    });
+    assert_eq!(test.contract.storage_version(), 0);
+
+    let migration_time = 1_000_000;
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = migration_time;
+    });
+    assert_eq!(test.contract.migrate(), 1);
+    assert_eq!(test.contract.storage_version(), 1);
+    assert_eq!(test.contract.k_last(), I256::from_i128(&test.env, 5_000_000_000_000_000));
+    // The accumulators start at the migration
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1, migration_time));
+    assert_eq!(test.contract.price_0_cumulative_last(), U256::from_u32(&test.env, 0));
+
+    let time_elapsed = 100;
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = migration_time + time_elapsed;
+    });
+    test.contract.sync();
+    // The price of token 0 is 2 in UQ128x128, weighted by the seconds since the migration only
+    let price_0 = U256::from_u128(&test.env, 2).shl(128);
+    assert_eq!(test.contract.price_0_cumulative_last(), price_0.mul(&U256::from_u32(&test.env, time_elapsed as u32)));
+}
@@ -0,0 +1,27 @@
+#![deny(warnings)]
+#![no_std]
+
+use soroban_sdk::{contractclient, contractspecfn, Address, Bytes, Env};
+pub struct Spec;
+
+/// Interface for contracts receiving the output of SoroswapRouter `swap_and_call`
+#[contractspecfn(name = "Spec", export = false)]
+#[contractclient(name = "SoroswapRouterCalleeClient")]
+
+/// Trait that must be implemented by the `target` address of a SoroswapRouter `swap_and_call`.
+pub trait SoroswapRouterCalleeTrait {
+
+    /// Called by the SoroswapRouter after the output of the swap has been sent to this contract, in the same
+    /// invocation. If this function fails, the whole swap is reverted.
+    ///
+    /// The router contract is the direct invoker of this function, so implementations can call
+    /// `router.require_auth()` to make sure that they are being called by the router they expect.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token` - The address of the token received, the last token of the swap path.
+    /// * `amount` - The amount of `token` sent to this contract.
+    /// * `data` - The opaque payload passed to `swap_and_call`.
+    fn soroswap_router_call(e: Env, token: Address, amount: i128, data: Bytes);
+}
@@ -33,6 +33,36 @@ pub enum SoroswapRouterError {
     /// SoroswapRouter: pair does not exist
     PairDoesNotExist = 409,
 
+    /// SoroswapRouter: pair is paused
+    PairPaused = 410,
+
+    /// SoroswapRouter: invalid distribution
+    InvalidDistribution = 411,
+
+    /// SoroswapRouter: insufficient liquidity minted
+    InsufficientLiquidity = 412,
+
+    /// SoroswapRouter: arithmetic overflow
+    Overflow = 413,
+
+    /// SoroswapRouter: the caller is not an operator of the owner
+    NotOperator = 414,
+
+    /// SoroswapRouter: an amount refers to an operation that has not been executed yet
+    InvalidOpAmount = 415,
+
+    /// SoroswapRouter: the validity window has not started yet
+    NotYetValid = 416,
+
+    /// SoroswapRouter: the validity window ended at an earlier ledger sequence
+    LedgerExpired = 417,
+
+    /// SoroswapRouter: the validity window starts at a later ledger sequence
+    LedgerNotReached = 418,
+
+    /// SoroswapRouter: referral fee is greater than the maximum allowed by the factory
+    ReferralFeeTooHigh = 419,
+
 }
 
 
@@ -57,6 +87,23 @@ pub enum CombinedRouterError {
     LibraryInsufficientOutputAmount = 513,
     LibraryInvalidPath = 514,
     LibrarySortIdenticalTokens = 515,
+    LibraryInvalidFee = 516,
+
+    RouterPairPaused = 517,
+    RouterInvalidDistribution = 518,
+    RouterInsufficientLiquidity = 519,
+
+    LibraryOverflow = 520,
+    RouterOverflow = 521,
+    RouterNotOperator = 522,
+    RouterInvalidOpAmount = 523,
+    RouterNotYetValid = 524,
+    RouterLedgerExpired = 525,
+    RouterLedgerNotReached = 526,
+    RouterReferralFeeTooHigh = 527,
+    LibraryInvalidPrice = 528,
+    LibraryInvalidAmp = 529,
+    LibraryInvalidWeights = 530,
 }
 
 impl From<SoroswapLibraryError> for CombinedRouterError {
@@ -68,6 +115,11 @@ impl From<SoroswapLibraryError> for CombinedRouterError {
             SoroswapLibraryError::InsufficientOutputAmount => CombinedRouterError::LibraryInsufficientOutputAmount,
             SoroswapLibraryError::InvalidPath => CombinedRouterError::LibraryInvalidPath,
             SoroswapLibraryError::SortIdenticalTokens => CombinedRouterError::LibrarySortIdenticalTokens,
+            SoroswapLibraryError::InvalidFee => CombinedRouterError::LibraryInvalidFee,
+            SoroswapLibraryError::Overflow => CombinedRouterError::LibraryOverflow,
+            SoroswapLibraryError::InvalidPrice => CombinedRouterError::LibraryInvalidPrice,
+            SoroswapLibraryError::InvalidAmp => CombinedRouterError::LibraryInvalidAmp,
+            SoroswapLibraryError::InvalidWeights => CombinedRouterError::LibraryInvalidWeights,
         }
     }
 }
@@ -84,6 +136,16 @@ impl From<SoroswapRouterError> for CombinedRouterError {
             SoroswapRouterError::InsufficientOutputAmount => CombinedRouterError::RouterInsufficientOutputAmount,
             SoroswapRouterError::ExcessiveInputAmount => CombinedRouterError::RouterExcessiveInputAmount,
             SoroswapRouterError::PairDoesNotExist => CombinedRouterError::RouterPairDoesNotExist,
+            SoroswapRouterError::PairPaused => CombinedRouterError::RouterPairPaused,
+            SoroswapRouterError::InvalidDistribution => CombinedRouterError::RouterInvalidDistribution,
+            SoroswapRouterError::InsufficientLiquidity => CombinedRouterError::RouterInsufficientLiquidity,
+            SoroswapRouterError::Overflow => CombinedRouterError::RouterOverflow,
+            SoroswapRouterError::NotOperator => CombinedRouterError::RouterNotOperator,
+            SoroswapRouterError::InvalidOpAmount => CombinedRouterError::RouterInvalidOpAmount,
+            SoroswapRouterError::NotYetValid => CombinedRouterError::RouterNotYetValid,
+            SoroswapRouterError::LedgerExpired => CombinedRouterError::RouterLedgerExpired,
+            SoroswapRouterError::LedgerNotReached => CombinedRouterError::RouterLedgerNotReached,
+            SoroswapRouterError::ReferralFeeTooHigh => CombinedRouterError::RouterReferralFeeTooHigh,
         }
     }
 }
@@ -26,6 +26,7 @@ pub struct AddLiquidityEvent {
     pub amount_a: i128,
     pub amount_b: i128,
     pub liquidity: i128,
+    pub from: Address,
     pub to: Address
 }
 
@@ -40,6 +41,7 @@ pub struct AddLiquidityEvent {
 /// * `amount_a` - The amount of `token_a` to add to the liquidity.
 /// * `amount_b` - The amount of `token_b` to add to the liquidity.
 /// * `liquidity` - The amount of liquidity tokens minted.
+/// * `from` - The address that paid `token_a` and `token_b`.
 /// * `to` - The address to receive the liquidity tokens.
 pub(crate) fn add_liquidity(
     e: &Env,
@@ -49,6 +51,7 @@ pub(crate) fn add_liquidity(
     amount_a: i128,
     amount_b: i128,
     liquidity: i128,
+    from: Address,
     to: Address,
 ) {
     let event = AddLiquidityEvent {
@@ -58,6 +61,7 @@ pub(crate) fn add_liquidity(
         amount_a,
         amount_b,
         liquidity,
+        from,
         to,
     };
 
@@ -76,6 +80,7 @@ pub struct RemoveLiquidityEvent {
     pub amount_a: i128,
     pub amount_b: i128,
     pub liquidity: i128,
+    pub from: Address,
     pub to: Address
 }
 
@@ -91,6 +96,7 @@ pub struct RemoveLiquidityEvent {
 /// * `amount_a` - The amount of `token_a` removed from the pool.
 /// * `amount_b` - The amount of `token_b` removed from the pool.
 /// * `liquidity` - The amount of liquidity tokens burned.
+/// * `from` - The address whose liquidity tokens were burned.
 /// * `to` - The address to receive the token_a and token_b.
 pub(crate) fn remove_liquidity(
     e: &Env,
@@ -100,6 +106,7 @@ pub(crate) fn remove_liquidity(
     amount_a: i128,
     amount_b: i128,
     liquidity: i128,
+    from: Address,
     to: Address,
 ) {
     let event = RemoveLiquidityEvent {
@@ -109,6 +116,7 @@ pub(crate) fn remove_liquidity(
         amount_a,
         amount_b,
         liquidity,
+        from,
         to,
     };
 
@@ -118,12 +126,22 @@ pub(crate) fn remove_liquidity(
 
 
 // SWAP EVENT
+#[contracttype] 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralFee {
+    pub referrer: Address,
+    pub token: Address,
+    pub amount: i128
+}
+
 #[contracttype] 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SwapEvent {
     pub path: Vec<Address>,
     pub amounts: Vec<i128>,
-    pub to: Address
+    pub from: Address,
+    pub to: Address,
+    pub referral: Option<ReferralFee>
 }
 
 /// Publishes an `SwapEvent` to the event stream.
@@ -134,18 +152,98 @@ pub struct SwapEvent {
 /// * `path` - A vector representing the trading route, where the first element is the input token 
 ///            and the last is the output token. Intermediate elements represent pairs to trade through.
 /// * `amounts` - A vector containing the amounts of tokens traded at each step of the trading route.
+/// * `from` - The address that paid the input tokens.
 /// * `to` - The address where the output tokens will be sent to.
+/// * `referral` - The referrer credited for the swap and the fee it was paid, if any.
 pub(crate) fn swap(
     e: &Env,
     path: Vec<Address>,
     amounts: Vec<i128>,
-    to: Address
+    from: Address,
+    to: Address,
+    referral: Option<ReferralFee>
 ) {
     let event = SwapEvent {
         path,
         amounts,
+        from,
         to,
+        referral,
     };
 
     e.events().publish(("SoroswapRouter", symbol_short!("swap")), event);
-}
\ No newline at end of file
+}
+
+
+// SPLIT SWAP EVENT
+#[contracttype] 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SplitSwapEvent {
+    pub paths: Vec<Vec<Address>>,
+    pub amounts: Vec<Vec<i128>>,
+    pub amount_in: i128,
+    pub amount_out: i128,
+    pub to: Address
+}
+
+/// Publishes a `SplitSwapEvent` to the event stream.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `paths` - The trading route of every leg of the split swap.
+/// * `amounts` - The amounts of tokens traded at each step of every leg.
+/// * `amount_in` - The total amount of input tokens sold across all legs.
+/// * `amount_out` - The total amount of output tokens received across all legs.
+/// * `to` - The address where the output tokens will be sent to.
+pub(crate) fn split_swap(
+    e: &Env,
+    paths: Vec<Vec<Address>>,
+    amounts: Vec<Vec<i128>>,
+    amount_in: i128,
+    amount_out: i128,
+    to: Address
+) {
+    let event = SplitSwapEvent {
+        paths,
+        amounts,
+        amount_in,
+        amount_out,
+        to,
+    };
+
+    e.events().publish(("SoroswapRouter", symbol_short!("split")), event);
+}
+
+
+// SET OPERATOR EVENT
+#[contracttype] 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SetOperatorEvent {
+    pub owner: Address,
+    pub operator: Address,
+    pub approved: bool
+}
+
+/// Publishes a `SetOperatorEvent` to the event stream.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `owner` - The address whose router allowances the operator may spend.
+/// * `operator` - The address approved or revoked as an operator.
+/// * `approved` - Whether the operator is now approved.
+pub(crate) fn set_operator(
+    e: &Env,
+    owner: Address,
+    operator: Address,
+    approved: bool
+) {
+    let event = SetOperatorEvent {
+        owner,
+        operator,
+        approved,
+    };
+
+    e.events().publish(("SoroswapRouter", symbol_short!("operator")), event);
+}
@@ -1,7 +1,8 @@
 #![no_std]
 use soroban_sdk::token::Client as TokenClient;
-use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
-use soroswap_library::{SoroswapLibraryError};
+use soroban_sdk::{contract, contractimpl, vec, Address, Bytes, Env, I256, Symbol, Vec};
+use soroswap_library::{SoroswapLibraryError, HopQuote, RouteHop};
+use soroswap_router_callee_interface::SoroswapRouterCalleeClient;
 
 mod pair;
 mod factory;
@@ -9,11 +10,20 @@ mod test;
 mod event;
 mod storage;
 mod error;
+mod multicall;
+mod validity;
+mod referral;
+mod math;
 
 use factory::SoroswapFactoryClient;
 use pair::SoroswapPairClient;
-use storage::{put_factory, has_factory, get_factory, extend_instance_ttl};
+use storage::{put_factory, has_factory, get_factory, extend_instance_ttl, put_operator, is_operator};
 pub use error::{SoroswapRouterError, CombinedRouterError};
+pub use multicall::{RouterOp, OpAmount, AddLiquidityOp, RemoveLiquidityOp, SwapExactTokensOp, SwapForExactTokensOp};
+pub use validity::Validity;
+pub use referral::{Referral, ReferralFeeToken};
+use event::ReferralFee;
+use math::{mul_i256, sqrt_i256};
 
 pub fn check_nonnegative_amount(amount: i128) -> Result<(), CombinedRouterError> {
     if amount < 0 {
@@ -24,21 +34,41 @@ pub fn check_nonnegative_amount(amount: i128) -> Result<(), CombinedRouterError>
 }
 
 
-/// Panics if the specified deadline has passed.
+/// Returns an error if the current ledger is outside of the validity window.
 ///
 /// # Arguments
 /// * `e` - The runtime environment.
-/// * `timestamp` - The deadline timestamp to compare against the current ledger timestamp.
-fn ensure_deadline(e: &Env, timestamp: u64) -> Result<(), CombinedRouterError> {
+/// * `validity` - The timestamp and ledger sequence bounds to compare against the current ledger.
+fn ensure_validity(e: &Env, validity: &Validity) -> Result<(), CombinedRouterError> {
     let ledger_timestamp = e.ledger().timestamp();
-    if ledger_timestamp >= timestamp {
-        Err(SoroswapRouterError::DeadlineExpired.into())
+    let ledger_sequence = e.ledger().sequence();
+    if validity.max_timestamp.is_some_and(|max_timestamp| ledger_timestamp >= max_timestamp) {
+        return Err(SoroswapRouterError::DeadlineExpired.into());
+    }
+    if validity.min_timestamp.is_some_and(|min_timestamp| ledger_timestamp < min_timestamp) {
+        return Err(SoroswapRouterError::NotYetValid.into());
+    }
+    if validity.max_ledger.is_some_and(|max_ledger| ledger_sequence >= max_ledger) {
+        return Err(SoroswapRouterError::LedgerExpired.into());
+    }
+    if validity.min_ledger.is_some_and(|min_ledger| ledger_sequence < min_ledger) {
+        return Err(SoroswapRouterError::LedgerNotReached.into());
+    }
+    Ok(())
+}
+
+
+/// Pairs reject deposits and swaps while they are paused. Checking it first lets the router
+/// return a meaningful error instead of failing inside the pair. The pairs deployed before they
+/// could be paused have no `paused` and are never paused.
+fn check_not_paused(pair_client: &SoroswapPairClient) -> Result<(), CombinedRouterError> {
+    if let Ok(Ok(true)) = pair_client.try_paused() {
+        Err(SoroswapRouterError::PairPaused.into())
     } else {
         Ok(())
     }
 }
 
-
 fn check_initialized(e: &Env) -> Result<(), CombinedRouterError> {
     if has_factory(e) {
         Ok(())
@@ -81,7 +111,7 @@ fn add_liquidity_amounts(
         factory_client.create_pair(&token_a, &token_b);
     }
 
-    let (reserve_a, reserve_b) = soroswap_library::get_reserves(
+    let (reserve_a, reserve_b) = soroswap_library::get_reserves_with_factory(
         e.clone(),
         factory.clone(),
         token_a.clone(),
@@ -158,11 +188,12 @@ fn swap(e: &Env, factory_address: &Address, amounts: &Vec<i128>, path: &Vec<Addr
             _to.clone()
         };
 
-        SoroswapPairClient::new(
+        let pair_client = SoroswapPairClient::new(
             &e,
             &soroswap_library::pair_for(e.clone(), factory_address.clone(), input, output)?,
-        )
-        .swap(&amount_0_out, &amount_1_out, &to);
+        );
+        check_not_paused(&pair_client)?;
+        pair_client.swap(&amount_0_out, &amount_1_out, &to);
 
     }
 
@@ -170,531 +201,2944 @@ fn swap(e: &Env, factory_address: &Address, amounts: &Vec<i128>, path: &Vec<Addr
 }
 
 
-/*
-    SOROSWAP ROUTER SMART CONTRACT INTERFACE:
-*/
+/// Executes a series of token swaps along a trading route where every hop goes through the pair of the given variant,
+/// a constant product, a stable or a weighted pair. All have the same `swap`, so they are called with the same client.
+/// Requires that the initial amount has already been sent to the first pair in the route.
+///
+/// # Arguments
+/// * `e` - The runtime environment.
+/// * `factory_address` - The address of the Soroswap factory contract.
+/// * `amounts` - A vector containing the output amounts for each step of the trading route.
+/// * `path` - A vector representing the trading route, where each element is a token address.
+/// * `hops` - The pair of each hop, with one element less than `path`.
+/// * `to` - The final destination address for the swapped tokens.
+fn swap_route(e: &Env, factory_address: &Address, amounts: &Vec<i128>, path: &Vec<Address>, hops: &Vec<RouteHop>, to: &Address) -> Result<(), CombinedRouterError> {
+    for i in 0..path.len() - 1 {
+        let (input, output): (Address, Address) = (path.get(i).unwrap(), path.get(i + 1).unwrap());
+        let (token_0, _token_1): (Address, Address) = soroswap_library::sort_tokens(input.clone(), output.clone())?;
 
-pub trait SoroswapRouterTrait {
+        let amount_out: i128 = amounts.get(i + 1).unwrap();
+        let (amount_0_out, amount_1_out): (i128, i128) = if input == token_0 {
+            (0, amount_out)
+        } else {
+            (amount_out, 0)
+        };
 
-    /// Initializes the contract and sets the factory address
-    fn initialize(e: Env, factory: Address) -> Result<(), CombinedRouterError>;
+        // before the end, "to" must be the next pair... "to" will be the user only at the end
+        let hop_to: Address = if i < path.len() - 2 {
+            soroswap_library::pair_for_variant(e.clone(), factory_address.clone(), output.clone(), path.get(i + 2).unwrap(), soroswap_library::check_hop(hops.get(i + 1).unwrap())?.1)?
+        } else {
+            to.clone()
+        };
 
-    /// Adds liquidity to a token pair's pool, creating it if it doesn't exist. Ensures that exactly the desired amounts
-    /// of both tokens are added, subject to minimum requirements.
-    ///
-    /// This function is responsible for transferring tokens from the user to the pool and minting liquidity tokens in return.
-    ///
-    /// # Arguments
-    /// * `e` - The contract environment (`Env`) in which the contract is executing.
-    /// * `token_a` - The address of the first token to add liquidity for.
-    /// * `token_b` - The address of the second token to add liquidity for.
-    /// * `amount_a_desired` - The desired amount of the first token to add.
-    /// * `amount_b_desired` - The desired amount of the second token to add.
-    /// * `amount_a_min` - The minimum required amount of the first token to add.
-    /// * `amount_b_min` - The minimum required amount of the second token to add.
-    /// * `to` - The address where the liquidity tokens will be minted and sent.
-    /// * `deadline` - The deadline for executing the operation.
-    ///
-    /// # Returns
-    /// A tuple containing the actual amounts of token A and B added to the pool, as well as the amount of liquidity tokens minted.
-    fn add_liquidity(
-        e: Env,
-        token_a: Address,
-        token_b: Address,
-        amount_a_desired: i128,
-        amount_b_desired: i128,
-        amount_a_min: i128,
-        amount_b_min: i128,
-        to: Address,
-        deadline: u64,
-    ) -> Result<(i128, i128, i128), CombinedRouterError>;
+        let pair_client = SoroswapPairClient::new(
+            e,
+            &soroswap_library::pair_for_variant(e.clone(), factory_address.clone(), input, output, soroswap_library::check_hop(hops.get(i).unwrap())?.1)?,
+        );
+        check_not_paused(&pair_client)?;
+        pair_client.swap(&amount_0_out, &amount_1_out, &hop_to);
+    }
 
-    /// Removes liquidity from a token pair's pool.
-    ///
-    /// This function facilitates the removal of liquidity from a Soroswap Liquidity Pool by burning a specified amount
-    /// of Liquidity Pool tokens (`liquidity`) owned by the caller. In return, it transfers back the corresponding
-    /// amounts of the paired tokens (`token_a` and `token_b`) to the caller's specified address (`to`).
-    ///
-    /// # Arguments
-    /// * `token_a` - The address of the first token in the Liquidity Pool.
-    /// * `token_b` - The address of the second token in the Liquidity Pool.
-    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
-    /// * `amount_a_min` - The minimum required amount of the first token to receive.
-    /// * `amount_b_min` - The minimum required amount of the second token to receive.
-    /// * `to` - The address where the paired tokens will be sent to, and from where the LP tokens will be taken.
-    /// * `deadline` - The deadline for executing the operation.
-    ///
-    /// # Returns
-    /// A tuple containing the amounts of `token_a` and `token_b` withdrawn from the pool.  
-    fn remove_liquidity(
-        e: Env,
-        token_a: Address,
-        token_b: Address,
-        liquidity: i128,
-        amount_a_min: i128,
-        amount_b_min: i128,
-        to: Address,
-        deadline: u64,
-    ) -> Result<(i128, i128), CombinedRouterError>;
+    Ok(())
+}
 
-    /// Swaps an exact amount of input tokens for as many output tokens as possible
-    /// along the specified trading route. The route is determined by the `path` vector,
-    /// where the first element is the input token, the last is the output token, 
-    /// and any intermediate elements represent pairs to trade through if a direct pair does not exist.
-    ///
-    /// # Arguments
-    /// * `amount_in` - The exact amount of input tokens to be swapped.
-    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
-    /// * `path` - A vector representing the trading route, where the first element is the input token 
-    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
-    /// * `to` - The address where the output tokens will be sent to.
-    /// * `deadline` - The deadline for executing the operation.
-    ///
-    /// # Returns
-    /// A vector containing the amounts of tokens received at each step of the trading route.
-    fn swap_exact_tokens_for_tokens(
-        e: Env,
-        amount_in: i128,
-        amount_out_min: i128,
-        path: Vec<Address>,
-        to: Address,
-        deadline: u64,
-    ) -> Result<Vec<i128>, CombinedRouterError>;
+/// Returns an error if the referral fee is greater than the maximum allowed by the factory.
+fn check_referral(e: &Env, factory_address: &Address, referral: &Option<Referral>) -> Result<(), CombinedRouterError> {
+    if let Some(referral) = referral {
+        if referral.fee_bps > SoroswapFactoryClient::new(e, factory_address).max_referral_fee() {
+            return Err(SoroswapRouterError::ReferralFeeTooHigh.into());
+        }
+    }
+    Ok(())
+}
 
-    /// Swaps tokens for an exact amount of output token, following the specified trading route.
-    /// The route is determined by the `path` vector, where the first element is the input token,
-    /// the last is the output token, and any intermediate elements represent pairs to trade through.
-    ///
-    /// # Arguments
-    /// * `amount_out` - The exact amount of output token to be received.
-    /// * `amount_in_max` - The maximum allowed amount of input tokens to be swapped.
-    /// * `path` - A vector representing the trading route, where the first element is the input token 
-    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
-    /// * `to` - The address where the output tokens will be sent to.
-    /// * `deadline` - The deadline for executing the operation.
-    ///
-    /// # Returns
-    /// A vector containing the amounts of tokens used at each step of the trading route.
-    fn swap_tokens_for_exact_tokens(
-        e: Env,
-        amount_out: i128,
-        amount_in_max: i128,
-        path: Vec<Address>,
-        to: Address,
-        deadline: u64,
-    ) -> Result<Vec<i128>, CombinedRouterError>;
+/// Executes the swap like `swap`, and pays the referral fee if there is one. A fee in the input token is sent
+/// by the payer to the referrer. For a fee in the output token, the output is sent to the router, which keeps
+/// `output_fee` for the referrer and sends the rest to `to`. Requires that the input amount has already been sent
+/// to the first pair in the route.
+///
+/// # Returns
+/// The referral fee paid, for the swap event.
+fn swap_with_referral(
+    e: &Env,
+    factory_address: &Address,
+    amounts: &Vec<i128>,
+    path: &Vec<Address>,
+    payer: &Payer,
+    to: &Address,
+    referral: Option<Referral>,
+    input_fee: i128,
+    output_fee: i128,
+) -> Result<Option<ReferralFee>, CombinedRouterError> {
+    let referral = match referral {
+        Some(referral) => referral,
+        None => {
+            swap(e, factory_address, amounts, path, to)?;
+            return Ok(None);
+        }
+    };
 
-    /*  *** Read only functions: *** */
+    let (token, amount) = match referral.fee_token {
+        ReferralFeeToken::Input => {
+            let token_in = path.get(0).unwrap();
+            if input_fee > 0 {
+                payer.transfer(e, &token_in, &referral.referrer, &input_fee);
+            }
+            swap(e, factory_address, amounts, path, to)?;
+            (token_in, input_fee)
+        }
+        ReferralFeeToken::Output => {
+            let token_out = path.get(path.len() - 1).unwrap();
+            let router = e.current_contract_address();
+            swap(e, factory_address, amounts, path, &router)?;
+
+            let token_out_client = TokenClient::new(e, &token_out);
+            let swap_amount_out = amounts.get(amounts.len() - 1).unwrap();
+            if output_fee > 0 {
+                token_out_client.transfer(&router, &referral.referrer, &output_fee);
+            }
+            token_out_client.transfer(&router, to, &swap_amount_out.checked_sub(output_fee).ok_or(SoroswapRouterError::Overflow)?);
+            (token_out, output_fee)
+        }
+    };
 
-    /// This function retrieves the factory contract's address associated with the provided environment.
-    /// It also checks if the factory has been initialized and raises an assertion error if not.
-    /// If the factory is not initialized, this code will raise an assertion error with the message "SoroswapRouter: not yet initialized".
-    ///
-    /// # Arguments
-    /// * `e` - The contract environment (`Env`) in which the contract is executing.
-    fn get_factory(e: Env) -> Result<Address, CombinedRouterError>;
+    Ok(Some(ReferralFee {
+        referrer: referral.referrer,
+        token,
+        amount,
+    }))
+}
 
-    /*
-    LIBRARY FUNCTIONS:
-    */
+/// Executes a series of token swaps along the provided trading route, for tokens that may take a fee on transfer
+/// or change balances by themselves. Instead of precomputed amounts, the input of each hop is the pair's
+/// actual balance minus its reserve, so the pair always receives what it needs to satisfy the K constant.
+/// Requires that the initial amount has already been sent to the first pair in the route.
+///
+/// # Arguments
+/// * `e` - The runtime environment.
+/// * `factory_address` - The address of the Soroswap factory contract.
+/// * `path` - A vector representing the trading route, where each element is a token address.
+/// * `_to` - The final destination address for the swapped tokens.
+///
+/// # Returns
+/// A vector with the amount received by the first pair followed by the output amount of each hop.
+fn swap_supporting_fee_on_transfer_tokens(e: &Env, factory_address: &Address, path: &Vec<Address>, _to: &Address) -> Result<Vec<i128>, CombinedRouterError>{
+    let mut amounts: Vec<i128> = Vec::new(e);
+    for i in 0..path.len() - 1 {
+        let (input, output): (Address, Address) = (path.get(i).unwrap(), path.get(i + 1).unwrap());
 
-    /// Calculates the deterministic address for a pair without making any external calls.
-    /// check <https://github.com/paltalabs/deterministic-address-soroban>
-    ///
-    /// # Arguments
-    ///
-    /// * `e` - The environment.
-    /// * `token_a` - The address of the first token.
-    /// * `token_b` - The address of the second token.
-    ///
-    /// # Returns
-    ///
-    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the pair, and `Err` indicates an error such as identical tokens or an issue with sorting.
-    fn router_pair_for(e: Env, token_a: Address, token_b: Address) -> Result<Address, CombinedRouterError>;
+        let (token_0, _token_1): (Address, Address) =
+            (soroswap_library::sort_tokens(input.clone(), output.clone()))?;
 
-    /// Given some amount of an asset and pair reserves, returns an equivalent amount of the other asset.
-    ///
-    /// # Arguments
-    ///
-    /// * `amount_a` - The amount of the first asset.
-    /// * `reserve_a` - Reserves of the first asset in the pair.
-    /// * `reserve_b` - Reserves of the second asset in the pair.
-    ///
-    /// # Returns
-    ///
-    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated equivalent amount, and `Err` indicates an error such as insufficient amount or liquidity
-    fn router_quote(amount_a: i128, reserve_a: i128, reserve_b: i128) -> Result<i128, CombinedRouterError>;
+        let pair_client = SoroswapPairClient::new(
+            &e,
+            &soroswap_library::pair_for(e.clone(), factory_address.clone(), input.clone(), output.clone())?,
+        );
+        check_not_paused(&pair_client)?;
+
+        let (reserve_input, reserve_output) =
+            soroswap_library::get_reserves_with_pair(e.clone(), pair_client.address.clone(), input.clone(), output.clone())?;
+
+        // What the pair really received, after any transfer fee
+        let amount_input = TokenClient::new(&e, &input).balance(&pair_client.address).checked_sub(reserve_input).ok_or(SoroswapRouterError::Overflow)?;
+        let fee = soroswap_library::get_fee_with_pair(e.clone(), pair_client.address.clone());
+        let amount_output = soroswap_library::get_amount_out_with_fee(amount_input, reserve_input, reserve_output, fee)?;
+        if i == 0 {
+            amounts.push_back(amount_input);
+        }
+        amounts.push_back(amount_output);
 
-    /// Given an input amount of an asset and pair reserves, returns the maximum output amount of the other asset.
-    ///
-    /// # Arguments
-    ///
-    /// * `amount_in` - The input amount of the asset.
-    /// * `reserve_in` - Reserves of the input asset in the pair.
-    /// * `reserve_out` - Reserves of the output asset in the pair.
-    ///
-    /// # Returns
-    ///
-    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount or liquidity.
-    fn router_get_amount_out(amount_in: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, CombinedRouterError>;
+        let (amount_0_out, amount_1_out): (i128, i128) = if input == token_0 {
+            (0, amount_output)
+        } else {
+            (amount_output, 0)
+        };
 
-    /// Given an output amount of an asset and pair reserves, returns a required input amount of the other asset.
-    ///
-    /// # Arguments
-    ///
-    /// * `amount_out` - The output amount of the asset.
-    /// * `reserve_in` - Reserves of the input asset in the pair.
-    /// * `reserve_out` - Reserves of the output asset in the pair.
-    ///
-    /// # Returns
-    ///
-    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount or liquidity.
-    fn router_get_amount_in(amount_out: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, CombinedRouterError>;
+        // before the end, "to" must be the next pair... "to" will be the user only at the end
+        let to: Address = if i < path.len() - 2 {
+            soroswap_library::pair_for(
+                e.clone(),
+                factory_address.clone(),
+                output.clone(),
+                path.get(i + 2).unwrap(),
+            )?
+        } else {
+            _to.clone()
+        };
 
-    /// Performs chained get_amount_out calculations on any number of pairs.
-    ///
-    /// # Arguments
-    ///
-    /// * `e` - The environment.
-    /// * `amount_in` - The input amount.
-    /// * `path` - Vector of token addresses representing the path.
-    ///
-    /// # Returns
-    ///
-    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
-    fn router_get_amounts_out(e: Env, amount_in: i128, path: Vec<Address>) -> Result<Vec<i128>, CombinedRouterError>;
-    
-    /// Performs chained get_amount_in calculations on any number of pairs.
-    ///
-    /// # Arguments
-    ///
-    /// * `e` - The environment.
-    /// * `amount_out` - The output amount.
+        pair_client.swap(&amount_0_out, &amount_1_out, &to);
+    }
+
+    Ok(amounts)
+}
+
+/// Pair fees are expressed in basis points
+const FEE_DENOMINATOR: i128 = 10_000;
+
+/// Calculates how much of `amount_in` should be swapped through a pair so that the rest, together with
+/// the output of the swap, can be deposited in the pair's new ratio with no leftover.
+///
+/// Solves `(amount_in - s) / (reserve_in + s) = amount_out(s) / (reserve_out - amount_out(s))` for `s`,
+/// taking the pair fee into account:
+/// `s = (sqrt(reserve_in * (reserve_in * (D + g)^2 + 4 * g * D * amount_in)) - reserve_in * (D + g)) / (2 * g)`,
+/// where `D` is the fee denominator and `g = D - fee`.
+///
+/// The discriminant grows with the square of the reserve, so it is computed in 256 bits.
+///
+/// # Arguments
+/// * `e` - The runtime environment.
+/// * `amount_in` - The amount of the input token to be zapped.
+/// * `reserve_in` - The reserve of the input token in the pair.
+/// * `fee` - The swap fee of the pair, in basis points.
+fn zap_swap_amount(e: &Env, amount_in: i128, reserve_in: i128, fee: u32) -> Result<i128, SoroswapRouterError> {
+    let g = FEE_DENOMINATOR.checked_sub(fee as i128).ok_or(SoroswapRouterError::Overflow)?;
+    let d_plus_g = FEE_DENOMINATOR + g;
+
+    // Both terms are products of an i128 and a factor below 2^29, so their sum fits in an I256
+    let inner = mul_i256(e, reserve_in, d_plus_g * d_plus_g).add(&mul_i256(e, amount_in, 4 * g * FEE_DENOMINATOR));
+    let reserve_in_i256 = I256::from_i128(e, reserve_in);
+    // The host traps on I256 overflows, so check that reserve_in * inner stays below 2^254 first
+    if inner > I256::from_i32(e, 1).shl(254).div(&reserve_in_i256) {
+        return Err(SoroswapRouterError::Overflow);
+    }
+    let discriminant = reserve_in_i256.mul(&inner);
+
+    sqrt_i256(e, &discriminant)
+        .sub(&mul_i256(e, reserve_in, d_plus_g))
+        .div(&I256::from_i128(e, 2 * g))
+        .to_i128()
+        .ok_or(SoroswapRouterError::Overflow)
+}
+
+/// Who pays for a router operation, and how the router takes the tokens from them.
+enum Payer {
+    /// The owner authorizes the call and the tokens are transferred directly from it.
+    Owner(Address),
+    /// An operator approved by the owner authorizes the call, and the router spends the
+    /// allowance the owner gave it with `transfer_from`.
+    Allowance { operator: Address, owner: Address },
+    /// The owner already authorized the whole `multicall` batch this operation is part of.
+    Batch(Address),
+}
+
+impl Payer {
+    fn require_auth(&self, e: &Env) -> Result<(), CombinedRouterError> {
+        match self {
+            Payer::Owner(owner) => {
+                owner.require_auth();
+                Ok(())
+            }
+            Payer::Allowance { operator, owner } => {
+                operator.require_auth();
+                if is_operator(e, owner, operator) {
+                    Ok(())
+                } else {
+                    Err(SoroswapRouterError::NotOperator.into())
+                }
+            }
+            Payer::Batch(_) => Ok(()),
+        }
+    }
+
+    /// The address the tokens are taken from.
+    fn owner(&self) -> Address {
+        match self {
+            Payer::Owner(owner) | Payer::Batch(owner) => owner.clone(),
+            Payer::Allowance { owner, .. } => owner.clone(),
+        }
+    }
+
+    fn transfer(&self, e: &Env, token: &Address, to: &Address, amount: &i128) {
+        match self {
+            Payer::Owner(owner) | Payer::Batch(owner) => TokenClient::new(e, token).transfer(owner, to, amount),
+            Payer::Allowance { owner, .. } => {
+                TokenClient::new(e, token).transfer_from(&e.current_contract_address(), owner, to, amount)
+            }
+        }
+    }
+}
+
+/// Adds liquidity to a token pair's pool, paying the tokens with `payer` and minting the liquidity tokens to `to`.
+/// `add_liquidity`, `add_liquidity_from` and `add_liquidity_operator` are all built on it.
+fn execute_add_liquidity(
+    e: Env,
+    token_a: Address,
+    token_b: Address,
+    amount_a_desired: i128,
+    amount_b_desired: i128,
+    amount_a_min: i128,
+    amount_b_min: i128,
+    payer: Payer,
+    to: Address,
+    validity: &Validity,
+) -> Result<(i128, i128, i128), CombinedRouterError> {
+    check_initialized(&e)?;
+    check_nonnegative_amount(amount_a_desired)?;
+    check_nonnegative_amount(amount_b_desired)?;
+    check_nonnegative_amount(amount_a_min)?;
+    check_nonnegative_amount(amount_b_min)?;
+    extend_instance_ttl(&e);
+    payer.require_auth(&e)?;
+    ensure_validity(&e, validity)?;
+
+    let factory = get_factory(&e);
+
+    let (amount_a, amount_b) = add_liquidity_amounts(
+        e.clone(),
+        factory.clone(),
+        token_a.clone(),
+        token_b.clone(),
+        amount_a_desired,
+        amount_b_desired,
+        amount_a_min,
+        amount_b_min,
+    )?;
+
+    let pair: Address = soroswap_library::pair_for(
+        e.clone(),
+        factory,
+        token_a.clone(),
+        token_b.clone(),
+    ).map_err(SoroswapLibraryError::from)?;
+
+    let pair_client = SoroswapPairClient::new(&e, &pair);
+    check_not_paused(&pair_client)?;
+
+    payer.transfer(&e, &token_a, &pair, &amount_a);
+    payer.transfer(&e, &token_b, &pair, &amount_b);
+
+    let liquidity = pair_client.deposit(&to);
+
+    event::add_liquidity(
+        &e,
+        token_a,
+        token_b,
+        pair,
+        amount_a,
+        amount_b,
+        liquidity,
+        payer.owner(),
+        to);
+        
+    Ok((amount_a, amount_b, liquidity))
+}
+
+/// Removes liquidity from a token pair's pool, burning the LP tokens of `payer` and sending the paired tokens to `to`.
+/// `remove_liquidity`, `remove_liquidity_from` and `remove_liquidity_operator` are all built on it.
+fn execute_remove_liquidity(
+    e: Env,
+    token_a: Address,
+    token_b: Address,
+    liquidity: i128,
+    amount_a_min: i128,
+    amount_b_min: i128,
+    payer: Payer,
+    to: Address,
+    validity: &Validity,
+) -> Result<(i128, i128), CombinedRouterError> {
+    check_initialized(&e)?;
+    check_nonnegative_amount(liquidity)?;
+    check_nonnegative_amount(amount_a_min)?;
+    check_nonnegative_amount(amount_b_min)?;
+    extend_instance_ttl(&e);
+    payer.require_auth(&e)?;
+    ensure_validity(&e, validity)?;
+
+    // Ensure that the pair exists in the Soroswap factory
+    let factory_address = get_factory(&e);
+    let factory = SoroswapFactoryClient::new(&e, &factory_address);
+
+    if !factory.pair_exists(&token_a, &token_b) {
+        return Err(SoroswapRouterError::PairDoesNotExist.into());
+    }
+
+    // Retrieve the pair's contract address using the Soroswap library
+    let pair: Address = soroswap_library::pair_for(
+        e.clone(),
+        get_factory(&e),
+        token_a.clone(),
+        token_b.clone(),
+    )?;
+
+    // Transfer LP tokens from the payer to the pair contract
+    payer.transfer(&e, &pair, &pair, &liquidity);
+    
+    // Withdraw paired tokens from the pool
+    let (amount_0, amount_1) = SoroswapPairClient::new(&e, &pair).withdraw(&to);
+
+    // Sort tokens to match the expected order
+    let (token_0, _token_1) = soroswap_library::sort_tokens(token_a.clone(), token_b.clone())?;
+    let (amount_a, amount_b) = if token_a == token_0 {
+        (amount_0, amount_1)
+    } else {
+        (amount_1, amount_0)
+    };
+
+    // Check if the received amounts meet the minimum requirements
+    if amount_a < amount_a_min {
+        return Err(SoroswapRouterError::InsufficientAAmount.into());
+    }
+    if amount_b < amount_b_min {
+        return Err(SoroswapRouterError::InsufficientBAmount.into());
+    }
+
+    event::remove_liquidity(
+        &e,
+        token_a,
+        token_b,
+        pair,
+        amount_a,
+        amount_b,
+        liquidity,
+        payer.owner(),
+        to);
+
+    // Return the amounts of paired tokens withdrawn
+    Ok((amount_a, amount_b))
+}
+
+/// Swaps an exact amount of input tokens paid by `payer` for as many output tokens as possible, sent to `to`.
+/// `swap_exact_tokens_for_tokens`, `swap_exact_tokens_from`, `swap_exact_tokens_operator` and
+/// `swap_exact_tokens_referral` are all built on it.
+///
+/// A referral fee is a share of `amount_in` when paid in the input token, and only the rest is swapped.
+/// When paid in the output token it is a share of the swap output, and `to` receives the rest.
+fn execute_swap_exact_tokens_for_tokens(
+    e: Env,
+    amount_in: i128,
+    amount_out_min: i128,
+    path: Vec<Address>,
+    payer: Payer,
+    to: Address,
+    referral: Option<Referral>,
+    validity: &Validity,
+) -> Result<Vec<i128>, CombinedRouterError> {
+    check_initialized(&e)?;
+    check_nonnegative_amount(amount_in)?;
+    check_nonnegative_amount(amount_out_min)?;
+    extend_instance_ttl(&e);
+    payer.require_auth(&e)?;
+    ensure_validity(&e, validity)?;
+
+    let factory_address = get_factory(&e);
+    check_referral(&e, &factory_address, &referral)?;
+    let input_fee = match &referral {
+        Some(referral) => referral.fee_in(ReferralFeeToken::Input, amount_in)?,
+        None => 0,
+    };
+
+    // Get the expected output amounts for each step of the trading route        
+    let amounts = soroswap_library::get_amounts_out(
+        e.clone(),
+        factory_address.clone(),
+        amount_in.checked_sub(input_fee).ok_or(SoroswapRouterError::Overflow)?,
+        path.clone(),
+    )?;
+
+    let swap_amount_out = amounts.get(amounts.len() - 1).unwrap();
+    let output_fee = match &referral {
+        Some(referral) => referral.fee_in(ReferralFeeToken::Output, swap_amount_out)?,
+        None => 0,
+    };
+
+    // Ensure that the final output amount meets the minimum requirement        
+    if swap_amount_out.checked_sub(output_fee).ok_or(SoroswapRouterError::Overflow)? < amount_out_min {
+        return Err(SoroswapRouterError::InsufficientOutputAmount.into());
+    }
+    
+    // Determine the pair contract address for the first step of the trading route
+    let pair = soroswap_library::pair_for(
+        e.clone(),
+        factory_address.clone(),
+        path.get(0).unwrap(),
+        path.get(1).unwrap(),
+    )?;
+    
+    // Transfer input tokens to the pair contract
+    // If the pair does not exist, this will fail here: Should be implement factory.pair_exists?
+    // If we implement, we will include an additional cross-contract call...
+    payer.transfer(&e, &path.get(0).unwrap(), &pair, &amounts.get(0).unwrap());
+
+    // Execute the tokens swap
+    let referral_fee = swap_with_referral(&e, &factory_address, &amounts, &path, &payer, &to, referral, input_fee, output_fee)?;
+
+    event::swap(
+        &e,
+        path,
+        amounts.clone(),
+        payer.owner(),
+        to,
+        referral_fee);
+
+    // Return the amounts of tokens received at each step of the trading route
+    Ok(amounts)
+}
+
+/// Swaps tokens paid by `payer` for an exact amount of output token, sent to `to`.
+/// `swap_tokens_for_exact_tokens`, `swap_for_exact_tokens_from`, `swap_for_exact_tokens_operator` and
+/// `swap_for_exact_tokens_referral` are all built on it.
+///
+/// A referral fee is paid on top of the swap: when paid in the input token it is a share of the swap input,
+/// and when paid in the output token it is a share of `amount_out` that the swap buys in addition.
+fn execute_swap_tokens_for_exact_tokens(
+    e: Env,
+    amount_out: i128,
+    amount_in_max: i128,
+    path: Vec<Address>,
+    payer: Payer,
+    to: Address,
+    referral: Option<Referral>,
+    validity: &Validity,
+) -> Result<Vec<i128>, CombinedRouterError> {
+    check_initialized(&e)?;
+    check_nonnegative_amount(amount_out)?;
+    check_nonnegative_amount(amount_in_max)?;
+    extend_instance_ttl(&e);
+    payer.require_auth(&e)?;
+    ensure_validity(&e, validity)?;
+
+    let factory_address = get_factory(&e);
+    check_referral(&e, &factory_address, &referral)?;
+    let output_fee = match &referral {
+        Some(referral) => referral.fee_in(ReferralFeeToken::Output, amount_out)?,
+        None => 0,
+    };
+
+    // Get the expected input amounts for each step of the trading route
+    let amounts = soroswap_library::get_amounts_in(
+        e.clone(),
+        factory_address.clone(),
+        amount_out.checked_add(output_fee).ok_or(SoroswapRouterError::Overflow)?,
+        path.clone(),
+    )?;
+
+    let input_fee = match &referral {
+        Some(referral) => referral.fee_in(ReferralFeeToken::Input, amounts.get(0).unwrap())?,
+        None => 0,
+    };
+    
+    // Ensure that the input amount does not exceed the maximum allowed
+    if amounts.get(0).unwrap().checked_add(input_fee).ok_or(SoroswapRouterError::Overflow)? > amount_in_max {
+        return Err(SoroswapRouterError::ExcessiveInputAmount.into());
+    }
+
+    // Determine the pair contract address for the first step of the trading route
+    let pair = soroswap_library::pair_for(
+        e.clone(),
+        factory_address.clone(),
+        path.get(0).unwrap(),
+        path.get(1).unwrap(),
+    )?;
+    // Transfer input tokens to the pair contract
+    // If the pair does not exist, this will fail here: Should be implement factory.pair_exists?
+    // If we implement, we will include an additional cross-contract call...
+    payer.transfer(&e, &path.get(0).unwrap(), &pair, &amounts.get(0).unwrap());
+
+    // Execute the token swap
+    let referral_fee = swap_with_referral(&e, &factory_address, &amounts, &path, &payer, &to, referral, input_fee, output_fee)?;
+
+    event::swap(
+        &e,
+        path,
+        amounts.clone(),
+        payer.owner(),
+        to,
+        referral_fee);
+
+    // Return the amounts of tokens used at each step of the trading route
+    Ok(amounts)
+}
+
+/// Swaps an exact amount of input tokens paid by `payer` for as many output tokens as possible, sent to `to`,
+/// through the pair of the given kind and variant at each hop. `swap_exact_tokens_route` is built on it.
+fn execute_swap_exact_tokens_route(
+    e: Env,
+    amount_in: i128,
+    amount_out_min: i128,
+    path: Vec<Address>,
+    hops: Vec<RouteHop>,
+    payer: Payer,
+    to: Address,
+    validity: &Validity,
+) -> Result<Vec<i128>, CombinedRouterError> {
+    check_initialized(&e)?;
+    check_nonnegative_amount(amount_in)?;
+    check_nonnegative_amount(amount_out_min)?;
+    extend_instance_ttl(&e);
+    payer.require_auth(&e)?;
+    ensure_validity(&e, validity)?;
+
+    let factory_address = get_factory(&e);
+
+    // Get the expected output amounts for each step of the trading route
+    let amounts = soroswap_library::get_amounts_out_route(e.clone(), factory_address.clone(), amount_in, path.clone(), hops.clone())?;
+
+    // Ensure that the final output amount meets the minimum requirement
+    if amounts.get(amounts.len() - 1).unwrap() < amount_out_min {
+        return Err(SoroswapRouterError::InsufficientOutputAmount.into());
+    }
+
+    // Transfer input tokens to the pair of the first step of the trading route
+    let pair = soroswap_library::pair_for_variant(e.clone(), factory_address.clone(), path.get(0).unwrap(), path.get(1).unwrap(), soroswap_library::check_hop(hops.get(0).unwrap())?.1)?;
+    payer.transfer(&e, &path.get(0).unwrap(), &pair, &amounts.get(0).unwrap());
+
+    swap_route(&e, &factory_address, &amounts, &path, &hops, &to)?;
+
+    event::swap(
+        &e,
+        path,
+        amounts.clone(),
+        payer.owner(),
+        to,
+        None);
+
+    Ok(amounts)
+}
+
+/// Swaps tokens paid by `payer` for an exact amount of output token, sent to `to`, through the pair of the
+/// given kind and variant at each hop. `swap_for_exact_tokens_route` is built on it.
+fn execute_swap_for_exact_tokens_route(
+    e: Env,
+    amount_out: i128,
+    amount_in_max: i128,
+    path: Vec<Address>,
+    hops: Vec<RouteHop>,
+    payer: Payer,
+    to: Address,
+    validity: &Validity,
+) -> Result<Vec<i128>, CombinedRouterError> {
+    check_initialized(&e)?;
+    check_nonnegative_amount(amount_out)?;
+    check_nonnegative_amount(amount_in_max)?;
+    extend_instance_ttl(&e);
+    payer.require_auth(&e)?;
+    ensure_validity(&e, validity)?;
+
+    let factory_address = get_factory(&e);
+
+    // Get the expected input amounts for each step of the trading route
+    let amounts = soroswap_library::get_amounts_in_route(e.clone(), factory_address.clone(), amount_out, path.clone(), hops.clone())?;
+
+    // Ensure that the input amount does not exceed the maximum allowed
+    if amounts.get(0).unwrap() > amount_in_max {
+        return Err(SoroswapRouterError::ExcessiveInputAmount.into());
+    }
+
+    // Transfer input tokens to the pair of the first step of the trading route
+    let pair = soroswap_library::pair_for_variant(e.clone(), factory_address.clone(), path.get(0).unwrap(), path.get(1).unwrap(), soroswap_library::check_hop(hops.get(0).unwrap())?.1)?;
+    payer.transfer(&e, &path.get(0).unwrap(), &pair, &amounts.get(0).unwrap());
+
+    swap_route(&e, &factory_address, &amounts, &path, &hops, &to)?;
+
+    event::swap(
+        &e,
+        path,
+        amounts.clone(),
+        payer.owner(),
+        to,
+        None);
+
+    Ok(amounts)
+}
+
+/// Swaps as much as possible of `amount_in_max`, paid by `payer`, without the execution price falling below
+/// `min_price`. The rest of `amount_in_max` is not transferred and stays with the payer.
+/// `swap_exact_tokens_price_limit` is built on it.
+fn execute_swap_exact_tokens_price_limit(
+    e: Env,
+    amount_in_max: i128,
+    min_price: i128,
+    path: Vec<Address>,
+    payer: Payer,
+    to: Address,
+    validity: &Validity,
+) -> Result<(i128, i128, i128), CombinedRouterError> {
+    check_initialized(&e)?;
+    check_nonnegative_amount(amount_in_max)?;
+    check_nonnegative_amount(min_price)?;
+    extend_instance_ttl(&e);
+    payer.require_auth(&e)?;
+    ensure_validity(&e, validity)?;
+
+    let factory_address = get_factory(&e);
+
+    // Largest input whose execution price on the path is at least min_price
+    let amount_in = soroswap_library::get_amount_in_for_price(
+        e.clone(),
+        factory_address.clone(),
+        amount_in_max,
+        min_price,
+        path.clone(),
+    )?;
+    let amount_unfilled = amount_in_max.checked_sub(amount_in).ok_or(SoroswapRouterError::Overflow)?;
+    if amount_in == 0 {
+        return Ok((0, amount_unfilled, 0));
+    }
+
+    let amounts = soroswap_library::get_amounts_out(
+        e.clone(),
+        factory_address.clone(),
+        amount_in,
+        path.clone(),
+    )?;
+
+    let pair = soroswap_library::pair_for(
+        e.clone(),
+        factory_address.clone(),
+        path.get(0).unwrap(),
+        path.get(1).unwrap(),
+    )?;
+    payer.transfer(&e, &path.get(0).unwrap(), &pair, &amount_in);
+
+    swap(&e, &factory_address, &amounts, &path, &to)?;
+
+    let amount_out = amounts.get(amounts.len() - 1).unwrap();
+    event::swap(
+        &e,
+        path,
+        amounts,
+        payer.owner(),
+        to,
+        None);
+
+    // Return the filled and unfilled input amounts, and the output amount
+    Ok((amount_in, amount_unfilled, amount_out))
+}
+
+/// Swaps an exact amount of input tokens paid by `payer` and sends the output to `target`, then calls
+/// `soroswap_router_call` on `target` with the output token, the output amount and `data`.
+/// `swap_and_call` is built on it.
+fn execute_swap_and_call(
+    e: Env,
+    amount_in: i128,
+    amount_out_min: i128,
+    path: Vec<Address>,
+    payer: Payer,
+    target: Address,
+    data: Bytes,
+    validity: &Validity,
+) -> Result<Vec<i128>, CombinedRouterError> {
+    let amounts = execute_swap_exact_tokens_for_tokens(e.clone(), amount_in, amount_out_min, path.clone(), payer, target.clone(), None, validity)?;
+
+    // The output is already in target, so it can use it right away
+    SoroswapRouterCalleeClient::new(&e, &target).soroswap_router_call(
+        &path.get(path.len() - 1).unwrap(),
+        &amounts.get(amounts.len() - 1).unwrap(),
+        &data,
+    );
+
+    Ok(amounts)
+}
+
+/// Removes liquidity like `execute_remove_liquidity`, checking the minimum amounts against what `to` received.
+fn execute_remove_liquidity_fot(
+    e: Env,
+    token_a: Address,
+    token_b: Address,
+    liquidity: i128,
+    amount_a_min: i128,
+    amount_b_min: i128,
+    to: Address,
+    validity: &Validity,
+) -> Result<(i128, i128), CombinedRouterError> {
+    check_initialized(&e)?;
+    check_nonnegative_amount(liquidity)?;
+    check_nonnegative_amount(amount_a_min)?;
+    check_nonnegative_amount(amount_b_min)?;
+    extend_instance_ttl(&e);
+    to.require_auth();
+    ensure_validity(&e, validity)?;
+
+    let factory_address = get_factory(&e);
+    let factory = SoroswapFactoryClient::new(&e, &factory_address);
+
+    if !factory.pair_exists(&token_a, &token_b) {
+        return Err(SoroswapRouterError::PairDoesNotExist.into());
+    }
+
+    let pair: Address = soroswap_library::pair_for(
+        e.clone(),
+        factory_address,
+        token_a.clone(),
+        token_b.clone(),
+    )?;
+
+    let token_a_client = TokenClient::new(&e, &token_a);
+    let token_b_client = TokenClient::new(&e, &token_b);
+    let balance_a_before = token_a_client.balance(&to);
+    let balance_b_before = token_b_client.balance(&to);
+
+    TokenClient::new(&e, &pair).transfer(&to, &pair, &liquidity);
+    SoroswapPairClient::new(&e, &pair).withdraw(&to);
+
+    // The pair sends the amounts it withdraws, but "to" may receive less
+    let amount_a = token_a_client.balance(&to).checked_sub(balance_a_before).ok_or(SoroswapRouterError::Overflow)?;
+    let amount_b = token_b_client.balance(&to).checked_sub(balance_b_before).ok_or(SoroswapRouterError::Overflow)?;
+
+    if amount_a < amount_a_min {
+        return Err(SoroswapRouterError::InsufficientAAmount.into());
+    }
+    if amount_b < amount_b_min {
+        return Err(SoroswapRouterError::InsufficientBAmount.into());
+    }
+
+    event::remove_liquidity(
+        &e,
+        token_a,
+        token_b,
+        pair,
+        amount_a,
+        amount_b,
+        liquidity,
+        to.clone(),
+        to);
+
+    Ok((amount_a, amount_b))
+}
+
+/// Swaps an exact amount of input tokens that may take a fee on transfer, paid and received by `to`.
+fn execute_swap_exact_tokens_for_tokens_fot(
+    e: Env,
+    amount_in: i128,
+    amount_out_min: i128,
+    path: Vec<Address>,
+    to: Address,
+    validity: &Validity,
+) -> Result<i128, CombinedRouterError> {
+    check_initialized(&e)?;
+    check_nonnegative_amount(amount_in)?;
+    check_nonnegative_amount(amount_out_min)?;
+    extend_instance_ttl(&e);
+    to.require_auth();
+    ensure_validity(&e, validity)?;
+
+    if path.len() < 2 {
+        return Err(SoroswapLibraryError::InvalidPath.into());
+    }
+
+    let factory_address = get_factory(&e);
+    let pair = soroswap_library::pair_for(
+        e.clone(),
+        factory_address.clone(),
+        path.get(0).unwrap(),
+        path.get(1).unwrap(),
+    )?;
+
+    TokenClient::new(&e, &path.get(0).unwrap()).transfer(&to, &pair, &amount_in);
+
+    let token_out = TokenClient::new(&e, &path.get(path.len() - 1).unwrap());
+    let balance_before = token_out.balance(&to);
+
+    let amounts = swap_supporting_fee_on_transfer_tokens(&e, &factory_address, &path, &to)?;
+
+    let amount_out = token_out.balance(&to).checked_sub(balance_before).ok_or(SoroswapRouterError::Overflow)?;
+    if amount_out < amount_out_min {
+        return Err(SoroswapRouterError::InsufficientOutputAmount.into());
+    }
+
+    event::swap(
+        &e,
+        path,
+        amounts,
+        to.clone(),
+        to,
+        None);
+
+    Ok(amount_out)
+}
+
+/// Swaps exact amounts of input tokens across several trading routes, paid and received by `to`.
+fn execute_swap_exact_tokens_split(
+    e: Env,
+    distribution: Vec<(Vec<Address>, i128)>,
+    amount_out_min: i128,
+    to: Address,
+    validity: &Validity,
+) -> Result<Vec<Vec<i128>>, CombinedRouterError> {
+    check_initialized(&e)?;
+    check_nonnegative_amount(amount_out_min)?;
+    extend_instance_ttl(&e);
+    to.require_auth();
+    ensure_validity(&e, validity)?;
+
+    if distribution.is_empty() {
+        return Err(SoroswapRouterError::InvalidDistribution.into());
+    }
+
+    // Every leg must sell the same token and buy the same token
+    let (first_path, _) = distribution.get(0).unwrap();
+    if first_path.len() < 2 {
+        return Err(SoroswapLibraryError::InvalidPath.into());
+    }
+    let token_in = first_path.get(0).unwrap();
+    let token_out = first_path.get(first_path.len() - 1).unwrap();
+    for (path, amount_in) in distribution.iter() {
+        check_nonnegative_amount(amount_in)?;
+        if path.len() < 2 {
+            return Err(SoroswapLibraryError::InvalidPath.into());
+        }
+        if path.get(0).unwrap() != token_in || path.get(path.len() - 1).unwrap() != token_out {
+            return Err(SoroswapRouterError::InvalidDistribution.into());
+        }
+    }
+
+    let factory_address = get_factory(&e);
+    let mut paths: Vec<Vec<Address>> = Vec::new(&e);
+    let mut legs_amounts: Vec<Vec<i128>> = Vec::new(&e);
+    let mut total_amount_in: i128 = 0;
+    let mut total_amount_out: i128 = 0;
+
+    for (path, amount_in) in distribution.iter() {
+        // Quoted after the previous legs, as they may have used some of the same pairs
+        let amounts = soroswap_library::get_amounts_out(
+            e.clone(),
+            factory_address.clone(),
+            amount_in,
+            path.clone(),
+        )?;
+
+        let pair = soroswap_library::pair_for(
+            e.clone(),
+            factory_address.clone(),
+            path.get(0).unwrap(),
+            path.get(1).unwrap(),
+        )?;
+        TokenClient::new(&e, &token_in).transfer(&to, &pair, &amounts.get(0).unwrap());
+
+        swap(&e, &factory_address, &amounts, &path, &to)?;
+
+        total_amount_in = total_amount_in.checked_add(amount_in).ok_or(SoroswapRouterError::Overflow)?;
+        total_amount_out = total_amount_out.checked_add(amounts.get(amounts.len() - 1).unwrap()).ok_or(SoroswapRouterError::Overflow)?;
+        paths.push_back(path);
+        legs_amounts.push_back(amounts);
+    }
+
+    // Ensure that the aggregated output amount meets the minimum requirement
+    if total_amount_out < amount_out_min {
+        return Err(SoroswapRouterError::InsufficientOutputAmount.into());
+    }
+
+    event::split_swap(
+        &e,
+        paths,
+        legs_amounts.clone(),
+        total_amount_in,
+        total_amount_out,
+        to);
+
+    Ok(legs_amounts)
+}
+
+/// Executes a batch of router operations paid by `from`, within `validity`.
+fn execute_multicall(
+    e: Env,
+    ops: Vec<RouterOp>,
+    from: Address,
+    validity: &Validity,
+) -> Result<Vec<Vec<i128>>, CombinedRouterError> {
+    check_initialized(&e)?;
+    extend_instance_ttl(&e);
+    from.require_auth();
+    ensure_validity(&e, validity)?;
+
+    let mut results: Vec<Vec<i128>> = Vec::new(&e);
+    for op in ops.iter() {
+        let payer = Payer::Batch(from.clone());
+        let result = match op {
+            RouterOp::AddLiquidity(op) => {
+                let amount_a_desired = op.amount_a_desired.resolve(&results)?;
+                let amount_b_desired = op.amount_b_desired.resolve(&results)?;
+                let (amount_a, amount_b, liquidity) = execute_add_liquidity(
+                    e.clone(), op.token_a, op.token_b, amount_a_desired, amount_b_desired,
+                    op.amount_a_min, op.amount_b_min, payer, op.to, validity)?;
+                vec![&e, amount_a, amount_b, liquidity]
+            }
+            RouterOp::RemoveLiquidity(op) => {
+                let liquidity = op.liquidity.resolve(&results)?;
+                let (amount_a, amount_b) = execute_remove_liquidity(
+                    e.clone(), op.token_a, op.token_b, liquidity,
+                    op.amount_a_min, op.amount_b_min, payer, op.to, validity)?;
+                vec![&e, amount_a, amount_b]
+            }
+            RouterOp::SwapExactTokens(op) => {
+                let amount_in = op.amount_in.resolve(&results)?;
+                execute_swap_exact_tokens_for_tokens(e.clone(), amount_in, op.amount_out_min, op.path, payer, op.to, None, validity)?
+            }
+            RouterOp::SwapForExactTokens(op) => {
+                let amount_in_max = op.amount_in_max.resolve(&results)?;
+                execute_swap_tokens_for_exact_tokens(e.clone(), op.amount_out, amount_in_max, op.path, payer, op.to, None, validity)?
+            }
+        };
+        results.push_back(result);
+    }
+
+    Ok(results)
+}
+
+/// Adds liquidity to a pair's pool using only `token_in`, paid by `to`.
+fn execute_zap_in(
+    e: Env,
+    token_in: Address,
+    amount_in: i128,
+    other_token: Address,
+    liquidity_min: i128,
+    to: Address,
+    validity: &Validity,
+) -> Result<(i128, i128, i128), CombinedRouterError> {
+    check_initialized(&e)?;
+    check_nonnegative_amount(amount_in)?;
+    check_nonnegative_amount(liquidity_min)?;
+    extend_instance_ttl(&e);
+    to.require_auth();
+    ensure_validity(&e, validity)?;
+
+    // Zapping needs a price, so the pair must already exist and have liquidity
+    let factory_address = get_factory(&e);
+    let factory = SoroswapFactoryClient::new(&e, &factory_address);
+    if !factory.pair_exists(&token_in, &other_token) {
+        return Err(SoroswapRouterError::PairDoesNotExist.into());
+    }
+
+    let pair: Address = soroswap_library::pair_for(
+        e.clone(),
+        factory_address.clone(),
+        token_in.clone(),
+        other_token.clone(),
+    )?;
+    let pair_client = SoroswapPairClient::new(&e, &pair);
+    check_not_paused(&pair_client)?;
+
+    let (reserve_in, reserve_other) = soroswap_library::get_reserves_with_factory(
+        e.clone(),
+        factory_address.clone(),
+        token_in.clone(),
+        other_token.clone(),
+    )?;
+    if reserve_in == 0 || reserve_other == 0 {
+        return Err(SoroswapLibraryError::InsufficientLiquidity.into());
+    }
+
+    // Swap the optimal fraction of token_in for other_token
+    let fee = soroswap_library::get_fee_with_pair(e.clone(), pair.clone());
+    let swap_amount = zap_swap_amount(&e, amount_in, reserve_in, fee)?;
+    let amount_out = soroswap_library::get_amount_out_with_fee(swap_amount, reserve_in, reserve_other, fee)?;
+
+    let path: Vec<Address> = Vec::from_array(&e, [token_in.clone(), other_token.clone()]);
+    let amounts: Vec<i128> = Vec::from_array(&e, [swap_amount, amount_out]);
+    TokenClient::new(&e, &token_in).transfer(&to, &pair, &swap_amount);
+    swap(&e, &factory_address, &amounts, &path, &to)?;
+
+    event::swap(
+        &e,
+        path,
+        amounts,
+        to.clone(),
+        to.clone(),
+        None);
+
+    // Deposit the rest of token_in together with the swapped other_token
+    let amount_in_left = amount_in.checked_sub(swap_amount).ok_or(SoroswapRouterError::Overflow)?;
+    let (amount_a, amount_b) = add_liquidity_amounts(
+        e.clone(),
+        factory_address,
+        token_in.clone(),
+        other_token.clone(),
+        amount_in_left,
+        amount_out,
+        0,
+        0,
+    )?;
+
+    TokenClient::new(&e, &token_in).transfer(&to, &pair, &amount_a);
+    TokenClient::new(&e, &other_token).transfer(&to, &pair, &amount_b);
+
+    let liquidity = pair_client.deposit(&to);
+    if liquidity < liquidity_min {
+        return Err(SoroswapRouterError::InsufficientLiquidity.into());
+    }
+
+    event::add_liquidity(
+        &e,
+        token_in,
+        other_token,
+        pair,
+        amount_a,
+        amount_b,
+        liquidity,
+        to.clone(),
+        to);
+
+    Ok((
+        amount_in_left.checked_sub(amount_a).ok_or(SoroswapRouterError::Overflow)?,
+        amount_out.checked_sub(amount_b).ok_or(SoroswapRouterError::Overflow)?,
+        liquidity,
+    ))
+}
+
+/// Removes liquidity from a pair's pool and swaps it all for `token_out`, received by `to`.
+fn execute_zap_out(
+    e: Env,
+    token_out: Address,
+    other_token: Address,
+    liquidity: i128,
+    amount_out_min: i128,
+    to: Address,
+    validity: &Validity,
+) -> Result<i128, CombinedRouterError> {
+    check_initialized(&e)?;
+    check_nonnegative_amount(liquidity)?;
+    check_nonnegative_amount(amount_out_min)?;
+    extend_instance_ttl(&e);
+    to.require_auth();
+    ensure_validity(&e, validity)?;
+
+    let factory_address = get_factory(&e);
+    let factory = SoroswapFactoryClient::new(&e, &factory_address);
+    if !factory.pair_exists(&token_out, &other_token) {
+        return Err(SoroswapRouterError::PairDoesNotExist.into());
+    }
+
+    let pair: Address = soroswap_library::pair_for(
+        e.clone(),
+        factory_address.clone(),
+        token_out.clone(),
+        other_token.clone(),
+    )?;
+    let pair_client = SoroswapPairClient::new(&e, &pair);
+
+    // Withdraw both tokens to the caller
+    TokenClient::new(&e, &pair).transfer(&to, &pair, &liquidity);
+    let (amount_0, amount_1) = pair_client.withdraw(&to);
+
+    let (token_0, _token_1) = soroswap_library::sort_tokens(token_out.clone(), other_token.clone())?;
+    let (amount_out_withdrawn, amount_other) = if token_out == token_0 {
+        (amount_0, amount_1)
+    } else {
+        (amount_1, amount_0)
+    };
+
+    event::remove_liquidity(
+        &e,
+        token_out.clone(),
+        other_token.clone(),
+        pair.clone(),
+        amount_out_withdrawn,
+        amount_other,
+        liquidity,
+        to.clone(),
+        to.clone());
+
+    // Swap the withdrawn other_token for token_out, with the reserves left after the withdrawal
+    let (reserve_other, reserve_out) = soroswap_library::get_reserves_with_factory(
+        e.clone(),
+        factory_address.clone(),
+        other_token.clone(),
+        token_out.clone(),
+    )?;
+    let fee = soroswap_library::get_fee_with_pair(e.clone(), pair.clone());
+    let amount_out_swapped = soroswap_library::get_amount_out_with_fee(amount_other, reserve_other, reserve_out, fee)?;
+
+    let path: Vec<Address> = Vec::from_array(&e, [other_token.clone(), token_out]);
+    let amounts: Vec<i128> = Vec::from_array(&e, [amount_other, amount_out_swapped]);
+    TokenClient::new(&e, &other_token).transfer(&to, &pair, &amount_other);
+    swap(&e, &factory_address, &amounts, &path, &to)?;
+
+    let amount_out = amount_out_withdrawn.checked_add(amount_out_swapped).ok_or(SoroswapRouterError::Overflow)?;
+    if amount_out < amount_out_min {
+        return Err(SoroswapRouterError::InsufficientOutputAmount.into());
+    }
+
+    event::swap(
+        &e,
+        path,
+        amounts,
+        to.clone(),
+        to,
+        None);
+
+    Ok(amount_out)
+}
+
+/*
+    SOROSWAP ROUTER SMART CONTRACT INTERFACE:
+*/
+
+pub trait SoroswapRouterTrait {
+
+    /// Initializes the contract and sets the factory address
+    fn initialize(e: Env, factory: Address) -> Result<(), CombinedRouterError>;
+
+    /// Approves or revokes an `operator` that may authorize the `*_operator` functions on behalf of `owner`.
+    /// The operator can only spend what `owner` approved the router to spend on each token.
+    ///
+    /// # Arguments
+    /// * `owner` - The address whose router allowances the operator may spend.
+    /// * `operator` - The address to approve or revoke.
+    /// * `approved` - Whether the operator is approved.
+    fn set_operator(e: Env, owner: Address, operator: Address, approved: bool) -> Result<(), CombinedRouterError>;
+
+    /// Returns whether `operator` is approved to act on behalf of `owner`.
+    fn is_operator(e: Env, owner: Address, operator: Address) -> bool;
+
+    /// Adds liquidity to a token pair's pool, creating it if it doesn't exist. Ensures that exactly the desired amounts
+    /// of both tokens are added, subject to minimum requirements.
+    ///
+    /// This function is responsible for transferring tokens from the user to the pool and minting liquidity tokens in return.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `token_a` - The address of the first token to add liquidity for.
+    /// * `token_b` - The address of the second token to add liquidity for.
+    /// * `amount_a_desired` - The desired amount of the first token to add.
+    /// * `amount_b_desired` - The desired amount of the second token to add.
+    /// * `amount_a_min` - The minimum required amount of the first token to add.
+    /// * `amount_b_min` - The minimum required amount of the second token to add.
+    /// * `to` - The address where the liquidity tokens will be minted and sent.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A tuple containing the actual amounts of token A and B added to the pool, as well as the amount of liquidity tokens minted.
+    fn add_liquidity(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        amount_a_desired: i128,
+        amount_b_desired: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128, i128), CombinedRouterError>;
+
+    /// Adds liquidity like `add_liquidity`, within the bounds of `validity` as well as `deadline`.
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token to add liquidity for.
+    /// * `token_b` - The address of the second token to add liquidity for.
+    /// * `amount_a_desired` - The desired amount of the first token to add.
+    /// * `amount_b_desired` - The desired amount of the second token to add.
+    /// * `amount_a_min` - The minimum required amount of the first token to add.
+    /// * `amount_b_min` - The minimum required amount of the second token to add.
+    /// * `to` - The address that pays the tokens and where the liquidity tokens will be minted and sent.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// The amounts of token A and B added to the pool, and the amount of liquidity tokens minted.
+    fn add_liquidity_within(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        amount_a_desired: i128,
+        amount_b_desired: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<(i128, i128, i128), CombinedRouterError>;
+
+    /// Adds liquidity to a token pair's pool like `add_liquidity`, but the tokens are paid by `from`
+    /// and the liquidity tokens are minted to `to`. Only `from` needs to authorize the call.
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token to add liquidity for.
+    /// * `token_b` - The address of the second token to add liquidity for.
+    /// * `amount_a_desired` - The desired amount of the first token to add.
+    /// * `amount_b_desired` - The desired amount of the second token to add.
+    /// * `amount_a_min` - The minimum required amount of the first token to add.
+    /// * `amount_b_min` - The minimum required amount of the second token to add.
+    /// * `from` - The address that pays the tokens.
+    /// * `to` - The address where the liquidity tokens will be minted and sent.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A tuple containing the actual amounts of token A and B added to the pool, as well as the amount of liquidity tokens minted.
+    fn add_liquidity_from(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        amount_a_desired: i128,
+        amount_b_desired: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        from: Address,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<(i128, i128, i128), CombinedRouterError>;
+
+    /// Adds liquidity like `add_liquidity_from`, authorized by an `operator` that `from` approved with `set_operator`.
+    /// The router takes the tokens with `transfer_from`, so `from` must approve the router for both desired amounts.
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token to add liquidity for.
+    /// * `token_b` - The address of the second token to add liquidity for.
+    /// * `amount_a_desired` - The desired amount of the first token to add.
+    /// * `amount_b_desired` - The desired amount of the second token to add.
+    /// * `amount_a_min` - The minimum required amount of the first token to add.
+    /// * `amount_b_min` - The minimum required amount of the second token to add.
+    /// * `operator` - The address authorizing the call on behalf of `from`.
+    /// * `from` - The address that pays the tokens.
+    /// * `to` - The address where the liquidity tokens will be minted and sent.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// The amounts of token A and B added to the pool, and the amount of liquidity tokens minted.
+    fn add_liquidity_operator(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        amount_a_desired: i128,
+        amount_b_desired: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        operator: Address,
+        from: Address,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<(i128, i128, i128), CombinedRouterError>;
+
+    /// Removes liquidity from a token pair's pool.
+    ///
+    /// This function facilitates the removal of liquidity from a Soroswap Liquidity Pool by burning a specified amount
+    /// of Liquidity Pool tokens (`liquidity`) owned by the caller. In return, it transfers back the corresponding
+    /// amounts of the paired tokens (`token_a` and `token_b`) to the caller's specified address (`to`).
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token in the Liquidity Pool.
+    /// * `token_b` - The address of the second token in the Liquidity Pool.
+    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
+    /// * `amount_a_min` - The minimum required amount of the first token to receive.
+    /// * `amount_b_min` - The minimum required amount of the second token to receive.
+    /// * `to` - The address where the paired tokens will be sent to, and from where the LP tokens will be taken.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of `token_a` and `token_b` withdrawn from the pool.  
+    fn remove_liquidity(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128), CombinedRouterError>;
+
+    /// Removes liquidity like `remove_liquidity`, within the bounds of `validity` as well as `deadline`.
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token in the Liquidity Pool.
+    /// * `token_b` - The address of the second token in the Liquidity Pool.
+    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
+    /// * `amount_a_min` - The minimum required amount of the first token to receive.
+    /// * `amount_b_min` - The minimum required amount of the second token to receive.
+    /// * `to` - The address where the paired tokens will be sent to, and from where the LP tokens will be taken.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of `token_a` and `token_b` withdrawn from the pool.
+    fn remove_liquidity_within(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<(i128, i128), CombinedRouterError>;
+
+    /// Removes liquidity from a token pair's pool like `remove_liquidity`, but the LP tokens are taken from `from`
+    /// and the paired tokens are sent to `to`. Only `from` needs to authorize the call.
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token in the Liquidity Pool.
+    /// * `token_b` - The address of the second token in the Liquidity Pool.
+    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
+    /// * `amount_a_min` - The minimum required amount of the first token to receive.
+    /// * `amount_b_min` - The minimum required amount of the second token to receive.
+    /// * `from` - The address from where the LP tokens will be taken.
+    /// * `to` - The address where the paired tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of `token_a` and `token_b` withdrawn from the pool.
+    fn remove_liquidity_from(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        from: Address,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<(i128, i128), CombinedRouterError>;
+
+    /// Removes liquidity like `remove_liquidity_from`, authorized by an `operator` that `from` approved with `set_operator`.
+    /// The router takes the LP tokens with the pair's `transfer_from`, so `from` must approve the router for `liquidity`.
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token in the Liquidity Pool.
+    /// * `token_b` - The address of the second token in the Liquidity Pool.
+    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
+    /// * `amount_a_min` - The minimum required amount of the first token to receive.
+    /// * `amount_b_min` - The minimum required amount of the second token to receive.
+    /// * `operator` - The address authorizing the call on behalf of `from`.
+    /// * `from` - The address from where the LP tokens will be taken.
+    /// * `to` - The address where the paired tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of `token_a` and `token_b` withdrawn from the pool.
+    fn remove_liquidity_operator(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        operator: Address,
+        from: Address,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<(i128, i128), CombinedRouterError>;
+
+    /// Removes liquidity from a token pair's pool where any of the tokens may take a fee on transfer (fot).
+    ///
+    /// Works like `remove_liquidity`, but the minimum amounts are checked against what `to` actually
+    /// received, measured as the change in its token balances.
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token in the Liquidity Pool.
+    /// * `token_b` - The address of the second token in the Liquidity Pool.
+    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
+    /// * `amount_a_min` - The minimum required amount of the first token to receive.
+    /// * `amount_b_min` - The minimum required amount of the second token to receive.
+    /// * `to` - The address where the paired tokens will be sent to, and from where the LP tokens will be taken.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of `token_a` and `token_b` received by `to`.
+    fn remove_liquidity_fot(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<(i128, i128), CombinedRouterError>;
+
+    /// Swaps an exact amount of input tokens for as many output tokens as possible
+    /// along the specified trading route. The route is determined by the `path` vector,
+    /// where the first element is the input token, the last is the output token, 
+    /// and any intermediate elements represent pairs to trade through if a direct pair does not exist.
+    ///
+    /// # Arguments
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_tokens_for_tokens(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Swaps an exact amount of input tokens like `swap_exact_tokens_for_tokens`, within the bounds of `validity` as
+    /// well as `deadline`.
+    ///
+    /// # Arguments
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - The trading route, from the input token to the output token.
+    /// * `to` - The address that pays the input tokens and where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_tokens_within(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Swaps an exact amount of input tokens for as many output tokens as possible like `swap_exact_tokens_for_tokens`,
+    /// but the input tokens are paid by `from` and the output tokens are sent to `to`. Only `from` needs to authorize the call.
+    ///
+    /// # Arguments
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `from` - The address that pays the input tokens.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_tokens_from(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        from: Address,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Swaps an exact amount of input tokens like `swap_exact_tokens_from`, but the call is authorized by an `operator`
+    /// that `from` approved with `set_operator`. The router takes the input tokens with `transfer_from`, so `from` must
+    /// have approved the router to spend at least `amount_in`.
+    ///
+    /// # Arguments
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `operator` - The address authorizing the call on behalf of `from`.
+    /// * `from` - The address that pays the input tokens.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_tokens_operator(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        operator: Address,
+        from: Address,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Swaps an exact amount of input tokens like `swap_exact_tokens_for_tokens`, paying a referrer a fee of at most
+    /// the `max_referral_fee` of the factory. An input token fee is taken from `amount_in` before the swap. An output
+    /// token fee is taken from the swap output, and `amount_out_min` applies to what `to` receives.
+    ///
+    /// # Arguments
+    /// * `amount_in` - The exact amount of input tokens to be sent from `to`, including an input token fee.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `to` - The address that pays the input tokens and where the output tokens will be sent to.
+    /// * `referral` - The referrer, its fee in basis points and the fee token.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// The amounts swapped at each step of the trading route, without the referral fee.
+    fn swap_exact_tokens_referral(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        referral: Referral,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Swaps tokens for an exact amount of output token, following the specified trading route.
+    /// The route is determined by the `path` vector, where the first element is the input token,
+    /// the last is the output token, and any intermediate elements represent pairs to trade through.
+    ///
+    /// # Arguments
+    /// * `amount_out` - The exact amount of output token to be received.
+    /// * `amount_in_max` - The maximum allowed amount of input tokens to be swapped.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_tokens_for_exact_tokens(
+        e: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Swaps tokens for an exact amount of output token like `swap_tokens_for_exact_tokens`, within the bounds of
+    /// `validity` as well as `deadline`.
+    ///
+    /// # Arguments
+    /// * `amount_out` - The exact amount of output token to be received.
+    /// * `amount_in_max` - The maximum allowed amount of input tokens to be swapped.
+    /// * `path` - The trading route, from the input token to the output token.
+    /// * `to` - The address that pays the input tokens and where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_for_exact_tokens_within(
+        e: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Swaps tokens for an exact amount of output token like `swap_tokens_for_exact_tokens`, but the input tokens
+    /// are paid by `from` and the output tokens are sent to `to`. Only `from` needs to authorize the call.
+    ///
+    /// # Arguments
+    /// * `amount_out` - The exact amount of output token to be received.
+    /// * `amount_in_max` - The maximum allowed amount of input tokens to be swapped.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `from` - The address that pays the input tokens.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_for_exact_tokens_from(
+        e: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        from: Address,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Swaps tokens for an exact amount of output token like `swap_for_exact_tokens_from`, but the call is authorized by
+    /// an `operator` that `from` approved with `set_operator`. The router takes the input tokens with `transfer_from`,
+    /// so `from` must have approved the router to spend the input amount.
+    ///
+    /// # Arguments
+    /// * `amount_out` - The exact amount of output token to be received.
+    /// * `amount_in_max` - The maximum allowed amount of input tokens to be swapped.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `operator` - The address authorizing the call on behalf of `from`.
+    /// * `from` - The address that pays the input tokens.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_for_exact_tokens_operator(
+        e: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        operator: Address,
+        from: Address,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Swaps tokens for an exact amount of output token like `swap_tokens_for_exact_tokens`, paying a referrer a fee of
+    /// at most the `max_referral_fee` of the factory on top of it. An input token fee counts towards `amount_in_max`.
+    /// For an output token fee, the swap buys the fee in addition to `amount_out`.
+    ///
+    /// # Arguments
+    /// * `amount_out` - The exact amount of output token to be received by `to`.
+    /// * `amount_in_max` - The maximum allowed amount of input tokens to be sent, including an input token fee.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `to` - The address that pays the input tokens and where the output tokens will be sent to.
+    /// * `referral` - The referrer, its fee in basis points and the fee token.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// The amounts swapped at each step of the trading route, without the referral fee.
+    fn swap_for_exact_tokens_referral(
+        e: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        referral: Referral,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Swaps the largest part of `amount_in_max` that keeps the execution price of the whole route at or above
+    /// `min_price`, instead of reverting when the price is not met. The rest stays with `to`, and nothing is
+    /// swapped if no input reaches `min_price`.
+    ///
+    /// # Arguments
+    /// * `amount_in_max` - The maximum amount of input tokens to be sent from `to`.
+    /// * `min_price` - The minimum execution price, as output tokens per input token with 14 decimals
+    ///                 (`PRICE_PRECISION`), like the prices of `router_quote_detailed`.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `to` - The address that pays the input tokens and where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A tuple containing the amount of input tokens swapped, the amount of input tokens left unswapped and the
+    /// amount of output tokens received.
+    fn swap_exact_tokens_price_limit(
+        e: Env,
+        amount_in_max: i128,
+        min_price: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<(i128, i128, i128), CombinedRouterError>;
+
+    /// Swaps an exact amount of input tokens like `swap_exact_tokens_for_tokens`, sends the output to the `target`
+    /// contract and calls its `soroswap_router_call` with the output token, the output amount and `data`, so it can
+    /// use the tokens right away. If the call fails, the swap is reverted.
+    ///
+    /// # Arguments
+    /// * `amount_in` - The exact amount of input tokens to be sent from `from`.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `from` - The address that pays the input tokens.
+    /// * `target` - The contract where the output tokens will be sent to, and that is called afterwards.
+    /// * `data` - An opaque payload forwarded to `soroswap_router_call`.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_and_call(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        from: Address,
+        target: Address,
+        data: Bytes,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Swaps an exact amount of input tokens for as many output tokens as possible along the specified
+    /// trading route, like `swap_exact_tokens_for_tokens`, but each hop goes through the pair given
+    /// in `hops`.
+    ///
+    /// # Arguments
+    /// * `amount_in` - The exact amount of input tokens to be sent from `to`.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `hops` - The pair of each hop, such as `Kind(Stable)` or `Variant(Stable, stable_1)`, with one element less than `path`.
+    /// * `to` - The address that pays the input tokens and where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_tokens_route(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        hops: Vec<RouteHop>,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Swaps tokens for an exact amount of output tokens along the specified trading route, like
+    /// `swap_tokens_for_exact_tokens`, but each hop goes through the pair given in `hops`.
+    ///
+    /// # Arguments
+    /// * `amount_out` - The exact amount of output tokens to receive.
+    /// * `amount_in_max` - The maximum allowed amount of input tokens to be sent from `to`.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `hops` - The pair of each hop, such as `Kind(Stable)` or `Variant(Stable, stable_1)`, with one element less than `path`.
+    /// * `to` - The address that pays the input tokens and where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_for_exact_tokens_route(
+        e: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        hops: Vec<RouteHop>,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Swaps an exact amount of input tokens for as many output tokens as possible along the specified
+    /// trading route, supporting tokens that take a fee on transfer (fot).
+    ///
+    /// Each hop swaps whatever the pair actually received, and `amount_out_min` is checked against the
+    /// change in the output token balance of `to`.
+    ///
+    /// # Arguments
+    /// * `amount_in` - The exact amount of input tokens to be sent from `to`.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// The amount of output tokens received by `to`.
+    fn swap_exact_tokens_for_tokens_fot(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<i128, CombinedRouterError>;
+
+    /// Swaps exact amounts of input tokens for as many output tokens as possible, splitting the trade
+    /// across several trading routes to reduce its price impact. Every leg is executed in order with the
+    /// reserves left by the previous legs, and `amount_out_min` is checked against the sum of the outputs
+    /// of all legs.
+    ///
+    /// # Arguments
+    /// * `distribution` - A vector of legs, each one with its trading route and the exact amount of input
+    ///                    tokens to swap through it. Every route must start with the same input token and
+    ///                    end with the same output token.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive across all legs.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// For every leg, a vector containing the amounts of tokens received at each step of its trading route.
+    fn swap_exact_tokens_split(
+        e: Env,
+        distribution: Vec<(Vec<Address>, i128)>,
+        amount_out_min: i128,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<Vec<i128>>, CombinedRouterError>;
+
+    /// Executes a batch of router operations atomically, in order. If any of them fails, none of them is executed.
+    ///
+    /// All the tokens are paid by `from`, which authorizes the whole batch once, and `deadline` is checked
+    /// for the whole batch. The amounts an operation takes can be the output of an earlier operation
+    /// (`OpAmount::Output`), so a position can be rebalanced with a `RemoveLiquidity`, a `SwapExactTokens`
+    /// and an `AddLiquidity` without the market moving in between. Such operations should send their
+    /// output tokens to `from`, so the later operations can pay with them.
+    ///
+    /// # Arguments
+    /// * `ops` - The operations to execute.
+    /// * `from` - The address that pays the tokens of every operation.
+    /// * `deadline` - The deadline for executing the batch.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// The result of every operation, in order: the values returned by the matching router function, as a `Vec`.
+    fn multicall(
+        e: Env,
+        ops: Vec<RouterOp>,
+        from: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<Vec<i128>>, CombinedRouterError>;
+
+    /// Adds liquidity to a pair's pool using only one of its tokens. The optimal fraction of `amount_in` is
+    /// swapped through the same pair for `other_token`, and both amounts are then deposited in the pair.
+    /// Because of rounding, a tiny amount of one of the tokens may not be deposited and stays with `to`.
+    ///
+    /// # Arguments
+    /// * `token_in` - The address of the token to be zapped.
+    /// * `amount_in` - The total amount of `token_in` to be used.
+    /// * `other_token` - The address of the other token of the pair.
+    /// * `liquidity_min` - The minimum amount of liquidity tokens to receive.
+    /// * `to` - The address that sends `token_in` and receives the liquidity tokens.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A tuple containing the leftover amounts of `token_in` and `other_token` that were not deposited, and the
+    /// amount of liquidity tokens minted.
+    fn zap_in(
+        e: Env,
+        token_in: Address,
+        amount_in: i128,
+        other_token: Address,
+        liquidity_min: i128,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<(i128, i128, i128), CombinedRouterError>;
+
+    /// Removes liquidity from a pair's pool and swaps the withdrawn `other_token` through the same pair,
+    /// so that `to` receives only `token_out`.
+    ///
+    /// # Arguments
+    /// * `token_out` - The address of the token to be received.
+    /// * `other_token` - The address of the other token of the pair.
+    /// * `liquidity` - The amount of liquidity tokens to remove.
+    /// * `amount_out_min` - The minimum required amount of `token_out` to receive.
+    /// * `to` - The address that sends the liquidity tokens and receives `token_out`.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// The total amount of `token_out` received.
+    fn zap_out(
+        e: Env,
+        token_out: Address,
+        other_token: Address,
+        liquidity: i128,
+        amount_out_min: i128,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<i128, CombinedRouterError>;
+
+    /*  *** Read only functions: *** */
+
+    /// This function retrieves the factory contract's address associated with the provided environment.
+    /// It also checks if the factory has been initialized and raises an assertion error if not.
+    /// If the factory is not initialized, this code will raise an assertion error with the message "SoroswapRouter: not yet initialized".
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    fn get_factory(e: Env) -> Result<Address, CombinedRouterError>;
+
+    /*
+    LIBRARY FUNCTIONS:
+    */
+
+    /// Calculates the deterministic address for a pair without making any external calls.
+    /// check <https://github.com/paltalabs/deterministic-address-soroban>
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the pair, and `Err` indicates an error such as identical tokens or an issue with sorting.
+    fn router_pair_for(e: Env, token_a: Address, token_b: Address) -> Result<Address, CombinedRouterError>;
+
+    /// Calculates the deterministic address for the pair of the given variant without making any external calls.
+    /// check <https://github.com/paltalabs/deterministic-address-soroban>
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    /// * `variant` - The variant of the pair, such as `cp`, `stable` or a fee tier.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the pair, and `Err` indicates an error such as identical tokens or an issue with sorting.
+    fn router_pair_for_variant(e: Env, token_a: Address, token_b: Address, variant: Symbol) -> Result<Address, CombinedRouterError>;
+
+    /// Given some amount of an asset and pair reserves, returns an equivalent amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_a` - The amount of the first asset.
+    /// * `reserve_a` - Reserves of the first asset in the pair.
+    /// * `reserve_b` - Reserves of the second asset in the pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated equivalent amount, and `Err` indicates an error such as insufficient amount or liquidity
+    fn router_quote(amount_a: i128, reserve_a: i128, reserve_b: i128) -> Result<i128, CombinedRouterError>;
+
+    /// Given an input amount of an asset and pair reserves, returns the maximum output amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount or liquidity.
+    fn router_get_amount_out(amount_in: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, CombinedRouterError>;
+
+    /// Given an output amount of an asset and pair reserves, returns a required input amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount or liquidity.
+    fn router_get_amount_in(amount_out: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, CombinedRouterError>;
+
+    /// Performs chained get_amount_out calculations on any number of pairs.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn router_get_amounts_out(e: Env, amount_in: i128, path: Vec<Address>) -> Result<Vec<i128>, CombinedRouterError>;
+    
+    /// Performs chained get_amount_in calculations on any number of pairs.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `amount_out` - The output amount.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn router_get_amounts_in(e: Env, amount_out: i128, path: Vec<Address>) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Performs chained get_amount_out calculations along a path where every hop goes through the pair of the
+    /// given kind, a constant product, a stable or a weighted pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `hops` - The pair of each hop, with one element less than `path`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, CombinedRouterError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn router_get_amounts_out_route(e: Env, amount_in: i128, path: Vec<Address>, hops: Vec<RouteHop>) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Performs chained get_amount_in calculations along a path where every hop goes through the pair of the
+    /// given kind, a constant product, a stable or a weighted pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `amount_out` - The output amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `hops` - The pair of each hop, with one element less than `path`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, CombinedRouterError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn router_get_amounts_in_route(e: Env, amount_out: i128, path: Vec<Address>, hops: Vec<RouteHop>) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Quotes a trade along a path and returns the breakdown of every hop: the pair address, its reserves
+    /// before and after the trade, the fee charged, the mid and execution prices (fixed point with 14
+    /// decimals) and the price impact in basis points.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `amount` - The exact input amount if `exact_in` is true, otherwise the exact output amount.
     /// * `path` - Vector of token addresses representing the path.
+    /// * `exact_in` - Whether `amount` is the input or the output of the trade.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<HopQuote>, CombinedRouterError>` where `Ok` contains the breakdown of each hop, and `Err` indicates an error such as an invalid path.
+    fn router_quote_detailed(e: Env, amount: i128, path: Vec<Address>, exact_in: bool) -> Result<Vec<HopQuote>, CombinedRouterError>;
+
+    
+
+}
+
+#[contract]
+struct SoroswapRouter;
+
+#[contractimpl]
+impl SoroswapRouterTrait for SoroswapRouter {
+    /// Initializes the contract and sets the factory address
+    fn initialize(e: Env, factory: Address) -> Result<(), CombinedRouterError> {
+        if !has_factory(&e) {
+            put_factory(&e, &factory);
+            event::initialized(&e, factory);
+            extend_instance_ttl(&e);
+            Ok(())
+        } else {
+            Err(SoroswapRouterError::InitializeAlreadyInitialized.into())
+        } 
+        
+    }  
+
+    /// Adds liquidity to a token pair's pool, creating it if it doesn't exist. Ensures that exactly the desired amounts
+    /// of both tokens are added, subject to minimum requirements.
+    /// This function is responsible for transferring tokens from the user to the pool and minting liquidity tokens in return.
+    /// # Arguments
+    /// * `token_a` - The address of the first token to add liquidity for.
+    /// * `token_b` - The address of the second token to add liquidity for.
+    /// * `amount_a_desired` - The desired amount of the first token to add.
+    /// * `amount_b_desired` - The desired amount of the second token to add.
+    /// * `amount_a_min` - The minimum required amount of the first token to add.
+    /// * `amount_b_min` - The minimum required amount of the second token to add.
+    /// * `to` - The address where the liquidity tokens will be minted and sent.
+    /// * `deadline` - The deadline for executing the operation.
+    /// # Returns
+    /// A tuple containing: amounts of token A and B added to the pool.
+    /// plus the amount of liquidity tokens minted.
+    fn add_liquidity(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        amount_a_desired: i128,
+        amount_b_desired: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128, i128), CombinedRouterError> {
+        execute_add_liquidity(e, token_a, token_b, amount_a_desired, amount_b_desired, amount_a_min, amount_b_min, Payer::Owner(to.clone()), to, &Validity::deadline(deadline))
+    }
+
+    /// Adds liquidity like `add_liquidity`, within the bounds of `validity` as well as `deadline`.
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token to add liquidity for.
+    /// * `token_b` - The address of the second token to add liquidity for.
+    /// * `amount_a_desired` - The desired amount of the first token to add.
+    /// * `amount_b_desired` - The desired amount of the second token to add.
+    /// * `amount_a_min` - The minimum required amount of the first token to add.
+    /// * `amount_b_min` - The minimum required amount of the second token to add.
+    /// * `to` - The address that pays the tokens and where the liquidity tokens will be minted and sent.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// The amounts of token A and B added to the pool, and the amount of liquidity tokens minted.
+    fn add_liquidity_within(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        amount_a_desired: i128,
+        amount_b_desired: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<(i128, i128, i128), CombinedRouterError> {
+        execute_add_liquidity(e, token_a, token_b, amount_a_desired, amount_b_desired, amount_a_min, amount_b_min, Payer::Owner(to.clone()), to, &Validity::window(deadline, validity))
+    }
+
+    /// Approves or revokes an `operator` that may authorize the `*_operator` functions on behalf of `owner`.
+    /// The operator can only spend what `owner` approved the router to spend on each token.
+    ///
+    /// # Arguments
+    /// * `owner` - The address whose router allowances the operator may spend.
+    /// * `operator` - The address to approve or revoke.
+    /// * `approved` - Whether the operator is approved.
+    fn set_operator(e: Env, owner: Address, operator: Address, approved: bool) -> Result<(), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        owner.require_auth();
+
+        put_operator(&e, &owner, &operator, approved);
+        event::set_operator(&e, owner, operator, approved);
+        Ok(())
+    }
+
+    /// Returns whether `operator` is approved to act on behalf of `owner`.
+    fn is_operator(e: Env, owner: Address, operator: Address) -> bool {
+        is_operator(&e, &owner, &operator)
+    }
+
+    /// Adds liquidity to a token pair's pool like `add_liquidity`, but the tokens are paid by `from`
+    /// and the liquidity tokens are minted to `to`. Only `from` needs to authorize the call.
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token to add liquidity for.
+    /// * `token_b` - The address of the second token to add liquidity for.
+    /// * `amount_a_desired` - The desired amount of the first token to add.
+    /// * `amount_b_desired` - The desired amount of the second token to add.
+    /// * `amount_a_min` - The minimum amount of the first token to add.
+    /// * `amount_b_min` - The minimum amount of the second token to add.
+    /// * `from` - The address that pays the tokens.
+    /// * `to` - The address where the liquidity tokens will be minted and sent.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A tuple containing the actual amounts of token A and B added to the pool, as well as the amount of liquidity tokens minted.
+    fn add_liquidity_from(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        amount_a_desired: i128,
+        amount_b_desired: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        from: Address,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<(i128, i128, i128), CombinedRouterError> {
+        execute_add_liquidity(e, token_a, token_b, amount_a_desired, amount_b_desired, amount_a_min, amount_b_min, Payer::Owner(from), to, &Validity::window(deadline, validity))
+    }
+
+    /// Adds liquidity like `add_liquidity_from`, authorized by an `operator` that `from` approved with `set_operator`.
+    /// The router takes the tokens with `transfer_from`, so `from` must approve the router for both desired amounts.
+    ///
+    /// # Arguments
+    /// * `token_a` - The first token of the pair.
+    /// * `token_b` - The second token of the pair.
+    /// * `amount_a_desired` - The desired amount of the first token to add.
+    /// * `amount_b_desired` - The desired amount of the second token to add.
+    /// * `amount_a_min` - The minimum amount of the first token to add.
+    /// * `amount_b_min` - The minimum amount of the second token to add.
+    /// * `operator` - The address authorizing the call on behalf of `from`.
+    /// * `from` - The address that pays the tokens.
+    /// * `to` - The address that receives the liquidity tokens.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// The amounts of token A and B added to the pool, and the amount of liquidity tokens minted.
+    fn add_liquidity_operator(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        amount_a_desired: i128,
+        amount_b_desired: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        operator: Address,
+        from: Address,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<(i128, i128, i128), CombinedRouterError> {
+        execute_add_liquidity(e, token_a, token_b, amount_a_desired, amount_b_desired, amount_a_min, amount_b_min, Payer::Allowance { operator, owner: from }, to, &Validity::window(deadline, validity))
+    }
+
+    /// Removes liquidity from a token pair's pool.
+    ///
+    /// This function facilitates the removal of liquidity from a Soroswap Liquidity Pool by burning a specified amount
+    /// of Liquidity Pool tokens (`liquidity`) owned by the caller. In return, it transfers back the corresponding
+    /// amounts of the paired tokens (`token_a` and `token_b`) to the caller's specified address (`to`).
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token in the Liquidity Pool.
+    /// * `token_b` - The address of the second token in the Liquidity Pool.
+    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
+    /// * `amount_a_min` - The minimum required amount of the first token to receive.
+    /// * `amount_b_min` - The minimum required amount of the second token to receive.
+    /// * `to` - The address where the paired tokens will be sent to, and from where the LP tokens will be taken.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of `token_a` and `token_b` withdrawn from the pool.
+    fn remove_liquidity(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128), CombinedRouterError> {
+        execute_remove_liquidity(e, token_a, token_b, liquidity, amount_a_min, amount_b_min, Payer::Owner(to.clone()), to, &Validity::deadline(deadline))
+    }
+
+    /// Removes liquidity like `remove_liquidity`, within the bounds of `validity` as well as `deadline`.
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token in the Liquidity Pool.
+    /// * `token_b` - The address of the second token in the Liquidity Pool.
+    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
+    /// * `amount_a_min` - The minimum required amount of the first token to receive.
+    /// * `amount_b_min` - The minimum required amount of the second token to receive.
+    /// * `to` - The address where the paired tokens will be sent to, and from where the LP tokens will be taken.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of `token_a` and `token_b` withdrawn from the pool.
+    fn remove_liquidity_within(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<(i128, i128), CombinedRouterError> {
+        execute_remove_liquidity(e, token_a, token_b, liquidity, amount_a_min, amount_b_min, Payer::Owner(to.clone()), to, &Validity::window(deadline, validity))
+    }
+
+    /// Removes liquidity from a token pair's pool like `remove_liquidity`, but the LP tokens are taken from `from`
+    /// and the paired tokens are sent to `to`. Only `from` needs to authorize the call.
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token in the Liquidity Pool.
+    /// * `token_b` - The address of the second token in the Liquidity Pool.
+    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
+    /// * `amount_a_min` - The minimum required amount of the first token to receive.
+    /// * `amount_b_min` - The minimum required amount of the second token to receive.
+    /// * `from` - The address from where the LP tokens will be taken.
+    /// * `to` - The address where the paired tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of `token_a` and `token_b` withdrawn from the pool.
+    fn remove_liquidity_from(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        from: Address,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<(i128, i128), CombinedRouterError> {
+        execute_remove_liquidity(e, token_a, token_b, liquidity, amount_a_min, amount_b_min, Payer::Owner(from), to, &Validity::window(deadline, validity))
+    }
+
+    /// Removes liquidity like `remove_liquidity_from`, authorized by an `operator` that `from` approved with `set_operator`.
+    /// The router takes the LP tokens with the pair's `transfer_from`, so `from` must approve the router for `liquidity`.
+    ///
+    /// # Arguments
+    /// * `token_a` - The first token of the pair.
+    /// * `token_b` - The address of the second token in the Liquidity Pool.
+    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
+    /// * `amount_a_min` - The minimum amount of the first token to receive.
+    /// * `amount_b_min` - The minimum amount of the second token to receive.
+    /// * `operator` - The address authorizing the call on behalf of `from`.
+    /// * `from` - The address from where the LP tokens will be taken.
+    /// * `to` - The address where the paired tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
     ///
     /// # Returns
+    /// A tuple containing the amounts of `token_a` and `token_b` withdrawn from the pool.
+    fn remove_liquidity_operator(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        operator: Address,
+        from: Address,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<(i128, i128), CombinedRouterError> {
+        execute_remove_liquidity(e, token_a, token_b, liquidity, amount_a_min, amount_b_min, Payer::Allowance { operator, owner: from }, to, &Validity::window(deadline, validity))
+    }
+
+    /// Removes liquidity from a token pair's pool where any of the tokens may take a fee on transfer (fot).
     ///
-    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
-    fn router_get_amounts_in(e: Env, amount_out: i128, path: Vec<Address>) -> Result<Vec<i128>, CombinedRouterError>;
+    /// Works like `remove_liquidity`, but the minimum amounts are checked against what `to` actually
+    /// received, measured as the change in its token balances.
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token in the Liquidity Pool.
+    /// * `token_b` - The address of the second token in the Liquidity Pool.
+    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
+    /// * `amount_a_min` - The minimum required amount of the first token to receive.
+    /// * `amount_b_min` - The minimum required amount of the second token to receive.
+    /// * `to` - The address where the paired tokens will be sent to, and from where the LP tokens will be taken.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of `token_a` and `token_b` received by `to`.
+    fn remove_liquidity_fot(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<(i128, i128), CombinedRouterError> {
+        execute_remove_liquidity_fot(e, token_a, token_b, liquidity, amount_a_min, amount_b_min, to, &Validity::window(deadline, validity))
+    }
 
-    
+    /// Swaps an exact amount of input tokens for as many output tokens as possible
+    /// along the specified trading route. The route is determined by the `path` vector,
+    /// where the first element is the input token, the last is the output token, 
+    /// and any intermediate elements represent pairs to trade through if a direct pair does not exist.
+    ///
+    /// # Arguments
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_tokens_for_tokens(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        execute_swap_exact_tokens_for_tokens(e, amount_in, amount_out_min, path, Payer::Owner(to.clone()), to, None, &Validity::deadline(deadline))
+    }
 
-}
+    /// Swaps an exact amount of input tokens like `swap_exact_tokens_for_tokens`, within the bounds of `validity` as
+    /// well as `deadline`.
+    ///
+    /// # Arguments
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - The trading route, from the input token to the output token.
+    /// * `to` - The address that pays the input tokens and where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_tokens_within(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        execute_swap_exact_tokens_for_tokens(e, amount_in, amount_out_min, path, Payer::Owner(to.clone()), to, None, &Validity::window(deadline, validity))
+    }
 
-#[contract]
-struct SoroswapRouter;
+    /// Swaps an exact amount of input tokens for as many output tokens as possible like `swap_exact_tokens_for_tokens`,
+    /// but the input tokens are paid by `from` and the output tokens are sent to `to`. Only `from` needs to authorize the call.
+    ///
+    /// # Arguments
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `from` - The address that pays the input tokens.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_tokens_from(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        from: Address,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        execute_swap_exact_tokens_for_tokens(e, amount_in, amount_out_min, path, Payer::Owner(from), to, None, &Validity::window(deadline, validity))
+    }
 
-#[contractimpl]
-impl SoroswapRouterTrait for SoroswapRouter {
-    /// Initializes the contract and sets the factory address
-    fn initialize(e: Env, factory: Address) -> Result<(), CombinedRouterError> {
-        if !has_factory(&e) {
-            put_factory(&e, &factory);
-            event::initialized(&e, factory);
-            extend_instance_ttl(&e);
-            Ok(())
-        } else {
-            Err(SoroswapRouterError::InitializeAlreadyInitialized.into())
-        } 
-        
-    }  
+    /// Swaps an exact amount of input tokens like `swap_exact_tokens_from`, but the call is authorized by an `operator`
+    /// that `from` approved with `set_operator`. The router takes the input tokens with `transfer_from`, so `from` must
+    /// have approved the router to spend at least `amount_in`.
+    ///
+    /// # Arguments
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - The trading route, from the input token to the output token.
+    /// * `operator` - The address authorizing the call on behalf of `from`.
+    /// * `from` - The address that pays the input tokens.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_tokens_operator(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        operator: Address,
+        from: Address,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        execute_swap_exact_tokens_for_tokens(e, amount_in, amount_out_min, path, Payer::Allowance { operator, owner: from }, to, None, &Validity::window(deadline, validity))
+    }
 
-    /// Adds liquidity to a token pair's pool, creating it if it doesn't exist. Ensures that exactly the desired amounts
-    /// of both tokens are added, subject to minimum requirements.
-    /// This function is responsible for transferring tokens from the user to the pool and minting liquidity tokens in return.
+    /// Swaps an exact amount of input tokens like `swap_exact_tokens_for_tokens`, paying a referrer a fee of at most
+    /// the `max_referral_fee` of the factory. An input token fee is taken from `amount_in` before the swap. An output
+    /// token fee is taken from the swap output, and `amount_out_min` applies to what `to` receives.
+    ///
     /// # Arguments
-    /// * `token_a` - The address of the first token to add liquidity for.
-    /// * `token_b` - The address of the second token to add liquidity for.
-    /// * `amount_a_desired` - The desired amount of the first token to add.
-    /// * `amount_b_desired` - The desired amount of the second token to add.
-    /// * `amount_a_min` - The minimum required amount of the first token to add.
-    /// * `amount_b_min` - The minimum required amount of the second token to add.
-    /// * `to` - The address where the liquidity tokens will be minted and sent.
+    /// * `amount_in` - The exact amount of input tokens to be sent from `to`, including an input token fee.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - The trading route, from the input token to the output token.
+    /// * `to` - The address that pays the input tokens and where the output tokens will be sent to.
+    /// * `referral` - The referrer, its fee in basis points and the fee token.
     /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
     /// # Returns
-    /// A tuple containing: amounts of token A and B added to the pool.
-    /// plus the amount of liquidity tokens minted.
-    fn add_liquidity(
+    /// The amounts swapped at each step of the trading route, without the referral fee.
+    fn swap_exact_tokens_referral(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        referral: Referral,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        execute_swap_exact_tokens_for_tokens(e, amount_in, amount_out_min, path, Payer::Owner(to.clone()), to, Some(referral), &Validity::window(deadline, validity))
+    }
+
+    /// Swaps tokens for an exact amount of output token, following the specified trading route.
+    /// The route is determined by the `path` vector, where the first element is the input token,
+    /// the last is the output token, and any intermediate elements represent pairs to trade through.
+    ///
+    /// # Arguments
+    /// * `amount_out` - The exact amount of output token to be received.
+    /// * `amount_in_max` - The maximum allowed amount of input tokens to be swapped.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_tokens_for_exact_tokens(
+        e: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        execute_swap_tokens_for_exact_tokens(e, amount_out, amount_in_max, path, Payer::Owner(to.clone()), to, None, &Validity::deadline(deadline))
+    }
+
+    /// Swaps tokens for an exact amount of output token like `swap_tokens_for_exact_tokens`, within the bounds of
+    /// `validity` as well as `deadline`.
+    ///
+    /// # Arguments
+    /// * `amount_out` - The exact amount of output token to be received.
+    /// * `amount_in_max` - The maximum allowed amount of input tokens to be swapped.
+    /// * `path` - The trading route, from the input token to the output token.
+    /// * `to` - The address that pays the input tokens and where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_for_exact_tokens_within(
+        e: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        execute_swap_tokens_for_exact_tokens(e, amount_out, amount_in_max, path, Payer::Owner(to.clone()), to, None, &Validity::window(deadline, validity))
+    }
+
+    /// Swaps tokens for an exact amount of output token like `swap_tokens_for_exact_tokens`, but the input tokens
+    /// are paid by `from` and the output tokens are sent to `to`. Only `from` needs to authorize the call.
+    ///
+    /// # Arguments
+    /// * `amount_out` - The exact amount of output token to be received.
+    /// * `amount_in_max` - The maximum allowed amount of input tokens to be swapped.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `from` - The address that pays the input tokens.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_for_exact_tokens_from(
+        e: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        from: Address,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        execute_swap_tokens_for_exact_tokens(e, amount_out, amount_in_max, path, Payer::Owner(from), to, None, &Validity::window(deadline, validity))
+    }
+
+    /// Swaps tokens for an exact amount of output token like `swap_for_exact_tokens_from`, but the call is authorized by
+    /// an `operator` that `from` approved with `set_operator`. The router takes the input tokens with `transfer_from`,
+    /// so `from` must have approved the router to spend the input amount.
+    ///
+    /// # Arguments
+    /// * `amount_out` - The exact amount of output token to be received.
+    /// * `amount_in_max` - The maximum allowed amount of input tokens to be swapped.
+    /// * `path` - The trading route, from the input token to the output token.
+    /// * `operator` - The address authorizing the call on behalf of `from`.
+    /// * `from` - The address that pays the input tokens.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_for_exact_tokens_operator(
+        e: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        operator: Address,
+        from: Address,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        execute_swap_tokens_for_exact_tokens(e, amount_out, amount_in_max, path, Payer::Allowance { operator, owner: from }, to, None, &Validity::window(deadline, validity))
+    }
+
+    /// Swaps tokens for an exact amount of output token like `swap_tokens_for_exact_tokens`, paying a referrer a fee of
+    /// at most the `max_referral_fee` of the factory on top of it. An input token fee counts towards `amount_in_max`.
+    /// For an output token fee, the swap buys the fee in addition to `amount_out`.
+    ///
+    /// # Arguments
+    /// * `amount_out` - The exact amount of output token to be received by `to`.
+    /// * `amount_in_max` - The maximum allowed amount of input tokens to be sent, including an input token fee.
+    /// * `path` - The trading route, from the input token to the output token.
+    /// * `to` - The address that pays the input tokens and where the output tokens will be sent to.
+    /// * `referral` - The referrer, its fee in basis points and the fee token.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// The amounts swapped at each step of the trading route, without the referral fee.
+    fn swap_for_exact_tokens_referral(
+        e: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        referral: Referral,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        execute_swap_tokens_for_exact_tokens(e, amount_out, amount_in_max, path, Payer::Owner(to.clone()), to, Some(referral), &Validity::window(deadline, validity))
+    }
+
+    /// Swaps the largest part of `amount_in_max` that keeps the execution price of the whole route at or above
+    /// `min_price`, instead of reverting when the price is not met. The rest stays with `to`, and nothing is
+    /// swapped if no input reaches `min_price`.
+    ///
+    /// # Arguments
+    /// * `amount_in_max` - The maximum amount of input tokens to be sent from `to`.
+    /// * `min_price` - The minimum execution price, as output tokens per input token with 14 decimals
+    ///                 (`PRICE_PRECISION`), like the prices of `router_quote_detailed`.
+    /// * `path` - The trading route, from the input token to the output token.
+    /// * `to` - The address that pays the input tokens and where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A tuple containing the amount of input tokens swapped, the amount of input tokens left unswapped and the
+    /// amount of output tokens received.
+    fn swap_exact_tokens_price_limit(
         e: Env,
-        token_a: Address,
-        token_b: Address,
-        amount_a_desired: i128,
-        amount_b_desired: i128,
-        amount_a_min: i128,
-        amount_b_min: i128,
+        amount_in_max: i128,
+        min_price: i128,
+        path: Vec<Address>,
         to: Address,
         deadline: u64,
+        validity: Option<Validity>,
     ) -> Result<(i128, i128, i128), CombinedRouterError> {
-        check_initialized(&e)?;
-        check_nonnegative_amount(amount_a_desired)?;
-        check_nonnegative_amount(amount_b_desired)?;
-        check_nonnegative_amount(amount_a_min)?;
-        check_nonnegative_amount(amount_b_min)?;
-        extend_instance_ttl(&e);
-        to.require_auth();
-        ensure_deadline(&e, deadline)?;
-
-        let factory = get_factory(&e);
-
-        let (amount_a, amount_b) = add_liquidity_amounts(
-            e.clone(),
-            factory.clone(),
-            token_a.clone(),
-            token_b.clone(),
-            amount_a_desired,
-            amount_b_desired,
-            amount_a_min,
-            amount_b_min,
-        )?;
-
-        let pair: Address = soroswap_library::pair_for(
-            e.clone(),
-            factory,
-            token_a.clone(),
-            token_b.clone(),
-        ).map_err(SoroswapLibraryError::from)?;
-
-        TokenClient::new(&e, &token_a).transfer(&to, &pair, &amount_a);
-        TokenClient::new(&e, &token_b).transfer(&to, &pair, &amount_b);
-
-        let liquidity = SoroswapPairClient::new(&e, &pair).deposit(&to);
-
-        event::add_liquidity(
-            &e,
-            token_a,
-            token_b,
-            pair,
-            amount_a,
-            amount_b,
-            liquidity,
-            to);
-            
-        Ok((amount_a, amount_b, liquidity))
+        execute_swap_exact_tokens_price_limit(e, amount_in_max, min_price, path, Payer::Owner(to.clone()), to, &Validity::window(deadline, validity))
     }
 
-    /// Removes liquidity from a token pair's pool.
-    ///
-    /// This function facilitates the removal of liquidity from a Soroswap Liquidity Pool by burning a specified amount
-    /// of Liquidity Pool tokens (`liquidity`) owned by the caller. In return, it transfers back the corresponding
-    /// amounts of the paired tokens (`token_a` and `token_b`) to the caller's specified address (`to`).
+    /// Swaps an exact amount of input tokens like `swap_exact_tokens_for_tokens`, sends the output to the `target`
+    /// contract and calls its `soroswap_router_call` with the output token, the output amount and `data`, so it can
+    /// use the tokens right away. If the call fails, the swap is reverted.
     ///
     /// # Arguments
-    /// * `token_a` - The address of the first token in the Liquidity Pool.
-    /// * `token_b` - The address of the second token in the Liquidity Pool.
-    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
-    /// * `amount_a_min` - The minimum required amount of the first token to receive.
-    /// * `amount_b_min` - The minimum required amount of the second token to receive.
-    /// * `to` - The address where the paired tokens will be sent to, and from where the LP tokens will be taken.
+    /// * `amount_in` - The exact amount of input tokens to be sent from `from`.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - The trading route, from the input token to the output token.
+    /// * `from` - The address that pays the input tokens.
+    /// * `target` - The contract where the output tokens will be sent to, and that is called afterwards.
+    /// * `data` - An opaque payload forwarded to `soroswap_router_call`.
     /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
     ///
     /// # Returns
-    /// A tuple containing the amounts of `token_a` and `token_b` withdrawn from the pool.
-    fn remove_liquidity(
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_and_call(
         e: Env,
-        token_a: Address,
-        token_b: Address,
-        liquidity: i128,
-        amount_a_min: i128,
-        amount_b_min: i128,
-        to: Address,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        from: Address,
+        target: Address,
+        data: Bytes,
         deadline: u64,
-    ) -> Result<(i128, i128), CombinedRouterError> {
-        check_initialized(&e)?;
-        check_nonnegative_amount(liquidity)?;
-        check_nonnegative_amount(amount_a_min)?;
-        check_nonnegative_amount(amount_b_min)?;
-        extend_instance_ttl(&e);
-        to.require_auth();
-        ensure_deadline(&e, deadline)?;
-
-        // Ensure that the pair exists in the Soroswap factory
-        let factory_address = get_factory(&e);
-        let factory = SoroswapFactoryClient::new(&e, &factory_address);
-
-        if !factory.pair_exists(&token_a, &token_b) {
-            return Err(SoroswapRouterError::PairDoesNotExist.into());
-        }
-
-        // Retrieve the pair's contract address using the Soroswap library
-        let pair: Address = soroswap_library::pair_for(
-            e.clone(),
-            get_factory(&e),
-            token_a.clone(),
-            token_b.clone(),
-        )?;
-
-        // Transfer LP tokens from the caller to the pair contract
-        TokenClient::new(&e, &pair).transfer(&to, &pair, &liquidity);
-        
-        // Withdraw paired tokens from the pool
-        let (amount_0, amount_1) = SoroswapPairClient::new(&e, &pair).withdraw(&to);
-
-        // Sort tokens to match the expected order
-        let (token_0, _token_1) = soroswap_library::sort_tokens(token_a.clone(), token_b.clone())?;
-        let (amount_a, amount_b) = if token_a == token_0 {
-            (amount_0, amount_1)
-        } else {
-            (amount_1, amount_0)
-        };
-
-        // Check if the received amounts meet the minimum requirements
-        if amount_a < amount_a_min {
-            return Err(SoroswapRouterError::InsufficientAAmount.into());
-        }
-        if amount_b < amount_b_min {
-            return Err(SoroswapRouterError::InsufficientBAmount.into());
-        }
-
-        event::remove_liquidity(
-            &e,
-            token_a,
-            token_b,
-            pair,
-            amount_a,
-            amount_b,
-            liquidity,
-            to);
-
-        // Return the amounts of paired tokens withdrawn
-        Ok((amount_a, amount_b))
+        validity: Option<Validity>,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        execute_swap_and_call(e, amount_in, amount_out_min, path, Payer::Owner(from), target, data, &Validity::window(deadline, validity))
     }
 
-    /// Swaps an exact amount of input tokens for as many output tokens as possible
-    /// along the specified trading route. The route is determined by the `path` vector,
-    /// where the first element is the input token, the last is the output token, 
-    /// and any intermediate elements represent pairs to trade through if a direct pair does not exist.
+    /// Swaps an exact amount of input tokens for as many output tokens as possible along the specified
+    /// trading route, like `swap_exact_tokens_for_tokens`, but each hop goes through the pair given
+    /// in `hops`.
     ///
     /// # Arguments
-    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_in` - The exact amount of input tokens to be sent from `to`.
     /// * `amount_out_min` - The minimum required amount of output tokens to receive.
-    /// * `path` - A vector representing the trading route, where the first element is the input token 
-    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
-    /// * `to` - The address where the output tokens will be sent to.
+    /// * `path` - The trading route, from the input token to the output token.
+    /// * `hops` - The pair of each hop, such as `Kind(Stable)` or `Variant(Stable, stable_1)`, with one element less than `path`.
+    /// * `to` - The address that pays the input tokens and where the output tokens will be sent to.
     /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
     ///
     /// # Returns
     /// A vector containing the amounts of tokens received at each step of the trading route.
-    fn swap_exact_tokens_for_tokens(
+    fn swap_exact_tokens_route(
         e: Env,
         amount_in: i128,
         amount_out_min: i128,
         path: Vec<Address>,
+        hops: Vec<RouteHop>,
         to: Address,
         deadline: u64,
+        validity: Option<Validity>,
     ) -> Result<Vec<i128>, CombinedRouterError> {
-        check_initialized(&e)?;
-        check_nonnegative_amount(amount_in)?;
-        check_nonnegative_amount(amount_out_min)?;
-        extend_instance_ttl(&e);
-        to.require_auth();
-        ensure_deadline(&e, deadline)?;
-
-        // Get the expected output amounts for each step of the trading route        
-        let factory_address = get_factory(&e);
-        let amounts = soroswap_library::get_amounts_out(
-            e.clone(),
-            factory_address.clone(),
-            amount_in,
-            path.clone(),
-        )?;
-
-        // Ensure that the final output amount meets the minimum requirement        
-        if amounts.get(amounts.len() - 1).unwrap() < amount_out_min {
-            return Err(SoroswapRouterError::InsufficientOutputAmount.into());
-        }
-        
-        // Determine the pair contract address for the first step of the trading route
-        let pair = soroswap_library::pair_for(
-            e.clone(),
-            factory_address.clone(),
-            path.get(0).unwrap(),
-            path.get(1).unwrap(),
-        )?;
-        
-        // Transfer input tokens to the pair contract
-        // If the pair does not exist, this will fail here: Should be implement factory.pair_exists?
-        // If we implement, we will include an additional cross-contract call...
-        TokenClient::new(&e, &path.get(0).unwrap()).transfer(&to, &pair, &amounts.get(0).unwrap());
-
-        // Execute the tokens swap
-        swap(&e, &factory_address, &amounts, &path, &to)?;
-    
-        event::swap(
-            &e,
-            path,
-            amounts.clone(),
-            to);
-
-        // Return the amounts of tokens received at each step of the trading route
-        Ok(amounts)
+        execute_swap_exact_tokens_route(e, amount_in, amount_out_min, path, hops, Payer::Owner(to.clone()), to, &Validity::window(deadline, validity))
     }
 
-    /// Swaps tokens for an exact amount of output token, following the specified trading route.
-    /// The route is determined by the `path` vector, where the first element is the input token,
-    /// the last is the output token, and any intermediate elements represent pairs to trade through.
+    /// Swaps tokens for an exact amount of output tokens along the specified trading route, like
+    /// `swap_tokens_for_exact_tokens`, but each hop goes through the pair given in `hops`.
     ///
     /// # Arguments
-    /// * `amount_out` - The exact amount of output token to be received.
-    /// * `amount_in_max` - The maximum allowed amount of input tokens to be swapped.
-    /// * `path` - A vector representing the trading route, where the first element is the input token 
-    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
-    /// * `to` - The address where the output tokens will be sent to.
+    /// * `amount_out` - The exact amount of output tokens to receive.
+    /// * `amount_in_max` - The maximum allowed amount of input tokens to be sent from `to`.
+    /// * `path` - The trading route, from the input token to the output token.
+    /// * `hops` - The pair of each hop, such as `Kind(Stable)` or `Variant(Stable, stable_1)`, with one element less than `path`.
+    /// * `to` - The address that pays the input tokens and where the output tokens will be sent to.
     /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
     ///
     /// # Returns
     /// A vector containing the amounts of tokens used at each step of the trading route.
-    fn swap_tokens_for_exact_tokens(
+    fn swap_for_exact_tokens_route(
         e: Env,
         amount_out: i128,
         amount_in_max: i128,
         path: Vec<Address>,
+        hops: Vec<RouteHop>,
         to: Address,
         deadline: u64,
+        validity: Option<Validity>,
     ) -> Result<Vec<i128>, CombinedRouterError> {
-        check_initialized(&e)?;
-        check_nonnegative_amount(amount_out)?;
-        check_nonnegative_amount(amount_in_max)?;
-        extend_instance_ttl(&e);
-        to.require_auth(); 
-        ensure_deadline(&e, deadline)?;
+        execute_swap_for_exact_tokens_route(e, amount_out, amount_in_max, path, hops, Payer::Owner(to.clone()), to, &Validity::window(deadline, validity))
+    }
 
-        // Get the expected input amounts for each step of the trading route
-        let factory_address = get_factory(&e);
-        let amounts = soroswap_library::get_amounts_in(
-            e.clone(),
-            factory_address.clone(),
-            amount_out,
-            path.clone(),
-        )?;
-        
-        // Ensure that the input amount does not exceed the maximum allowed
-        if amounts.get(0).unwrap() > amount_in_max {
-            return Err(SoroswapRouterError::ExcessiveInputAmount.into());
-        }
+    /// Swaps an exact amount of input tokens for as many output tokens as possible along the specified
+    /// trading route, supporting tokens that take a fee on transfer (fot).
+    ///
+    /// Each hop swaps whatever the pair actually received, and `amount_out_min` is checked against the
+    /// change in the output token balance of `to`.
+    ///
+    /// # Arguments
+    /// * `amount_in` - The exact amount of input tokens to be sent from `to`.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// The amount of output tokens received by `to`.
+    fn swap_exact_tokens_for_tokens_fot(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<i128, CombinedRouterError> {
+        execute_swap_exact_tokens_for_tokens_fot(e, amount_in, amount_out_min, path, to, &Validity::window(deadline, validity))
+    }
 
-        // Determine the pair contract address for the first step of the trading route
-        let pair = soroswap_library::pair_for(
-            e.clone(),
-            factory_address.clone(),
-            path.get(0).unwrap(),
-            path.get(1).unwrap(),
-        )?;
-        // Transfer input tokens to the pair contract
-        // If the pair does not exist, this will fail here: Should be implement factory.pair_exists?
-        // If we implement, we will include an additional cross-contract call...
-        TokenClient::new(&e, &path.get(0).unwrap()).transfer(&to, &pair, &amounts.get(0).unwrap());
+    /// Swaps exact amounts of input tokens for as many output tokens as possible, splitting the trade
+    /// across several trading routes to reduce its price impact. Every leg is executed in order with the
+    /// reserves left by the previous legs, and `amount_out_min` is checked against the sum of the outputs
+    /// of all legs.
+    ///
+    /// # Arguments
+    /// * `distribution` - A vector of legs, each one with its trading route and the exact amount of input
+    ///                    tokens to swap through it. Every route must start with the same input token and
+    ///                    end with the same output token.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive across all legs.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// For every leg, a vector containing the amounts of tokens received at each step of its trading route.
+    fn swap_exact_tokens_split(
+        e: Env,
+        distribution: Vec<(Vec<Address>, i128)>,
+        amount_out_min: i128,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<Vec<i128>>, CombinedRouterError> {
+        execute_swap_exact_tokens_split(e, distribution, amount_out_min, to, &Validity::window(deadline, validity))
+    }
 
-        // Execute the token swap
-        swap(&e, &factory_address, &amounts, &path, &to)?;
-    
-        event::swap(
-            &e,
-            path,
-            amounts.clone(),
-            to);
+    /// Executes a batch of router operations atomically, in order. If any of them fails, none of them is executed.
+    ///
+    /// All the tokens are paid by `from`, which authorizes the whole batch once, and `deadline` is checked
+    /// for the whole batch. The amounts an operation takes can be the output of an earlier operation
+    /// (`OpAmount::Output`), so a position can be rebalanced with a `RemoveLiquidity`, a `SwapExactTokens`
+    /// and an `AddLiquidity` without the market moving in between. Such operations should send their
+    /// output tokens to `from`, so the later operations can pay with them.
+    ///
+    /// # Arguments
+    /// * `ops` - The operations to execute.
+    /// * `from` - The address that pays the tokens of every operation.
+    /// * `deadline` - The deadline for executing the batch.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// The result of every operation, in order: the values returned by the matching router function, as a `Vec`.
+    fn multicall(
+        e: Env,
+        ops: Vec<RouterOp>,
+        from: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<Vec<Vec<i128>>, CombinedRouterError> {
+        execute_multicall(e, ops, from, &Validity::window(deadline, validity))
+    }
+
+    /// Adds liquidity to a pair's pool using only one of its tokens. The optimal fraction of `amount_in` is
+    /// swapped through the same pair for `other_token`, and both amounts are then deposited in the pair.
+    /// Because of rounding, a tiny amount of one of the tokens may not be deposited and stays with `to`.
+    ///
+    /// # Arguments
+    /// * `token_in` - The address of the token to be zapped.
+    /// * `amount_in` - The total amount of `token_in` to be used.
+    /// * `other_token` - The address of the other token of the pair.
+    /// * `liquidity_min` - The minimum amount of liquidity tokens to receive.
+    /// * `to` - The address that sends `token_in` and receives the liquidity tokens.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// A tuple containing the leftover amounts of `token_in` and `other_token` that were not deposited, and the
+    /// amount of liquidity tokens minted.
+    fn zap_in(
+        e: Env,
+        token_in: Address,
+        amount_in: i128,
+        other_token: Address,
+        liquidity_min: i128,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<(i128, i128, i128), CombinedRouterError> {
+        execute_zap_in(e, token_in, amount_in, other_token, liquidity_min, to, &Validity::window(deadline, validity))
+    }
 
-        // Return the amounts of tokens used at each step of the trading route
-        Ok(amounts)
+    /// Removes liquidity from a pair's pool and swaps the withdrawn `other_token` through the same pair,
+    /// so that `to` receives only `token_out`.
+    ///
+    /// # Arguments
+    /// * `token_out` - The address of the token to be received.
+    /// * `other_token` - The address of the other token of the pair.
+    /// * `liquidity` - The amount of liquidity tokens to remove.
+    /// * `amount_out_min` - The minimum required amount of `token_out` to receive.
+    /// * `to` - The address that sends the liquidity tokens and receives `token_out`.
+    /// * `deadline` - The deadline for executing the operation.
+    /// * `validity` - Optional extra bounds, such as a not-before time or ledger sequences.
+    ///
+    /// # Returns
+    /// The total amount of `token_out` received.
+    fn zap_out(
+        e: Env,
+        token_out: Address,
+        other_token: Address,
+        liquidity: i128,
+        amount_out_min: i128,
+        to: Address,
+        deadline: u64,
+        validity: Option<Validity>,
+    ) -> Result<i128, CombinedRouterError> {
+        execute_zap_out(e, token_out, other_token, liquidity, amount_out_min, to, &Validity::window(deadline, validity))
     }
 
     /*  *** Read only functions: *** */
@@ -737,6 +3181,31 @@ impl SoroswapRouterTrait for SoroswapRouter {
     }
 
 
+    /// Calculates the deterministic address for the pair of the given variant without making any external calls.
+    /// check <https://github.com/paltalabs/deterministic-address-soroban>
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    /// * `variant` - The variant of the pair, such as `cp`, `stable` or a fee tier.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the pair, and `Err` indicates an error such as identical tokens or an issue with sorting.
+    fn router_pair_for_variant(e: Env, token_a: Address, token_b: Address, variant: Symbol) -> Result<Address, CombinedRouterError> {
+        extend_instance_ttl(&e);
+        Ok(soroswap_library::pair_for_variant(
+            e.clone(),
+            get_factory(&e),
+            token_a.clone(),
+            token_b.clone(),
+            variant,
+        )?)
+    }
+
+
     /// Given some amount of an asset and pair reserves, returns an equivalent amount of the other asset.
     ///
     /// # Arguments
@@ -819,5 +3288,66 @@ impl SoroswapRouterTrait for SoroswapRouter {
         Ok(soroswap_library::get_amounts_in(e, factory, amount_out, path)?)
     }
 
+    /// Performs chained get_amount_out calculations along a path where every hop goes through the pair of the
+    /// given kind, a constant product, a stable or a weighted pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `hops` - The pair of each hop, with one element less than `path`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, CombinedRouterError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn router_get_amounts_out_route(e: Env, amount_in: i128, path: Vec<Address>, hops: Vec<RouteHop>) -> Result<Vec<i128>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let factory = get_factory(&e);
+        Ok(soroswap_library::get_amounts_out_route(e, factory, amount_in, path, hops)?)
+    }
+
+    /// Performs chained get_amount_in calculations along a path where every hop goes through the pair of the
+    /// given kind, a constant product, a stable or a weighted pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `amount_out` - The output amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `hops` - The pair of each hop, with one element less than `path`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, CombinedRouterError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn router_get_amounts_in_route(e: Env, amount_out: i128, path: Vec<Address>, hops: Vec<RouteHop>) -> Result<Vec<i128>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let factory = get_factory(&e);
+        Ok(soroswap_library::get_amounts_in_route(e, factory, amount_out, path, hops)?)
+    }
+
+    /// Quotes a trade along a path and returns the breakdown of every hop: the pair address, its reserves
+    /// before and after the trade, the fee charged, the mid and execution prices (fixed point with 14
+    /// decimals) and the price impact in basis points.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `amount` - The exact input amount if `exact_in` is true, otherwise the exact output amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `exact_in` - Whether `amount` is the input or the output of the trade.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<HopQuote>, CombinedRouterError>` where `Ok` contains the breakdown of each hop, and `Err` indicates an error such as an invalid path.
+    fn router_quote_detailed(e: Env, amount: i128, path: Vec<Address>, exact_in: bool) -> Result<Vec<HopQuote>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let factory = get_factory(&e);
+        Ok(soroswap_library::quote_detailed(e, factory, amount, path, exact_in)?)
+    }
+
 
 }
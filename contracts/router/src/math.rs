@@ -0,0 +1,34 @@
+use soroban_sdk::{Env, I256};
+
+// Same helpers as in the pair. The product of two i128 values always fits in an I256
+pub fn mul_i256(e: &Env, a: i128, b: i128) -> I256 {
+    I256::from_i128(e, a).mul(&I256::from_i128(e, b))
+}
+
+// Integer square root (rounded down) of a non-negative I256, using Newton's method
+pub fn sqrt_i256(e: &Env, n: &I256) -> I256 {
+    let zero = I256::from_i32(e, 0);
+    if *n <= zero {
+        return zero;
+    }
+
+    let mut bits: u32 = 256;
+    for byte in n.to_be_bytes().iter() {
+        if byte == 0 {
+            bits -= 8;
+        } else {
+            bits -= byte.leading_zeros();
+            break;
+        }
+    }
+
+    // 2^ceil(bits / 2) is not below the root, so the iterations decrease until they reach it
+    let mut x = I256::from_i32(e, 1).shl((bits + 1) / 2);
+    loop {
+        let y = x.add(&n.div(&x)).shr(1);
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
@@ -0,0 +1,83 @@
+//! Definition of the operations that can be batched with `multicall`
+use soroban_sdk::{contracttype, Address, Vec};
+
+use crate::error::CombinedRouterError;
+
+/// An amount given to an operation of a `multicall` batch.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OpAmount {
+    /// A fixed amount.
+    Amount(i128),
+    /// The output of an earlier operation of the batch: the index of the operation,
+    /// and the index of the amount in the `Vec` that operation returned.
+    Output(u32, u32),
+}
+
+impl OpAmount {
+    /// Resolves the amount against the results of the operations executed so far.
+    pub(crate) fn resolve(&self, results: &Vec<Vec<i128>>) -> Result<i128, CombinedRouterError> {
+        match self {
+            OpAmount::Amount(amount) => Ok(*amount),
+            OpAmount::Output(op_index, output_index) => results
+                .get(*op_index)
+                .and_then(|outputs| outputs.get(*output_index))
+                .ok_or(CombinedRouterError::RouterInvalidOpAmount),
+        }
+    }
+}
+
+/// Arguments of `add_liquidity` inside a batch. Returns `[amount_a, amount_b, liquidity]`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddLiquidityOp {
+    pub token_a: Address,
+    pub token_b: Address,
+    pub amount_a_desired: OpAmount,
+    pub amount_b_desired: OpAmount,
+    pub amount_a_min: i128,
+    pub amount_b_min: i128,
+    pub to: Address,
+}
+
+/// Arguments of `remove_liquidity` inside a batch. Returns `[amount_a, amount_b]`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemoveLiquidityOp {
+    pub token_a: Address,
+    pub token_b: Address,
+    pub liquidity: OpAmount,
+    pub amount_a_min: i128,
+    pub amount_b_min: i128,
+    pub to: Address,
+}
+
+/// Arguments of `swap_exact_tokens_for_tokens` inside a batch. Returns the amounts of every step of the route.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapExactTokensOp {
+    pub amount_in: OpAmount,
+    pub amount_out_min: i128,
+    pub path: Vec<Address>,
+    pub to: Address,
+}
+
+/// Arguments of `swap_tokens_for_exact_tokens` inside a batch. Returns the amounts of every step of the route.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapForExactTokensOp {
+    pub amount_out: i128,
+    pub amount_in_max: OpAmount,
+    pub path: Vec<Address>,
+    pub to: Address,
+}
+
+/// An operation of a `multicall` batch.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RouterOp {
+    AddLiquidity(AddLiquidityOp),
+    RemoveLiquidity(RemoveLiquidityOp),
+    SwapExactTokens(SwapExactTokensOp),
+    SwapForExactTokens(SwapForExactTokensOp),
+}
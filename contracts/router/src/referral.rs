@@ -0,0 +1,44 @@
+//! Definition of the referral fees that integrators can charge on router swaps
+use soroban_sdk::{contracttype, Address};
+
+use crate::error::{CombinedRouterError, SoroswapRouterError};
+
+const BASIS_POINTS: i128 = 10_000;
+
+/// The token of a swap the referral fee is paid in.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReferralFeeToken {
+    Input,
+    Output,
+}
+
+/// A referrer credited for a swap, and the fee it charges. The fee can not be greater than the
+/// `max_referral_fee` of the factory.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Referral {
+    pub referrer: Address,
+    pub fee_bps: u32,
+    pub fee_token: ReferralFeeToken,
+}
+
+impl Referral {
+    /// `fee_bps` of `amount`, rounded down. Computed from the quotient and the remainder by 10_000
+    /// so that it can not overflow.
+    pub(crate) fn fee(&self, amount: i128) -> Result<i128, CombinedRouterError> {
+        let fee_bps = i128::from(self.fee_bps);
+        (amount / BASIS_POINTS).checked_mul(fee_bps)
+            .and_then(|fee| fee.checked_add(amount % BASIS_POINTS * fee_bps / BASIS_POINTS))
+            .ok_or(SoroswapRouterError::Overflow.into())
+    }
+
+    /// The fee of `amount` if it is paid in `fee_token`, or 0 otherwise.
+    pub(crate) fn fee_in(&self, fee_token: ReferralFeeToken, amount: i128) -> Result<i128, CombinedRouterError> {
+        if self.fee_token == fee_token {
+            self.fee(amount)
+        } else {
+            Ok(0)
+        }
+    }
+}
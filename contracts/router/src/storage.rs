@@ -5,11 +5,14 @@ use soroban_sdk::{contracttype, Env, Address};
 
 enum DataKey {
     Factory, // Address of the Factory Contract. Instance Data Type
+    Operator(Address, Address), // (owner, operator) allowed to spend the owner's router allowances. Persistent Data Type
 }
 
 const DAY_IN_LEDGERS: u32 = 17280;
 const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+const PERSISTENT_BUMP_AMOUNT: u32 = 60 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
 
 pub fn extend_instance_ttl(e: &Env) {
     e.storage()
@@ -28,3 +31,23 @@ pub fn has_factory(e: &Env) -> bool {
 pub fn get_factory(e: &Env) -> Address {
     e.storage().instance().get(&DataKey::Factory).unwrap()
 }
+
+pub fn put_operator(e: &Env, owner: &Address, operator: &Address, approved: bool) {
+    let key = DataKey::Operator(owner.clone(), operator.clone());
+    if approved {
+        e.storage().persistent().set(&key, &true);
+        e.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_operator(e: &Env, owner: &Address, operator: &Address) -> bool {
+    let key = DataKey::Operator(owner.clone(), operator.clone());
+    if e.storage().persistent().has(&key) {
+        e.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        true
+    } else {
+        false
+    }
+}
@@ -5,6 +5,7 @@ use soroban_sdk::{
     Env, 
     BytesN, 
     Address, 
+    String,
     testutils::{
         Address as _,
     },
@@ -21,6 +22,18 @@ pub fn create_token_contract<'a>(e: &Env, admin: & Address) -> TokenClient<'a> {
     TokenClient::new(&e, &e.register_stellar_asset_contract(admin.clone()))
 }
 
+// Fee On Transfer Token Contract, burns `transfer_fee` basis points of every transfer
+mod fee_on_transfer_token {
+    soroban_sdk::contractimport!(file = "../token/fee-on-transfer/target/wasm32-unknown-unknown/release/soroban_fee_on_transfer_token_contract.wasm");
+}
+
+pub fn create_fee_on_transfer_token_contract<'a>(e: &Env, admin: & Address, transfer_fee: u32) -> TokenClient<'a> {
+    let token_address = e.register_contract_wasm(None, fee_on_transfer_token::WASM);
+    fee_on_transfer_token::Client::new(&e, &token_address)
+        .initialize(&admin, &7, &String::from_str(&e, "Fee Token"), &String::from_str(&e, "FEE"), &transfer_fee);
+    TokenClient::new(&e, &token_address)
+}
+
 // Pair Contract
 mod pair {
     soroban_sdk::contractimport!(file = "../pair/target/wasm32-unknown-unknown/release/soroswap_pair.wasm");
@@ -36,6 +49,20 @@ fn pair_contract_wasm(e: &Env) -> BytesN<32> {
     e.deployer().upload_contract_wasm(WASM)
 }
 
+// Stable Pair Contract
+mod stable_pair {
+    soroban_sdk::contractimport!(file = "../stable-pair/target/wasm32-unknown-unknown/release/soroswap_stable_pair.wasm");
+    pub type SoroswapStablePairClient<'a> = Client<'a>;
+}
+use stable_pair::SoroswapStablePairClient;
+
+// Weighted Pair Contract
+mod weighted_pair {
+    soroban_sdk::contractimport!(file = "../weighted-pair/target/wasm32-unknown-unknown/release/soroswap_weighted_pair.wasm");
+    pub type SoroswapWeightedPairClient<'a> = Client<'a>;
+}
+use weighted_pair::SoroswapWeightedPairClient;
+
 // SoroswapFactory Contract
 mod factory {
     soroban_sdk::contractimport!(file = "../factory/target/wasm32-unknown-unknown/release/soroswap_factory.wasm");
@@ -146,6 +173,17 @@ pub mod library_functions;
 pub mod swap_tokens_for_exact_tokens;
 pub mod swap_exact_tokens_for_tokens;
 pub mod events;
+pub mod fee_on_transfer;
+pub mod swap_split;
+pub mod zap;
+pub mod from_to;
+pub mod operator;
+pub mod multicall;
+pub mod validity;
+pub mod referral;
+pub mod price_limit;
+pub mod swap_and_call;
+pub mod route;
 
 // BUDGET TEST MOD
 mod budget;
@@ -1,6 +1,7 @@
 use crate::test::{SoroswapRouterTest, SoroswapPairClient};
 extern crate std;
 use crate::error::{CombinedRouterError};
+use soroswap_library::CONSTANT_PRODUCT_KIND;
 
 
 use soroban_sdk::{
@@ -360,7 +361,7 @@ fn test_add_liquidity() {
     // TODO: Get rid of this hack?
     test.env.budget().reset_unlimited();
     // We test that factory has only 1 pair
-    assert_eq!(test.factory.all_pairs(&0), pair_address); 
+    assert_eq!(test.factory.all_pairs_with_kind(&0), (pair_address.clone(), CONSTANT_PRODUCT_KIND)); 
     assert_eq!(test.factory.all_pairs_length(), 1);
     
     let pair_client = SoroswapPairClient::new(&test.env, &pair_address);
@@ -376,7 +377,7 @@ fn test_add_liquidity() {
     assert_eq!(test.token_1.balance(&pair_address), amount_1);
    
     // Check initial reserves
-    assert_eq!(pair_client.get_reserves(), (amount_0, amount_1));
+    assert_eq!(pair_client.get_reserves(), (amount_0, amount_1, ledger_timestamp));
 
     // Check initial total_supply
     assert_eq!(pair_client.total_supply(), expected_liquidity);
@@ -453,7 +454,7 @@ fn test_add_liquidity_deducted_amount_reserve() {
     // TODO: Get rid of this hack?
     test.env.budget().reset_unlimited();
     // We test that factory has only 1 pair
-    assert_eq!(test.factory.all_pairs(&0), pair_address); 
+    assert_eq!(test.factory.all_pairs_with_kind(&0), (pair_address.clone(), CONSTANT_PRODUCT_KIND)); 
     assert_eq!(test.factory.all_pairs_length(), 1);
     
     let pair_client = SoroswapPairClient::new(&test.env, &pair_address);
@@ -470,7 +471,7 @@ fn test_add_liquidity_deducted_amount_reserve() {
     assert_eq!(test.token_1.balance(&pair_address), amount_1);
    
     // Check initial reserves
-    assert_eq!(pair_client.get_reserves(), (amount_0, amount_1));
+    assert_eq!(pair_client.get_reserves(), (amount_0, amount_1, ledger_timestamp));
 
 }
 
@@ -612,4 +613,25 @@ fn amount_b_desired_higher() {
 
     assert_eq!(new_added_token_0, amount_0);
     assert_eq!(new_added_token_1, amount_1);
-}
\ No newline at end of file
+}
+#[test]
+fn test_add_liquidity_pair_paused() {
+    let test = SoroswapRouterTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &10_000, &10_000);
+
+    test.factory.set_pair_paused(&test.token_0.address, &test.token_1.address, &CONSTANT_PRODUCT_KIND, &true);
+
+    let result = test.contract.try_add_liquidity(
+        &test.token_0.address,  // token_a: Address,
+        &test.token_1.address,  // token_b: Address,
+        &10000,                 // amount_a_desired: i128,
+        &10000,                 // amount_b_desired: i128,
+        &0,                     // amount_a_min: i128,
+        &0,                     // amount_b_min: i128,
+        &test.user,             // to: Address,
+        &1000,                  // deadline: u64,
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterPairPaused)));
+}
@@ -0,0 +1,179 @@
+use soroban_sdk::{Address, Env, vec, Vec, testutils::Address as _};
+
+use crate::SoroswapRouterClient;
+use crate::test::{
+    create_soroswap_factory,
+    create_soroswap_router,
+    create_token_contract,
+    create_fee_on_transfer_token_contract,
+    SoroswapFactoryClient,
+    TokenClient,
+};
+use crate::error::CombinedRouterError;
+
+// The fee token burns 1% of every transfer
+struct FeeOnTransferTest<'a> {
+    env: Env,
+    contract: SoroswapRouterClient<'a>,
+    fee_token: TokenClient<'a>,
+    token: TokenClient<'a>,
+    factory: SoroswapFactoryClient<'a>,
+    user: Address,
+}
+
+impl<'a> FeeOnTransferTest<'a> {
+    fn setup() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.budget().reset_unlimited();
+
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+
+        let fee_token = create_fee_on_transfer_token_contract(&env, &admin, 100);
+        let token = create_token_contract(&env, &admin);
+        fee_token.mint(&user, &10_000_000_000);
+        token.mint(&user, &10_000_000_000);
+
+        let factory = create_soroswap_factory(&env, &admin);
+        let contract = create_soroswap_router(&env);
+        contract.initialize(&factory.address);
+
+        // The pair receives 990_000_000 of the fee token
+        contract.add_liquidity(
+            &fee_token.address,
+            &token.address,
+            &1_000_000_000,
+            &1_000_000_000,
+            &0,
+            &0,
+            &user,
+            &1000,
+        );
+
+        FeeOnTransferTest {
+            env,
+            contract,
+            fee_token,
+            token,
+            factory,
+            user,
+        }
+    }
+}
+
+#[test]
+fn fee_on_transfer_reserves() {
+    let test = FeeOnTransferTest::setup();
+    let pair = test.factory.get_pair(&test.fee_token.address, &test.token.address);
+    assert_eq!(test.fee_token.balance(&pair), 990_000_000);
+    assert_eq!(test.token.balance(&pair), 1_000_000_000);
+}
+
+#[test]
+#[should_panic]
+fn swap_exact_tokens_for_tokens_fee_on_transfer_fails() {
+    let test = FeeOnTransferTest::setup();
+    let path: Vec<Address> = vec![&test.env, test.fee_token.address.clone(), test.token.address.clone()];
+
+    // The pair receives less than the amount used to compute the output, so K is not met
+    test.contract.swap_exact_tokens_for_tokens(&1_000_000, &0, &path, &test.user, &1000);
+}
+
+#[test]
+fn swap_exact_tokens_for_tokens_fot_input() {
+    let test = FeeOnTransferTest::setup();
+    let path: Vec<Address> = vec![&test.env, test.fee_token.address.clone(), test.token.address.clone()];
+
+    let initial_fee_token = test.fee_token.balance(&test.user);
+    let initial_token = test.token.balance(&test.user);
+
+    // The pair receives 990_000, which gives 996006 after the 0.3% swap fee
+    let expected_amount_out = 996006;
+    let amount_out = test.contract.swap_exact_tokens_for_tokens_fot(
+        &1_000_000,
+        &expected_amount_out,
+        &path,
+        &test.user,
+        &1000, &None);
+
+    assert_eq!(amount_out, expected_amount_out);
+    assert_eq!(test.fee_token.balance(&test.user), initial_fee_token - 1_000_000);
+    assert_eq!(test.token.balance(&test.user), initial_token + expected_amount_out);
+}
+
+#[test]
+fn swap_exact_tokens_for_tokens_fot_output() {
+    let test = FeeOnTransferTest::setup();
+    let path: Vec<Address> = vec![&test.env, test.token.address.clone(), test.fee_token.address.clone()];
+
+    let initial_fee_token = test.fee_token.balance(&test.user);
+
+    // The pair sends 986046, but the user receives 1% less
+    let expected_amount_out = 976186;
+    let result = test.contract.try_swap_exact_tokens_for_tokens_fot(
+        &1_000_000,
+        &(expected_amount_out + 1),
+        &path,
+        &test.user,
+        &1000, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+
+    let amount_out = test.contract.swap_exact_tokens_for_tokens_fot(
+        &1_000_000,
+        &expected_amount_out,
+        &path,
+        &test.user,
+        &1000, &None);
+    assert_eq!(amount_out, expected_amount_out);
+    assert_eq!(test.fee_token.balance(&test.user), initial_fee_token + expected_amount_out);
+}
+
+#[test]
+fn swap_exact_tokens_for_tokens_fot_invalid_path() {
+    let test = FeeOnTransferTest::setup();
+    let path: Vec<Address> = vec![&test.env, test.fee_token.address.clone()];
+    let result = test.contract.try_swap_exact_tokens_for_tokens_fot(
+        &1_000_000,
+        &0,
+        &path,
+        &test.user,
+        &1000, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPath)));
+}
+
+#[test]
+fn remove_liquidity_fot() {
+    let test = FeeOnTransferTest::setup();
+
+    // The pair sends 99498743 of the fee token, but the user receives 1% less
+    let liquidity = 100_000_000;
+    let expected_fee_token = 98503756;
+    let expected_token = 100503781;
+
+    let result = test.contract.try_remove_liquidity_fot(
+        &test.fee_token.address,
+        &test.token.address,
+        &liquidity,
+        &(expected_fee_token + 1),
+        &expected_token,
+        &test.user,
+        &1000, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientAAmount)));
+
+    let initial_fee_token = test.fee_token.balance(&test.user);
+    let initial_token = test.token.balance(&test.user);
+    let (amount_fee_token, amount_token) = test.contract.remove_liquidity_fot(
+        &test.fee_token.address,
+        &test.token.address,
+        &liquidity,
+        &expected_fee_token,
+        &expected_token,
+        &test.user,
+        &1000, &None);
+
+    assert_eq!(amount_fee_token, expected_fee_token);
+    assert_eq!(amount_token, expected_token);
+    assert_eq!(test.fee_token.balance(&test.user), initial_fee_token + expected_fee_token);
+    assert_eq!(test.token.balance(&test.user), initial_token + expected_token);
+}
@@ -0,0 +1,272 @@
+use soroban_sdk::{
+    testutils::{Address as _, Events, MockAuth, MockAuthInvoke},
+    vec,
+    Address,
+    IntoVal,
+    symbol_short,
+    Vec};
+
+use crate::test::{SoroswapRouterTest, SoroswapPairClient};
+use crate::test::add_liquidity::add_liquidity;
+use crate::event::{AddLiquidityEvent, RemoveLiquidityEvent, SwapEvent};
+use crate::Validity;
+
+const INITIAL_USER_BALANCE: i128 = 10_000_000_000_000_000_000;
+
+#[test]
+fn add_liquidity_from() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let recipient = Address::generate(&test.env);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let amount_0: i128 = 1_000_000_000_000_000_000;
+    let amount_1: i128 = 4_000_000_000_000_000_000;
+
+    let (deposited_0, deposited_1, liquidity) = test.contract.add_liquidity_from(
+        &test.token_0.address,
+        &test.token_1.address,
+        &amount_0,
+        &amount_1,
+        &0,
+        &0,
+        &test.user,
+        &recipient,
+        &deadline,
+        &None,
+    );
+
+    // Only the payer had to authorize it
+    let auths = test.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, test.user);
+
+    let pair_address = test.contract.router_pair_for(&test.token_0.address, &test.token_1.address);
+    let pair_client = SoroswapPairClient::new(&test.env, &pair_address);
+    assert_eq!((deposited_0, deposited_1), (amount_0, amount_1));
+    assert_eq!(pair_client.balance(&recipient), liquidity);
+    assert_eq!(pair_client.balance(&test.user), 0);
+    assert_eq!(test.token_0.balance(&test.user), INITIAL_USER_BALANCE - amount_0);
+    assert_eq!(test.token_1.balance(&test.user), INITIAL_USER_BALANCE - amount_1);
+
+    let add_liquidity_event = test.env.events().all().last().unwrap();
+    let expected_add_liquidity_event: AddLiquidityEvent = AddLiquidityEvent {
+        token_a: test.token_0.address.clone(),
+        token_b: test.token_1.address.clone(),
+        pair: pair_address,
+        amount_a: amount_0,
+        amount_b: amount_1,
+        liquidity,
+        from: test.user.clone(),
+        to: recipient,
+    };
+    assert_eq!(
+        vec![&test.env, add_liquidity_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("add")).into_val(&test.env),
+                (expected_add_liquidity_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn remove_liquidity_from() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let recipient = Address::generate(&test.env);
+
+    let amount_0: i128 = 1_000_000_000_000_000_000;
+    let amount_1: i128 = 4_000_000_000_000_000_000;
+    let (_, _, liquidity) = add_liquidity(&test, &amount_0, &amount_1);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let (amount_a, amount_b) = test.contract.remove_liquidity_from(
+        &test.token_0.address,
+        &test.token_1.address,
+        &liquidity,
+        &0,
+        &0,
+        &test.user,
+        &recipient,
+        &deadline,
+        &None,
+    );
+
+    let auths = test.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, test.user);
+
+    // The LP tokens come from the user, and the withdrawn tokens go to the recipient
+    let pair_address = test.contract.router_pair_for(&test.token_0.address, &test.token_1.address);
+    assert_eq!(SoroswapPairClient::new(&test.env, &pair_address).balance(&test.user), 0);
+    assert_eq!(test.token_0.balance(&recipient), amount_a);
+    assert_eq!(test.token_1.balance(&recipient), amount_b);
+    assert_eq!(test.token_0.balance(&test.user), INITIAL_USER_BALANCE - amount_0);
+    assert_eq!(test.token_1.balance(&test.user), INITIAL_USER_BALANCE - amount_1);
+
+    let remove_liquidity_event = test.env.events().all().last().unwrap();
+    let expected_remove_liquidity_event: RemoveLiquidityEvent = RemoveLiquidityEvent {
+        token_a: test.token_0.address.clone(),
+        token_b: test.token_1.address.clone(),
+        pair: pair_address,
+        amount_a,
+        amount_b,
+        liquidity,
+        from: test.user.clone(),
+        to: recipient,
+    };
+    assert_eq!(
+        vec![&test.env, remove_liquidity_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("remove")).into_val(&test.env),
+                (expected_remove_liquidity_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn swap_exact_tokens_from() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let recipient = Address::generate(&test.env);
+
+    let amount_0: i128 = 1_000_000_000_000_000_000;
+    let amount_1: i128 = 4_000_000_000_000_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let amount_in: i128 = 1_000_000;
+    let expected_amounts = test.contract.router_get_amounts_out(&amount_in, &path);
+
+    let amounts = test.contract.swap_exact_tokens_from(
+        &amount_in,
+        &0,
+        &path,
+        &test.user,
+        &recipient,
+        &deadline,
+        &None,
+    );
+
+    let auths = test.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, test.user);
+
+    let amount_out = expected_amounts.get(1).unwrap();
+    assert_eq!(amounts, expected_amounts);
+    assert_eq!(test.token_0.balance(&test.user), INITIAL_USER_BALANCE - amount_0 - amount_in);
+    assert_eq!(test.token_1.balance(&test.user), INITIAL_USER_BALANCE - amount_1);
+    assert_eq!(test.token_1.balance(&recipient), amount_out);
+
+    let swap_event = test.env.events().all().last().unwrap();
+    let expected_swap_event: SwapEvent = SwapEvent {
+        path,
+        amounts,
+        from: test.user.clone(),
+        to: recipient,
+        referral: None,
+    };
+    assert_eq!(
+        vec![&test.env, swap_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("swap")).into_val(&test.env),
+                (expected_swap_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn swap_for_exact_tokens_from() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let recipient = Address::generate(&test.env);
+
+    let amount_0: i128 = 1_000_000_000_000_000_000;
+    let amount_1: i128 = 4_000_000_000_000_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let amount_out: i128 = 4_000_000;
+    let expected_amounts = test.contract.router_get_amounts_in(&amount_out, &path);
+
+    let amounts = test.contract.swap_for_exact_tokens_from(
+        &amount_out,
+        &i128::MAX,
+        &path,
+        &test.user,
+        &recipient,
+        &deadline,
+        &None,
+    );
+
+    let auths = test.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, test.user);
+
+    let amount_in = expected_amounts.get(0).unwrap();
+    assert_eq!(amounts, expected_amounts);
+    assert_eq!(test.token_0.balance(&test.user), INITIAL_USER_BALANCE - amount_0 - amount_in);
+    assert_eq!(test.token_1.balance(&test.user), INITIAL_USER_BALANCE - amount_1);
+    assert_eq!(test.token_1.balance(&recipient), amount_out);
+
+    let swap_event = test.env.events().all().last().unwrap();
+    let expected_swap_event: SwapEvent = SwapEvent {
+        path,
+        amounts,
+        from: test.user.clone(),
+        to: recipient,
+        referral: None,
+    };
+    assert_eq!(
+        vec![&test.env, swap_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("swap")).into_val(&test.env),
+                (expected_swap_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn swap_exact_tokens_from_requires_payer_auth() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let recipient = Address::generate(&test.env);
+
+    let amount_0: i128 = 1_000_000_000_000_000_000;
+    let amount_1: i128 = 4_000_000_000_000_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+
+    // The recipient authorizes, but the user pays
+    test.contract
+        .mock_auths(&[MockAuth {
+            address: &recipient,
+            invoke: &MockAuthInvoke {
+                contract: &test.contract.address,
+                fn_name: "swap_exact_tokens_from",
+                args: (1_000_000_i128, 0_i128, path.clone(), test.user.clone(), recipient.clone(), deadline, None::<Validity>).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .swap_exact_tokens_from(&1_000_000, &0, &path, &test.user, &recipient, &deadline, &None);
+}
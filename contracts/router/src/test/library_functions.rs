@@ -181,3 +181,72 @@ fn test_get_amounts_in() {
 
 
     
+
+// router_quote_detailed
+
+#[test]
+fn test_quote_detailed_not_yet_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let path = vec![&test.env, test.token_0.address, test.token_1.address];
+    let result = test.contract.try_router_quote_detailed(&1000, &path, &true);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn try_router_quote_detailed_invalid_path() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let path = vec![&test.env, test.token_0.address];
+    let result = test.contract.try_router_quote_detailed(&1000, &path, &true);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPath)));
+}
+
+#[test]
+fn test_quote_detailed() {
+    let test = SoroswapRouterTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address);
+
+    let amount_0: i128 = 10_000;
+    let amount_1: i128 = 10_000;
+
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+
+    // fee = ceil(1000*30/10000) = 3
+    // 997*10000/10997 = 906.6 = 906
+    let hops = test.contract.router_quote_detailed(&1000, &path, &true);
+    assert_eq!(hops.len(), 1);
+    let hop = hops.get(0).unwrap();
+    assert_eq!(hop.pair, test.factory.get_pair(&test.token_0.address, &test.token_1.address));
+    assert_eq!((hop.amount_in, hop.amount_out), (1000, 906));
+    assert_eq!((hop.reserve_in_before, hop.reserve_out_before), (10_000, 10_000));
+    assert_eq!((hop.reserve_in_after, hop.reserve_out_after), (11_000, 9_094));
+    assert_eq!(hop.fee_amount, 3);
+    assert_eq!(hop.mid_price, 100_000_000_000_000);
+    assert_eq!(hop.execution_price, 90_600_000_000_000);
+    assert_eq!(hop.price_impact, 940);
+    assert_eq!(vec![&test.env, 1000, 906], test.contract.router_get_amounts_out(&1000, &path));
+
+    // Exact output gives the same amounts as router_get_amounts_in
+    let hops = test.contract.router_quote_detailed(&906, &path, &false);
+    let amounts_in = test.contract.router_get_amounts_in(&906, &path);
+    assert_eq!(hops.get(0).unwrap().amount_in, amounts_in.get(0).unwrap());
+    assert_eq!(hops.get(0).unwrap().amount_out, 906);
+}
+
+#[test]
+fn try_router_library_overflow() {
+    let test = SoroswapRouterTest::setup();
+    let result = test.contract.try_router_quote(&100_000_000_000_000_000_000, &1, &100_000_000_000_000_000_000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryOverflow)));
+    let result = test.contract.try_router_get_amount_out(&i128::MAX, &i128::MAX, &100);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryOverflow)));
+    let result = test.contract.try_router_get_amount_in(&(10_000_000_000_000_000_000 - 1), &i128::MAX, &10_000_000_000_000_000_000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryOverflow)));
+
+    // Intermediate products above i128::MAX are computed in 256 bits
+    assert_eq!(test.contract.router_get_amount_out(&(i128::MAX / 10), &100, &100), 99);
+    assert_eq!(test.contract.router_get_amount_in(&1_000_000_000_000_000_000, &10_000_000_000_000_000_000, &10_000_000_000_000_000_000), 1_114_454_474_534_715_258);
+}
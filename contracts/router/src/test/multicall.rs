@@ -0,0 +1,218 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec,
+    Address,
+    Vec};
+
+use crate::test::{SoroswapRouterTest, SoroswapPairClient};
+use crate::test::add_liquidity::add_liquidity;
+use crate::error::CombinedRouterError;
+use crate::multicall::{RouterOp, OpAmount, AddLiquidityOp, RemoveLiquidityOp, SwapExactTokensOp, SwapForExactTokensOp};
+
+const INITIAL_USER_BALANCE: i128 = 10_000_000_000_000_000_000;
+
+#[test]
+fn multicall_not_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let ops: Vec<RouterOp> = Vec::new(&test.env);
+    let result = test.contract.try_multicall(&ops, &test.user, &0, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn multicall_deadline_expired() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = 100;
+    });
+    let ops: Vec<RouterOp> = Vec::new(&test.env);
+    let result = test.contract.try_multicall(&ops, &test.user, &100, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterDeadlineExpired)));
+}
+
+#[test]
+fn multicall_rebalance() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+
+    let amount_0: i128 = 1_000_000_000_000_000_000;
+    let amount_1: i128 = 4_000_000_000_000_000_000;
+    let (_, _, liquidity) = add_liquidity(&test, &amount_0, &amount_1);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    // Remove half of the position, swap the withdrawn token_1 for token_0,
+    // and deposit all the token_0 withdrawn and bought back in the pool
+    let ops: Vec<RouterOp> = vec![
+        &test.env,
+        RouterOp::RemoveLiquidity(RemoveLiquidityOp {
+            token_a: test.token_0.address.clone(),
+            token_b: test.token_1.address.clone(),
+            liquidity: OpAmount::Amount(liquidity / 2),
+            amount_a_min: 0,
+            amount_b_min: 0,
+            to: test.user.clone(),
+        }),
+        RouterOp::SwapExactTokens(SwapExactTokensOp {
+            amount_in: OpAmount::Output(0, 1),
+            amount_out_min: 0,
+            path: vec![&test.env, test.token_1.address.clone(), test.token_0.address.clone()],
+            to: test.user.clone(),
+        }),
+        RouterOp::AddLiquidity(AddLiquidityOp {
+            token_a: test.token_0.address.clone(),
+            token_b: test.token_1.address.clone(),
+            amount_a_desired: OpAmount::Output(1, 1),
+            amount_b_desired: OpAmount::Amount(amount_1),
+            amount_a_min: 0,
+            amount_b_min: 0,
+            to: test.user.clone(),
+        }),
+    ];
+    let results = test.contract.multicall(&ops, &test.user, &deadline, &None);
+
+    // The whole batch is authorized once
+    let auths = test.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, test.user);
+
+    assert_eq!(results.len(), 3);
+    let removed = results.get(0).unwrap();
+    let swapped = results.get(1).unwrap();
+    let added = results.get(2).unwrap();
+    assert_eq!(removed.len(), 2);
+    assert_eq!(added.len(), 3);
+
+    // Each output was fed into the next operation
+    assert_eq!(swapped.get(0).unwrap(), removed.get(1).unwrap());
+    assert_eq!(added.get(0).unwrap(), swapped.get(1).unwrap());
+
+    let pair_address = test.contract.router_pair_for(&test.token_0.address, &test.token_1.address);
+    let pair_client = SoroswapPairClient::new(&test.env, &pair_address);
+    assert_eq!(pair_client.balance(&test.user), liquidity - liquidity / 2 + added.get(2).unwrap());
+    assert_eq!(
+        test.token_0.balance(&test.user),
+        INITIAL_USER_BALANCE - amount_0 + removed.get(0).unwrap() + swapped.get(1).unwrap() - added.get(0).unwrap()
+    );
+    assert_eq!(
+        test.token_1.balance(&test.user),
+        INITIAL_USER_BALANCE - amount_1 + removed.get(1).unwrap() - swapped.get(0).unwrap() - added.get(1).unwrap()
+    );
+}
+
+#[test]
+fn multicall_swap_for_exact_tokens() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+
+    let amount_0: i128 = 1_000_000_000_000_000_000;
+    let amount_1: i128 = 4_000_000_000_000_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let recipient = Address::generate(&test.env);
+
+    // Buy token_1, then spend at most what was bought to buy back some token_0
+    let ops: Vec<RouterOp> = vec![
+        &test.env,
+        RouterOp::SwapExactTokens(SwapExactTokensOp {
+            amount_in: OpAmount::Amount(1_000_000),
+            amount_out_min: 0,
+            path: vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()],
+            to: test.user.clone(),
+        }),
+        RouterOp::SwapForExactTokens(SwapForExactTokensOp {
+            amount_out: 500_000,
+            amount_in_max: OpAmount::Output(0, 1),
+            path: vec![&test.env, test.token_1.address.clone(), test.token_0.address.clone()],
+            to: recipient.clone(),
+        }),
+    ];
+    let results = test.contract.multicall(&ops, &test.user, &deadline, &None);
+
+    let bought = results.get(0).unwrap().get(1).unwrap();
+    let spent = results.get(1).unwrap().get(0).unwrap();
+    assert!(spent <= bought);
+    assert_eq!(test.token_0.balance(&recipient), 500_000);
+    assert_eq!(test.token_1.balance(&test.user), INITIAL_USER_BALANCE - amount_1 + bought - spent);
+}
+
+#[test]
+fn multicall_invalid_op_amount() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+
+    let amount_0: i128 = 1_000_000_000_000_000_000;
+    let amount_1: i128 = 4_000_000_000_000_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+
+    // An operation can only use the outputs of the operations before it
+    let ops: Vec<RouterOp> = vec![
+        &test.env,
+        RouterOp::SwapExactTokens(SwapExactTokensOp {
+            amount_in: OpAmount::Output(0, 0),
+            amount_out_min: 0,
+            path: path.clone(),
+            to: test.user.clone(),
+        }),
+    ];
+    let result = test.contract.try_multicall(&ops, &test.user, &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidOpAmount)));
+
+    // And only the outputs they returned
+    let ops: Vec<RouterOp> = vec![
+        &test.env,
+        RouterOp::SwapExactTokens(SwapExactTokensOp {
+            amount_in: OpAmount::Amount(1_000_000),
+            amount_out_min: 0,
+            path: path.clone(),
+            to: test.user.clone(),
+        }),
+        RouterOp::SwapExactTokens(SwapExactTokensOp {
+            amount_in: OpAmount::Output(0, 2),
+            amount_out_min: 0,
+            path,
+            to: test.user.clone(),
+        }),
+    ];
+    let result = test.contract.try_multicall(&ops, &test.user, &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidOpAmount)));
+}
+
+#[test]
+fn multicall_is_atomic() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+
+    let amount_0: i128 = 1_000_000_000_000_000_000;
+    let amount_1: i128 = 4_000_000_000_000_000_000;
+    let (_, _, liquidity) = add_liquidity(&test, &amount_0, &amount_1);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    // The swap can not meet its minimum, so the removal before it is reverted too
+    let ops: Vec<RouterOp> = vec![
+        &test.env,
+        RouterOp::RemoveLiquidity(RemoveLiquidityOp {
+            token_a: test.token_0.address.clone(),
+            token_b: test.token_1.address.clone(),
+            liquidity: OpAmount::Amount(liquidity / 2),
+            amount_a_min: 0,
+            amount_b_min: 0,
+            to: test.user.clone(),
+        }),
+        RouterOp::SwapExactTokens(SwapExactTokensOp {
+            amount_in: OpAmount::Output(0, 1),
+            amount_out_min: i128::MAX,
+            path: vec![&test.env, test.token_1.address.clone(), test.token_0.address.clone()],
+            to: test.user.clone(),
+        }),
+    ];
+    let result = test.contract.try_multicall(&ops, &test.user, &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+
+    let pair_address = test.contract.router_pair_for(&test.token_0.address, &test.token_1.address);
+    assert_eq!(SoroswapPairClient::new(&test.env, &pair_address).balance(&test.user), liquidity);
+    assert_eq!(test.token_0.balance(&test.user), INITIAL_USER_BALANCE - amount_0);
+    assert_eq!(test.token_1.balance(&test.user), INITIAL_USER_BALANCE - amount_1);
+}
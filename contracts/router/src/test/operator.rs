@@ -0,0 +1,270 @@
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec,
+    Address,
+    IntoVal,
+    symbol_short,
+    Vec};
+
+use crate::test::{SoroswapRouterTest, SoroswapPairClient};
+use crate::test::add_liquidity::add_liquidity;
+use crate::error::CombinedRouterError;
+use crate::event::SetOperatorEvent;
+
+const INITIAL_USER_BALANCE: i128 = 10_000_000_000_000_000_000;
+
+fn expiration_ledger(test: &SoroswapRouterTest) -> u32 {
+    test.env.ledger().sequence() + 1000
+}
+
+#[test]
+fn set_operator() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let operator = Address::generate(&test.env);
+
+    assert!(!test.contract.is_operator(&test.user, &operator));
+    test.contract.set_operator(&test.user, &operator, &true);
+    assert!(test.contract.is_operator(&test.user, &operator));
+    // Approvals are per owner
+    assert!(!test.contract.is_operator(&test.admin, &operator));
+
+    let set_operator_event = test.env.events().all().last().unwrap();
+    let expected_set_operator_event: SetOperatorEvent = SetOperatorEvent {
+        owner: test.user.clone(),
+        operator: operator.clone(),
+        approved: true,
+    };
+    assert_eq!(
+        vec![&test.env, set_operator_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("operator")).into_val(&test.env),
+                (expected_set_operator_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    test.contract.set_operator(&test.user, &operator, &false);
+    assert!(!test.contract.is_operator(&test.user, &operator));
+}
+
+#[test]
+fn set_operator_not_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let operator = Address::generate(&test.env);
+    let result = test.contract.try_set_operator(&test.user, &operator, &true);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn add_liquidity_operator() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let operator = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let amount_0: i128 = 1_000_000_000_000_000_000;
+    let amount_1: i128 = 4_000_000_000_000_000_000;
+    test.token_0.approve(&test.user, &test.contract.address, &amount_0, &expiration_ledger(&test));
+    test.token_1.approve(&test.user, &test.contract.address, &amount_1, &expiration_ledger(&test));
+    test.contract.set_operator(&test.user, &operator, &true);
+
+    let (deposited_0, deposited_1, liquidity) = test.contract.add_liquidity_operator(
+        &test.token_0.address,
+        &test.token_1.address,
+        &amount_0,
+        &amount_1,
+        &0,
+        &0,
+        &operator,
+        &test.user,
+        &recipient,
+        &deadline,
+        &None,
+    );
+
+    // Only the operator had to authorize it
+    let auths = test.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, operator);
+
+    let pair_address = test.contract.router_pair_for(&test.token_0.address, &test.token_1.address);
+    assert_eq!((deposited_0, deposited_1), (amount_0, amount_1));
+    assert_eq!(SoroswapPairClient::new(&test.env, &pair_address).balance(&recipient), liquidity);
+    assert_eq!(test.token_0.balance(&test.user), INITIAL_USER_BALANCE - amount_0);
+    assert_eq!(test.token_1.balance(&test.user), INITIAL_USER_BALANCE - amount_1);
+
+    // The allowances were spent
+    assert_eq!(test.token_0.allowance(&test.user, &test.contract.address), 0);
+    assert_eq!(test.token_1.allowance(&test.user, &test.contract.address), 0);
+}
+
+#[test]
+fn remove_liquidity_operator() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let operator = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+
+    let amount_0: i128 = 1_000_000_000_000_000_000;
+    let amount_1: i128 = 4_000_000_000_000_000_000;
+    let (_, _, liquidity) = add_liquidity(&test, &amount_0, &amount_1);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    // The LP tokens are taken with the pair's own transfer_from
+    let pair_address = test.contract.router_pair_for(&test.token_0.address, &test.token_1.address);
+    let pair_client = SoroswapPairClient::new(&test.env, &pair_address);
+    pair_client.approve(&test.user, &test.contract.address, &liquidity, &expiration_ledger(&test));
+    test.contract.set_operator(&test.user, &operator, &true);
+
+    let (amount_a, amount_b) = test.contract.remove_liquidity_operator(
+        &test.token_0.address,
+        &test.token_1.address,
+        &liquidity,
+        &0,
+        &0,
+        &operator,
+        &test.user,
+        &recipient,
+        &deadline,
+        &None,
+    );
+
+    let auths = test.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, operator);
+
+    assert_eq!(pair_client.balance(&test.user), 0);
+    assert_eq!(pair_client.allowance(&test.user, &test.contract.address), 0);
+    assert_eq!(test.token_0.balance(&recipient), amount_a);
+    assert_eq!(test.token_1.balance(&recipient), amount_b);
+}
+
+#[test]
+fn swap_exact_tokens_operator() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let operator = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+
+    let amount_0: i128 = 1_000_000_000_000_000_000;
+    let amount_1: i128 = 4_000_000_000_000_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let amount_in: i128 = 1_000_000;
+    let expected_amounts = test.contract.router_get_amounts_out(&amount_in, &path);
+
+    test.token_0.approve(&test.user, &test.contract.address, &amount_in, &expiration_ledger(&test));
+    test.contract.set_operator(&test.user, &operator, &true);
+
+    let amounts = test.contract.swap_exact_tokens_operator(
+        &amount_in,
+        &0,
+        &path,
+        &operator,
+        &test.user,
+        &recipient,
+        &deadline,
+        &None,
+    );
+
+    let auths = test.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, operator);
+
+    assert_eq!(amounts, expected_amounts);
+    assert_eq!(test.token_0.balance(&test.user), INITIAL_USER_BALANCE - amount_0 - amount_in);
+    assert_eq!(test.token_1.balance(&recipient), expected_amounts.get(1).unwrap());
+    assert_eq!(test.token_0.allowance(&test.user, &test.contract.address), 0);
+}
+
+#[test]
+fn swap_for_exact_tokens_operator() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let operator = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+
+    let amount_0: i128 = 1_000_000_000_000_000_000;
+    let amount_1: i128 = 4_000_000_000_000_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let amount_out: i128 = 4_000_000;
+    let expected_amounts = test.contract.router_get_amounts_in(&amount_out, &path);
+    let amount_in = expected_amounts.get(0).unwrap();
+
+    // Approve more than needed; only the input amount is spent
+    test.token_0.approve(&test.user, &test.contract.address, &(amount_in + 1_000), &expiration_ledger(&test));
+    test.contract.set_operator(&test.user, &operator, &true);
+
+    let amounts = test.contract.swap_for_exact_tokens_operator(
+        &amount_out,
+        &i128::MAX,
+        &path,
+        &operator,
+        &test.user,
+        &recipient,
+        &deadline,
+        &None,
+    );
+
+    let auths = test.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, operator);
+
+    assert_eq!(amounts, expected_amounts);
+    assert_eq!(test.token_0.balance(&test.user), INITIAL_USER_BALANCE - amount_0 - amount_in);
+    assert_eq!(test.token_1.balance(&recipient), amount_out);
+    assert_eq!(test.token_0.allowance(&test.user, &test.contract.address), 1_000);
+}
+
+#[test]
+fn swap_exact_tokens_operator_not_approved() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let operator = Address::generate(&test.env);
+
+    let amount_0: i128 = 1_000_000_000_000_000_000;
+    let amount_1: i128 = 4_000_000_000_000_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+
+    // An allowance to the router is not enough: the owner must also approve the operator
+    test.token_0.approve(&test.user, &test.contract.address, &1_000_000, &expiration_ledger(&test));
+    let result = test.contract.try_swap_exact_tokens_operator(&1_000_000, &0, &path, &operator, &test.user, &operator, &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotOperator)));
+
+    // Neither is a revoked approval
+    test.contract.set_operator(&test.user, &operator, &true);
+    test.contract.set_operator(&test.user, &operator, &false);
+    let result = test.contract.try_swap_exact_tokens_operator(&1_000_000, &0, &path, &operator, &test.user, &operator, &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotOperator)));
+}
+
+#[test]
+#[should_panic]
+fn swap_exact_tokens_operator_insufficient_allowance() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let operator = Address::generate(&test.env);
+
+    let amount_0: i128 = 1_000_000_000_000_000_000;
+    let amount_1: i128 = 4_000_000_000_000_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+
+    // The operator can not spend more than the owner approved to the router
+    test.token_0.approve(&test.user, &test.contract.address, &999_999, &expiration_ledger(&test));
+    test.contract.set_operator(&test.user, &operator, &true);
+    test.contract.swap_exact_tokens_operator(&1_000_000, &0, &path, &operator, &test.user, &operator, &deadline, &None);
+}
@@ -0,0 +1,105 @@
+use soroban_sdk::{
+    testutils::Events,
+    vec,
+    Address,
+    IntoVal,
+    symbol_short,
+    Val,
+    Vec};
+use soroswap_library::PRICE_PRECISION;
+
+use crate::test::SoroswapRouterTest;
+use crate::test::add_liquidity::add_liquidity;
+use crate::error::CombinedRouterError;
+
+const INITIAL_USER_BALANCE: i128 = 10_000_000_000_000_000_000;
+const AMOUNT_0: i128 = 1_000_000_000_000_000_000;
+const AMOUNT_1: i128 = 4_000_000_000_000_000_000;
+
+fn setup_pool<'a>() -> SoroswapRouterTest<'a> {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &AMOUNT_0, &AMOUNT_1);
+    test
+}
+
+fn path(test: &SoroswapRouterTest) -> Vec<Address> {
+    vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]
+}
+
+#[test]
+fn swap_exact_tokens_price_limit_not_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let result = test.contract.try_swap_exact_tokens_price_limit(&1_000_000, &PRICE_PRECISION, &path(&test), &test.user, &0, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn swap_exact_tokens_price_limit_invalid_price() {
+    let test = setup_pool();
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let result = test.contract.try_swap_exact_tokens_price_limit(&1_000_000, &-1, &path(&test), &test.user, &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNegativeNotAllowed)));
+
+    let result = test.contract.try_swap_exact_tokens_price_limit(&1_000_000, &0, &path(&test), &test.user, &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPrice)));
+}
+
+#[test]
+fn swap_exact_tokens_price_limit_partial_fill() {
+    let test = setup_pool();
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    // The mid price is 4, so selling the whole AMOUNT_0 would get a price below 2.
+    // Only the part that keeps the price at or above 3.8 is swapped
+    let amount_in_max = AMOUNT_0;
+    let min_price = 38 * PRICE_PRECISION / 10;
+    let (amount_in, amount_unfilled, amount_out) = test.contract.swap_exact_tokens_price_limit(
+        &amount_in_max, &min_price, &path(&test), &test.user, &deadline, &None);
+
+    assert!(amount_in > 0);
+    assert!(amount_unfilled > 0);
+    assert_eq!(amount_in + amount_unfilled, amount_in_max);
+    assert!(amount_out * PRICE_PRECISION >= min_price * amount_in);
+
+    // The unfilled amount was never transferred
+    assert_eq!(test.token_0.balance(&test.user), INITIAL_USER_BALANCE - AMOUNT_0 - amount_in);
+    assert_eq!(test.token_1.balance(&test.user), INITIAL_USER_BALANCE - AMOUNT_1 + amount_out);
+
+    // Trading 1% more would have been below the price limit
+    let amounts = test.contract.router_get_amounts_out(&(amount_in + amount_in / 100), &path(&test));
+    assert!(amounts.get(1).unwrap() * PRICE_PRECISION < min_price * amounts.get(0).unwrap());
+}
+
+#[test]
+fn swap_exact_tokens_price_limit_full_fill() {
+    let test = setup_pool();
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let amount_in_max: i128 = 1_000_000;
+    let expected_amounts = test.contract.router_get_amounts_out(&amount_in_max, &path(&test));
+    let result = test.contract.swap_exact_tokens_price_limit(
+        &amount_in_max, &(3 * PRICE_PRECISION), &path(&test), &test.user, &deadline, &None);
+
+    assert_eq!(result, (amount_in_max, 0, expected_amounts.get(1).unwrap()));
+    assert_eq!(test.token_0.balance(&test.user), INITIAL_USER_BALANCE - AMOUNT_0 - amount_in_max);
+}
+
+#[test]
+fn swap_exact_tokens_price_limit_no_fill() {
+    let test = setup_pool();
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    // No trade gets the mid price, because of the swap fee
+    let amount_in_max: i128 = 1_000_000;
+    let result = test.contract.swap_exact_tokens_price_limit(
+        &amount_in_max, &(4 * PRICE_PRECISION), &path(&test), &test.user, &deadline, &None);
+
+    assert_eq!(result, (0, amount_in_max, 0));
+    assert_eq!(test.token_0.balance(&test.user), INITIAL_USER_BALANCE - AMOUNT_0);
+    assert_eq!(test.token_1.balance(&test.user), INITIAL_USER_BALANCE - AMOUNT_1);
+    // Nothing was swapped, so there is no swap event
+    let swap_topics: Vec<Val> = ("SoroswapRouter", symbol_short!("swap")).into_val(&test.env);
+    assert!(test.env.events().all().iter().all(|(_, topics, _)| topics != swap_topics));
+}
@@ -0,0 +1,247 @@
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec,
+    Address,
+    IntoVal,
+    symbol_short,
+    Vec};
+
+use crate::test::SoroswapRouterTest;
+use crate::test::add_liquidity::add_liquidity;
+use crate::error::CombinedRouterError;
+use crate::event::{SwapEvent, ReferralFee};
+use crate::referral::{Referral, ReferralFeeToken};
+
+const INITIAL_USER_BALANCE: i128 = 10_000_000_000_000_000_000;
+const AMOUNT_0: i128 = 1_000_000_000_000_000_000;
+const AMOUNT_1: i128 = 4_000_000_000_000_000_000;
+const REFERRAL_FEE_BPS: u32 = 50;
+
+fn setup_pool<'a>() -> SoroswapRouterTest<'a> {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &AMOUNT_0, &AMOUNT_1);
+    test.factory.set_max_referral_fee(&100);
+    test
+}
+
+fn path(test: &SoroswapRouterTest) -> Vec<Address> {
+    vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]
+}
+
+fn referral(referrer: &Address, fee_bps: u32, fee_token: ReferralFeeToken) -> Referral {
+    Referral {
+        referrer: referrer.clone(),
+        fee_bps,
+        fee_token,
+    }
+}
+
+#[test]
+fn swap_exact_tokens_referral_fee_too_high() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &AMOUNT_0, &AMOUNT_1);
+    let referrer = Address::generate(&test.env);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    // Referral fees are not allowed until the factory sets a maximum
+    let result = test.contract.try_swap_exact_tokens_referral(
+        &1_000_000, &0, &path(&test), &test.user, &referral(&referrer, 1, ReferralFeeToken::Input), &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterReferralFeeTooHigh)));
+
+    test.factory.set_max_referral_fee(&100);
+    let result = test.contract.try_swap_exact_tokens_referral(
+        &1_000_000, &0, &path(&test), &test.user, &referral(&referrer, 101, ReferralFeeToken::Input), &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterReferralFeeTooHigh)));
+
+    let result = test.contract.try_swap_for_exact_tokens_referral(
+        &1_000_000, &i128::MAX, &path(&test), &test.user, &referral(&referrer, 101, ReferralFeeToken::Output), &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterReferralFeeTooHigh)));
+}
+
+#[test]
+fn swap_exact_tokens_referral_without_fee() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &AMOUNT_0, &AMOUNT_1);
+    let referrer = Address::generate(&test.env);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    // A referrer can be credited for the volume without charging a fee
+    let amount_in: i128 = 1_000_000;
+    let expected_amounts = test.contract.router_get_amounts_out(&amount_in, &path(&test));
+    let amounts = test.contract.swap_exact_tokens_referral(
+        &amount_in, &0, &path(&test), &test.user, &referral(&referrer, 0, ReferralFeeToken::Input), &deadline, &None);
+    assert_eq!(amounts, expected_amounts);
+    assert_eq!(test.token_0.balance(&referrer), 0);
+
+    let swap_event = test.env.events().all().last().unwrap();
+    let expected_swap_event: SwapEvent = SwapEvent {
+        path: path(&test),
+        amounts,
+        from: test.user.clone(),
+        to: test.user.clone(),
+        referral: Some(ReferralFee {
+            referrer,
+            token: test.token_0.address.clone(),
+            amount: 0,
+        }),
+    };
+    assert_eq!(
+        vec![&test.env, swap_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("swap")).into_val(&test.env),
+                (expected_swap_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn swap_exact_tokens_referral_input_fee() {
+    let test = setup_pool();
+    let referrer = Address::generate(&test.env);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    // The fee is taken from amount_in, and only the rest is swapped
+    let amount_in: i128 = 1_000_000;
+    let fee = amount_in * REFERRAL_FEE_BPS as i128 / 10_000;
+    let expected_amounts = test.contract.router_get_amounts_out(&(amount_in - fee), &path(&test));
+
+    let amounts = test.contract.swap_exact_tokens_referral(
+        &amount_in, &0, &path(&test), &test.user, &referral(&referrer, REFERRAL_FEE_BPS, ReferralFeeToken::Input), &deadline, &None);
+
+    assert_eq!(fee, 5_000);
+    assert_eq!(amounts, expected_amounts);
+    assert_eq!(test.token_0.balance(&referrer), fee);
+    assert_eq!(test.token_0.balance(&test.user), INITIAL_USER_BALANCE - AMOUNT_0 - amount_in);
+    assert_eq!(test.token_1.balance(&test.user), INITIAL_USER_BALANCE - AMOUNT_1 + expected_amounts.get(1).unwrap());
+
+    let swap_event = test.env.events().all().last().unwrap();
+    let expected_swap_event: SwapEvent = SwapEvent {
+        path: path(&test),
+        amounts,
+        from: test.user.clone(),
+        to: test.user.clone(),
+        referral: Some(ReferralFee {
+            referrer,
+            token: test.token_0.address.clone(),
+            amount: fee,
+        }),
+    };
+    assert_eq!(
+        vec![&test.env, swap_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("swap")).into_val(&test.env),
+                (expected_swap_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn swap_exact_tokens_referral_output_fee() {
+    let test = setup_pool();
+    let referrer = Address::generate(&test.env);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    // The whole amount_in is swapped, and the fee is taken from the output
+    let amount_in: i128 = 1_000_000;
+    let expected_amounts = test.contract.router_get_amounts_out(&amount_in, &path(&test));
+    let swap_amount_out = expected_amounts.get(1).unwrap();
+    let fee = swap_amount_out * REFERRAL_FEE_BPS as i128 / 10_000;
+
+    // amount_out_min is checked against what the user receives
+    let result = test.contract.try_swap_exact_tokens_referral(
+        &amount_in, &(swap_amount_out - fee + 1), &path(&test), &test.user, &referral(&referrer, REFERRAL_FEE_BPS, ReferralFeeToken::Output), &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+
+    let amounts = test.contract.swap_exact_tokens_referral(
+        &amount_in, &(swap_amount_out - fee), &path(&test), &test.user, &referral(&referrer, REFERRAL_FEE_BPS, ReferralFeeToken::Output), &deadline, &None);
+
+    assert_eq!(amounts, expected_amounts);
+    assert_eq!(test.token_1.balance(&referrer), fee);
+    assert_eq!(test.token_1.balance(&test.user), INITIAL_USER_BALANCE - AMOUNT_1 + swap_amount_out - fee);
+    assert_eq!(test.token_0.balance(&test.user), INITIAL_USER_BALANCE - AMOUNT_0 - amount_in);
+    // Nothing is left in the router
+    assert_eq!(test.token_1.balance(&test.contract.address), 0);
+
+    let swap_event = test.env.events().all().last().unwrap();
+    let expected_swap_event: SwapEvent = SwapEvent {
+        path: path(&test),
+        amounts,
+        from: test.user.clone(),
+        to: test.user.clone(),
+        referral: Some(ReferralFee {
+            referrer,
+            token: test.token_1.address.clone(),
+            amount: fee,
+        }),
+    };
+    assert_eq!(
+        vec![&test.env, swap_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("swap")).into_val(&test.env),
+                (expected_swap_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn swap_for_exact_tokens_referral_input_fee() {
+    let test = setup_pool();
+    let referrer = Address::generate(&test.env);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    // The fee is paid on top of the swap input
+    let amount_out: i128 = 4_000_000;
+    let expected_amounts = test.contract.router_get_amounts_in(&amount_out, &path(&test));
+    let swap_amount_in = expected_amounts.get(0).unwrap();
+    let fee = swap_amount_in * REFERRAL_FEE_BPS as i128 / 10_000;
+
+    // amount_in_max is checked against the input including the fee
+    let result = test.contract.try_swap_for_exact_tokens_referral(
+        &amount_out, &(swap_amount_in + fee - 1), &path(&test), &test.user, &referral(&referrer, REFERRAL_FEE_BPS, ReferralFeeToken::Input), &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterExcessiveInputAmount)));
+
+    let amounts = test.contract.swap_for_exact_tokens_referral(
+        &amount_out, &(swap_amount_in + fee), &path(&test), &test.user, &referral(&referrer, REFERRAL_FEE_BPS, ReferralFeeToken::Input), &deadline, &None);
+
+    assert_eq!(amounts, expected_amounts);
+    assert_eq!(test.token_0.balance(&referrer), fee);
+    assert_eq!(test.token_0.balance(&test.user), INITIAL_USER_BALANCE - AMOUNT_0 - swap_amount_in - fee);
+    assert_eq!(test.token_1.balance(&test.user), INITIAL_USER_BALANCE - AMOUNT_1 + amount_out);
+}
+
+#[test]
+fn swap_for_exact_tokens_referral_output_fee() {
+    let test = setup_pool();
+    let referrer = Address::generate(&test.env);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    // The swap buys the fee in addition to amount_out
+    let amount_out: i128 = 4_000_000;
+    let fee = amount_out * REFERRAL_FEE_BPS as i128 / 10_000;
+    let expected_amounts = test.contract.router_get_amounts_in(&(amount_out + fee), &path(&test));
+
+    let amounts = test.contract.swap_for_exact_tokens_referral(
+        &amount_out, &i128::MAX, &path(&test), &test.user, &referral(&referrer, REFERRAL_FEE_BPS, ReferralFeeToken::Output), &deadline, &None);
+
+    assert_eq!(fee, 20_000);
+    assert_eq!(amounts, expected_amounts);
+    assert_eq!(test.token_1.balance(&referrer), fee);
+    assert_eq!(test.token_1.balance(&test.user), INITIAL_USER_BALANCE - AMOUNT_1 + amount_out);
+    assert_eq!(test.token_0.balance(&test.user), INITIAL_USER_BALANCE - AMOUNT_0 - expected_amounts.get(0).unwrap());
+    assert_eq!(test.token_1.balance(&test.contract.address), 0);
+}
@@ -0,0 +1,243 @@
+use soroban_sdk::{
+    vec,
+    symbol_short,
+    Address,
+    IntoVal,
+    Symbol,
+    Vec};
+use soroswap_library::{PairKind, RouteHop, CONSTANT_PRODUCT_KIND, STABLE_KIND};
+
+use crate::test::{SoroswapRouterTest, SoroswapStablePairClient, SoroswapWeightedPairClient, create_token_contract, stable_pair, weighted_pair};
+use crate::error::CombinedRouterError;
+
+const AMP: u32 = 100;
+const FEE: u32 = 4;
+const STABLE_RESERVE: i128 = 10_000_000_000;
+// 80/20 weighted pair where 1 token_0 is worth 1 token_1
+const WEIGHT_0: u32 = 8_000;
+const WEIGHTED_FEE: u32 = 30;
+const WEIGHTED_RESERVE_0: i128 = 80_000_000_000;
+const WEIGHTED_RESERVE_1: i128 = 20_000_000_000;
+
+fn create_stable_pair<'a>(test: &SoroswapRouterTest<'a>) -> SoroswapStablePairClient<'a> {
+    let stable_pair_hash = test.env.deployer().upload_contract_wasm(stable_pair::WASM);
+    test.factory.set_stable_pair_wasm_hash(&stable_pair_hash);
+    let address = test.factory.create_stable_pair(&test.token_0.address, &test.token_1.address, &AMP, &FEE);
+    let stable_pair = SoroswapStablePairClient::new(&test.env, &address);
+
+    test.token_0.transfer(&test.user, &stable_pair.address, &STABLE_RESERVE);
+    test.token_1.transfer(&test.user, &stable_pair.address, &STABLE_RESERVE);
+    stable_pair.deposit(&test.user);
+    stable_pair
+}
+
+fn setup_stable_pool<'a>() -> (SoroswapRouterTest<'a>, SoroswapStablePairClient<'a>) {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let stable_pair = create_stable_pair(&test);
+    (test, stable_pair)
+}
+
+fn setup_weighted_pool<'a>() -> (SoroswapRouterTest<'a>, SoroswapWeightedPairClient<'a>) {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let weighted_pair_hash = test.env.deployer().upload_contract_wasm(weighted_pair::WASM);
+    test.factory.set_weighted_pair_wasm_hash(&weighted_pair_hash);
+    let address = test.factory.create_weighted_pair(&test.token_0.address, &test.token_1.address, &WEIGHT_0, &WEIGHTED_FEE);
+    let weighted_pair = SoroswapWeightedPairClient::new(&test.env, &address);
+
+    test.token_0.transfer(&test.user, &weighted_pair.address, &WEIGHTED_RESERVE_0);
+    test.token_1.transfer(&test.user, &weighted_pair.address, &WEIGHTED_RESERVE_1);
+    weighted_pair.deposit(&test.user);
+    (test, weighted_pair)
+}
+
+// Stable pair with a 0.01% fee next to the one of create_stable_pair, deployed from a template of the factory
+const STABLE_1_VARIANT: Symbol = symbol_short!("stable_1");
+
+fn create_stable_1_pair<'a>(test: &SoroswapRouterTest<'a>) -> SoroswapStablePairClient<'a> {
+    let stable_pair_hash = test.env.deployer().upload_contract_wasm(stable_pair::WASM);
+    test.factory.set_pool_template(&STABLE_1_VARIANT, &stable_pair_hash, &vec![&test.env, AMP.into_val(&test.env), 1_u32.into_val(&test.env)]);
+    let address = test.factory.create_pair_with_kind(&test.token_0.address, &test.token_1.address, &STABLE_1_VARIANT);
+    let stable_pair = SoroswapStablePairClient::new(&test.env, &address);
+
+    test.token_0.transfer(&test.user, &stable_pair.address, &STABLE_RESERVE);
+    test.token_1.transfer(&test.user, &stable_pair.address, &STABLE_RESERVE);
+    stable_pair.deposit(&test.user);
+    stable_pair
+}
+
+fn path(test: &SoroswapRouterTest) -> Vec<Address> {
+    vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]
+}
+
+#[test]
+fn swap_route_not_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let hops = vec![&test.env, RouteHop::Kind(PairKind::Stable)];
+
+    let result = test.contract.try_swap_exact_tokens_route(&1_000, &0, &path(&test), &hops, &test.user, &0, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+    let result = test.contract.try_swap_for_exact_tokens_route(&1_000, &0, &path(&test), &hops, &test.user, &0, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+    let result = test.contract.try_router_get_amounts_out_route(&1_000, &path(&test), &hops);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn swap_route_invalid_hops() {
+    let (test, _stable_pair) = setup_stable_pool();
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let hops = vec![&test.env, RouteHop::Kind(PairKind::Stable), RouteHop::Kind(PairKind::Stable)];
+
+    let result = test.contract.try_swap_exact_tokens_route(&1_000, &0, &path(&test), &hops, &test.user, &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPath)));
+    let result = test.contract.try_router_get_amounts_in_route(&1_000, &path(&test), &Vec::new(&test.env));
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPath)));
+
+    // Stable math can not be applied to the constant product pair, nor constant product math to the stable pair
+    let hops = vec![&test.env, RouteHop::Variant(PairKind::Stable, CONSTANT_PRODUCT_KIND)];
+    let result = test.contract.try_swap_exact_tokens_route(&1_000, &0, &path(&test), &hops, &test.user, &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPath)));
+    let hops = vec![&test.env, RouteHop::Variant(PairKind::ConstantProduct, STABLE_KIND)];
+    let result = test.contract.try_swap_for_exact_tokens_route(&1_000, &i128::MAX, &path(&test), &hops, &test.user, &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPath)));
+}
+
+#[test]
+fn swap_exact_tokens_route_stable() {
+    let (test, stable_pair) = setup_stable_pool();
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let hops = vec![&test.env, RouteHop::Kind(PairKind::Stable)];
+    let amount_in = 1_000_000_000;
+    let expected_amounts = vec![&test.env, amount_in, 999_098_173];
+    assert_eq!(test.contract.router_get_amounts_out_route(&amount_in, &path(&test), &hops), expected_amounts);
+
+    let result = test.contract.try_swap_exact_tokens_route(&amount_in, &999_098_174, &path(&test), &hops, &test.user, &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+
+    let initial_0 = test.token_0.balance(&test.user);
+    let initial_1 = test.token_1.balance(&test.user);
+    let amounts = test.contract.swap_exact_tokens_route(&amount_in, &999_098_173, &path(&test), &hops, &test.user, &deadline, &None);
+    assert_eq!(amounts, expected_amounts);
+
+    assert_eq!(test.token_0.balance(&test.user), initial_0 - amount_in);
+    assert_eq!(test.token_1.balance(&test.user), initial_1 + 999_098_173);
+    assert_eq!(stable_pair.get_reserves().0, STABLE_RESERVE + amount_in);
+    assert_eq!(stable_pair.get_reserves().1, STABLE_RESERVE - 999_098_173);
+}
+
+#[test]
+fn swap_for_exact_tokens_route_stable() {
+    let (test, _stable_pair) = setup_stable_pool();
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let hops = vec![&test.env, RouteHop::Kind(PairKind::Stable)];
+    let amount_out = 999_098_173;
+    let expected_amounts = vec![&test.env, 1_000_000_000, amount_out];
+    assert_eq!(test.contract.router_get_amounts_in_route(&amount_out, &path(&test), &hops), expected_amounts);
+
+    let result = test.contract.try_swap_for_exact_tokens_route(&amount_out, &999_999_999, &path(&test), &hops, &test.user, &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterExcessiveInputAmount)));
+
+    let initial_0 = test.token_0.balance(&test.user);
+    let initial_1 = test.token_1.balance(&test.user);
+    let amounts = test.contract.swap_for_exact_tokens_route(&amount_out, &1_000_000_000, &path(&test), &hops, &test.user, &deadline, &None);
+    assert_eq!(amounts, expected_amounts);
+
+    assert_eq!(test.token_0.balance(&test.user), initial_0 - 1_000_000_000);
+    assert_eq!(test.token_1.balance(&test.user), initial_1 + amount_out);
+}
+
+#[test]
+fn swap_route_constant_product_and_stable() {
+    let (test, stable_pair) = setup_stable_pool();
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    // A constant product pair of token_1 and token_2
+    let token_2 = create_token_contract(&test.env, &test.admin);
+    token_2.mint(&test.user, &10_000_000_000_000_000_000);
+    test.contract.add_liquidity(&test.token_1.address, &token_2.address, &10_000_000_000, &20_000_000_000, &0, &0, &test.user, &deadline);
+
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_2.address.clone()];
+    let hops = vec![&test.env, RouteHop::Kind(PairKind::Stable), RouteHop::Kind(PairKind::ConstantProduct)];
+    let amount_in = 1_000_000_000;
+    let expected_amounts = test.contract.router_get_amounts_out_route(&amount_in, &path, &hops);
+    assert_eq!(expected_amounts.get(1).unwrap(), 999_098_173);
+
+    let initial_0 = test.token_0.balance(&test.user);
+    let initial_2 = token_2.balance(&test.user);
+    let amounts = test.contract.swap_exact_tokens_route(&amount_in, &0, &path, &hops, &test.user, &deadline, &None);
+    assert_eq!(amounts, expected_amounts);
+
+    assert_eq!(test.token_0.balance(&test.user), initial_0 - amount_in);
+    assert_eq!(token_2.balance(&test.user), initial_2 + expected_amounts.get(2).unwrap());
+    assert_eq!(stable_pair.get_reserves().0, STABLE_RESERVE + amount_in);
+
+    // The constant product pair of token_0 and token_1 was not used
+    assert_eq!(test.factory.pair_exists(&test.token_0.address, &test.token_1.address), false);
+}
+
+#[test]
+fn swap_exact_tokens_route_weighted() {
+    let (test, weighted_pair) = setup_weighted_pool();
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let hops = vec![&test.env, RouteHop::Kind(PairKind::Weighted)];
+    let amount_in = 1_000_000_000;
+    let expected_amounts = vec![&test.env, amount_in, 966_694_903];
+    assert_eq!(test.contract.router_get_amounts_out_route(&amount_in, &path(&test), &hops), expected_amounts);
+
+    let initial_1 = test.token_1.balance(&test.user);
+    let amounts = test.contract.swap_exact_tokens_route(&amount_in, &966_694_903, &path(&test), &hops, &test.user, &deadline, &None);
+    assert_eq!(amounts, expected_amounts);
+
+    assert_eq!(test.token_1.balance(&test.user), initial_1 + 966_694_903);
+    assert_eq!(weighted_pair.get_reserves().0, WEIGHTED_RESERVE_0 + amount_in);
+    assert_eq!(weighted_pair.get_reserves().1, WEIGHTED_RESERVE_1 - 966_694_903);
+}
+
+#[test]
+fn swap_for_exact_tokens_route_weighted() {
+    let (test, _weighted_pair) = setup_weighted_pool();
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let hops = vec![&test.env, RouteHop::Kind(PairKind::Weighted)];
+    let amount_out = 966_694_903;
+    let expected_amounts = vec![&test.env, 1_000_000_000, amount_out];
+    assert_eq!(test.contract.router_get_amounts_in_route(&amount_out, &path(&test), &hops), expected_amounts);
+
+    let initial_0 = test.token_0.balance(&test.user);
+    let amounts = test.contract.swap_for_exact_tokens_route(&amount_out, &1_000_000_000, &path(&test), &hops, &test.user, &deadline, &None);
+    assert_eq!(amounts, expected_amounts);
+    assert_eq!(test.token_0.balance(&test.user), initial_0 - 1_000_000_000);
+}
+
+#[test]
+fn swap_route_weighted_max_ratio() {
+    let (test, _weighted_pair) = setup_weighted_pool();
+    let hops = vec![&test.env, RouteHop::Kind(PairKind::Weighted)];
+
+    // Weighted pairs do not take in more than 30% of a reserve
+    let result = test.contract.try_router_get_amounts_out_route(&(WEIGHTED_RESERVE_0 * 3 / 10 + 1), &path(&test), &hops);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInsufficientLiquidity)));
+}
+
+#[test]
+fn swap_route_variant() {
+    let (test, stable_pair) = setup_stable_pool();
+    let stable_1_pair = create_stable_1_pair(&test);
+    assert_eq!(test.contract.router_pair_for_variant(&test.token_0.address, &test.token_1.address, &STABLE_KIND), stable_pair.address);
+    assert_eq!(test.contract.router_pair_for_variant(&test.token_1.address, &test.token_0.address, &STABLE_1_VARIANT), stable_1_pair.address);
+
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let amount_in = 1_000_000_000;
+    let hops = vec![&test.env, RouteHop::Variant(PairKind::Stable, STABLE_1_VARIANT)];
+
+    // Both variants have the same reserves, the one with the lower fee gives more
+    let stable_amounts = test.contract.router_get_amounts_out_route(&amount_in, &path(&test), &vec![&test.env, RouteHop::Kind(PairKind::Stable)]);
+    let expected_amounts = test.contract.router_get_amounts_out_route(&amount_in, &path(&test), &hops);
+    assert!(expected_amounts.get(1).unwrap() > stable_amounts.get(1).unwrap());
+
+    let amounts = test.contract.swap_exact_tokens_route(&amount_in, &0, &path(&test), &hops, &test.user, &deadline, &None);
+    assert_eq!(amounts, expected_amounts);
+    assert_eq!(test.token_0.balance(&stable_1_pair.address), STABLE_RESERVE + amount_in);
+    assert_eq!(test.token_0.balance(&stable_pair.address), STABLE_RESERVE);
+}
@@ -0,0 +1,141 @@
+use soroban_sdk::{contract, contractimpl, contracttype, token, vec, Address, Bytes, Env, Vec};
+use soroswap_router_callee_interface::SoroswapRouterCalleeTrait;
+
+use crate::test::SoroswapRouterTest;
+use crate::test::add_liquidity::add_liquidity;
+use crate::error::CombinedRouterError;
+use crate::Validity;
+
+const INITIAL_USER_BALANCE: i128 = 10_000_000_000_000_000_000;
+const AMOUNT_0: i128 = 1_000_000_000_000_000_000;
+const AMOUNT_1: i128 = 4_000_000_000_000_000_000;
+
+// MOCK RECEIVER CONTRACT
+// Works like a lending market: deposits everything it receives from the router
+#[derive(Clone)]
+#[contracttype]
+enum MockReceiverDataKey {
+    Router, // Address. The only router allowed to call soroswap_router_call
+    Deposited, // (Address, i128). The token and amount deposited in the last soroswap_router_call
+    LastData, // Bytes. The payload received in the last soroswap_router_call
+}
+
+#[contract]
+pub struct MockReceiver;
+
+#[contractimpl]
+impl MockReceiver {
+    pub fn initialize(e: Env, router: Address) {
+        e.storage().instance().set(&MockReceiverDataKey::Router, &router);
+    }
+
+    pub fn deposited(e: Env) -> (Address, i128) {
+        e.storage().instance().get(&MockReceiverDataKey::Deposited).unwrap()
+    }
+
+    pub fn last_data(e: Env) -> Bytes {
+        e.storage().instance().get(&MockReceiverDataKey::LastData).unwrap()
+    }
+}
+
+#[contractimpl]
+impl SoroswapRouterCalleeTrait for MockReceiver {
+    fn soroswap_router_call(e: Env, token: Address, amount: i128, data: Bytes) {
+        let router: Address = e.storage().instance().get(&MockReceiverDataKey::Router).unwrap();
+        router.require_auth();
+        // The tokens must have arrived before the call. An empty payload is rejected
+        if token::Client::new(&e, &token).balance(&e.current_contract_address()) < amount || data.is_empty() {
+            panic!("invalid deposit");
+        }
+        e.storage().instance().set(&MockReceiverDataKey::Deposited, &(token, amount));
+        e.storage().instance().set(&MockReceiverDataKey::LastData, &data);
+    }
+}
+
+fn create_mock_receiver<'a>(test: &SoroswapRouterTest) -> MockReceiverClient<'a> {
+    let receiver = MockReceiverClient::new(&test.env, &test.env.register_contract(None, MockReceiver {}));
+    receiver.initialize(&test.contract.address);
+    receiver
+}
+
+fn setup_pool<'a>() -> SoroswapRouterTest<'a> {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &AMOUNT_0, &AMOUNT_1);
+    test
+}
+
+fn path(test: &SoroswapRouterTest) -> Vec<Address> {
+    vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]
+}
+
+#[test]
+fn swap_and_call_not_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let receiver = create_mock_receiver(&test);
+    let data = Bytes::from_slice(&test.env, &[1, 2, 3]);
+    let result = test.contract.try_swap_and_call(&1_000_000, &0, &path(&test), &test.user, &receiver.address, &data, &0, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn swap_and_call() {
+    let test = setup_pool();
+    let receiver = create_mock_receiver(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let data = Bytes::from_slice(&test.env, &[1, 2, 3]);
+
+    let amount_in: i128 = 1_000_000;
+    let expected_amounts = test.contract.router_get_amounts_out(&amount_in, &path(&test));
+    let amounts = test.contract.swap_and_call(&amount_in, &0, &path(&test), &test.user, &receiver.address, &data, &deadline, &None);
+    assert_eq!(amounts, expected_amounts);
+
+    let amount_out = expected_amounts.get(1).unwrap();
+    assert_eq!(receiver.deposited(), (test.token_1.address.clone(), amount_out));
+    assert_eq!(receiver.last_data(), data);
+    assert_eq!(test.token_1.balance(&receiver.address), amount_out);
+    assert_eq!(test.token_0.balance(&test.user), INITIAL_USER_BALANCE - AMOUNT_0 - amount_in);
+    assert_eq!(test.token_1.balance(&test.user), INITIAL_USER_BALANCE - AMOUNT_1);
+}
+
+#[test]
+fn swap_and_call_insufficient_output_amount() {
+    let test = setup_pool();
+    let receiver = create_mock_receiver(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let data = Bytes::from_slice(&test.env, &[1, 2, 3]);
+
+    let result = test.contract.try_swap_and_call(&1_000_000, &i128::MAX, &path(&test), &test.user, &receiver.address, &data, &deadline, &None);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+}
+
+#[test]
+fn swap_and_call_reverts_with_callee() {
+    let test = setup_pool();
+    let receiver = create_mock_receiver(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    // The receiver rejects an empty payload, so the swap is reverted too
+    let result = test.contract.try_swap_and_call(&1_000_000, &0, &path(&test), &test.user, &receiver.address, &Bytes::new(&test.env), &deadline, &None);
+    assert!(result.is_err());
+    assert_eq!(test.token_0.balance(&test.user), INITIAL_USER_BALANCE - AMOUNT_0);
+    assert_eq!(test.token_1.balance(&receiver.address), 0);
+}
+
+#[test]
+fn swap_and_call_with_validity() {
+    let test = setup_pool();
+    let receiver = create_mock_receiver(&test);
+    let data = Bytes::from_slice(&test.env, &[4, 5, 6]);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let validity = Validity {
+        min_timestamp: None,
+        max_timestamp: None,
+        min_ledger: Some(test.env.ledger().sequence()),
+        max_ledger: None,
+    };
+
+    let amounts = test.contract.swap_and_call(&1_000_000, &0, &path(&test), &test.user, &receiver.address, &data, &deadline, &Some(validity));
+    assert_eq!(amounts, vec![&test.env, 1_000_000, receiver.deposited().1]);
+    assert_eq!(receiver.last_data(), data);
+}
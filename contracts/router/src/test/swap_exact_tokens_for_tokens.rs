@@ -301,3 +301,65 @@ fn swap_exact_tokens_for_tokens_2_hops() {
     assert_eq!(token_2.balance(&test.user), initial_user_balance -amount_2 + expected_amount_out);
 }
 
+
+#[test]
+fn swap_exact_tokens_for_tokens_pair_paused() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;  
+
+    let mut path: Vec<Address> = Vec::new(&test.env);
+    path.push_back(test.token_0.address.clone());
+    path.push_back(test.token_1.address.clone());
+
+    let amount_0: i128 = 1_000_000_000_000_000_000;
+    let amount_1: i128 = 4_000_000_000_000_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    // The guardian pauses all the pairs
+    test.factory.set_paused(&true);
+
+    test.env.budget().reset_unlimited();
+    let result = test.contract.try_swap_exact_tokens_for_tokens(
+        &1_000_000, //amount_in
+        &0,  // amount_out_min
+        &path, // path
+        &test.user, // to
+        &deadline); // deadline
+
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterPairPaused)));
+}
+
+#[test]
+fn swap_exact_tokens_for_tokens_large_reserves() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let mut path: Vec<Address> = Vec::new(&test.env);
+    path.push_back(test.token_0.address.clone());
+    path.push_back(test.token_1.address.clone());
+
+    // Reserves of 10^37, whose product does not fit in an i128
+    let amount: i128 = 10_000_000_000_000_000_000_000_000_000_000_000_000;
+    test.token_0.mint(&test.user, &(2 * amount));
+    test.token_1.mint(&test.user, &(2 * amount));
+    add_liquidity(&test, &amount, &amount);
+
+    // (amount_in - fee) * reserve_out is computed in 256 bits
+    let amount_in: i128 = 1_000_000_000_000_000_000_000_000_000_000_000_000;
+    let expected_amount_out: i128 = 906_610_893_880_149_131_581_340_365_554_242_066;
+    test.env.budget().reset_unlimited();
+    let executed_amounts = test.contract.swap_exact_tokens_for_tokens(
+        &amount_in,  // amount_in
+        &0,          // amount_out_min
+        &path,       // path
+        &test.user,  // to
+        &deadline,   // deadline
+    );
+
+    assert_eq!(executed_amounts, vec![&test.env, amount_in, expected_amount_out]);
+    let pair_address = test.factory.get_pair(&test.token_0.address, &test.token_1.address);
+    assert_eq!(test.token_0.balance(&pair_address), amount + amount_in);
+    assert_eq!(test.token_1.balance(&pair_address), amount - expected_amount_out);
+}
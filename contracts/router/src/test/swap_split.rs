@@ -0,0 +1,251 @@
+use soroban_sdk::{Address, testutils::{Events, Ledger}, vec, IntoVal, symbol_short, Vec};
+
+use crate::test::{SoroswapRouterTest, create_token_contract, TokenClient};
+use crate::test::add_liquidity::add_liquidity;
+use crate::error::CombinedRouterError;
+use crate::event::SplitSwapEvent;
+
+// token_0 can be swapped for token_1 directly, or through token_2
+fn setup_split_pairs<'a>(test: &SoroswapRouterTest<'a>) -> TokenClient<'a> {
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address);
+
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let token_2 = create_token_contract(&test.env, &test.admin);
+    token_2.mint(&test.user, &10_000_000_000_000_000_000);
+
+    test.contract.add_liquidity(
+        &test.token_0.address, //     token_a: Address,
+        &token_2.address, //     token_b: Address,
+        &1_000_000_000, //     amount_a_desired: i128,
+        &1_000_000_000, //     amount_b_desired: i128,
+        &0, //     amount_a_min: i128,
+        &0 , //     amount_b_min: i128,
+        &test.user, //     to: Address,
+        &deadline//     deadline: u64,
+    );
+    test.contract.add_liquidity(
+        &token_2.address, //     token_a: Address,
+        &test.token_1.address, //     token_b: Address,
+        &1_000_000_000, //     amount_a_desired: i128,
+        &4_000_000_000, //     amount_b_desired: i128,
+        &0, //     amount_a_min: i128,
+        &0 , //     amount_b_min: i128,
+        &test.user, //     to: Address,
+        &deadline//     deadline: u64,
+    );
+    token_2
+}
+
+#[test]
+fn swap_exact_tokens_split_not_initialized() {
+    let test = SoroswapRouterTest::setup();
+    test.env.budget().reset_unlimited();
+    let distribution: Vec<(Vec<Address>, i128)> = Vec::new(&test.env);
+
+    let result = test.contract.try_swap_exact_tokens_split(
+        &distribution, // distribution
+        &0,            // amount_out_min
+        &test.user,    // to
+        &0,            // deadline
+        &None,         // validity
+    );
+
+    assert_eq!(
+        result,
+        Err(Ok(CombinedRouterError::RouterNotInitialized))
+    );
+}
+
+#[test]
+fn swap_exact_tokens_split_empty_distribution() {
+    let test = SoroswapRouterTest::setup();
+    setup_split_pairs(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let distribution: Vec<(Vec<Address>, i128)> = Vec::new(&test.env);
+
+    let result = test.contract.try_swap_exact_tokens_split(
+        &distribution, // distribution
+        &0,            // amount_out_min
+        &test.user,    // to
+        &deadline,     // deadline
+        &None,         // validity
+    );
+
+    assert_eq!(
+        result,
+        Err(Ok(CombinedRouterError::RouterInvalidDistribution))
+    );
+}
+
+#[test]
+fn swap_exact_tokens_split_different_tokens() {
+    let test = SoroswapRouterTest::setup();
+    let token_2 = setup_split_pairs(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    // The second leg ends in token_2 instead of token_1
+    let distribution: Vec<(Vec<Address>, i128)> = vec![
+        &test.env,
+        (vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()], 1_000_000),
+        (vec![&test.env, test.token_0.address.clone(), token_2.address.clone()], 1_000_000),
+    ];
+
+    let result = test.contract.try_swap_exact_tokens_split(
+        &distribution, // distribution
+        &0,            // amount_out_min
+        &test.user,    // to
+        &deadline,     // deadline
+        &None,         // validity
+    );
+
+    assert_eq!(
+        result,
+        Err(Ok(CombinedRouterError::RouterInvalidDistribution))
+    );
+}
+
+#[test]
+fn swap_exact_tokens_split_invalid_path() {
+    let test = SoroswapRouterTest::setup();
+    setup_split_pairs(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let distribution: Vec<(Vec<Address>, i128)> = vec![
+        &test.env,
+        (vec![&test.env, test.token_0.address.clone()], 1_000_000),
+    ];
+
+    let result = test.contract.try_swap_exact_tokens_split(
+        &distribution, // distribution
+        &0,            // amount_out_min
+        &test.user,    // to
+        &deadline,     // deadline
+        &None,         // validity
+    );
+
+    assert_eq!(
+        result,
+        Err(Ok(CombinedRouterError::LibraryInvalidPath))
+    );
+}
+
+#[test]
+fn swap_exact_tokens_split_negative_amount() {
+    let test = SoroswapRouterTest::setup();
+    setup_split_pairs(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let distribution: Vec<(Vec<Address>, i128)> = vec![
+        &test.env,
+        (vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()], -1),
+    ];
+
+    let result = test.contract.try_swap_exact_tokens_split(
+        &distribution, // distribution
+        &0,            // amount_out_min
+        &test.user,    // to
+        &deadline,     // deadline
+        &None,         // validity
+    );
+
+    assert_eq!(
+        result,
+        Err(Ok(CombinedRouterError::RouterNegativeNotAllowed))
+    );
+}
+
+#[test]
+fn swap_exact_tokens_split_insufficient_output_amount() {
+    let test = SoroswapRouterTest::setup();
+    let token_2 = setup_split_pairs(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let distribution: Vec<(Vec<Address>, i128)> = vec![
+        &test.env,
+        (vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()], 100_000_000),
+        (vec![&test.env, test.token_0.address.clone(), token_2.address.clone(), test.token_1.address.clone()], 100_000_000),
+    ];
+
+    // Each leg gives less than the minimum, only their sum is checked
+    let expected_amount_out = 362644357 + 331584769;
+    let result = test.contract.try_swap_exact_tokens_split(
+        &distribution,                 // distribution
+        &(expected_amount_out + 1),    // amount_out_min
+        &test.user,                    // to
+        &deadline,                     // deadline
+        &None,                         // validity
+    );
+
+    assert_eq!(
+        result,
+        Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount))
+    );
+}
+
+#[test]
+fn swap_exact_tokens_split() {
+    let test = SoroswapRouterTest::setup();
+    let token_2 = setup_split_pairs(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let initial_user_balance_0 = test.token_0.balance(&test.user);
+    let initial_user_balance_1 = test.token_1.balance(&test.user);
+    let initial_user_balance_2 = token_2.balance(&test.user);
+
+    let direct_path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let path_through_2 = vec![&test.env, test.token_0.address.clone(), token_2.address.clone(), test.token_1.address.clone()];
+    let distribution: Vec<(Vec<Address>, i128)> = vec![
+        &test.env,
+        (direct_path.clone(), 100_000_000),
+        (path_through_2.clone(), 100_000_000),
+        (direct_path.clone(), 50_000_000),
+    ];
+
+    // First leg: (99700000*4000000000)/(1000000000 + 99700000) = 362644357,552
+    // Second leg: (99700000*1000000000)/(1000000000 + 99700000) = 90661089,388
+    //             (90389105*4000000000)/(1000000000 + 90389105) = 331584769,636
+    // Third leg, with the reserves left by the first one: (49850000*3637355643)/(1100000000 + 49850000) = 157692028,986
+    let legs_amounts = test.contract.swap_exact_tokens_split(
+        &distribution, // distribution
+        &0,            // amount_out_min
+        &test.user,    // to
+        &deadline,     // deadline
+        &None,         // validity
+    );
+
+    let expected_legs_amounts: Vec<Vec<i128>> = vec![
+        &test.env,
+        vec![&test.env, 100_000_000, 362644357],
+        vec![&test.env, 100_000_000, 90661089, 331584769],
+        vec![&test.env, 50_000_000, 157692028],
+    ];
+    assert_eq!(legs_amounts, expected_legs_amounts);
+
+    let expected_amount_out = 362644357 + 331584769 + 157692028;
+    assert_eq!(test.token_0.balance(&test.user), initial_user_balance_0 - 250_000_000);
+    assert_eq!(test.token_1.balance(&test.user), initial_user_balance_1 + expected_amount_out);
+    assert_eq!(token_2.balance(&test.user), initial_user_balance_2);
+
+    let split_swap_event = test.env.events().all().last().unwrap();
+    let expected_split_swap_event: SplitSwapEvent = SplitSwapEvent {
+        paths: vec![&test.env, direct_path.clone(), path_through_2, direct_path],
+        amounts: expected_legs_amounts,
+        amount_in: 250_000_000,
+        amount_out: expected_amount_out,
+        to: test.user.clone(),
+    };
+
+    assert_eq!(
+        vec![&test.env, split_swap_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("split")).into_val(&test.env),
+                (expected_split_swap_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
@@ -0,0 +1,268 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec,
+    Address,
+    Vec};
+
+use crate::test::{SoroswapRouterTest, SoroswapPairClient};
+use crate::test::add_liquidity::add_liquidity;
+use crate::error::CombinedRouterError;
+use crate::Validity;
+
+const LEDGER_TIMESTAMP: u64 = 1_000;
+const LEDGER_SEQUENCE: u32 = 500;
+const DEADLINE: u64 = LEDGER_TIMESTAMP + 1_000;
+
+fn setup_pool<'a>() -> SoroswapRouterTest<'a> {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000_000_000_000, &4_000_000_000_000_000_000);
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = LEDGER_TIMESTAMP;
+        li.sequence_number = LEDGER_SEQUENCE;
+    });
+    test
+}
+
+fn unbounded() -> Validity {
+    Validity {
+        min_timestamp: None,
+        max_timestamp: None,
+        min_ledger: None,
+        max_ledger: None,
+    }
+}
+
+fn path(test: &SoroswapRouterTest) -> Vec<Address> {
+    vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]
+}
+
+fn try_swap(test: &SoroswapRouterTest, deadline: u64, validity: Validity) -> Result<Vec<i128>, CombinedRouterError> {
+    match test.contract.try_swap_exact_tokens_from(&1_000_000, &0, &path(test), &test.user, &test.user, &deadline, &Some(validity)) {
+        Ok(amounts) => Ok(amounts.unwrap()),
+        Err(error) => Err(error.unwrap()),
+    }
+}
+
+#[test]
+fn validity_not_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let result = test.contract.try_swap_exact_tokens_from(&1_000_000, &0, &path(&test), &test.user, &test.user, &DEADLINE, &Some(unbounded()));
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn validity_swap() {
+    let test = setup_pool();
+    let expected_amounts = test.contract.router_get_amounts_out(&1_000_000, &path(&test));
+
+    // The current ledger is inside all the bounds; lower bounds are inclusive
+    let validity = Validity {
+        min_timestamp: Some(LEDGER_TIMESTAMP),
+        max_timestamp: Some(LEDGER_TIMESTAMP + 1),
+        min_ledger: Some(LEDGER_SEQUENCE),
+        max_ledger: Some(LEDGER_SEQUENCE + 1),
+    };
+    assert_eq!(try_swap(&test, DEADLINE, validity), Ok(expected_amounts));
+}
+
+#[test]
+fn validity_unbounded() {
+    let test = setup_pool();
+    assert!(try_swap(&test, DEADLINE, unbounded()).is_ok());
+}
+
+#[test]
+fn validity_deadline_expired() {
+    let test = setup_pool();
+    let validity = Validity {
+        max_timestamp: Some(LEDGER_TIMESTAMP),
+        ..unbounded()
+    };
+    assert_eq!(try_swap(&test, DEADLINE, validity), Err(CombinedRouterError::RouterDeadlineExpired));
+}
+
+#[test]
+fn validity_keeps_earlier_deadline() {
+    let test = setup_pool();
+    // The later max_timestamp of the validity does not extend the deadline
+    let validity = Validity {
+        max_timestamp: Some(DEADLINE),
+        ..unbounded()
+    };
+    assert_eq!(try_swap(&test, LEDGER_TIMESTAMP, validity), Err(CombinedRouterError::RouterDeadlineExpired));
+}
+
+#[test]
+fn validity_not_yet_valid() {
+    let test = setup_pool();
+    let validity = Validity {
+        min_timestamp: Some(LEDGER_TIMESTAMP + 1),
+        ..unbounded()
+    };
+    assert_eq!(try_swap(&test, DEADLINE, validity), Err(CombinedRouterError::RouterNotYetValid));
+}
+
+#[test]
+fn validity_ledger_expired() {
+    let test = setup_pool();
+    let validity = Validity {
+        max_ledger: Some(LEDGER_SEQUENCE),
+        ..unbounded()
+    };
+    assert_eq!(try_swap(&test, DEADLINE, validity), Err(CombinedRouterError::RouterLedgerExpired));
+}
+
+#[test]
+fn validity_ledger_not_reached() {
+    let test = setup_pool();
+    let validity = Validity {
+        min_ledger: Some(LEDGER_SEQUENCE + 1),
+        ..unbounded()
+    };
+    assert_eq!(try_swap(&test, DEADLINE, validity), Err(CombinedRouterError::RouterLedgerNotReached));
+}
+
+#[test]
+fn validity_add_and_remove_liquidity() {
+    let test = setup_pool();
+    let recipient = Address::generate(&test.env);
+    let validity = Validity {
+        min_ledger: Some(LEDGER_SEQUENCE),
+        ..unbounded()
+    };
+
+    let (amount_a, amount_b, liquidity) = test.contract.add_liquidity_from(
+        &test.token_0.address,
+        &test.token_1.address,
+        &1_000_000,
+        &4_000_000,
+        &0,
+        &0,
+        &test.user,
+        &recipient,
+        &DEADLINE,
+        &Some(validity.clone()),
+    );
+    assert_eq!((amount_a, amount_b), (1_000_000, 4_000_000));
+
+    let pair_address = test.contract.router_pair_for(&test.token_0.address, &test.token_1.address);
+    assert_eq!(SoroswapPairClient::new(&test.env, &pair_address).balance(&recipient), liquidity);
+
+    let (amount_a, amount_b) = test.contract.remove_liquidity_from(
+        &test.token_0.address,
+        &test.token_1.address,
+        &liquidity,
+        &0,
+        &0,
+        &recipient,
+        &recipient,
+        &DEADLINE,
+        &Some(validity),
+    );
+    assert_eq!((amount_a, amount_b), (test.token_0.balance(&recipient), test.token_1.balance(&recipient)));
+}
+
+#[test]
+fn validity_zap_out() {
+    let test = setup_pool();
+    let (_, _, liquidity) = add_liquidity(&test, &1_000_000, &4_000_000);
+    let balance_before = test.token_0.balance(&test.user);
+
+    let validity = Validity {
+        max_ledger: Some(LEDGER_SEQUENCE),
+        ..unbounded()
+    };
+    let result = test.contract.try_zap_out(
+        &test.token_0.address, // token_out
+        &test.token_1.address, // other_token
+        &liquidity,            // liquidity
+        &0,                    // amount_out_min
+        &test.user,            // to
+        &DEADLINE,             // deadline
+        &Some(validity),       // validity
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterLedgerExpired)));
+
+    let amount_out = test.contract.zap_out(
+        &test.token_0.address, // token_out
+        &test.token_1.address, // other_token
+        &liquidity,            // liquidity
+        &0,                    // amount_out_min
+        &test.user,            // to
+        &DEADLINE,             // deadline
+        &Some(unbounded()),    // validity
+    );
+    assert_eq!(amount_out, test.token_0.balance(&test.user) - balance_before);
+}
+
+#[test]
+fn validity_within() {
+    let test = setup_pool();
+    let not_yet_valid = Validity {
+        min_timestamp: Some(LEDGER_TIMESTAMP + 1),
+        ..unbounded()
+    };
+    let valid = Validity {
+        min_timestamp: Some(LEDGER_TIMESTAMP),
+        ..unbounded()
+    };
+
+    let result = test.contract.try_add_liquidity_within(
+        &test.token_0.address,
+        &test.token_1.address,
+        &1_000_000,
+        &4_000_000,
+        &0,
+        &0,
+        &test.user,
+        &DEADLINE,
+        &Some(not_yet_valid.clone()),
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotYetValid)));
+    let (_, _, liquidity) = test.contract.add_liquidity_within(
+        &test.token_0.address,
+        &test.token_1.address,
+        &1_000_000,
+        &4_000_000,
+        &0,
+        &0,
+        &test.user,
+        &DEADLINE,
+        &Some(valid.clone()),
+    );
+
+    let result = test.contract.try_remove_liquidity_within(
+        &test.token_0.address,
+        &test.token_1.address,
+        &liquidity,
+        &0,
+        &0,
+        &test.user,
+        &DEADLINE,
+        &Some(not_yet_valid.clone()),
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotYetValid)));
+    test.contract.remove_liquidity_within(
+        &test.token_0.address,
+        &test.token_1.address,
+        &liquidity,
+        &0,
+        &0,
+        &test.user,
+        &DEADLINE,
+        &Some(valid.clone()),
+    );
+
+    let result = test.contract.try_swap_exact_tokens_within(&1_000_000, &0, &path(&test), &test.user, &DEADLINE, &Some(not_yet_valid.clone()));
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotYetValid)));
+    let expected_amounts = test.contract.router_get_amounts_out(&1_000_000, &path(&test));
+    let amounts = test.contract.swap_exact_tokens_within(&1_000_000, &0, &path(&test), &test.user, &DEADLINE, &Some(valid.clone()));
+    assert_eq!(amounts, expected_amounts);
+
+    let result = test.contract.try_swap_for_exact_tokens_within(&1_000_000, &i128::MAX, &path(&test), &test.user, &DEADLINE, &Some(not_yet_valid));
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotYetValid)));
+    let expected_amounts = test.contract.router_get_amounts_in(&1_000_000, &path(&test));
+    let amounts = test.contract.swap_for_exact_tokens_within(&1_000_000, &i128::MAX, &path(&test), &test.user, &DEADLINE, &Some(valid));
+    assert_eq!(amounts, expected_amounts);
+}
@@ -0,0 +1,210 @@
+use soroban_sdk::testutils::Ledger;
+
+use crate::test::{SoroswapRouterTest, SoroswapPairClient, create_token_contract};
+use crate::test::add_liquidity::add_liquidity;
+use crate::error::CombinedRouterError;
+
+// Deposits 1_000_000_000 of token_0 and 4_000_000_000 of token_1, minting 2_000_000_000 liquidity tokens
+// of which 1_000 are locked in the pair
+fn setup_zap<'a>(test: &SoroswapRouterTest<'a>) -> SoroswapPairClient<'a> {
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000, &4_000_000_000);
+    SoroswapPairClient::new(&test.env, &test.factory.get_pair(&test.token_0.address, &test.token_1.address))
+}
+
+#[test]
+fn zap_in_not_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let result = test.contract.try_zap_in(
+        &test.token_0.address, // token_in
+        &0,                    // amount_in
+        &test.token_1.address, // other_token
+        &0,                    // liquidity_min
+        &test.user,            // to
+        &0,                    // deadline
+        &None,                 // validity
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn zap_in_pair_does_not_exist() {
+    let test = SoroswapRouterTest::setup();
+    setup_zap(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let token_2 = create_token_contract(&test.env, &test.admin);
+
+    let result = test.contract.try_zap_in(
+        &test.token_0.address, // token_in
+        &100_000_000,          // amount_in
+        &token_2.address,      // other_token
+        &0,                    // liquidity_min
+        &test.user,            // to
+        &deadline,             // deadline
+        &None,                 // validity
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterPairDoesNotExist)));
+}
+
+#[test]
+fn zap_in_insufficient_liquidity() {
+    let test = SoroswapRouterTest::setup();
+    setup_zap(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let result = test.contract.try_zap_in(
+        &test.token_0.address, // token_in
+        &100_000_000,          // amount_in
+        &test.token_1.address, // other_token
+        &97_471_052,           // liquidity_min
+        &test.user,            // to
+        &deadline,             // deadline
+        &None,                 // validity
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientLiquidity)));
+}
+
+#[test]
+fn zap_in_token_0() {
+    let test = SoroswapRouterTest::setup();
+    let pair_client = setup_zap(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let initial_balance_0 = test.token_0.balance(&test.user);
+    let initial_balance_1 = test.token_1.balance(&test.user);
+    let initial_liquidity = pair_client.balance(&test.user);
+
+    // Optimal swap amount: (sqrt(10^9 * (10^9 * 19970^2 + 4 * 9970 * 10000 * 10^8)) - 10^9 * 19970) / (2 * 9970) = 48_882_173
+    // Swap output: (48_735_526 * 4_000_000_000) / (1_000_000_000 + 48_735_526) = 185_882_998
+    // The remaining 51_117_827 token_0 only need 51_117_824 to match the 185_882_998 token_1
+    // Liquidity: 185_882_998 * 2_000_000_000 / 3_814_117_002 = 97_471_051
+    let (dust_in, dust_other, liquidity) = test.contract.zap_in(
+        &test.token_0.address, // token_in
+        &100_000_000,          // amount_in
+        &test.token_1.address, // other_token
+        &97_471_051,           // liquidity_min
+        &test.user,            // to
+        &deadline,             // deadline
+        &None,                 // validity
+    );
+
+    assert_eq!((dust_in, dust_other, liquidity), (3, 0, 97_471_051));
+    assert_eq!(test.token_0.balance(&test.user), initial_balance_0 - 100_000_000 + dust_in);
+    assert_eq!(test.token_1.balance(&test.user), initial_balance_1);
+    assert_eq!(pair_client.balance(&test.user), initial_liquidity + liquidity);
+    assert_eq!(pair_client.get_reserves(), (1_099_999_997, 4_000_000_000, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn zap_in_token_1() {
+    let test = SoroswapRouterTest::setup();
+    let pair_client = setup_zap(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let initial_balance_0 = test.token_0.balance(&test.user);
+    let initial_balance_1 = test.token_1.balance(&test.user);
+
+    let (dust_in, dust_other, liquidity) = test.contract.zap_in(
+        &test.token_1.address, // token_in
+        &400_000_000,          // amount_in
+        &test.token_0.address, // other_token
+        &0,                    // liquidity_min
+        &test.user,            // to
+        &deadline,             // deadline
+        &None,                 // validity
+    );
+
+    assert_eq!((dust_in, dust_other, liquidity), (6, 0, 97_471_052));
+    assert_eq!(test.token_0.balance(&test.user), initial_balance_0);
+    assert_eq!(test.token_1.balance(&test.user), initial_balance_1 - 400_000_000 + dust_in);
+    assert_eq!(pair_client.get_reserves(), (1_000_000_000, 4_399_999_994, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn zap_in_large_reserves() {
+    let test = SoroswapRouterTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address);
+    add_liquidity(&test, &1_000_000_000_000_000_000, &4_000_000_000_000_000_000);
+    let pair_client = SoroswapPairClient::new(&test.env, &test.factory.get_pair(&test.token_0.address, &test.token_1.address));
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    // reserve_in^2 * 19970^2 is about 4 * 10^44, far above i128::MAX, so the discriminant needs 256 bits
+    // Optimal swap amount: 48_882_173_994_193_580, swap output: 185_883_003_657_477_884
+    let (dust_in, dust_other, liquidity) = test.contract.zap_in(
+        &test.token_0.address,     // token_in
+        &100_000_000_000_000_000,  // amount_in
+        &test.token_1.address,     // other_token
+        &0,                        // liquidity_min
+        &test.user,                // to
+        &deadline,                 // deadline
+        &None,                     // validity
+    );
+
+    assert_eq!((dust_in, dust_other, liquidity), (2, 0, 97_471_054_944_421_997));
+    assert_eq!(pair_client.get_reserves(), (1_099_999_999_999_999_998, 4_000_000_000_000_000_000, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn zap_out_pair_does_not_exist() {
+    let test = SoroswapRouterTest::setup();
+    setup_zap(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let token_2 = create_token_contract(&test.env, &test.admin);
+
+    let result = test.contract.try_zap_out(
+        &test.token_0.address, // token_out
+        &token_2.address,      // other_token
+        &100_000_000,          // liquidity
+        &0,                    // amount_out_min
+        &test.user,            // to
+        &deadline,             // deadline
+        &None,                 // validity
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterPairDoesNotExist)));
+}
+
+#[test]
+fn zap_out_insufficient_output_amount() {
+    let test = SoroswapRouterTest::setup();
+    setup_zap(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let result = test.contract.try_zap_out(
+        &test.token_0.address, // token_out
+        &test.token_1.address, // other_token
+        &100_000_000,          // liquidity
+        &97_364_605,           // amount_out_min
+        &test.user,            // to
+        &deadline,             // deadline
+        &None,                 // validity
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+}
+
+#[test]
+fn zap_out() {
+    let test = SoroswapRouterTest::setup();
+    let pair_client = setup_zap(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let initial_balance_0 = test.token_0.balance(&test.user);
+    let initial_balance_1 = test.token_1.balance(&test.user);
+    let initial_liquidity = pair_client.balance(&test.user);
+
+    // Withdrawn: 50_000_000 token_0 and 200_000_000 token_1
+    // Swap output: (199_400_000 * 950_000_000) / (3_800_000_000 + 199_400_000) = 47_364_604
+    let amount_out = test.contract.zap_out(
+        &test.token_0.address, // token_out
+        &test.token_1.address, // other_token
+        &100_000_000,          // liquidity
+        &97_364_604,           // amount_out_min
+        &test.user,            // to
+        &deadline,             // deadline
+        &None,                 // validity
+    );
+
+    assert_eq!(amount_out, 50_000_000 + 47_364_604);
+    assert_eq!(test.token_0.balance(&test.user), initial_balance_0 + amount_out);
+    assert_eq!(test.token_1.balance(&test.user), initial_balance_1);
+    assert_eq!(pair_client.balance(&test.user), initial_liquidity - 100_000_000);
+    assert_eq!(pair_client.get_reserves(), (950_000_000 - 47_364_604, 4_000_000_000, test.env.ledger().timestamp()));
+}
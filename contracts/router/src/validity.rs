@@ -0,0 +1,37 @@
+//! Definition of the validity windows of router calls
+use soroban_sdk::contracttype;
+
+/// The window in which a call can be executed. Every bound is optional; lower bounds are inclusive
+/// and upper bounds are exclusive, so `max_timestamp` works like the `deadline` of the router functions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Validity {
+    pub min_timestamp: Option<u64>,
+    pub max_timestamp: Option<u64>,
+    pub min_ledger: Option<u32>,
+    pub max_ledger: Option<u32>,
+}
+
+impl Validity {
+    /// The window of the router functions that take a `deadline`.
+    pub(crate) fn deadline(deadline: u64) -> Validity {
+        Validity {
+            min_timestamp: None,
+            max_timestamp: Some(deadline),
+            min_ledger: None,
+            max_ledger: None,
+        }
+    }
+
+    /// The window of the router functions that take a `deadline` and an optional `validity`:
+    /// the bounds of `validity`, with the earlier of its `max_timestamp` and `deadline`.
+    pub(crate) fn window(deadline: u64, validity: Option<Validity>) -> Validity {
+        match validity {
+            Some(validity) => Validity {
+                max_timestamp: Some(validity.max_timestamp.map_or(deadline, |max_timestamp| max_timestamp.min(deadline))),
+                ..validity
+            },
+            None => Validity::deadline(deadline),
+        }
+    }
+}
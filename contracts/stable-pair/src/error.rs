@@ -0,0 +1,53 @@
+use soroban_sdk::{self, contracterror};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SoroswapStablePairError {
+    /// SoroswapStablePair: already initialized
+    InitializeAlreadyInitialized = 601,
+    /// SoroswapStablePair: not yet initialized
+    NotInitialized = 602,
+
+    /// SoroswapStablePair: token_0 must be less than token_1 while initializing
+    InitializeTokenOrderInvalid = 603,
+    /// SoroswapStablePair: amplification coefficient is out of range while initializing
+    InitializeInvalidAmp = 604,
+    /// SoroswapStablePair: fee is greater than the maximum allowed while initializing
+    InitializeInvalidFee = 605,
+
+    /// SoroswapStablePair: insufficient amount of token 0 sent while doing deposit
+    DepositInsufficientAmountToken0 = 606,
+    /// SoroswapStablePair: insufficient amount of token 1 sent while doing deposit
+    DepositInsufficientAmountToken1 = 607,
+    /// SoroswapStablePair: insufficient first liquidity minted while doing deposit
+    DepositInsufficientFirstLiquidity = 608,
+    /// SoroswapStablePair: insufficient liquidity minted while doing deposit
+    DepositInsufficientLiquidityMinted = 609,
+
+    /// SoroswapStablePair: insufficient output amount while doing swap
+    SwapInsufficientOutputAmount = 610,
+    /// SoroswapStablePair: negatives amounts out dont supported while doing swap
+    SwapNegativesOutNotSupported = 611,
+    /// SoroswapStablePair: insufficient liquidity to do the swap
+    SwapInsufficientLiquidity = 612,
+    /// SoroswapStablePair: invalid to to do the swap
+    SwapInvalidTo = 613,
+    /// SoroswapStablePair: insufficient input amount while doing swap
+    SwapInsufficientInputAmount = 614,
+    /// SoroswapStablePair: the StableSwap invariant is not met while doing swap
+    SwapInvariantNotMet = 615,
+
+    /// SoroswapStablePair: liquidity was not initialized yet while doing withdraw
+    WithdrawLiquidityNotInitialized = 616,
+    /// SoroswapStablePair: insufficient sent shares while doing withdraw
+    WithdrawInsufficientSentShares = 617,
+    /// SoroswapStablePair: insufficient liquidity burned while doing withdraw
+    WithdrawInsufficientLiquidityBurned = 618,
+
+    /// SoroswapStablePair: OVERFLOW while doing the math of a deposit, swap or withdraw
+    UpdateOverflow = 619,
+
+    /// SoroswapStablePair: this pair or all the pairs are paused by the factory, only withdraws are allowed
+    Paused = 620,
+}
@@ -0,0 +1,602 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, contractmeta, token, Address, BytesN, Env, String, I256, U256};
+use soroswap_factory_interface::SoroswapFactoryClient;
+use soroban_token_sdk::metadata::TokenMetadata;
+
+
+mod storage;
+mod error;
+mod test;
+mod math;
+
+use storage::*;
+use soroswap_pair_core::*;
+use soroswap_pair_token::{SoroswapPairToken, internal_mint, internal_burn, write_metadata, TakeFirstNCharsAndConcat};
+use error::SoroswapStablePairError;
+use math::{checked_mul_div, checked_mul_div_ceil, compute_d, is_above_curve, marginal_price};
+
+// First topic of the events of the stable pairs
+const PAIR_NAME: &str = "SoroswapStablePair";
+
+static MINIMUM_LIQUIDITY: i128 = 1000;
+// Fees are expressed in basis points. The factory cannot set a fee greater than 10%
+static FEE_DENOMINATOR: i128 = 10_000;
+static MAXIMUM_FEE: u32 = 1_000;
+// Same bounds as Curve. With A = 1 the curve is already close to the constant product
+static MINIMUM_AMP: u32 = 1;
+static MAXIMUM_AMP: u32 = 1_000_000;
+
+fn create_symbol(e: &Env, symbol_0: &String, symbol_1: &String) -> String {
+    let symbol_0_short = symbol_0.take_first_n_chars(&e, 6);
+    let symbol_1_short = symbol_1.take_first_n_chars(&e, 6);
+    let hyphen = String::from_str(&e, "-");
+    let end = String::from_str(&e, "-SOROSWAP-SLP");
+    symbol_0_short.concat(&e, hyphen).concat(&e, symbol_1_short).concat(&e, end)
+}
+
+fn create_name(e: &Env, symbol_0: &String, symbol_1: &String) -> String {
+    let symbol_0_short = symbol_0.take_first_n_chars(&e, 6);
+    let symbol_1_short = symbol_1.take_first_n_chars(&e, 6);
+    let hyphen = String::from_str(&e, "-");
+    let end = String::from_str(&e, " Soroswap Stable LP");
+    symbol_0_short.concat(&e, hyphen).concat(&e, symbol_1_short).concat(&e, end)
+}
+
+// Metadata that is added on to the WASM custom section
+contractmeta!(
+    key = "Description",
+    val = "Soroswap.Finance Protocol - StableSwap AMM for pegged assets, with an amplification coefficient"
+);
+
+pub trait SoroswapStablePairTrait{
+    // Sets the token contract addresses, the amplification coefficient and the swap fee for this pool
+    fn initialize(e: Env, factory: Address, token_0: Address, token_1: Address, amp: u32, fee: u32) -> Result<(), SoroswapStablePairError>;
+
+    fn deposit(e:Env, to: Address)  -> Result<i128, SoroswapStablePairError>;
+
+    // Swaps. This function should be called from another contract that has already sent tokens to the pair contract.
+    // Same signature as SoroswapPair::swap, so the router can call both kinds of pairs with the same client
+    fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address) -> Result<(), SoroswapStablePairError>;
+
+    fn withdraw(e: Env, to: Address) -> Result<(i128, i128), SoroswapStablePairError>;
+
+    // transfers the excess token balances from the pair to the specified to address
+    fn skim(e: Env, to: Address) -> Result<(), SoroswapStablePairError>;
+
+    // updates the reserves of the pair to match the current token balances
+    fn sync(e: Env) -> Result<(), SoroswapStablePairError>;
+
+    fn token_0(e: Env) -> Address;
+    fn token_1(e: Env) -> Address;
+    fn factory(e: Env) -> Address;
+
+    // Amplification coefficient (A). The higher it is, the flatter the curve around the 1:1 price
+    fn amp(e: Env) -> u32;
+
+    // Swap fee in basis points
+    fn fee(e: Env) -> u32;
+
+    fn get_reserves(e: Env) -> (i128, i128, u64);
+
+    // Accumulated marginal price of token 0 in terms of token 1, weighted by the seconds it was active.
+    // Used to build time-weighted average prices (TWAP), like the ones of SoroswapPair
+    fn price_0_cumulative_last(e: Env) -> U256;
+    fn price_1_cumulative_last(e: Env) -> U256;
+
+    // Invariant D of the reserves after the last deposit or withdraw while the protocol fee was on
+    fn d_last(e: Env) -> I256;
+
+    // Upgrades the code of the pair to the given wasm hash. Only the factory can upgrade a pair
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), SoroswapStablePairError>;
+
+    // Returns true if this pair or all the pairs were paused by the factory.
+    // While paused, deposits and swaps are rejected but withdraws still work
+    fn paused(e: Env) -> bool;
+    fn set_paused(e: Env, is_paused: bool) -> Result<(), SoroswapStablePairError>;
+
+}
+
+#[contract]
+struct SoroswapStablePair;
+
+#[contractimpl]
+impl SoroswapStablePairTrait for SoroswapStablePair {
+
+    /// Initializes a new Soroswap stable pair by setting token addresses, factory, amplification coefficient and swap fee.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `factory` - The address of the Soroswap factory contract.
+    /// * `token_0` - The address of the first token in the pair.
+    /// * `token_1` - The address of the second token in the pair.
+    /// * `amp` - The amplification coefficient, between 1 and 1_000_000.
+    /// * `fee` - The swap fee, in basis points.
+    fn initialize(e: Env, factory: Address, token_0: Address, token_1: Address, amp: u32, fee: u32) -> Result<(), SoroswapStablePairError> {
+        if has_token_0(&e) {
+            return Err(SoroswapStablePairError::InitializeAlreadyInitialized);
+        }
+
+        if token_0 >= token_1 {
+            return Err(SoroswapStablePairError::InitializeTokenOrderInvalid);
+        }
+
+        if amp < MINIMUM_AMP || amp > MAXIMUM_AMP {
+            return Err(SoroswapStablePairError::InitializeInvalidAmp);
+        }
+
+        if fee > MAXIMUM_FEE {
+            return Err(SoroswapStablePairError::InitializeInvalidFee);
+        }
+
+        put_factory(&e, factory);
+
+        let symbol_0: String = token::Client::new(&e, &token_0).symbol();
+        let symbol_1: String = token::Client::new(&e, &token_1).symbol();
+
+        let decimal: u32 = 7;
+        let name: String = create_name(&e, &symbol_0, &symbol_1);
+        let symbol: String = create_symbol(&e, &symbol_0, &symbol_1);
+
+        write_metadata(
+            &e,
+            TokenMetadata {
+                decimal ,
+                name,
+                symbol,
+            },
+        );
+
+        put_token_0(&e, token_0);
+        put_token_1(&e, token_1);
+        put_reserve_0(&e, 0);
+        put_reserve_1(&e, 0);
+        put_amp(&e, amp);
+        put_fee(&e, fee);
+        extend_instance_ttl(&e);
+
+        Ok(())
+    }
+
+    /// Returns the address of the first token in the stable pair.
+    fn token_0(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        get_token_0(&e)
+    }
+
+    /// Returns the address of the second token in the stable pair.
+    fn token_1(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        get_token_1(&e)
+    }
+
+    /// Returns the address of the Soroswap factory contract.
+    fn factory(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        get_factory(&e)
+    }
+
+    /// Deposits tokens into the stable pair and mints LP tokens in return.
+    ///
+    /// The first deposit mints the invariant D of the deposited amounts, minus the minimum liquidity.
+    /// Later deposits mint in proportion to the reserves, like in `SoroswapPair`, so an unbalanced deposit
+    /// cannot be used to swap without paying the fee.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `to` - The address where the minted LP tokens will be sent.
+    ///
+    /// # Returns
+    /// The amount of minted LP tokens.
+    ///
+    /// # Errors
+    /// - `NotInitialized`: The stable pair has not been initialized.
+    /// - `Paused`: The factory paused all the pairs.
+    /// - `DepositInsufficientAmountToken0`: Insufficient amount of token 0 sent.
+    /// - `DepositInsufficientAmountToken1`: Insufficient amount of token 1 sent.
+    /// - `DepositInsufficientFirstLiquidity`: Insufficient first liquidity minted.
+    /// - `DepositInsufficientLiquidityMinted`: Insufficient liquidity minted.
+    /// - `UpdateOverflow`: Overflow occurred during update.
+    fn deposit(e: Env, to: Address) -> Result<i128, SoroswapStablePairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e){
+            return Err(SoroswapStablePairError::NotInitialized)
+        }
+
+        if is_paused(&e) {
+            return Err(SoroswapStablePairError::Paused);
+        }
+
+        let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        let amount_0 = balance_0.checked_sub(reserve_0).ok_or(SoroswapStablePairError::DepositInsufficientAmountToken0)?;
+        let amount_1 = balance_1.checked_sub(reserve_1).ok_or(SoroswapStablePairError::DepositInsufficientAmountToken1)?;
+
+        if amount_0 <= 0 {
+            return Err(SoroswapStablePairError::DepositInsufficientAmountToken0);
+        }
+
+        if amount_1 <= 0 {
+            return Err(SoroswapStablePairError::DepositInsufficientAmountToken1);
+        }
+
+        let fee_on: bool = mint_fee(&e, reserve_0, reserve_1)?;
+        let total_supply = SoroswapPairToken::total_supply(e.clone());
+
+        let liquidity = if total_supply == 0 {
+            // When the liquidity pool is being initialized, we block the minimum liquidity forever in this contract
+            internal_mint(e.clone(), e.current_contract_address(), MINIMUM_LIQUIDITY);
+            let d = compute_d(&e, amount_0, amount_1, get_amp(&e))
+                .and_then(|d| d.to_i128())
+                .ok_or(SoroswapStablePairError::UpdateOverflow)?;
+            if d <= MINIMUM_LIQUIDITY {
+                return Err(SoroswapStablePairError::DepositInsufficientFirstLiquidity);
+            }
+            d.checked_sub(MINIMUM_LIQUIDITY).ok_or(SoroswapStablePairError::UpdateOverflow)?
+        } else {
+            let shares_0 = checked_mul_div(&e, amount_0, total_supply, reserve_0).ok_or(SoroswapStablePairError::UpdateOverflow)?;
+            let shares_1 = checked_mul_div(&e, amount_1, total_supply, reserve_1).ok_or(SoroswapStablePairError::UpdateOverflow)?;
+            shares_0.min(shares_1)
+        };
+
+        if liquidity <= 0 {
+            return Err(SoroswapStablePairError::DepositInsufficientLiquidityMinted);
+        }
+
+        internal_mint(e.clone(), to.clone(), liquidity.clone());
+        update(&e, balance_0, balance_1)?;
+
+        if fee_on {
+            put_d_last_of_reserves(&e);
+        }
+
+        event::deposit(&e, PAIR_NAME, to, amount_0, amount_1, liquidity, balance_0, balance_1);
+
+        Ok(liquidity)
+    }
+
+    /// Executes a token swap within the stable pair.
+    ///
+    /// The balances after the swap, minus the swap fee on the amounts in, must be on or above the
+    /// StableSwap curve of the reserves before the swap.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `amount_0_out` - The desired amount of the first token to receive.
+    /// * `amount_1_out` - The desired amount of the second token to receive.
+    /// * `to` - The address where the swapped tokens will be sent.
+    ///
+    /// # Errors
+    /// Returns an error if the swap cannot be executed. Possible errors include:
+    /// - `NotInitialized`
+    /// - `Paused`
+    /// - `SwapInsufficientOutputAmount`
+    /// - `SwapNegativesOutNotSupported`
+    /// - `SwapInsufficientLiquidity`
+    /// - `SwapInvalidTo`
+    /// - `SwapInsufficientInputAmount`
+    /// - `SwapInvariantNotMet`: If the balances end up below the curve.
+    /// - `UpdateOverflow`: If any of the amounts overflows.
+    fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address) -> Result<(), SoroswapStablePairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapStablePairError::NotInitialized);
+        }
+
+        if is_paused(&e) {
+            return Err(SoroswapStablePairError::Paused);
+        }
+
+        let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+
+        if amount_0_out == 0 && amount_1_out == 0 {
+            return Err(SoroswapStablePairError::SwapInsufficientOutputAmount);
+        }
+        if amount_0_out < 0 || amount_1_out < 0 {
+            return Err(SoroswapStablePairError::SwapNegativesOutNotSupported);
+        }
+        if amount_0_out >= reserve_0 || amount_1_out >= reserve_1 {
+            return Err(SoroswapStablePairError::SwapInsufficientLiquidity);
+        }
+        if to == get_token_0(&e) || to == get_token_1(&e) {
+            return Err(SoroswapStablePairError::SwapInvalidTo);
+        }
+
+        if amount_0_out > 0 {
+            transfer_token_0_from_pair(&e, &to, amount_0_out);
+        }
+        if amount_1_out > 0 {
+            transfer_token_1_from_pair(&e, &to, amount_1_out);
+        }
+
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+
+        // The reserves are larger than the amounts out, so these subtractions cannot go below zero
+        let amount_0_in = balance_0.checked_sub(reserve_0 - amount_0_out).ok_or(SoroswapStablePairError::UpdateOverflow)?.max(0);
+        let amount_1_in = balance_1.checked_sub(reserve_1 - amount_1_out).ok_or(SoroswapStablePairError::UpdateOverflow)?.max(0);
+
+        if amount_0_in == 0 && amount_1_in == 0 {
+            return Err(SoroswapStablePairError::SwapInsufficientInputAmount);
+        }
+
+        let fee = get_fee(&e) as i128;
+        let fee_0 = checked_mul_div_ceil(&e, amount_0_in, fee, FEE_DENOMINATOR).ok_or(SoroswapStablePairError::UpdateOverflow)?;
+        let fee_1 = checked_mul_div_ceil(&e, amount_1_in, fee, FEE_DENOMINATOR).ok_or(SoroswapStablePairError::UpdateOverflow)?;
+
+        let balance_0_minus_fee = balance_0.checked_sub(fee_0).ok_or(SoroswapStablePairError::UpdateOverflow)?;
+        let balance_1_minus_fee = balance_1.checked_sub(fee_1).ok_or(SoroswapStablePairError::UpdateOverflow)?;
+
+        let amp = get_amp(&e);
+        let d = compute_d(&e, reserve_0, reserve_1, amp).ok_or(SoroswapStablePairError::UpdateOverflow)?;
+        if !is_above_curve(&e, balance_0_minus_fee, balance_1_minus_fee, &d, amp) {
+            return Err(SoroswapStablePairError::SwapInvariantNotMet);
+        }
+
+        update(&e, balance_0, balance_1)?;
+
+        event::swap(&e, PAIR_NAME, to, amount_0_in, amount_1_in, amount_0_out, amount_1_out);
+
+        Ok(())
+    }
+
+    /// Withdraws liquidity from the stable pair, burning LP tokens and returning the corresponding tokens to the user.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `to` - The address where the withdrawn tokens will be sent.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of token 0 and token 1 withdrawn from the pair.
+    fn withdraw(e: Env, to: Address) -> Result<(i128, i128), SoroswapStablePairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapStablePairError::NotInitialized);
+        }
+
+        // The shares sent by the user to burn their LP position
+        let balance_shares = SoroswapPairToken::balance(e.clone(), e.current_contract_address());
+        if balance_shares == 0 {
+            return Err(SoroswapStablePairError::WithdrawLiquidityNotInitialized);
+        }
+
+        let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        let user_sent_shares = balance_shares.checked_sub(MINIMUM_LIQUIDITY).ok_or(SoroswapStablePairError::UpdateOverflow)?;
+
+        if user_sent_shares <= 0 {
+            return Err(SoroswapStablePairError::WithdrawInsufficientSentShares);
+        }
+
+        let fee_on: bool = mint_fee(&e, reserve_0, reserve_1)?;
+        let total_supply = SoroswapPairToken::total_supply(e.clone());
+
+        let amount_0 = checked_mul_div(&e, balance_0, user_sent_shares, total_supply).ok_or(SoroswapStablePairError::UpdateOverflow)?;
+        let amount_1 = checked_mul_div(&e, balance_1, user_sent_shares, total_supply).ok_or(SoroswapStablePairError::UpdateOverflow)?;
+
+        if amount_0 <= 0 || amount_1 <= 0 {
+            return Err(SoroswapStablePairError::WithdrawInsufficientLiquidityBurned);
+        }
+
+        internal_burn(e.clone(), e.current_contract_address(), user_sent_shares);
+
+        transfer_token_0_from_pair(&e, &to, amount_0);
+        transfer_token_1_from_pair(&e, &to, amount_1);
+
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        update(&e, balance_0, balance_1)?;
+
+        if fee_on {
+            put_d_last_of_reserves(&e);
+        }
+
+        event::withdraw(&e, PAIR_NAME, to, user_sent_shares, amount_0, amount_1, balance_0, balance_1);
+        Ok((amount_0, amount_1))
+    }
+
+    /// Skims excess tokens from reserves and sends them to the specified address.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `to` - The address where the excess tokens will be sent.
+    fn skim(e: Env, to: Address) -> Result<(), SoroswapStablePairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapStablePairError::NotInitialized);
+        }
+
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        let skimmed_0 = balance_0.checked_sub(reserve_0).ok_or(SoroswapStablePairError::UpdateOverflow)?;
+        let skimmed_1 = balance_1.checked_sub(reserve_1).ok_or(SoroswapStablePairError::UpdateOverflow)?;
+        transfer_token_0_from_pair(&e, &to, skimmed_0);
+        transfer_token_1_from_pair(&e, &to, skimmed_1);
+        event::skim(&e, PAIR_NAME, skimmed_0, skimmed_1);
+        Ok(())
+    }
+
+    /// Forces reserves to match current balances.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    fn sync(e: Env) -> Result<(), SoroswapStablePairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapStablePairError::NotInitialized);
+        }
+
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        update(&e, balance_0, balance_1)
+    }
+
+    /// Returns the amplification coefficient of the stable pair.
+    fn amp(e: Env) -> u32 {
+        extend_instance_ttl(&e);
+        get_amp(&e)
+    }
+
+    /// Returns the swap fee of the stable pair, in basis points.
+    fn fee(e: Env) -> u32 {
+        extend_instance_ttl(&e);
+        get_fee(&e)
+    }
+
+    /// Returns the current reserves and the last block timestamp, like `SoroswapPair::get_reserves`.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    ///
+    /// # Returns
+    /// A tuple containing the reserves of token 0 and token 1, and the ledger timestamp of the last reserves update.
+    fn get_reserves(e: Env) -> (i128, i128, u64) {
+        extend_instance_ttl(&e);
+
+        (get_reserve_0(&e), get_reserve_1(&e), get_block_timestamp_last(&e))
+    }
+
+    /// Returns the cumulative marginal price of token 0 (priced in token 1) as of the last reserves update.
+    ///
+    /// The price is the slope of the StableSwap curve at the reserves, not the ratio of the reserves. Like in
+    /// `SoroswapPair`, it is an unsigned 128.128 fixed point number multiplied by the seconds it was valid, and
+    /// the accumulator wraps around at 2^256, so consumers should compute differences with wrapping arithmetic.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    fn price_0_cumulative_last(e: Env) -> U256 {
+        extend_instance_ttl(&e);
+
+        get_price_0_cumulative_last(&e)
+    }
+
+    /// Returns the cumulative marginal price of token 1 (priced in token 0) as of the last reserves update.
+    /// See `price_0_cumulative_last`.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    fn price_1_cumulative_last(e: Env) -> U256 {
+        extend_instance_ttl(&e);
+
+        get_price_1_cumulative_last(&e)
+    }
+
+    /// Returns the invariant D of the reserves after the last deposit or withdraw made while the protocol fee
+    /// was on, or zero. It plays the role of `SoroswapPair::k_last`.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    fn d_last(e: Env) -> I256 {
+        extend_instance_ttl(&e);
+
+        get_d_last(&e)
+    }
+
+    /// Upgrades the code of the stable pair, keeping its stored data. Can only be called by the factory.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `new_wasm_hash` - The hash of the new wasm code, already uploaded to the network.
+    ///
+    /// # Errors
+    /// - `NotInitialized`: The stable pair has not been initialized.
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), SoroswapStablePairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapStablePairError::NotInitialized);
+        }
+
+        upgrade(&e, PAIR_NAME, new_wasm_hash);
+        Ok(())
+    }
+
+    /// Returns true if deposits and swaps are currently blocked, either because this pair was paused
+    /// or because the factory paused all the pairs.
+    fn paused(e: Env) -> bool {
+        extend_instance_ttl(&e);
+        has_token_0(&e) && is_paused(&e)
+    }
+
+    /// Pauses or unpauses this pair. Can only be called by the factory, on behalf of its guardian.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `is_paused` - Whether deposits and swaps should be blocked.
+    ///
+    /// # Errors
+    /// - `NotInitialized`: The stable pair has not been initialized.
+    fn set_paused(e: Env, is_paused: bool) -> Result<(), SoroswapStablePairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapStablePairError::NotInitialized);
+        }
+
+        set_paused(&e, PAIR_NAME, is_paused);
+        Ok(())
+    }
+}
+
+fn mint_fee(e: &Env, reserve_0: i128, reserve_1: i128) -> Result<bool, SoroswapStablePairError> {
+
+    /*
+            Same as SoroswapPair::mint_fee, with the invariant D in place of sqrt(k). Both grow linearly
+            with the reserves, so the growth of D between two deposits or withdraws comes from the swap fees
+    */
+
+    let factory_client = SoroswapFactoryClient::new(&e, &get_factory(&e));
+    let fee_on = factory_client.fees_enabled();
+    let d_last = get_d_last(&e);
+    let zero = I256::from_i32(&e, 0);
+
+    if fee_on {
+        if d_last != zero {
+            let d = compute_d(&e, reserve_0, reserve_1, get_amp(&e)).ok_or(SoroswapStablePairError::UpdateOverflow)?;
+            if d > d_last {
+                /*
+                    The protocol gets a fraction phi = fee_numerator/fee_denominator of the growth in D:
+                    shares = total_supply * (d - d_last) / ((1/phi - 1) * d + d_last)
+                */
+                let (fee_numerator, fee_denominator) = factory_client.protocol_fee_fraction();
+                let fee_numerator = I256::from_i128(&e, fee_numerator as i128);
+                let fee_denominator = I256::from_i128(&e, fee_denominator as i128);
+                let total_supply = I256::from_i128(&e, SoroswapPairToken::total_supply(e.clone()));
+                let numerator = total_supply.mul(&d.sub(&d_last));
+                let denominator = d.mul(&fee_denominator.sub(&fee_numerator)).add(&d_last.mul(&fee_numerator));
+                // numerator * fee_numerator / denominator, split so that no product needs more than 256 bits
+                let liquidity_pool_shares_fees = numerator.div(&denominator).mul(&fee_numerator)
+                    .add(&numerator.rem_euclid(&denominator).mul(&fee_numerator).div(&denominator))
+                    .to_i128().ok_or(SoroswapStablePairError::UpdateOverflow)?;
+
+                if liquidity_pool_shares_fees > 0 {
+                    internal_mint(e.clone(), factory_client.fee_to(), liquidity_pool_shares_fees);
+                }
+            }
+        }
+    } else if d_last != zero {
+        put_d_last(&e, zero);
+    }
+
+    Ok(fee_on)
+}
+
+// Stores the invariant of the current reserves, to measure the fees earned until the next deposit or withdraw.
+// Reserves without an invariant store zero, which skips the protocol fee of the next one
+fn put_d_last_of_reserves(e: &Env) {
+    let d = compute_d(e, get_reserve_0(e), get_reserve_1(e), get_amp(e)).unwrap_or(I256::from_i32(e, 0));
+    put_d_last(e, d);
+}
+
+fn update(e: &Env, balance_0: i128, balance_1: i128) -> Result<(), SoroswapStablePairError> {
+    // prices are the slope of the curve at the reserves prior to this update
+    accumulate_prices(e, |reserve_0, reserve_1| {
+        let amp = get_amp(e);
+        let d = compute_d(e, reserve_0, reserve_1, amp)?;
+        Some((marginal_price(e, reserve_0, reserve_1, &d, amp), marginal_price(e, reserve_1, reserve_0, &d, amp)))
+    }).ok_or(SoroswapStablePairError::UpdateOverflow)?;
+
+    update_reserves(e, PAIR_NAME, balance_0, balance_1);
+    Ok(())
+}
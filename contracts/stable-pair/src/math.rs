@@ -0,0 +1,132 @@
+use soroban_sdk::{Env, I256, U256};
+
+// Newton's method converges in a few iterations for any valid balances. The bound only stops degenerate inputs
+const MAX_ITERATIONS: u32 = 255;
+
+// The product of two i128 values always fits in an I256, so none of these helpers can overflow
+// before the final conversion back to i128
+pub fn mul_i256(e: &Env, a: i128, b: i128) -> I256 {
+    I256::from_i128(e, a).mul(&I256::from_i128(e, b))
+}
+
+// a * b / c rounded down, or None if c is zero or the result does not fit in an i128
+pub fn checked_mul_div(e: &Env, a: i128, b: i128, c: i128) -> Option<i128> {
+    if c == 0 {
+        return None;
+    }
+    mul_i256(e, a, b).div(&I256::from_i128(e, c)).to_i128()
+}
+
+// a * b / c rounded up, for non-negative a and b and a positive c
+pub fn checked_mul_div_ceil(e: &Env, a: i128, b: i128, c: i128) -> Option<i128> {
+    if c <= 0 {
+        return None;
+    }
+    let c = I256::from_i128(e, c);
+    let one = I256::from_i32(e, 1);
+    mul_i256(e, a, b).add(&c).sub(&one).div(&c).to_i128()
+}
+
+// A·n^n of the StableSwap whitepaper for a pool of n = 2 tokens
+fn ann(e: &Env, amp: u32) -> I256 {
+    I256::from_i128(e, amp as i128 * 4)
+}
+
+fn ceil_div(e: &Env, a: &I256, b: &I256) -> I256 {
+    a.add(b).sub(&I256::from_i32(e, 1)).div(b)
+}
+
+fn within_one(e: &Env, a: &I256, b: &I256) -> bool {
+    let one = I256::from_i32(e, 1);
+    if a > b {
+        a.sub(b) <= one
+    } else {
+        b.sub(a) <= one
+    }
+}
+
+// StableSwap invariant D of the balances x and y, found with Newton's method on
+// A·n^n·(x + y) + D = A·n^n·D + D^3 / (n^n·x·y)
+// Returns None for non-positive balances or if the iterations do not converge.
+// Intermediate values are around D^2, so balances above 10^37 trap on overflow
+pub fn compute_d(e: &Env, x: i128, y: i128, amp: u32) -> Option<I256> {
+    if x <= 0 || y <= 0 {
+        return None;
+    }
+    let one = I256::from_i32(e, 1);
+    let two = I256::from_i32(e, 2);
+    let three = I256::from_i32(e, 3);
+    let ann = ann(e, amp);
+    let (x, y) = (I256::from_i128(e, x), I256::from_i128(e, y));
+    let sum = x.add(&y);
+
+    let mut d = sum.clone();
+    for _ in 0..MAX_ITERATIONS {
+        // D^3 / (4·x·y), divided in two steps to keep it small
+        let d_p = d.mul(&d).div(&x.mul(&two)).mul(&d).div(&y.mul(&two));
+        let previous = d.clone();
+        d = ann.mul(&sum).add(&d_p.mul(&two)).mul(&d)
+            .div(&ann.sub(&one).mul(&d).add(&d_p.mul(&three)));
+        if within_one(e, &d, &previous) {
+            return Some(d);
+        }
+    }
+    None
+}
+
+// Returns true if the balances x and y are on or above the curve of the invariant d, that is, if
+// A·n^n·(x + y) + D >= A·n^n·D + D^3 / (n^n·x·y). The last term is rounded up, so the check never
+// accepts balances below the curve. The library rounds its quotes up to this same check
+pub fn is_above_curve(e: &Env, x: i128, y: i128, d: &I256, amp: u32) -> bool {
+    if x <= 0 || y <= 0 {
+        return false;
+    }
+    // The rounding depends on the order of the divisions, so both sides use the smaller balance first
+    let (x, y) = if x <= y { (x, y) } else { (y, x) };
+    let two = I256::from_i32(e, 2);
+    let ann = ann(e, amp);
+    let (x, y) = (I256::from_i128(e, x), I256::from_i128(e, y));
+
+    let left = ann.mul(&x.add(&y)).add(d);
+    let d_p = ceil_div(e, &ceil_div(e, &d.mul(d), &x.mul(&two)).mul(d), &y.mul(&two));
+    let right = ann.mul(d).add(&d_p);
+    left >= right
+}
+
+// Number of significant bits of a U256
+fn bits(n: &U256) -> u32 {
+    let mut bits: u32 = 256;
+    for byte in n.to_be_bytes().iter() {
+        if byte == 0 {
+            bits -= 8;
+        } else {
+            bits -= byte.leading_zeros();
+            break;
+        }
+    }
+    bits
+}
+
+// numerator / denominator as an unsigned 128.128 fixed point number (UQ128x128), for a positive denominator
+// and a quotient below 2^128. The fraction is computed with the remainder and the denominator shifted right
+// until the denominator fits in 128 bits, so that the shifted remainder fits in a U256
+fn uq128x128_div(numerator: &U256, denominator: &U256) -> U256 {
+    let quotient = numerator.div(denominator);
+    let remainder = numerator.rem_euclid(denominator);
+    let shift = bits(denominator).saturating_sub(128);
+    let fraction = remainder.shr(shift).shl(128).div(&denominator.shr(shift));
+    quotient.shl(128).add(&fraction)
+}
+
+// Marginal price of x in terms of y on the curve of the invariant d, as a UQ128x128 number. It is the ratio
+// of the partial derivatives of the invariant, (4·A·n^n·x²·y² + D^3·y) / (4·A·n^n·x²·y² + D^3·x), with both
+// terms divided by D^3. It goes from y / x, the constant product price, to 1 as A grows, so it fits for any
+// reserves below 2^127
+pub fn marginal_price(e: &Env, x: i128, y: i128, d: &I256, amp: u32) -> U256 {
+    let (x, y) = (U256::from_u128(e, x as u128), U256::from_u128(e, y as u128));
+    let d = U256::from_be_bytes(e, &d.to_be_bytes());
+    // (x·y / D)^2 / D is at most sqrt(x·y) / 8, so the term stays far below 2^256
+    let p = x.mul(&y).div(&d);
+    let term = U256::from_u32(e, amp * 16).mul(&p.mul(&p).div(&d));
+    uq128x128_div(&term.add(&y), &term.add(&x))
+}
@@ -0,0 +1,26 @@
+use soroban_sdk::{contracttype, Env, I256};
+
+// Keys of the data only the stable pairs have. The tokens, factory, fee, reserves and price accumulators are
+// stored by soroswap-pair-core
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Amp, // u32. Amplification coefficient, set by the factory at creation. Instance storage
+    DLast, // I256. Invariant D of the reserves after the last deposit or withdraw. Instance storage
+}
+
+pub fn get_amp(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::Amp).unwrap()
+}
+
+pub fn put_amp(e: &Env, amp: u32) {
+    e.storage().instance().set(&DataKey::Amp, &amp);
+}
+
+pub fn get_d_last(e: &Env) -> I256 {
+    e.storage().instance().get(&DataKey::DLast).unwrap_or(I256::from_i32(e, 0))
+}
+
+pub fn put_d_last(e: &Env, d_last: I256) {
+    e.storage().instance().set(&DataKey::DLast, &d_last);
+}
@@ -0,0 +1,88 @@
+use crate::test::{SoroswapStablePairTest};
+use crate::test::stable_pair::SoroswapStablePairError;
+
+pub fn add_liquidity(test: &SoroswapStablePairTest, amount_0: &i128, amount_1: &i128) -> i128 {
+    test.token_0.transfer(&test.user, &test.contract.address, amount_0);
+    test.token_1.transfer(&test.user, &test.contract.address, amount_1);
+    test.contract.deposit(&test.user)
+}
+
+#[test]
+fn deposit_not_yet_initialized() {
+    let test = SoroswapStablePairTest::setup();
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::NotInitialized)));
+}
+
+#[test]
+fn deposit_zero_amounts() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::DepositInsufficientAmountToken0)));
+
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000);
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::DepositInsufficientAmountToken1)));
+}
+
+#[test]
+fn deposit_insufficient_first_liquidity() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    test.token_0.transfer(&test.user, &test.contract.address, &500);
+    test.token_1.transfer(&test.user, &test.contract.address, &500);
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::DepositInsufficientFirstLiquidity)));
+}
+
+#[test]
+fn deposit_balanced() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    let amount: i128 = 10_000_000_000;
+
+    // For balanced amounts the invariant D is the sum of both
+    let liquidity = add_liquidity(&test, &amount, &amount);
+    assert_eq!(liquidity, 2 * amount - 1_000);
+    assert_eq!(test.contract.balance(&test.user), 2 * amount - 1_000);
+    assert_eq!(test.contract.total_supply(), 2 * amount);
+    assert_eq!(test.contract.get_reserves(), (amount, amount, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn deposit_unbalanced_first_liquidity() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+
+    // With A = 100 the invariant D of unbalanced amounts is just below their sum
+    let liquidity = add_liquidity(&test, &10_000_000_000, &20_000_000_000);
+    assert_eq!(liquidity, 29_990_680_311 - 1_000);
+}
+
+#[test]
+fn deposit_proportional() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    let amount: i128 = 10_000_000_000;
+    add_liquidity(&test, &amount, &amount);
+
+    // Later deposits are minted on the smaller of both proportions
+    let liquidity = add_liquidity(&test, &(amount / 2), &amount);
+    assert_eq!(liquidity, amount);
+    assert_eq!(test.contract.total_supply(), 3 * amount);
+    assert_eq!(test.contract.get_reserves(), (amount + amount / 2, 2 * amount, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn deposit_paused() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    test.factory.set_paused(&true);
+    assert_eq!(test.contract.paused(), true);
+
+    test.token_0.transfer(&test.user, &test.contract.address, &10_000_000);
+    test.token_1.transfer(&test.user, &test.contract.address, &10_000_000);
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::Paused)));
+}
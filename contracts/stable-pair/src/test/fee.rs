@@ -0,0 +1,66 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapStablePairTest};
+use soroban_sdk::I256;
+
+const AMOUNT: i128 = 10_000_000_000;
+const MINIMUM_LIQUIDITY: i128 = 1_000;
+
+#[test]
+fn fee_off() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    let liquidity = add_liquidity(&test, &AMOUNT, &AMOUNT);
+    assert_eq!(test.contract.d_last(), I256::from_i32(&test.env, 0));
+
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000_000);
+    test.contract.swap(&0, &999_098_173, &test.user);
+
+    test.contract.transfer(&test.user, &test.contract.address, &liquidity);
+    test.contract.withdraw(&test.user);
+    assert_eq!(test.contract.balance(&test.admin), 0);
+    assert_eq!(test.contract.total_supply(), MINIMUM_LIQUIDITY);
+    assert_eq!(test.contract.d_last(), I256::from_i32(&test.env, 0));
+}
+
+#[test]
+fn fee_on_add_swap_remove() {
+    let test = SoroswapStablePairTest::setup();
+    test.factory.set_fees_enabled(&true);
+    assert_eq!(test.factory.fee_to(), test.admin);
+    test.initialize();
+
+    // At equal reserves D is the sum of the reserves
+    let liquidity = add_liquidity(&test, &AMOUNT, &AMOUNT);
+    assert_eq!(liquidity, 2 * AMOUNT - MINIMUM_LIQUIDITY);
+    assert_eq!(test.contract.d_last(), I256::from_i128(&test.env, 2 * AMOUNT));
+
+    // The 0.04% fee on 1_000_000_000 grows D from 20_000_000_000 to 20_000_399_807
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000_000);
+    test.contract.swap(&0, &999_098_173, &test.user);
+
+    // The protocol gets 1/6 of the growth of D:
+    // 20_000_000_000 * 399_807 / (5 * 20_000_399_807 + 20_000_000_000) = 66_633
+    test.contract.transfer(&test.user, &test.contract.address, &liquidity);
+    let (amount_0, amount_1) = test.contract.withdraw(&test.user);
+    assert_eq!((amount_0, amount_1), (10_999_962_801, 9_000_871_389));
+    assert_eq!(test.contract.balance(&test.admin), 66_633);
+    assert_eq!(test.contract.total_supply(), MINIMUM_LIQUIDITY + 66_633);
+
+    // The reserves left, 37_199 and 30_438, are the ones D is measured against next time
+    assert_eq!(test.contract.get_reserves(), (37_199, 30_438, test.env.ledger().timestamp()));
+    assert_eq!(test.contract.d_last(), I256::from_i128(&test.env, 67_635));
+}
+
+#[test]
+fn fee_turned_off_resets_d_last() {
+    let test = SoroswapStablePairTest::setup();
+    test.factory.set_fees_enabled(&true);
+    test.initialize();
+    add_liquidity(&test, &AMOUNT, &AMOUNT);
+    assert_eq!(test.contract.d_last(), I256::from_i128(&test.env, 2 * AMOUNT));
+
+    test.factory.set_fees_enabled(&false);
+    add_liquidity(&test, &AMOUNT, &AMOUNT);
+    assert_eq!(test.contract.d_last(), I256::from_i32(&test.env, 0));
+    assert_eq!(test.contract.balance(&test.admin), 0);
+}
@@ -0,0 +1,51 @@
+use crate::test::{SoroswapStablePairTest, AMP, FEE};
+use soroban_sdk::String;
+use crate::test::stable_pair::SoroswapStablePairError;
+
+#[test]
+fn initialize_token_1_less_than_token_0() {
+    let test = SoroswapStablePairTest::setup();
+    let res = test.contract.try_initialize(&test.factory.address, &test.token_1.address, &test.token_0.address, &AMP, &FEE);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::InitializeTokenOrderInvalid)));
+}
+
+#[test]
+fn double_initialize() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    let res = test.contract.try_initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &AMP, &FEE);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::InitializeAlreadyInitialized)));
+}
+
+#[test]
+fn initialize_invalid_amp() {
+    let test = SoroswapStablePairTest::setup();
+    let res = test.contract.try_initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &0, &FEE);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::InitializeInvalidAmp)));
+    let res = test.contract.try_initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &1_000_001, &FEE);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::InitializeInvalidAmp)));
+}
+
+#[test]
+fn initialize_invalid_fee() {
+    let test = SoroswapStablePairTest::setup();
+    let res = test.contract.try_initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &AMP, &1_001);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::InitializeInvalidFee)));
+}
+
+#[test]
+fn initialize_initial_values() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    assert_eq!(test.factory.guardian(), test.admin);
+    assert_eq!(test.contract.token_0(), test.token_0.address);
+    assert_eq!(test.contract.token_1(), test.token_1.address);
+    assert_eq!(test.contract.factory(), test.factory.address);
+    assert_eq!(test.contract.amp(), AMP);
+    assert_eq!(test.contract.fee(), FEE);
+    assert_eq!(test.contract.get_reserves(), (0, 0, 0));
+    assert_eq!(test.contract.paused(), false);
+    assert_eq!(test.contract.symbol(), String::from_str(&test.env, "TOK0-ABCDEF-SOROSWAP-SLP"));
+    assert_eq!(test.contract.name(), String::from_str(&test.env, "TOK0-ABCDEF Soroswap Stable LP"));
+    assert_eq!(test.contract.decimals(), 7);
+}
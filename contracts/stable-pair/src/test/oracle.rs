@@ -0,0 +1,70 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapStablePairTest};
+use soroban_sdk::{testutils::{Ledger}, Env, U256};
+
+const AMOUNT: i128 = 10_000_000_000;
+
+fn seconds(e: &Env, time_elapsed: u64) -> U256 {
+    U256::from_u128(e, time_elapsed as u128)
+}
+
+#[test]
+fn cumulative_prices_first_deposit() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = 12345;
+    });
+    add_liquidity(&test, &AMOUNT, &AMOUNT);
+
+    // There were no reserves before the deposit, so there is no price to accumulate
+    assert_eq!(test.contract.get_reserves(), (AMOUNT, AMOUNT, 12345));
+    assert_eq!(test.contract.price_0_cumulative_last(), U256::from_u32(&test.env, 0));
+    assert_eq!(test.contract.price_1_cumulative_last(), U256::from_u32(&test.env, 0));
+}
+
+#[test]
+fn cumulative_prices_balanced() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = 12345;
+    });
+    add_liquidity(&test, &AMOUNT, &AMOUNT);
+
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = 12345 + 10;
+    });
+    test.contract.sync();
+
+    // At equal reserves the curve has a slope of exactly 1 for any amplification
+    let one = U256::from_u32(&test.env, 1).shl(128);
+    assert_eq!(test.contract.price_0_cumulative_last(), one.mul(&seconds(&test.env, 10)));
+    assert_eq!(test.contract.price_1_cumulative_last(), one.mul(&seconds(&test.env, 10)));
+}
+
+#[test]
+fn cumulative_prices_after_swap() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = 12345;
+    });
+    add_liquidity(&test, &AMOUNT, &AMOUNT);
+
+    // Same swap as swap_token_0_for_token_1, at the same timestamp as the deposit
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000_000);
+    test.contract.swap(&0, &999_098_173, &test.user);
+    assert_eq!(test.contract.price_0_cumulative_last(), U256::from_u32(&test.env, 0));
+
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = 12345 + 10;
+    });
+    test.contract.sync();
+
+    // The marginal price of token 0 is about 0.99899 token 1, while the ratio of the reserves is about 0.818
+    let price_0 = U256::from_u128(&test.env, 339_937_316_762_350_746_493_494_539_024_249_837_675);
+    let price_1 = U256::from_u128(&test.env, 340_627_767_319_426_510_772_338_454_355_093_017_407);
+    assert_eq!(test.contract.price_0_cumulative_last(), price_0.mul(&seconds(&test.env, 10)));
+    assert_eq!(test.contract.price_1_cumulative_last(), price_1.mul(&seconds(&test.env, 10)));
+}
@@ -0,0 +1,36 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapStablePairTest};
+use crate::test::stable_pair::SoroswapStablePairError;
+
+const AMOUNT: i128 = 10_000_000_000;
+
+#[test]
+fn set_paused_not_initialized() {
+    let test = SoroswapStablePairTest::setup();
+    let res = test.contract.try_set_paused(&true);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::NotInitialized)));
+}
+
+#[test]
+fn set_paused_blocks_only_this_pair() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    add_liquidity(&test, &AMOUNT, &AMOUNT);
+
+    test.contract.set_paused(&true);
+    assert_eq!(test.contract.paused(), true);
+    assert_eq!(test.factory.paused(), false);
+
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000);
+    let res = test.contract.try_swap(&0, &1_000, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::Paused)));
+
+    test.token_1.transfer(&test.user, &test.contract.address, &1_000_000);
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::Paused)));
+
+    // Once unpaused everything works again
+    test.contract.set_paused(&false);
+    assert_eq!(test.contract.paused(), false);
+    test.contract.swap(&0, &1_000, &test.user);
+}
@@ -0,0 +1,81 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapStablePairTest};
+use crate::test::stable_pair::SoroswapStablePairError;
+
+const AMOUNT: i128 = 10_000_000_000;
+
+#[test]
+fn swap_not_yet_initialized() {
+    let test = SoroswapStablePairTest::setup();
+    let res = test.contract.try_swap(&0, &0, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::NotInitialized)));
+}
+
+#[test]
+fn swap_invalid_amounts() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    let res = test.contract.try_swap(&0, &0, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::SwapInsufficientOutputAmount)));
+    let res = test.contract.try_swap(&-1, &1, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::SwapNegativesOutNotSupported)));
+    let res = test.contract.try_swap(&0, &1, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::SwapInsufficientLiquidity)));
+
+    add_liquidity(&test, &AMOUNT, &AMOUNT);
+    let res = test.contract.try_swap(&0, &1_000, &test.token_0.address);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::SwapInvalidTo)));
+    let res = test.contract.try_swap(&0, &1_000, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::SwapInsufficientInputAmount)));
+}
+
+#[test]
+fn swap_token_0_for_token_1() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    add_liquidity(&test, &AMOUNT, &AMOUNT);
+
+    // 10% of the reserves. A constant product pair with a 0.3% fee would only give 906_610_893
+    let amount_in: i128 = 1_000_000_000;
+    let amount_out: i128 = 999_098_173;
+    test.token_0.transfer(&test.user, &test.contract.address, &amount_in);
+
+    // One more unit would leave the balances below the curve
+    let res = test.contract.try_swap(&0, &(amount_out + 1), &test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::SwapInvariantNotMet)));
+
+    let balance_1_before = test.token_1.balance(&test.user);
+    test.contract.swap(&0, &amount_out, &test.user);
+    assert_eq!(test.token_1.balance(&test.user), balance_1_before + amount_out);
+    assert_eq!(test.contract.get_reserves(), (AMOUNT + amount_in, AMOUNT - amount_out, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn swap_token_1_for_token_0() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    add_liquidity(&test, &AMOUNT, &AMOUNT);
+
+    // The pool is symmetric, so the quote is the same in both directions
+    let amount_in: i128 = 1_000_000_000;
+    let amount_out: i128 = 999_098_173;
+    test.token_1.transfer(&test.user, &test.contract.address, &amount_in);
+
+    let res = test.contract.try_swap(&(amount_out + 1), &0, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::SwapInvariantNotMet)));
+
+    test.contract.swap(&amount_out, &0, &test.user);
+    assert_eq!(test.contract.get_reserves(), (AMOUNT - amount_out, AMOUNT + amount_in, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn swap_paused() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    add_liquidity(&test, &AMOUNT, &AMOUNT);
+    test.factory.set_paused(&true);
+
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000);
+    let res = test.contract.try_swap(&0, &1_000, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::Paused)));
+}
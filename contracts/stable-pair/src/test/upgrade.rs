@@ -0,0 +1,61 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapStablePairTest, stable_pair, AMP, FEE};
+use crate::test::stable_pair::SoroswapStablePairError;
+use soroban_sdk::{
+    IntoVal,
+    testutils::{
+        MockAuth,
+        MockAuthInvoke,
+    },
+};
+
+const AMOUNT: i128 = 10_000_000_000;
+
+#[test]
+fn upgrade_not_initialized() {
+    let test = SoroswapStablePairTest::setup();
+    let new_wasm_hash = test.env.deployer().upload_contract_wasm(stable_pair::WASM);
+    let res = test.contract.try_upgrade(&new_wasm_hash);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::NotInitialized)));
+}
+
+#[test]
+fn upgrade_keeps_data() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    add_liquidity(&test, &AMOUNT, &AMOUNT);
+
+    let new_wasm_hash = test.env.deployer().upload_contract_wasm(stable_pair::WASM);
+    test.contract.upgrade(&new_wasm_hash);
+
+    assert_eq!(test.contract.token_0(), test.token_0.address);
+    assert_eq!(test.contract.token_1(), test.token_1.address);
+    assert_eq!(test.contract.factory(), test.factory.address);
+    assert_eq!(test.contract.amp(), AMP);
+    assert_eq!(test.contract.fee(), FEE);
+    assert_eq!(test.contract.get_reserves(), (AMOUNT, AMOUNT, test.env.ledger().timestamp()));
+}
+
+#[test]
+#[should_panic]
+fn upgrade_with_mock_auth_not_allowed() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize();
+    let new_wasm_hash = test.env.deployer().upload_contract_wasm(stable_pair::WASM);
+
+    // Only the factory can upgrade the pair
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.admin.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "upgrade",
+                    args: (new_wasm_hash.clone(),).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .upgrade(&new_wasm_hash);
+}
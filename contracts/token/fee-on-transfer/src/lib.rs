@@ -0,0 +1,12 @@
+#![no_std]
+
+mod admin;
+mod allowance;
+mod balance;
+mod contract;
+mod metadata;
+mod storage_types;
+mod test;
+mod transfer_fee;
+
+pub use crate::contract::FeeOnTransferTokenClient;
@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+use crate::{contract::FeeOnTransferToken, FeeOnTransferTokenClient};
+use soroban_sdk::{testutils::Address as _, Address, Env, IntoVal};
+
+fn create_token<'a>(e: &Env, admin: &Address, transfer_fee: u32) -> FeeOnTransferTokenClient<'a> {
+    let token = FeeOnTransferTokenClient::new(e, &e.register_contract(None, FeeOnTransferToken {}));
+    token.initialize(admin, &7, &"name".into_val(e), &"symbol".into_val(e), &transfer_fee);
+    token
+}
+
+#[test]
+fn transfer_with_fee() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+
+    // 1% is burned from every transfer
+    let token = create_token(&e, &admin, 100);
+    assert_eq!(token.transfer_fee(), 100);
+
+    token.mint(&user1, &10000);
+    assert_eq!(token.balance(&user1), 10000);
+
+    token.transfer(&user1, &user2, &1000);
+    assert_eq!(token.balance(&user1), 9000);
+    assert_eq!(token.balance(&user2), 990);
+
+    token.approve(&user1, &user3, &500, &200);
+    token.transfer_from(&user3, &user1, &user2, &500);
+    assert_eq!(token.balance(&user1), 8500);
+    assert_eq!(token.balance(&user2), 1485);
+    assert_eq!(token.allowance(&user1, &user3), 0);
+}
+
+#[test]
+fn transfer_without_fee() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin, 0);
+
+    token.mint(&user1, &1000);
+    token.transfer(&user1, &user2, &1000);
+    assert_eq!(token.balance(&user1), 0);
+    assert_eq!(token.balance(&user2), 1000);
+}
+
+#[test]
+#[should_panic(expected = "transfer fee overflow")]
+fn transfer_fee_overflow() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin, 100);
+
+    token.mint(&user1, &i128::MAX);
+    token.transfer(&user1, &user2, &i128::MAX);
+}
+
+#[test]
+#[should_panic(expected = "transfer fee must be at most 10000 basis points")]
+fn transfer_fee_too_high() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    create_token(&e, &admin, 10001);
+}
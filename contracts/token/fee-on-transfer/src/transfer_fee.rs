@@ -0,0 +1,25 @@
+use soroban_sdk::Env;
+
+use crate::storage_types::DataKey;
+
+// Transfer fees are expressed in basis points
+pub(crate) const TRANSFER_FEE_DENOMINATOR: i128 = 10_000;
+
+pub fn read_transfer_fee(e: &Env) -> u32 {
+    let key = DataKey::TransferFee;
+    e.storage().instance().get(&key).unwrap_or(0)
+}
+
+pub fn write_transfer_fee(e: &Env, fee: u32) {
+    let key = DataKey::TransferFee;
+    e.storage().instance().set(&key, &fee);
+}
+
+/// Splits a transferred amount into the amount received and the fee that is burned.
+pub fn split_transfer_fee(e: &Env, amount: i128) -> (i128, i128) {
+    let fee = amount
+        .checked_mul(read_transfer_fee(e) as i128)
+        .expect("transfer fee overflow")
+        / TRANSFER_FEE_DENOMINATOR;
+    (amount - fee, fee)
+}
@@ -0,0 +1,64 @@
+use soroban_sdk::{self, contracterror};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SoroswapWeightedPairError {
+    /// SoroswapWeightedPair: already initialized
+    InitializeAlreadyInitialized = 701,
+    /// SoroswapWeightedPair: not yet initialized
+    NotInitialized = 702,
+
+    /// SoroswapWeightedPair: token_0 must be less than token_1 while initializing
+    InitializeTokenOrderInvalid = 703,
+    /// SoroswapWeightedPair: weight of token 0 is out of range while initializing
+    InitializeInvalidWeights = 704,
+    /// SoroswapWeightedPair: fee is greater than the maximum allowed while initializing
+    InitializeInvalidFee = 705,
+
+    /// SoroswapWeightedPair: insufficient amount of token 0 sent while doing deposit
+    DepositInsufficientAmountToken0 = 706,
+    /// SoroswapWeightedPair: insufficient amount of token 1 sent while doing deposit
+    DepositInsufficientAmountToken1 = 707,
+    /// SoroswapWeightedPair: insufficient first liquidity minted while doing deposit
+    DepositInsufficientFirstLiquidity = 708,
+    /// SoroswapWeightedPair: insufficient liquidity minted while doing deposit
+    DepositInsufficientLiquidityMinted = 709,
+
+    /// SoroswapWeightedPair: exactly one of the tokens must be sent while doing a single deposit
+    DepositSingleInvalidAmounts = 710,
+    /// SoroswapWeightedPair: liquidity was not initialized yet while doing a single deposit
+    DepositSingleNoLiquidity = 711,
+    /// SoroswapWeightedPair: the amount sent is more than 30% of the reserve while doing a single deposit
+    DepositSingleMaxInRatio = 712,
+
+    /// SoroswapWeightedPair: insufficient output amount while doing swap
+    SwapInsufficientOutputAmount = 713,
+    /// SoroswapWeightedPair: negatives amounts out dont supported while doing swap
+    SwapNegativesOutNotSupported = 714,
+    /// SoroswapWeightedPair: insufficient liquidity to do the swap
+    SwapInsufficientLiquidity = 715,
+    /// SoroswapWeightedPair: invalid to to do the swap
+    SwapInvalidTo = 716,
+    /// SoroswapWeightedPair: insufficient input amount while doing swap
+    SwapInsufficientInputAmount = 717,
+    /// SoroswapWeightedPair: the weighted invariant is not met while doing swap
+    SwapInvariantNotMet = 718,
+    /// SoroswapWeightedPair: an amount in is more than 30% of its reserve while doing swap
+    SwapMaxInRatio = 719,
+    /// SoroswapWeightedPair: an amount out is more than 30% of its reserve while doing swap
+    SwapMaxOutRatio = 720,
+
+    /// SoroswapWeightedPair: liquidity was not initialized yet while doing withdraw
+    WithdrawLiquidityNotInitialized = 721,
+    /// SoroswapWeightedPair: insufficient sent shares while doing withdraw
+    WithdrawInsufficientSentShares = 722,
+    /// SoroswapWeightedPair: insufficient liquidity burned while doing withdraw
+    WithdrawInsufficientLiquidityBurned = 723,
+
+    /// SoroswapWeightedPair: OVERFLOW while doing the math of a deposit, swap or withdraw
+    UpdateOverflow = 724,
+
+    /// SoroswapWeightedPair: this pair or all the pairs are paused by the factory, only withdraws are allowed
+    Paused = 725,
+}
@@ -0,0 +1,122 @@
+//! Fixed-point math of the weighted pairs.
+//!
+//! Values are `i128` with 18 decimals, so [`ONE`] is 1.0. `ln` and `exp` reduce their argument with
+//! powers of two and then sum a series that converges in a few dozen terms, and `pow` is `exp(e·ln(b))`.
+//! The result of [`pow`] can be off by up to [`MAX_POW_RELATIVE_ERROR`], so the pairs and the quotes use
+//! [`pow_up`] and [`pow_down`], which add that margin in favor of the pool.
+//!
+//! This is a copy of the `fixed_point` module of `soroswap-library`, which cannot be a dependency of this
+//! contract because it uses a different version of `soroban-sdk`. Both copies must stay identical, so the
+//! library quotes match what the pair accepts.
+
+/// 1.0 in fixed point.
+pub const ONE: i128 = 1_000_000_000_000_000_000;
+
+/// ln(2) in fixed point, rounded down.
+pub const LN_2: i128 = 693_147_180_559_945_309;
+
+/// Upper bound of the relative error of [`pow`], in fixed point (10^-14).
+pub const MAX_POW_RELATIVE_ERROR: i128 = 10_000;
+
+/// Natural logarithm of `a`.
+///
+/// Returns None if `a` is not positive.
+pub fn ln(a: i128) -> Option<i128> {
+    if a <= 0 {
+        return None;
+    }
+
+    // a = m·2^k with m in [1, 2)
+    let mut m = a;
+    let mut k: i128 = 0;
+    while m < ONE {
+        m *= 2;
+        k -= 1;
+    }
+    while m >= 2 * ONE {
+        m /= 2;
+        k += 1;
+    }
+
+    // ln(m) = 2·atanh(z) = 2·(z + z^3/3 + z^5/5 + ...) with z = (m - 1) / (m + 1), which is below 1/3
+    let z = (m - ONE) * ONE / (m + ONE);
+    let z_squared = z * z / ONE;
+    let mut sum = z;
+    let mut term = z;
+    let mut n = 3;
+    loop {
+        term = term * z_squared / ONE;
+        if term == 0 {
+            break;
+        }
+        sum += term / n;
+        n += 2;
+    }
+
+    Some(2 * sum + k * LN_2)
+}
+
+/// Natural exponential of `x`.
+///
+/// Returns None if the result does not fit in an `i128`.
+pub fn exp(x: i128) -> Option<i128> {
+    // x = k·ln(2) + r with r in [0, ln(2)), so exp(x) = 2^k·exp(r)
+    let k = x.div_euclid(LN_2);
+    let r = x - k * LN_2;
+
+    // exp(r) = 1 + r + r^2/2! + r^3/3! + ...
+    let mut sum = ONE;
+    let mut term = ONE;
+    let mut n = 1;
+    loop {
+        term = term * r / ONE / n;
+        if term == 0 {
+            break;
+        }
+        sum += term;
+        n += 1;
+    }
+
+    if k >= 0 {
+        if k >= 127 {
+            return None;
+        }
+        sum.checked_mul(1 << k)
+    } else if k <= -127 {
+        Some(0)
+    } else {
+        Some(sum >> -k)
+    }
+}
+
+/// `base` raised to `exponent`.
+///
+/// Returns None if `base` is not positive or on overflow.
+pub fn pow(base: i128, exponent: i128) -> Option<i128> {
+    if base == ONE || exponent == 0 {
+        return Some(ONE);
+    }
+    exp(ln(base)?.checked_mul(exponent)? / ONE)
+}
+
+/// `base` raised to `exponent`, rounded up by the maximum error of [`pow`].
+pub fn pow_up(base: i128, exponent: i128) -> Option<i128> {
+    if base == ONE || exponent == 0 {
+        return Some(ONE);
+    }
+    let raw = pow(base, exponent)?;
+    raw.checked_add(max_pow_error(raw))
+}
+
+/// `base` raised to `exponent`, rounded down by the maximum error of [`pow`]. Never negative.
+pub fn pow_down(base: i128, exponent: i128) -> Option<i128> {
+    if base == ONE || exponent == 0 {
+        return Some(ONE);
+    }
+    let raw = pow(base, exponent)?;
+    Some((raw - max_pow_error(raw)).max(0))
+}
+
+fn max_pow_error(raw: i128) -> i128 {
+    raw / (ONE / MAX_POW_RELATIVE_ERROR) + 1
+}
@@ -0,0 +1,594 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, contractmeta, token, Address, BytesN, Env, String};
+use soroban_token_sdk::metadata::TokenMetadata;
+
+
+mod storage;
+mod error;
+mod test;
+mod math;
+// Same module as in soroswap-library, where the quotes also need pow_up
+#[allow(dead_code)]
+mod fixed_point;
+
+use storage::*;
+use soroswap_pair_core::*;
+use soroswap_pair_token::{SoroswapPairToken, internal_mint, internal_burn, write_metadata, TakeFirstNCharsAndConcat};
+use error::SoroswapWeightedPairError;
+use math::{checked_mul_div, checked_mul_div_ceil, exceeds_max_ratio, is_above_invariant, WEIGHT_DENOMINATOR};
+use fixed_point::{pow_down, ONE};
+
+// First topic of the events of the weighted pairs
+const PAIR_NAME: &str = "SoroswapWeightedPair";
+
+static MINIMUM_LIQUIDITY: i128 = 1000;
+// Fees are expressed in basis points. The factory cannot set a fee greater than 10%
+static FEE_DENOMINATOR: i128 = 10_000;
+static MAXIMUM_FEE: u32 = 1_000;
+// Each token weighs at least 1% of the pair
+static MINIMUM_WEIGHT: u32 = 100;
+static MAXIMUM_WEIGHT: u32 = 9_900;
+
+fn create_symbol(e: &Env, symbol_0: &String, symbol_1: &String) -> String {
+    let symbol_0_short = symbol_0.take_first_n_chars(&e, 6);
+    let symbol_1_short = symbol_1.take_first_n_chars(&e, 6);
+    let hyphen = String::from_str(&e, "-");
+    let end = String::from_str(&e, "-SOROSWAP-WLP");
+    symbol_0_short.concat(&e, hyphen).concat(&e, symbol_1_short).concat(&e, end)
+}
+
+fn create_name(e: &Env, symbol_0: &String, symbol_1: &String) -> String {
+    let symbol_0_short = symbol_0.take_first_n_chars(&e, 6);
+    let symbol_1_short = symbol_1.take_first_n_chars(&e, 6);
+    let hyphen = String::from_str(&e, "-");
+    let end = String::from_str(&e, " Soroswap Weighted LP");
+    symbol_0_short.concat(&e, hyphen).concat(&e, symbol_1_short).concat(&e, end)
+}
+
+// Metadata that is added on to the WASM custom section
+contractmeta!(
+    key = "Description",
+    val = "Soroswap.Finance Protocol - Weighted constant mean AMM, with token weights fixed at creation"
+);
+
+pub trait SoroswapWeightedPairTrait{
+    // Sets the token contract addresses, the weight of the first token and the swap fee for this pool
+    fn initialize(e: Env, factory: Address, token_0: Address, token_1: Address, weight_0: u32, fee: u32) -> Result<(), SoroswapWeightedPairError>;
+
+    fn deposit(e:Env, to: Address)  -> Result<i128, SoroswapWeightedPairError>;
+
+    // Deposits only one of the tokens. It should be called from another contract that has already sent the tokens to the pair contract.
+    fn deposit_single(e:Env, to: Address)  -> Result<i128, SoroswapWeightedPairError>;
+
+    // Swaps. This function should be called from another contract that has already sent tokens to the pair contract.
+    // Same signature as SoroswapPair::swap, so the router can call every kind of pair with the same client
+    fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address) -> Result<(), SoroswapWeightedPairError>;
+
+    fn withdraw(e: Env, to: Address) -> Result<(i128, i128), SoroswapWeightedPairError>;
+
+    // transfers the excess token balances from the pair to the specified to address
+    fn skim(e: Env, to: Address) -> Result<(), SoroswapWeightedPairError>;
+
+    // updates the reserves of the pair to match the current token balances
+    fn sync(e: Env) -> Result<(), SoroswapWeightedPairError>;
+
+    fn token_0(e: Env) -> Address;
+    fn token_1(e: Env) -> Address;
+    fn factory(e: Env) -> Address;
+
+    // Weights of token 0 and token 1 in basis points. They always add up to 10_000
+    fn weights(e: Env) -> (u32, u32);
+
+    // Swap fee in basis points
+    fn fee(e: Env) -> u32;
+
+    fn get_reserves(e: Env) -> (i128, i128, u64);
+
+    // Upgrades the code of the pair to the given wasm hash. Only the factory can upgrade a pair
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), SoroswapWeightedPairError>;
+
+    // Returns true if this pair or all the pairs were paused by the factory.
+    // While paused, deposits and swaps are rejected but withdraws still work
+    fn paused(e: Env) -> bool;
+    fn set_paused(e: Env, is_paused: bool) -> Result<(), SoroswapWeightedPairError>;
+
+}
+
+#[contract]
+struct SoroswapWeightedPair;
+
+#[contractimpl]
+impl SoroswapWeightedPairTrait for SoroswapWeightedPair {
+
+    /// Initializes a new Soroswap weighted pair by setting token addresses, factory, weights and swap fee.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `factory` - The address of the Soroswap factory contract.
+    /// * `token_0` - The address of the first token in the pair.
+    /// * `token_1` - The address of the second token in the pair.
+    /// * `weight_0` - The weight of the first token, in basis points between 100 and 9_900. The second token gets the rest.
+    /// * `fee` - The swap fee, in basis points.
+    fn initialize(e: Env, factory: Address, token_0: Address, token_1: Address, weight_0: u32, fee: u32) -> Result<(), SoroswapWeightedPairError> {
+        if has_token_0(&e) {
+            return Err(SoroswapWeightedPairError::InitializeAlreadyInitialized);
+        }
+
+        if token_0 >= token_1 {
+            return Err(SoroswapWeightedPairError::InitializeTokenOrderInvalid);
+        }
+
+        if weight_0 < MINIMUM_WEIGHT || weight_0 > MAXIMUM_WEIGHT {
+            return Err(SoroswapWeightedPairError::InitializeInvalidWeights);
+        }
+
+        if fee > MAXIMUM_FEE {
+            return Err(SoroswapWeightedPairError::InitializeInvalidFee);
+        }
+
+        put_factory(&e, factory);
+
+        let symbol_0: String = token::Client::new(&e, &token_0).symbol();
+        let symbol_1: String = token::Client::new(&e, &token_1).symbol();
+
+        let decimal: u32 = 7;
+        let name: String = create_name(&e, &symbol_0, &symbol_1);
+        let symbol: String = create_symbol(&e, &symbol_0, &symbol_1);
+
+        write_metadata(
+            &e,
+            TokenMetadata {
+                decimal ,
+                name,
+                symbol,
+            },
+        );
+
+        put_token_0(&e, token_0);
+        put_token_1(&e, token_1);
+        put_reserve_0(&e, 0);
+        put_reserve_1(&e, 0);
+        put_weight_0(&e, weight_0);
+        put_fee(&e, fee);
+        extend_instance_ttl(&e);
+
+        Ok(())
+    }
+
+    /// Returns the address of the first token in the weighted pair.
+    fn token_0(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        get_token_0(&e)
+    }
+
+    /// Returns the address of the second token in the weighted pair.
+    fn token_1(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        get_token_1(&e)
+    }
+
+    /// Returns the address of the Soroswap factory contract.
+    fn factory(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        get_factory(&e)
+    }
+
+    /// Deposits both tokens into the weighted pair and mints LP tokens in return.
+    ///
+    /// The first deposit mints the invariant `amount_0^weight_0 · amount_1^weight_1` of the deposited amounts,
+    /// minus the minimum liquidity. Later deposits mint in proportion to the reserves, like in `SoroswapPair`.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `to` - The address where the minted LP tokens will be sent.
+    ///
+    /// # Returns
+    /// The amount of minted LP tokens.
+    ///
+    /// # Errors
+    /// - `NotInitialized`: The weighted pair has not been initialized.
+    /// - `Paused`: The factory paused all the pairs.
+    /// - `DepositInsufficientAmountToken0`: Insufficient amount of token 0 sent.
+    /// - `DepositInsufficientAmountToken1`: Insufficient amount of token 1 sent.
+    /// - `DepositInsufficientFirstLiquidity`: Insufficient first liquidity minted.
+    /// - `DepositInsufficientLiquidityMinted`: Insufficient liquidity minted.
+    /// - `UpdateOverflow`: Overflow occurred during update.
+    fn deposit(e: Env, to: Address) -> Result<i128, SoroswapWeightedPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e){
+            return Err(SoroswapWeightedPairError::NotInitialized)
+        }
+
+        if is_paused(&e) {
+            return Err(SoroswapWeightedPairError::Paused);
+        }
+
+        let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        let amount_0 = balance_0.checked_sub(reserve_0).ok_or(SoroswapWeightedPairError::DepositInsufficientAmountToken0)?;
+        let amount_1 = balance_1.checked_sub(reserve_1).ok_or(SoroswapWeightedPairError::DepositInsufficientAmountToken1)?;
+
+        if amount_0 <= 0 {
+            return Err(SoroswapWeightedPairError::DepositInsufficientAmountToken0);
+        }
+
+        if amount_1 <= 0 {
+            return Err(SoroswapWeightedPairError::DepositInsufficientAmountToken1);
+        }
+
+        let total_supply = SoroswapPairToken::total_supply(e.clone());
+
+        let liquidity = if total_supply == 0 {
+            // When the liquidity pool is being initialized, we block the minimum liquidity forever in this contract
+            internal_mint(e.clone(), e.current_contract_address(), MINIMUM_LIQUIDITY);
+            let invariant = first_invariant(&e, amount_0, amount_1, get_weight_0(&e)).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+            if invariant <= MINIMUM_LIQUIDITY {
+                return Err(SoroswapWeightedPairError::DepositInsufficientFirstLiquidity);
+            }
+            invariant.checked_sub(MINIMUM_LIQUIDITY).ok_or(SoroswapWeightedPairError::UpdateOverflow)?
+        } else {
+            let shares_0 = checked_mul_div(&e, amount_0, total_supply, reserve_0).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+            let shares_1 = checked_mul_div(&e, amount_1, total_supply, reserve_1).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+            shares_0.min(shares_1)
+        };
+
+        if liquidity <= 0 {
+            return Err(SoroswapWeightedPairError::DepositInsufficientLiquidityMinted);
+        }
+
+        internal_mint(e.clone(), to.clone(), liquidity.clone());
+        update_reserves(&e, PAIR_NAME, balance_0, balance_1);
+
+        event::deposit(&e, PAIR_NAME, to, amount_0, amount_1, liquidity, balance_0, balance_1);
+
+        Ok(liquidity)
+    }
+
+    /// Deposits only one of the tokens into the weighted pair and mints LP tokens in return.
+    ///
+    /// The LP tokens grow the supply as much as the deposit grows the invariant:
+    /// `total_supply · ((reserve + amount) / reserve)^weight - total_supply`. The part of the deposit that
+    /// is worth the other token pays the swap fee. The amount can be at most 30% of the reserve.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `to` - The address where the minted LP tokens will be sent.
+    ///
+    /// # Returns
+    /// The amount of minted LP tokens.
+    ///
+    /// # Errors
+    /// - `NotInitialized`
+    /// - `Paused`
+    /// - `DepositSingleNoLiquidity`: The pair has no liquidity yet.
+    /// - `DepositSingleInvalidAmounts`: Not exactly one of the tokens was sent.
+    /// - `DepositSingleMaxInRatio`: The amount is more than 30% of the reserve.
+    /// - `DepositInsufficientLiquidityMinted`
+    /// - `UpdateOverflow`
+    fn deposit_single(e: Env, to: Address) -> Result<i128, SoroswapWeightedPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e){
+            return Err(SoroswapWeightedPairError::NotInitialized)
+        }
+
+        if is_paused(&e) {
+            return Err(SoroswapWeightedPairError::Paused);
+        }
+
+        let total_supply = SoroswapPairToken::total_supply(e.clone());
+        if total_supply == 0 {
+            return Err(SoroswapWeightedPairError::DepositSingleNoLiquidity);
+        }
+
+        let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        let amount_0 = balance_0.checked_sub(reserve_0).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+        let amount_1 = balance_1.checked_sub(reserve_1).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+
+        if amount_0 < 0 || amount_1 < 0 || (amount_0 > 0) == (amount_1 > 0) {
+            return Err(SoroswapWeightedPairError::DepositSingleInvalidAmounts);
+        }
+
+        let weight_0 = get_weight_0(&e);
+        let (amount, reserve, weight) = if amount_0 > 0 {
+            (amount_0, reserve_0, weight_0)
+        } else {
+            (amount_1, reserve_1, WEIGHT_DENOMINATOR as u32 - weight_0)
+        };
+
+        if exceeds_max_ratio(&e, amount, reserve) {
+            return Err(SoroswapWeightedPairError::DepositSingleMaxInRatio);
+        }
+
+        // Depositing a single token is like swapping the share of the other token first, so that
+        // share, (1 - weight) of the amount, pays the swap fee
+        let fee_numerator = (WEIGHT_DENOMINATOR - weight as i128) * get_fee(&e) as i128;
+        let fee = checked_mul_div_ceil(&e, amount, fee_numerator, WEIGHT_DENOMINATOR * FEE_DENOMINATOR).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+        let reserve_after_fee = reserve.checked_add(amount - fee).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+
+        let base = checked_mul_div(&e, reserve_after_fee, ONE, reserve).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+        let power = pow_down(base, weight as i128 * ONE / WEIGHT_DENOMINATOR).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+        // pow_down can go below ONE for tiny amounts, which mints nothing
+        let liquidity = checked_mul_div(&e, total_supply, (power - ONE).max(0), ONE).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+
+        if liquidity <= 0 {
+            return Err(SoroswapWeightedPairError::DepositInsufficientLiquidityMinted);
+        }
+
+        internal_mint(e.clone(), to.clone(), liquidity.clone());
+        update_reserves(&e, PAIR_NAME, balance_0, balance_1);
+
+        event::deposit(&e, PAIR_NAME, to, amount_0, amount_1, liquidity, balance_0, balance_1);
+
+        Ok(liquidity)
+    }
+
+    /// Executes a token swap within the weighted pair.
+    ///
+    /// The balances after the swap, minus the swap fee on the amounts in, must keep
+    /// `balance_0^weight_0 · balance_1^weight_1` at or above its value for the reserves before the swap.
+    /// Amounts in and out are limited to 30% of the reserves.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `amount_0_out` - The desired amount of the first token to receive.
+    /// * `amount_1_out` - The desired amount of the second token to receive.
+    /// * `to` - The address where the swapped tokens will be sent.
+    ///
+    /// # Errors
+    /// - `NotInitialized`
+    /// - `Paused`
+    /// - `SwapInsufficientOutputAmount`
+    /// - `SwapNegativesOutNotSupported`
+    /// - `SwapInsufficientLiquidity`
+    /// - `SwapMaxOutRatio`
+    /// - `SwapInvalidTo`
+    /// - `SwapInsufficientInputAmount`
+    /// - `SwapMaxInRatio`
+    /// - `SwapInvariantNotMet`
+    /// - `UpdateOverflow`
+    fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address) -> Result<(), SoroswapWeightedPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapWeightedPairError::NotInitialized);
+        }
+
+        if is_paused(&e) {
+            return Err(SoroswapWeightedPairError::Paused);
+        }
+
+        let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+
+        if amount_0_out == 0 && amount_1_out == 0 {
+            return Err(SoroswapWeightedPairError::SwapInsufficientOutputAmount);
+        }
+        if amount_0_out < 0 || amount_1_out < 0 {
+            return Err(SoroswapWeightedPairError::SwapNegativesOutNotSupported);
+        }
+        if amount_0_out >= reserve_0 || amount_1_out >= reserve_1 {
+            return Err(SoroswapWeightedPairError::SwapInsufficientLiquidity);
+        }
+        if exceeds_max_ratio(&e, amount_0_out, reserve_0) || exceeds_max_ratio(&e, amount_1_out, reserve_1) {
+            return Err(SoroswapWeightedPairError::SwapMaxOutRatio);
+        }
+        if to == get_token_0(&e) || to == get_token_1(&e) {
+            return Err(SoroswapWeightedPairError::SwapInvalidTo);
+        }
+
+        if amount_0_out > 0 {
+            transfer_token_0_from_pair(&e, &to, amount_0_out);
+        }
+        if amount_1_out > 0 {
+            transfer_token_1_from_pair(&e, &to, amount_1_out);
+        }
+
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+
+        // The reserves are larger than the amounts out, so these subtractions cannot go below zero
+        let amount_0_in = balance_0.checked_sub(reserve_0 - amount_0_out).ok_or(SoroswapWeightedPairError::UpdateOverflow)?.max(0);
+        let amount_1_in = balance_1.checked_sub(reserve_1 - amount_1_out).ok_or(SoroswapWeightedPairError::UpdateOverflow)?.max(0);
+
+        if amount_0_in == 0 && amount_1_in == 0 {
+            return Err(SoroswapWeightedPairError::SwapInsufficientInputAmount);
+        }
+        if exceeds_max_ratio(&e, amount_0_in, reserve_0) || exceeds_max_ratio(&e, amount_1_in, reserve_1) {
+            return Err(SoroswapWeightedPairError::SwapMaxInRatio);
+        }
+
+        let fee = get_fee(&e) as i128;
+        let fee_0 = checked_mul_div_ceil(&e, amount_0_in, fee, FEE_DENOMINATOR).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+        let fee_1 = checked_mul_div_ceil(&e, amount_1_in, fee, FEE_DENOMINATOR).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+
+        let balance_0_minus_fee = balance_0.checked_sub(fee_0).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+        let balance_1_minus_fee = balance_1.checked_sub(fee_1).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+
+        // Growth of each reserve, rounded down
+        let ratio_0 = checked_mul_div(&e, balance_0_minus_fee, ONE, reserve_0).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+        let ratio_1 = checked_mul_div(&e, balance_1_minus_fee, ONE, reserve_1).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+
+        let weight_0 = get_weight_0(&e);
+        if !is_above_invariant(ratio_0, ratio_1, weight_0, WEIGHT_DENOMINATOR as u32 - weight_0) {
+            return Err(SoroswapWeightedPairError::SwapInvariantNotMet);
+        }
+
+        update_reserves(&e, PAIR_NAME, balance_0, balance_1);
+
+        event::swap(&e, PAIR_NAME, to, amount_0_in, amount_1_in, amount_0_out, amount_1_out);
+
+        Ok(())
+    }
+
+    /// Withdraws liquidity from the weighted pair, burning LP tokens and returning the corresponding tokens to the user.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `to` - The address where the withdrawn tokens will be sent.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of token 0 and token 1 withdrawn from the pair.
+    fn withdraw(e: Env, to: Address) -> Result<(i128, i128), SoroswapWeightedPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapWeightedPairError::NotInitialized);
+        }
+
+        // The shares sent by the user to burn their LP position
+        let balance_shares = SoroswapPairToken::balance(e.clone(), e.current_contract_address());
+        if balance_shares == 0 {
+            return Err(SoroswapWeightedPairError::WithdrawLiquidityNotInitialized);
+        }
+
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        let user_sent_shares = balance_shares.checked_sub(MINIMUM_LIQUIDITY).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+
+        if user_sent_shares <= 0 {
+            return Err(SoroswapWeightedPairError::WithdrawInsufficientSentShares);
+        }
+
+        let total_supply = SoroswapPairToken::total_supply(e.clone());
+
+        let amount_0 = checked_mul_div(&e, balance_0, user_sent_shares, total_supply).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+        let amount_1 = checked_mul_div(&e, balance_1, user_sent_shares, total_supply).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+
+        if amount_0 <= 0 || amount_1 <= 0 {
+            return Err(SoroswapWeightedPairError::WithdrawInsufficientLiquidityBurned);
+        }
+
+        internal_burn(e.clone(), e.current_contract_address(), user_sent_shares);
+
+        transfer_token_0_from_pair(&e, &to, amount_0);
+        transfer_token_1_from_pair(&e, &to, amount_1);
+
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        update_reserves(&e, PAIR_NAME, balance_0, balance_1);
+
+        event::withdraw(&e, PAIR_NAME, to, user_sent_shares, amount_0, amount_1, balance_0, balance_1);
+        Ok((amount_0, amount_1))
+    }
+
+    /// Skims excess tokens from reserves and sends them to the specified address.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `to` - The address where the excess tokens will be sent.
+    fn skim(e: Env, to: Address) -> Result<(), SoroswapWeightedPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapWeightedPairError::NotInitialized);
+        }
+
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        let skimmed_0 = balance_0.checked_sub(reserve_0).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+        let skimmed_1 = balance_1.checked_sub(reserve_1).ok_or(SoroswapWeightedPairError::UpdateOverflow)?;
+        transfer_token_0_from_pair(&e, &to, skimmed_0);
+        transfer_token_1_from_pair(&e, &to, skimmed_1);
+        event::skim(&e, PAIR_NAME, skimmed_0, skimmed_1);
+        Ok(())
+    }
+
+    /// Forces reserves to match current balances.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    fn sync(e: Env) -> Result<(), SoroswapWeightedPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapWeightedPairError::NotInitialized);
+        }
+
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        update_reserves(&e, PAIR_NAME, balance_0, balance_1);
+        Ok(())
+    }
+
+    /// Returns the weights of token 0 and token 1 of the weighted pair, in basis points.
+    fn weights(e: Env) -> (u32, u32) {
+        extend_instance_ttl(&e);
+        let weight_0 = get_weight_0(&e);
+        (weight_0, WEIGHT_DENOMINATOR as u32 - weight_0)
+    }
+
+    /// Returns the swap fee of the weighted pair, in basis points.
+    fn fee(e: Env) -> u32 {
+        extend_instance_ttl(&e);
+        get_fee(&e)
+    }
+
+    /// Returns the current reserves and the last block timestamp, like `SoroswapPair::get_reserves`.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    ///
+    /// # Returns
+    /// A tuple containing the reserves of token 0 and token 1, and the ledger timestamp of the last reserves update.
+    fn get_reserves(e: Env) -> (i128, i128, u64) {
+        extend_instance_ttl(&e);
+
+        (get_reserve_0(&e), get_reserve_1(&e), get_block_timestamp_last(&e))
+    }
+
+    /// Upgrades the code of the weighted pair, keeping its stored data. Can only be called by the factory.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `new_wasm_hash` - The hash of the new wasm code, already uploaded to the network.
+    ///
+    /// # Errors
+    /// - `NotInitialized`: The weighted pair has not been initialized.
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), SoroswapWeightedPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapWeightedPairError::NotInitialized);
+        }
+
+        upgrade(&e, PAIR_NAME, new_wasm_hash);
+        Ok(())
+    }
+
+    /// Returns true if deposits and swaps are currently blocked, either because this pair was paused
+    /// or because the factory paused all the pairs.
+    fn paused(e: Env) -> bool {
+        extend_instance_ttl(&e);
+        has_token_0(&e) && is_paused(&e)
+    }
+
+    /// Pauses or unpauses this pair. Can only be called by the factory, on behalf of its guardian.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `is_paused` - Whether deposits and swaps should be blocked.
+    ///
+    /// # Errors
+    /// - `NotInitialized`: The weighted pair has not been initialized.
+    fn set_paused(e: Env, is_paused: bool) -> Result<(), SoroswapWeightedPairError> {
+        extend_instance_ttl(&e);
+
+        if !has_token_0(&e) {
+            return Err(SoroswapWeightedPairError::NotInitialized);
+        }
+
+        set_paused(&e, PAIR_NAME, is_paused);
+        Ok(())
+    }
+}
+
+// Invariant of the first deposit, amount_0^weight_0 · amount_1^weight_1. It is computed as
+// min · (max / min)^weight_max, which cannot overflow and is exactly the amount for balanced deposits
+fn first_invariant(e: &Env, amount_0: i128, amount_1: i128, weight_0: u32) -> Option<i128> {
+    let (amount_min, amount_max, weight_max) = if amount_0 <= amount_1 {
+        (amount_0, amount_1, WEIGHT_DENOMINATOR - weight_0 as i128)
+    } else {
+        (amount_1, amount_0, weight_0 as i128)
+    };
+    let ratio = checked_mul_div(e, amount_max, ONE, amount_min)?;
+    let power = pow_down(ratio, weight_max * ONE / WEIGHT_DENOMINATOR)?;
+    checked_mul_div(e, amount_min, power, ONE)
+}
@@ -0,0 +1,48 @@
+use soroban_sdk::{Env, I256};
+use crate::fixed_point::ln;
+
+// Weights are expressed in basis points
+pub const WEIGHT_DENOMINATOR: i128 = 10_000;
+// Swaps and single sided deposits can take in or out at most 30% of a reserve, like in Balancer.
+// Beyond that, the error of the fixed point math would be a larger part of the trade
+pub const MAX_RATIO: i128 = 3_000;
+
+// The product of two i128 values always fits in an I256, so none of these helpers can overflow
+// before the final conversion back to i128
+pub fn mul_i256(e: &Env, a: i128, b: i128) -> I256 {
+    I256::from_i128(e, a).mul(&I256::from_i128(e, b))
+}
+
+// a * b / c rounded down, or None if c is zero or the result does not fit in an i128
+pub fn checked_mul_div(e: &Env, a: i128, b: i128, c: i128) -> Option<i128> {
+    if c == 0 {
+        return None;
+    }
+    mul_i256(e, a, b).div(&I256::from_i128(e, c)).to_i128()
+}
+
+// a * b / c rounded up, for non-negative a and b and a positive c
+pub fn checked_mul_div_ceil(e: &Env, a: i128, b: i128, c: i128) -> Option<i128> {
+    if c <= 0 {
+        return None;
+    }
+    let c = I256::from_i128(e, c);
+    let one = I256::from_i32(e, 1);
+    mul_i256(e, a, b).add(&c).sub(&one).div(&c).to_i128()
+}
+
+// Returns true if amount is more than MAX_RATIO of the reserve
+pub fn exceeds_max_ratio(e: &Env, amount: i128, reserve: i128) -> bool {
+    mul_i256(e, amount, WEIGHT_DENOMINATOR) > mul_i256(e, reserve, MAX_RATIO)
+}
+
+// Returns true if the weighted invariant does not decrease when the reserves grow by the ratios q_0 and q_1,
+// that is, if q_0^weight_0 · q_1^weight_1 >= 1. It compares weight_0·ln(q_0) + weight_1·ln(q_1) with zero
+// instead, which only needs ln. The ratios are fixed point numbers rounded down, so the check never
+// accepts balances below the invariant by more than the rounding of ln
+pub fn is_above_invariant(q_0: i128, q_1: i128, weight_0: u32, weight_1: u32) -> bool {
+    match (ln(q_0), ln(q_1)) {
+        (Some(ln_0), Some(ln_1)) => ln_0 * weight_0 as i128 + ln_1 * weight_1 as i128 >= 0,
+        _ => false,
+    }
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::{contracttype, Env};
+
+// Keys of the data only the weighted pairs have. The tokens, factory, fee and reserves are stored by
+// soroswap-pair-core
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Weight0, // u32. Weight of token 0 in basis points, set by the factory at creation. Instance storage
+}
+
+pub fn get_weight_0(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::Weight0).unwrap()
+}
+
+pub fn put_weight_0(e: &Env, weight_0: u32) {
+    e.storage().instance().set(&DataKey::Weight0, &weight_0);
+}
@@ -0,0 +1,94 @@
+use crate::test::{SoroswapWeightedPairTest};
+use crate::test::weighted_pair::SoroswapWeightedPairError;
+
+pub fn add_liquidity(test: &SoroswapWeightedPairTest, amount_0: &i128, amount_1: &i128) -> i128 {
+    test.token_0.transfer(&test.user, &test.contract.address, amount_0);
+    test.token_1.transfer(&test.user, &test.contract.address, amount_1);
+    test.contract.deposit(&test.user)
+}
+
+#[test]
+fn deposit_not_yet_initialized() {
+    let test = SoroswapWeightedPairTest::setup();
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::NotInitialized)));
+}
+
+#[test]
+fn deposit_zero_amounts() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::DepositInsufficientAmountToken0)));
+
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000);
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::DepositInsufficientAmountToken1)));
+}
+
+#[test]
+fn deposit_insufficient_first_liquidity() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000);
+    test.token_1.transfer(&test.user, &test.contract.address, &1_000);
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::DepositInsufficientFirstLiquidity)));
+}
+
+#[test]
+fn deposit_balanced() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    let amount: i128 = 10_000_000_000;
+
+    // For equal amounts the invariant is the amount itself, whatever the weights
+    let liquidity = add_liquidity(&test, &amount, &amount);
+    assert_eq!(liquidity, amount - 1_000);
+    assert_eq!(test.contract.balance(&test.user), amount - 1_000);
+    assert_eq!(test.contract.total_supply(), amount);
+    assert_eq!(test.contract.get_reserves(), (amount, amount, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn deposit_weighted_first_liquidity() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+
+    // 80_000_000_000^0.8 · 20_000_000_000^0.2
+    let liquidity = add_liquidity(&test, &80_000_000_000, &20_000_000_000);
+    assert_eq!(liquidity, 60_628_662_660 - 1_000);
+
+    // Token 1 weighs 20%: 10_000_000_000^0.8 · 20_000_000_000^0.2
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    let liquidity = add_liquidity(&test, &10_000_000_000, &20_000_000_000);
+    assert_eq!(liquidity, 11_486_983_549 - 1_000);
+}
+
+#[test]
+fn deposit_proportional() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    let amount: i128 = 10_000_000_000;
+    add_liquidity(&test, &amount, &amount);
+
+    // Later deposits are minted on the smaller of both proportions
+    let liquidity = add_liquidity(&test, &(amount / 2), &amount);
+    assert_eq!(liquidity, amount / 2);
+    assert_eq!(test.contract.total_supply(), amount + amount / 2);
+    assert_eq!(test.contract.get_reserves(), (amount + amount / 2, 2 * amount, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn deposit_paused() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    test.factory.set_paused(&true);
+    assert_eq!(test.contract.paused(), true);
+
+    test.token_0.transfer(&test.user, &test.contract.address, &10_000_000);
+    test.token_1.transfer(&test.user, &test.contract.address, &10_000_000);
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::Paused)));
+}
@@ -0,0 +1,91 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapWeightedPairTest};
+use crate::test::weighted_pair::SoroswapWeightedPairError;
+
+const RESERVE_0: i128 = 80_000_000_000;
+const RESERVE_1: i128 = 20_000_000_000;
+// Invariant of the reserves, minted by the first deposit
+const TOTAL_SUPPLY: i128 = 60_628_662_660;
+
+#[test]
+fn deposit_single_not_yet_initialized() {
+    let test = SoroswapWeightedPairTest::setup();
+    let res = test.contract.try_deposit_single(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::NotInitialized)));
+}
+
+#[test]
+fn deposit_single_no_liquidity() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000_000);
+    let res = test.contract.try_deposit_single(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::DepositSingleNoLiquidity)));
+}
+
+#[test]
+fn deposit_single_invalid_amounts() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    add_liquidity(&test, &RESERVE_0, &RESERVE_1);
+
+    let res = test.contract.try_deposit_single(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::DepositSingleInvalidAmounts)));
+
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000_000);
+    test.token_1.transfer(&test.user, &test.contract.address, &1_000_000_000);
+    let res = test.contract.try_deposit_single(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::DepositSingleInvalidAmounts)));
+}
+
+#[test]
+fn deposit_single_max_in_ratio() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    add_liquidity(&test, &RESERVE_0, &RESERVE_1);
+
+    test.token_1.transfer(&test.user, &test.contract.address, &(RESERVE_1 * 3 / 10 + 1));
+    let res = test.contract.try_deposit_single(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::DepositSingleMaxInRatio)));
+}
+
+#[test]
+fn deposit_single_token_0() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    add_liquidity(&test, &RESERVE_0, &RESERVE_1);
+
+    // total_supply · ((reserve_0 + amount) / reserve_0)^0.8 - total_supply, with 20% of the amount paying the fee
+    let amount: i128 = 1_000_000_000;
+    test.token_0.transfer(&test.user, &test.contract.address, &amount);
+    let liquidity = test.contract.deposit_single(&test.user);
+    assert_eq!(liquidity, 605_169_662);
+    assert_eq!(test.contract.total_supply(), TOTAL_SUPPLY + liquidity);
+    assert_eq!(test.contract.get_reserves(), (RESERVE_0 + amount, RESERVE_1, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn deposit_single_token_1() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    add_liquidity(&test, &RESERVE_0, &RESERVE_1);
+
+    // Token 1 weighs 20%, so 80% of the amount pays the fee
+    let amount: i128 = 1_000_000_000;
+    test.token_1.transfer(&test.user, &test.contract.address, &amount);
+    let liquidity = test.contract.deposit_single(&test.user);
+    assert_eq!(liquidity, 593_112_948);
+    assert_eq!(test.contract.get_reserves(), (RESERVE_0, RESERVE_1 + amount, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn deposit_single_paused() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    add_liquidity(&test, &RESERVE_0, &RESERVE_1);
+    test.factory.set_paused(&true);
+
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000_000);
+    let res = test.contract.try_deposit_single(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::Paused)));
+}
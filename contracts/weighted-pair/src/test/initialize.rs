@@ -0,0 +1,51 @@
+use crate::test::{SoroswapWeightedPairTest, WEIGHT_0, FEE};
+use soroban_sdk::String;
+use crate::test::weighted_pair::SoroswapWeightedPairError;
+
+#[test]
+fn initialize_token_1_less_than_token_0() {
+    let test = SoroswapWeightedPairTest::setup();
+    let res = test.contract.try_initialize(&test.factory.address, &test.token_1.address, &test.token_0.address, &WEIGHT_0, &FEE);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::InitializeTokenOrderInvalid)));
+}
+
+#[test]
+fn double_initialize() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    let res = test.contract.try_initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &WEIGHT_0, &FEE);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::InitializeAlreadyInitialized)));
+}
+
+#[test]
+fn initialize_invalid_weights() {
+    let test = SoroswapWeightedPairTest::setup();
+    let res = test.contract.try_initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &99, &FEE);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::InitializeInvalidWeights)));
+    let res = test.contract.try_initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &9_901, &FEE);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::InitializeInvalidWeights)));
+}
+
+#[test]
+fn initialize_invalid_fee() {
+    let test = SoroswapWeightedPairTest::setup();
+    let res = test.contract.try_initialize(&test.factory.address, &test.token_0.address, &test.token_1.address, &WEIGHT_0, &1_001);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::InitializeInvalidFee)));
+}
+
+#[test]
+fn initialize_initial_values() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    assert_eq!(test.factory.guardian(), test.admin);
+    assert_eq!(test.contract.token_0(), test.token_0.address);
+    assert_eq!(test.contract.token_1(), test.token_1.address);
+    assert_eq!(test.contract.factory(), test.factory.address);
+    assert_eq!(test.contract.weights(), (WEIGHT_0, 10_000 - WEIGHT_0));
+    assert_eq!(test.contract.fee(), FEE);
+    assert_eq!(test.contract.get_reserves(), (0, 0, 0));
+    assert_eq!(test.contract.paused(), false);
+    assert_eq!(test.contract.symbol(), String::from_str(&test.env, "TOK0-ABCDEF-SOROSWAP-WLP"));
+    assert_eq!(test.contract.name(), String::from_str(&test.env, "TOK0-ABCDEF Soroswap Weighted LP"));
+    assert_eq!(test.contract.decimals(), 7);
+}
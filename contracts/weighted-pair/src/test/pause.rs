@@ -0,0 +1,37 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapWeightedPairTest};
+use crate::test::weighted_pair::SoroswapWeightedPairError;
+
+const RESERVE_0: i128 = 80_000_000_000;
+const RESERVE_1: i128 = 20_000_000_000;
+
+#[test]
+fn set_paused_not_initialized() {
+    let test = SoroswapWeightedPairTest::setup();
+    let res = test.contract.try_set_paused(&true);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::NotInitialized)));
+}
+
+#[test]
+fn set_paused_blocks_only_this_pair() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    add_liquidity(&test, &RESERVE_0, &RESERVE_1);
+
+    test.contract.set_paused(&true);
+    assert_eq!(test.contract.paused(), true);
+    assert_eq!(test.factory.paused(), false);
+
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000);
+    let res = test.contract.try_swap(&0, &1_000, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::Paused)));
+
+    test.token_1.transfer(&test.user, &test.contract.address, &1_000_000);
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::Paused)));
+
+    // Once unpaused everything works again
+    test.contract.set_paused(&false);
+    assert_eq!(test.contract.paused(), false);
+    test.contract.swap(&0, &1_000, &test.user);
+}
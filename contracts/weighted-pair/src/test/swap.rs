@@ -0,0 +1,97 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapWeightedPairTest};
+use crate::test::weighted_pair::SoroswapWeightedPairError;
+
+const RESERVE_0: i128 = 80_000_000_000;
+const RESERVE_1: i128 = 20_000_000_000;
+
+#[test]
+fn swap_not_yet_initialized() {
+    let test = SoroswapWeightedPairTest::setup();
+    let res = test.contract.try_swap(&0, &0, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::NotInitialized)));
+}
+
+#[test]
+fn swap_invalid_amounts() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    let res = test.contract.try_swap(&0, &0, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::SwapInsufficientOutputAmount)));
+    let res = test.contract.try_swap(&-1, &1, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::SwapNegativesOutNotSupported)));
+    let res = test.contract.try_swap(&0, &1, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::SwapInsufficientLiquidity)));
+
+    add_liquidity(&test, &RESERVE_0, &RESERVE_1);
+    let res = test.contract.try_swap(&0, &1_000, &test.token_0.address);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::SwapInvalidTo)));
+    let res = test.contract.try_swap(&0, &1_000, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::SwapInsufficientInputAmount)));
+}
+
+#[test]
+fn swap_max_ratios() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    add_liquidity(&test, &RESERVE_0, &RESERVE_1);
+
+    // At most 30% of each reserve can go out or come in
+    let res = test.contract.try_swap(&0, &(RESERVE_1 * 3 / 10 + 1), &test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::SwapMaxOutRatio)));
+
+    test.token_0.transfer(&test.user, &test.contract.address, &(RESERVE_0 * 3 / 10 + 1));
+    let res = test.contract.try_swap(&0, &1_000, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::SwapMaxInRatio)));
+}
+
+#[test]
+fn swap_token_0_for_token_1() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    add_liquidity(&test, &RESERVE_0, &RESERVE_1);
+
+    // Token 0 weighs 4 times token 1, so 1 token 0 is worth 1 token 1 at these reserves.
+    // A 50/50 constant product pair with these reserves would only give 246_181_957
+    let amount_in: i128 = 1_000_000_000;
+    let amount_out: i128 = 966_694_903;
+    test.token_0.transfer(&test.user, &test.contract.address, &amount_in);
+
+    // One more unit would decrease the invariant
+    let res = test.contract.try_swap(&0, &(amount_out + 1), &test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::SwapInvariantNotMet)));
+
+    let balance_1_before = test.token_1.balance(&test.user);
+    test.contract.swap(&0, &amount_out, &test.user);
+    assert_eq!(test.token_1.balance(&test.user), balance_1_before + amount_out);
+    assert_eq!(test.contract.get_reserves(), (RESERVE_0 + amount_in, RESERVE_1 - amount_out, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn swap_token_1_for_token_0() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    add_liquidity(&test, &RESERVE_0, &RESERVE_1);
+
+    let amount_in: i128 = 1_000_000_000;
+    let amount_out: i128 = 967_053_449;
+    test.token_1.transfer(&test.user, &test.contract.address, &amount_in);
+
+    let res = test.contract.try_swap(&(amount_out + 1), &0, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::SwapInvariantNotMet)));
+
+    test.contract.swap(&amount_out, &0, &test.user);
+    assert_eq!(test.contract.get_reserves(), (RESERVE_0 - amount_out, RESERVE_1 + amount_in, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn swap_paused() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    add_liquidity(&test, &RESERVE_0, &RESERVE_1);
+    test.factory.set_paused(&true);
+
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000);
+    let res = test.contract.try_swap(&0, &1_000, &test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::Paused)));
+}
@@ -0,0 +1,61 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapWeightedPairTest, weighted_pair, FEE};
+use crate::test::weighted_pair::SoroswapWeightedPairError;
+use soroban_sdk::{
+    IntoVal,
+    testutils::{
+        MockAuth,
+        MockAuthInvoke,
+    },
+};
+
+const RESERVE_0: i128 = 80_000_000_000;
+const RESERVE_1: i128 = 20_000_000_000;
+
+#[test]
+fn upgrade_not_initialized() {
+    let test = SoroswapWeightedPairTest::setup();
+    let new_wasm_hash = test.env.deployer().upload_contract_wasm(weighted_pair::WASM);
+    let res = test.contract.try_upgrade(&new_wasm_hash);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::NotInitialized)));
+}
+
+#[test]
+fn upgrade_keeps_data() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    add_liquidity(&test, &RESERVE_0, &RESERVE_1);
+
+    let new_wasm_hash = test.env.deployer().upload_contract_wasm(weighted_pair::WASM);
+    test.contract.upgrade(&new_wasm_hash);
+
+    assert_eq!(test.contract.token_0(), test.token_0.address);
+    assert_eq!(test.contract.token_1(), test.token_1.address);
+    assert_eq!(test.contract.factory(), test.factory.address);
+    assert_eq!(test.contract.fee(), FEE);
+    assert_eq!(test.contract.get_reserves(), (RESERVE_0, RESERVE_1, test.env.ledger().timestamp()));
+}
+
+#[test]
+#[should_panic]
+fn upgrade_with_mock_auth_not_allowed() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    let new_wasm_hash = test.env.deployer().upload_contract_wasm(weighted_pair::WASM);
+
+    // Only the factory can upgrade the pair
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.admin.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "upgrade",
+                    args: (new_wasm_hash.clone(),).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .upgrade(&new_wasm_hash);
+}
@@ -0,0 +1,61 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapWeightedPairTest};
+use crate::test::weighted_pair::SoroswapWeightedPairError;
+
+#[test]
+fn withdraw_not_yet_initialized() {
+    let test = SoroswapWeightedPairTest::setup();
+    let res = test.contract.try_withdraw(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::NotInitialized)));
+}
+
+#[test]
+fn withdraw_without_liquidity() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    let res = test.contract.try_withdraw(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::WithdrawLiquidityNotInitialized)));
+}
+
+#[test]
+fn withdraw_insufficient_sent_shares() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    add_liquidity(&test, &10_000_000_000, &10_000_000_000);
+    // Only the minimum liquidity is in the pair
+    let res = test.contract.try_withdraw(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::WithdrawInsufficientSentShares)));
+}
+
+#[test]
+fn withdraw_proportional() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    let amount_0: i128 = 80_000_000_000;
+    let amount_1: i128 = 20_000_000_000;
+    let liquidity = add_liquidity(&test, &amount_0, &amount_1);
+    let total_supply = test.contract.total_supply();
+
+    let shares = liquidity / 2;
+    test.contract.transfer(&test.user, &test.contract.address, &shares);
+    let (withdrawn_0, withdrawn_1) = test.contract.withdraw(&test.user);
+
+    assert_eq!(withdrawn_0, amount_0 * shares / total_supply);
+    assert_eq!(withdrawn_1, amount_1 * shares / total_supply);
+    assert_eq!(test.contract.total_supply(), total_supply - shares);
+    assert_eq!(test.contract.get_reserves(), (amount_0 - withdrawn_0, amount_1 - withdrawn_1, test.env.ledger().timestamp()));
+}
+
+#[test]
+fn withdraw_while_paused() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize();
+    let liquidity = add_liquidity(&test, &10_000_000_000, &10_000_000_000);
+    test.factory.set_paused(&true);
+
+    // Withdraws are never paused
+    test.contract.transfer(&test.user, &test.contract.address, &liquidity);
+    let (withdrawn_0, withdrawn_1) = test.contract.withdraw(&test.user);
+    assert_eq!(withdrawn_0, 10_000_000_000 - 1_000);
+    assert_eq!(withdrawn_1, 10_000_000_000 - 1_000);
+}